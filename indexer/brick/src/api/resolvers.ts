@@ -2,7 +2,7 @@ import MainDomain from '../domain/main.js'
 import {
   AccountType,
   BrickEvent,
-  InstructionType,
+  EventType,
 } from '../utils/layouts/index.js'
 import {
   GlobalBrickStats,
@@ -19,7 +19,7 @@ export type AccountsFilters = {
 
 export type EventsFilters = {
   account: string
-  types?: InstructionType[]
+  types?: EventType[]
   signer?: string
   startDate?: number
   endDate?: number
@@ -40,11 +40,9 @@ export type UserTranasctionFilters = {
 export type GlobalStatsFilters = AccountsFilters
 
 export class APIResolvers {
-  protected registerBuyInstructions = [
-    InstructionType.RegisterBuy, 
-    InstructionType.RegisterBuyCnft, 
-    InstructionType.RegisterBuyCounter, 
-    InstructionType.RegisterBuyToken
+  protected purchaseEvents = [
+    EventType.ProductPurchased, 
+    EventType.EscrowCreated
   ]
   constructor(protected domain: MainDomain) {}
 
@@ -119,7 +117,7 @@ export class APIResolvers {
     for (const product of products) {
       const productEvents = await this.getEvents({
         account: product.address,
-        types: this.registerBuyInstructions,
+        types: this.purchaseEvents,
         startDate,
         endDate,
         skip,
@@ -131,7 +129,7 @@ export class APIResolvers {
 
     const purchases = await this.getEvents({ 
       account: user,
-      types: this.registerBuyInstructions,
+      types: this.purchaseEvents,
       startDate,
       endDate,
       skip,
@@ -9,7 +9,7 @@ import {
   GraphQLUnionType,
 } from 'graphql'
 import { GraphQLBigNumber, GraphQLLong, GraphQLJSON } from '@aleph-indexer/core'
-import { InstructionType } from '../utils/layouts/index.js'
+import { EventType } from '../utils/layouts/index.js'
 
 // ------------------- TYPES ---------------------------
 
@@ -21,83 +21,6 @@ export const PaymentFeePayer = new GraphQLEnumType({
   },
 })
 
-export const EditMarketplaceParams = new GraphQLObjectType({
-  name: 'EditMarketplaceParams',
-  fields: {
-    fee: { type: new GraphQLNonNull(GraphQLInt) },
-    feeReduction: { type: new GraphQLNonNull(GraphQLInt) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLInt) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLInt) },
-    useCnfts: { type: new GraphQLNonNull(GraphQLBoolean) },
-    deliverToken: { type: new GraphQLNonNull(GraphQLBoolean) },
-    transferable: { type: new GraphQLNonNull(GraphQLBoolean) },
-    chainCounter: { type: new GraphQLNonNull(GraphQLBoolean) },
-    permissionless: { type: new GraphQLNonNull(GraphQLBoolean) },
-    rewardsEnabled: { type: new GraphQLNonNull(GraphQLBoolean) },
-    feePayer: { type: new GraphQLNonNull(PaymentFeePayer) },
-  },
-})
-
-export const InitMarketplaceParams = new GraphQLObjectType({
-  name: 'InitMarketplaceParams',
-  fields: {
-    fee: { type: new GraphQLNonNull(GraphQLInt) },
-    feeReduction: { type: new GraphQLNonNull(GraphQLInt) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLInt) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLInt) },
-    useCnfts: { type: new GraphQLNonNull(GraphQLBoolean) },
-    deliverToken: { type: new GraphQLNonNull(GraphQLBoolean) },
-    transferable: { type: new GraphQLNonNull(GraphQLBoolean) },
-    chainCounter: { type: new GraphQLNonNull(GraphQLBoolean) },
-    permissionless: { type: new GraphQLNonNull(GraphQLBoolean) },
-    rewardsEnabled: { type: new GraphQLNonNull(GraphQLBoolean) },
-    accessMintBump: { type: new GraphQLNonNull(GraphQLInt) },
-    feePayer: { type: new GraphQLNonNull(PaymentFeePayer) },
-  },
-})
-
-export const InitProductTreeParams = new GraphQLObjectType({
-  name: 'InitProductTreeParams',
-  fields: {
-    firstId: { type: new GraphQLNonNull(GraphQLList(GraphQLString)) },
-    secondId: { type: new GraphQLNonNull(GraphQLList(GraphQLString)) },
-    productPrice: { type: new GraphQLNonNull(GraphQLBigNumber) },
-    maxDepth: { type: new GraphQLNonNull(GraphQLInt) },
-    maxBufferSize: { type: new GraphQLNonNull(GraphQLInt) },
-    name: { type: new GraphQLNonNull(GraphQLString) },
-    metadataUrl: { type: new GraphQLNonNull(GraphQLString) },
-    feeBasisPoints: { type: new GraphQLNonNull(GraphQLInt) },
-  },
-})
-
-export const InitProductParams = new GraphQLObjectType({
-  name: 'InitProductParams',
-  fields: {
-    firstId: { type: new GraphQLNonNull(GraphQLList(GraphQLString)) },
-    secondId: { type: new GraphQLNonNull(GraphQLList(GraphQLString)) },
-    productPrice: { type: new GraphQLNonNull(GraphQLBigNumber) },
-    productMintBump: { type: new GraphQLNonNull(GraphQLInt) },
-  },
-})
-
-export const RegisterBuyCnftParams = new GraphQLObjectType({
-  name: 'RegisterBuyCnftParams',
-  fields: {
-    amount: { type: new GraphQLNonNull(GraphQLInt) },
-    name: { type: new GraphQLNonNull(GraphQLString) },
-    symbol: { type: new GraphQLNonNull(GraphQLString) },
-    uri: { type: new GraphQLNonNull(GraphQLString) },
-  },
-})
-
-export const UpdateProductTreeParams = new GraphQLObjectType({
-  name: 'UpdateProductTreeParams',
-  fields: {
-    maxDepth: { type: new GraphQLNonNull(GraphQLInt) },
-    maxBufferSize: { type: new GraphQLNonNull(GraphQLInt) },
-  },
-})
-
 export const TokenConfig = new GraphQLObjectType({
   name: 'TokenConfig',
   fields: {
@@ -130,10 +53,6 @@ export const RewardsConfig = new GraphQLObjectType({
   name: 'RewardsConfig',
   fields: {
     rewardMint: { type: new GraphQLNonNull(GraphQLString) },
-    bountyVaults: { type: new GraphQLNonNull(GraphQLList(GraphQLString)) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLInt) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLInt) },
-    rewardsEnabled: { type: new GraphQLNonNull(GraphQLBoolean) },
   },
 })
 
@@ -141,7 +60,6 @@ export const MarketplaceBumps = new GraphQLObjectType({
   name: 'MarketplaceBumps',
   fields: {
     bump: { type: new GraphQLNonNull(GraphQLInt) },
-    vaultBumps: { type: new GraphQLNonNull(GraphQLList(GraphQLInt)) },
     accessMintBump: { type: new GraphQLNonNull(GraphQLInt) },
   },
 })
@@ -166,7 +84,17 @@ export const RewardBumps = new GraphQLObjectType({
   name: 'RewardBumps',
   fields: {
     bump: { type: new GraphQLNonNull(GraphQLInt) },
-    vaultBumps: { type: new GraphQLNonNull(GraphQLList(GraphQLInt)) },
+  },
+})
+
+export const Ruling = new GraphQLObjectType({
+  name: 'Ruling',
+  fields: {
+    arbiter: { type: new GraphQLNonNull(GraphQLString) },
+    buyerAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    sellerAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    fee: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    settledTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
@@ -230,18 +158,30 @@ export const AccountsEnum = new GraphQLEnumType({
 export const Marketplace = new GraphQLObjectType({
   name: 'Marketplace',
   fields: {
+    version: { type: new GraphQLNonNull(GraphQLInt) },
     authority: { type: new GraphQLNonNull(GraphQLString) },
+    creator: { type: new GraphQLNonNull(GraphQLString) },
+    pendingAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    id: { type: new GraphQLNonNull(GraphQLBigNumber) },
     tokenConfig: { type: new GraphQLNonNull(TokenConfig) },
     permissionConfig: { type: new GraphQLNonNull(PermissionConfig) },
     feesConfig: { type: new GraphQLNonNull(FeesConfig) },
     rewardsConfig: { type: new GraphQLNonNull(RewardsConfig) },
     bumps: { type: new GraphQLNonNull(MarketplaceBumps) },
+    openProducts: { type: new GraphQLNonNull(GraphQLInt) },
+    pendingRewards: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    paused: { type: new GraphQLNonNull(GraphQLBoolean) },
+    campaignsEndTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    arbiter: { type: new GraphQLNonNull(GraphQLString) },
+    vestingPeriod: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    bountyVaults: { type: new GraphQLNonNull(GraphQLInt) },
   },
 })
 
 export const Product = new GraphQLObjectType({
   name: 'Product',
   fields: {
+    version: { type: new GraphQLNonNull(GraphQLInt) },
     authority: { type: new GraphQLNonNull(GraphQLString) },
     firstId: { type: new GraphQLNonNull(GraphQLList(GraphQLInt)) },
     secondId: { type: new GraphQLNonNull(GraphQLList(GraphQLInt)) },
@@ -250,22 +190,37 @@ export const Product = new GraphQLObjectType({
     merkleTree: { type: new GraphQLNonNull(GraphQLString) },
     sellerConfig: { type: new GraphQLNonNull(SellerConfig) },
     bumps: { type: new GraphQLNonNull(ProductBumps) },
+    maxSupply: { type: GraphQLBigNumber },
+    sold: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    maxUnitsPerWallet: { type: GraphQLInt },
+    saleStart: { type: GraphQLBigNumber },
+    saleEnd: { type: GraphQLBigNumber },
+    escrowTimeout: { type: GraphQLBigNumber },
+    subscriptionPeriod: { type: GraphQLBigNumber },
+    receipts: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    openEscrows: { type: new GraphQLNonNull(GraphQLInt) },
+    outstandingUnits: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    subscriptionsEndTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
 export const Reward = new GraphQLObjectType({
   name: 'Reward',
   fields: {
+    version: { type: new GraphQLNonNull(GraphQLInt) },
     authority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    rewardVaults: { type: new GraphQLNonNull(GraphQLList(GraphQLString)) },
     bumps: { type: new GraphQLNonNull(RewardBumps) },
+    lockedUntil: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    createdTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    pendingRewards: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
 export const Access = new GraphQLObjectType({
   name: 'Access',
   fields: {
+    version: { type: new GraphQLNonNull(GraphQLInt) },
     authority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     bump: { type: new GraphQLNonNull(GraphQLInt) },
@@ -275,8 +230,15 @@ export const Access = new GraphQLObjectType({
 export const Payment = new GraphQLObjectType({
   name: 'Payment',
   fields: {
-    units: { type: GraphQLInt },
+    version: { type: new GraphQLNonNull(GraphQLInt) },
+    units: { type: new GraphQLNonNull(GraphQLInt) },
     bump: { type: new GraphQLNonNull(GraphQLInt) },
+    expiresAt: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    paid: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    campaign: { type: new GraphQLNonNull(GraphQLString) },
+    sellerBonus: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    buyerBonus: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    subscriptionGranted: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
@@ -289,9 +251,8 @@ export const ParsedAccountsData = new GraphQLUnionType({
         return 'Marketplace';
       case 'sellerConfig' in obj:
         return 'Product';
-      case 'rewardVaults' in obj:
+      case 'lockedUntil' in obj:
         return 'Reward';
-      case 'marketplace' in obj:
       case 'units' in obj:
         return 'Payment';
       default:
@@ -330,23 +291,35 @@ export const AccountsInfo = new GraphQLList(BrickAccountsInfo)
 export const BrickEvent = new GraphQLEnumType({
   name: 'BrickEvent',
   values: {
-    AcceptAccess: { value: 'AcceptAccess' },
-    AirdropAccess: { value: 'AirdropAccess' },
-    EditProduct: { value: 'EditProduct' },
-    EditMarketplace: { value: 'EditMarketplace' },
-    InitBounty: { value: 'InitBounty' },
-    InitMarketplace: { value: 'InitMarketplace' },
-    InitProductTree: { value: 'InitProductTree' },
-    InitProduct: { value: 'InitProduct' },
-    InitRewardVault: { value: 'InitRewardVault' },
-    InitReward: { value: 'InitReward' },
-    RegisterBuyCnft: { value: 'RegisterBuyCnft' },
-    RegisterBuyCounter: { value: 'RegisterBuyCounter' },
-    RegisterBuyToken: { value: 'RegisterBuyToken' },
-    RegisterBuy: { value: 'RegisterBuy' },
-    RequestAccess: { value: 'RequestAccess' },
-    UpdateTree: { value: 'UpdateTree' },
-    WithdrawReward: { value: 'WithdrawReward' },
+    MarketplaceInitialized: { value: 'MarketplaceInitialized' },
+    MarketplaceEdited: { value: 'MarketplaceEdited' },
+    MarketplaceAuthorityProposed: { value: 'MarketplaceAuthorityProposed' },
+    MarketplaceAuthorityAccepted: { value: 'MarketplaceAuthorityAccepted' },
+    MarketplacePauseSet: { value: 'MarketplacePauseSet' },
+    MarketplaceClosed: { value: 'MarketplaceClosed' },
+    BountyVaultInitialized: { value: 'BountyVaultInitialized' },
+    BountyWithdrawn: { value: 'BountyWithdrawn' },
+    BountyVaultClosed: { value: 'BountyVaultClosed' },
+    AccessRequested: { value: 'AccessRequested' },
+    AccessGranted: { value: 'AccessGranted' },
+    ProductInitialized: { value: 'ProductInitialized' },
+    ProductEdited: { value: 'ProductEdited' },
+    ProductClosed: { value: 'ProductClosed' },
+    TreeUpdated: { value: 'TreeUpdated' },
+    ProductPurchased: { value: 'ProductPurchased' },
+    EscrowCreated: { value: 'EscrowCreated' },
+    EscrowReleased: { value: 'EscrowReleased' },
+    PurchaseRefunded: { value: 'PurchaseRefunded' },
+    ProductRedeemed: { value: 'ProductRedeemed' },
+    DisputeOpened: { value: 'DisputeOpened' },
+    DisputeSettled: { value: 'DisputeSettled' },
+    CampaignInitialized: { value: 'CampaignInitialized' },
+    CampaignRewardInitialized: { value: 'CampaignRewardInitialized' },
+    RewardClosed: { value: 'RewardClosed' },
+    RewardInitialized: { value: 'RewardInitialized' },
+    RewardVaultInitialized: { value: 'RewardVaultInitialized' },
+    RewardWithdrawn: { value: 'RewardWithdrawn' },
+    AccountMigrated: { value: 'AccountMigrated' },
   },
 })
 
@@ -367,512 +340,716 @@ const Event = new GraphQLInterfaceType({
 
 /*-----------------------* CUSTOM EVENTS TYPES *-----------------------*/
 
-export const AcceptAccessInfo = new GraphQLObjectType({
-  name: 'AcceptAccessInfo',
+export const MarketplaceInitializedInfo = new GraphQLObjectType({
+  name: 'MarketplaceInitializedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgram2022: { type: new GraphQLNonNull(GraphQLString) },
-    associatedTokenProgram: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    receiver: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    request: { type: new GraphQLNonNull(GraphQLString) },
-    accessMint: { type: new GraphQLNonNull(GraphQLString) },
-    accessVault: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+    id: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    tokenConfig: { type: new GraphQLNonNull(TokenConfig) },
+    permissionConfig: { type: new GraphQLNonNull(PermissionConfig) },
+    feesConfig: { type: new GraphQLNonNull(FeesConfig) },
+    rewardsConfig: { type: new GraphQLNonNull(RewardsConfig) },
+    arbiter: { type: new GraphQLNonNull(GraphQLString) },
+    vestingPeriod: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
-export const AcceptAccessEvent = new GraphQLObjectType({
-  name: 'AcceptAccessEvent',
+export const MarketplaceInitializedEvent = new GraphQLObjectType({
+  name: 'MarketplaceInitializedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.AcceptAccess,
+  isTypeOf: (item) => item.type === EventType.MarketplaceInitialized,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(AcceptAccessInfo) },
+    info: { type: new GraphQLNonNull(MarketplaceInitializedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const AirdropAccessInfo = new GraphQLObjectType({
-  name: 'AirdropAccessInfo',
+export const MarketplaceEditedInfo = new GraphQLObjectType({
+  name: 'MarketplaceEditedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgram2022: { type: new GraphQLNonNull(GraphQLString) },
-    associatedTokenProgram: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    receiver: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    accessMint: { type: new GraphQLNonNull(GraphQLString) },
-    accessVault: { type: new GraphQLNonNull(GraphQLString) },
+    tokenConfig: { type: new GraphQLNonNull(TokenConfig) },
+    permissionConfig: { type: new GraphQLNonNull(PermissionConfig) },
+    feesConfig: { type: new GraphQLNonNull(FeesConfig) },
+    rewardsConfig: { type: new GraphQLNonNull(RewardsConfig) },
+    arbiter: { type: new GraphQLNonNull(GraphQLString) },
+    vestingPeriod: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
-export const AirdropAccessEvent = new GraphQLObjectType({
-  name: 'AirdropAccessEvent',
+export const MarketplaceEditedEvent = new GraphQLObjectType({
+  name: 'MarketplaceEditedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.AirdropAccess,
+  isTypeOf: (item) => item.type === EventType.MarketplaceEdited,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(AirdropAccessInfo) },
+    info: { type: new GraphQLNonNull(MarketplaceEditedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const EditProductInfo = new GraphQLObjectType({
-  name: 'EditProductInfo',
+export const MarketplaceAuthorityProposedInfo = new GraphQLObjectType({
+  name: 'MarketplaceAuthorityProposedInfo',
   fields: {
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    product: { type: new GraphQLNonNull(GraphQLString) },
-    paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    productPrice: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+    pendingAuthority: { type: new GraphQLNonNull(GraphQLString) },
   },
 })
 
-export const EditProductEvent = new GraphQLObjectType({
-  name: 'EditProductEvent',
+export const MarketplaceAuthorityProposedEvent = new GraphQLObjectType({
+  name: 'MarketplaceAuthorityProposedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.EditProduct,
+  isTypeOf: (item) => item.type === EventType.MarketplaceAuthorityProposed,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(EditProductInfo) },
+    info: { type: new GraphQLNonNull(MarketplaceAuthorityProposedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const EditMarketplaceInfo = new GraphQLObjectType({
-  name: 'EditMarketplaceInfo',
+export const MarketplaceAuthorityAcceptedInfo = new GraphQLObjectType({
+  name: 'MarketplaceAuthorityAcceptedInfo',
   fields: {
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    previousAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+  },
+})
+
+export const MarketplaceAuthorityAcceptedEvent = new GraphQLObjectType({
+  name: 'MarketplaceAuthorityAcceptedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.MarketplaceAuthorityAccepted,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(MarketplaceAuthorityAcceptedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const MarketplacePauseSetInfo = new GraphQLObjectType({
+  name: 'MarketplacePauseSetInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    paused: { type: new GraphQLNonNull(GraphQLBoolean) },
+  },
+})
+
+export const MarketplacePauseSetEvent = new GraphQLObjectType({
+  name: 'MarketplacePauseSetEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.MarketplacePauseSet,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(MarketplacePauseSetInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const MarketplaceClosedInfo = new GraphQLObjectType({
+  name: 'MarketplaceClosedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+  },
+})
+
+export const MarketplaceClosedEvent = new GraphQLObjectType({
+  name: 'MarketplaceClosedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.MarketplaceClosed,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(MarketplaceClosedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const BountyVaultInitializedInfo = new GraphQLObjectType({
+  name: 'BountyVaultInitializedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
     rewardMint: { type: new GraphQLNonNull(GraphQLString) },
-    discountMint: { type: new GraphQLNonNull(GraphQLString) },
-    params: { type: new GraphQLNonNull(EditMarketplaceParams) },
   },
 })
 
-export const EditMarketplaceEvent = new GraphQLObjectType({
-  name: 'EditMarketplaceEvent',
+export const BountyVaultInitializedEvent = new GraphQLObjectType({
+  name: 'BountyVaultInitializedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.EditMarketplace,
+  isTypeOf: (item) => item.type === EventType.BountyVaultInitialized,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(EditMarketplaceInfo) },
+    info: { type: new GraphQLNonNull(BountyVaultInitializedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const InitBountyInfo = new GraphQLObjectType({
-  name: 'InitBountyInfo',
+export const BountyWithdrawnInfo = new GraphQLObjectType({
+  name: 'BountyWithdrawnInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    associatedTokenProgram: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
     rewardMint: { type: new GraphQLNonNull(GraphQLString) },
+    receiverVault: { type: new GraphQLNonNull(GraphQLString) },
+    amount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+  },
+})
+
+export const BountyWithdrawnEvent = new GraphQLObjectType({
+  name: 'BountyWithdrawnEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.BountyWithdrawn,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(BountyWithdrawnInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const BountyVaultClosedInfo = new GraphQLObjectType({
+  name: 'BountyVaultClosedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
     bountyVault: { type: new GraphQLNonNull(GraphQLString) },
+    rewardMint: { type: new GraphQLNonNull(GraphQLString) },
+  },
+})
+
+export const BountyVaultClosedEvent = new GraphQLObjectType({
+  name: 'BountyVaultClosedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.BountyVaultClosed,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(BountyVaultClosedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const AccessRequestedInfo = new GraphQLObjectType({
+  name: 'AccessRequestedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    user: { type: new GraphQLNonNull(GraphQLString) },
   },
 })
 
-export const InitBountyEvent = new GraphQLObjectType({
-  name: 'InitBountyEvent',
+export const AccessRequestedEvent = new GraphQLObjectType({
+  name: 'AccessRequestedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.InitBounty,
+  isTypeOf: (item) => item.type === EventType.AccessRequested,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(InitBountyInfo) },
+    info: { type: new GraphQLNonNull(AccessRequestedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const InitMarketplaceInfo = new GraphQLObjectType({
-  name: 'InitMarketplaceInfo',
+export const AccessGrantedInfo = new GraphQLObjectType({
+  name: 'AccessGrantedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgram2022: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    user: { type: new GraphQLNonNull(GraphQLString) },
     accessMint: { type: new GraphQLNonNull(GraphQLString) },
-    rewardMint: { type: new GraphQLNonNull(GraphQLString) },
-    discountMint: { type: new GraphQLNonNull(GraphQLString) },
-    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
-    params: { type: new GraphQLNonNull(InitMarketplaceParams) },
   },
 })
 
-export const InitMarketplaceEvent = new GraphQLObjectType({
-  name: 'InitMarketplaceEvent',
+export const AccessGrantedEvent = new GraphQLObjectType({
+  name: 'AccessGrantedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.InitMarketplace,
+  isTypeOf: (item) => item.type === EventType.AccessGranted,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(InitMarketplaceInfo) },
+    info: { type: new GraphQLNonNull(AccessGrantedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const InitProductTreeInfo = new GraphQLObjectType({
-  name: 'InitProductTreeInfo',
-  fields: {
-    tokenMetadataProgram: { type: new GraphQLNonNull(GraphQLString) },
-    logWrapper: { type: new GraphQLNonNull(GraphQLString) },
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    bubblegumProgram: { type: new GraphQLNonNull(GraphQLString) },
-    compressionProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    associatedTokenProgram: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+export const ProductInitializedInfo = new GraphQLObjectType({
+  name: 'ProductInitializedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
     productMint: { type: new GraphQLNonNull(GraphQLString) },
-    paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    accessMint: { type: new GraphQLNonNull(GraphQLString) },
-    productMintVault: { type: new GraphQLNonNull(GraphQLString) },
-    accessVault: { type: new GraphQLNonNull(GraphQLString) },
-    masterEdition: { type: new GraphQLNonNull(GraphQLString) },
-    metadata: { type: new GraphQLNonNull(GraphQLString) },
     merkleTree: { type: new GraphQLNonNull(GraphQLString) },
-    treeAuthority: { type: new GraphQLNonNull(GraphQLString) },
-    params: { type: new GraphQLNonNull(InitProductTreeParams) },
+    firstId: { type: new GraphQLNonNull(GraphQLList(GraphQLInt)) },
+    secondId: { type: new GraphQLNonNull(GraphQLList(GraphQLInt)) },
+    paymentMint: { type: new GraphQLNonNull(GraphQLString) },
+    productPrice: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    maxSupply: { type: GraphQLBigNumber },
+    maxUnitsPerWallet: { type: GraphQLInt },
+    saleStart: { type: GraphQLBigNumber },
+    saleEnd: { type: GraphQLBigNumber },
+    escrowTimeout: { type: GraphQLBigNumber },
+    subscriptionPeriod: { type: GraphQLBigNumber },
   },
 })
 
-export const InitProductTreeEvent = new GraphQLObjectType({
-  name: 'InitProductTreeEvent',
+export const ProductInitializedEvent = new GraphQLObjectType({
+  name: 'ProductInitializedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.InitProductTree,
+  isTypeOf: (item) => item.type === EventType.ProductInitialized,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(InitProductTreeInfo) },
+    info: { type: new GraphQLNonNull(ProductInitializedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const InitProductInfo = new GraphQLObjectType({
-  name: 'InitProductInfo',
+export const ProductEditedInfo = new GraphQLObjectType({
+  name: 'ProductEditedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgram2022: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
-    productMint: { type: new GraphQLNonNull(GraphQLString) },
     paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    accessMint: { type: new GraphQLNonNull(GraphQLString) },
-    accessVault: { type: new GraphQLNonNull(GraphQLString) },
-    params: { type: new GraphQLNonNull(InitProductParams) },
+    productPrice: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    maxSupply: { type: GraphQLBigNumber },
+    maxUnitsPerWallet: { type: GraphQLInt },
+    saleStart: { type: GraphQLBigNumber },
+    saleEnd: { type: GraphQLBigNumber },
+    escrowTimeout: { type: GraphQLBigNumber },
+    subscriptionPeriod: { type: GraphQLBigNumber },
   },
 })
 
-export const InitProductEvent = new GraphQLObjectType({
-  name: 'InitProductEvent',
+export const ProductEditedEvent = new GraphQLObjectType({
+  name: 'ProductEditedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.InitProduct,
+  isTypeOf: (item) => item.type === EventType.ProductEdited,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(InitProductInfo) },
+    info: { type: new GraphQLNonNull(ProductEditedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const InitRewardVaultInfo = new GraphQLObjectType({
-  name: 'InitRewardVaultInfo',
+export const ProductClosedInfo = new GraphQLObjectType({
+  name: 'ProductClosedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    associatedTokenProgram: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    reward: { type: new GraphQLNonNull(GraphQLString) },
-    rewardMint: { type: new GraphQLNonNull(GraphQLString) },
-    rewardVault: { type: new GraphQLNonNull(GraphQLString) },
+    product: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
   },
 })
 
-export const InitRewardVaultEvent = new GraphQLObjectType({
-  name: 'InitRewardVaultEvent',
+export const ProductClosedEvent = new GraphQLObjectType({
+  name: 'ProductClosedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.InitRewardVault,
+  isTypeOf: (item) => item.type === EventType.ProductClosed,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(InitRewardVaultInfo) },
+    info: { type: new GraphQLNonNull(ProductClosedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const InitRewardInfo = new GraphQLObjectType({
-  name: 'InitRewardInfo',
+export const TreeUpdatedInfo = new GraphQLObjectType({
+  name: 'TreeUpdatedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    reward: { type: new GraphQLNonNull(GraphQLString) },
-    rewardMint: { type: new GraphQLNonNull(GraphQLString) },
-    rewardVault: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    product: { type: new GraphQLNonNull(GraphQLString) },
+    merkleTree: { type: new GraphQLNonNull(GraphQLString) },
+    maxDepth: { type: new GraphQLNonNull(GraphQLInt) },
+    maxBufferSize: { type: new GraphQLNonNull(GraphQLInt) },
   },
 })
 
-export const InitRewardEvent = new GraphQLObjectType({
-  name: 'InitRewardEvent',
+export const TreeUpdatedEvent = new GraphQLObjectType({
+  name: 'TreeUpdatedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.InitReward,
+  isTypeOf: (item) => item.type === EventType.TreeUpdated,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(InitRewardInfo) },
+    info: { type: new GraphQLNonNull(TreeUpdatedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const RegisterBuyCnftInfo = new GraphQLObjectType({
-  name: 'RegisterBuyCnftInfo',
-  fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    logWrapper: { type: new GraphQLNonNull(GraphQLString) },
-    bubblegumProgram: { type: new GraphQLNonNull(GraphQLString) },
-    compressionProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenMetadataProgram: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    seller: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceAuth: { type: new GraphQLNonNull(GraphQLString) },
+export const ProductPurchasedInfo = new GraphQLObjectType({
+  name: 'ProductPurchasedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
     paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    productMint: { type: new GraphQLNonNull(GraphQLString) },
-    buyerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLString) },
-    sellerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLString) },
-    buyerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    metadata: { type: new GraphQLNonNull(GraphQLString) },
-    masterEdition: { type: new GraphQLNonNull(GraphQLString) },
-    treeAuthority: { type: new GraphQLNonNull(GraphQLString) },
-    bubblegumSigner: { type: new GraphQLNonNull(GraphQLString) },
-    merkleTree: { type: new GraphQLNonNull(GraphQLString) },
-    params: { type: new GraphQLNonNull(RegisterBuyCnftParams) },
+    units: { type: new GraphQLNonNull(GraphQLInt) },
+    grossAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    fee: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    sellerAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    campaign: { type: new GraphQLNonNull(GraphQLString) },
+    sellerReward: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    buyerReward: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
-export const RegisterBuyCnftEvent = new GraphQLObjectType({
-  name: 'RegisterBuyCnftEvent',
+export const ProductPurchasedEvent = new GraphQLObjectType({
+  name: 'ProductPurchasedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.RegisterBuyCnft,
+  isTypeOf: (item) => item.type === EventType.ProductPurchased,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(RegisterBuyCnftInfo) },
+    info: { type: new GraphQLNonNull(ProductPurchasedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const RegisterBuyCounterInfo = new GraphQLObjectType({
-  name: 'RegisterBuyCounterInfo',
+export const EscrowCreatedInfo = new GraphQLObjectType({
+  name: 'EscrowCreatedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    seller: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceAuth: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
-    payment: { type: new GraphQLNonNull(GraphQLString) },
+    escrow: { type: new GraphQLNonNull(GraphQLString) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
     paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    buyerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLString) },
-    sellerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLString) },
-    buyerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    amount: { type: new GraphQLNonNull(GraphQLInt) },
+    units: { type: new GraphQLNonNull(GraphQLInt) },
+    grossAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    escrowedAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    releaseTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
-export const RegisterBuyCounterEvent = new GraphQLObjectType({
-  name: 'RegisterBuyCounterEvent',
+export const EscrowCreatedEvent = new GraphQLObjectType({
+  name: 'EscrowCreatedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.RegisterBuyCounter,
+  isTypeOf: (item) => item.type === EventType.EscrowCreated,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(RegisterBuyCounterInfo) },
+    info: { type: new GraphQLNonNull(EscrowCreatedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const RegisterBuyTokenInfo = new GraphQLObjectType({
-  name: 'RegisterBuyTokenInfo',
+export const EscrowReleasedInfo = new GraphQLObjectType({
+  name: 'EscrowReleasedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgram2022: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    product: { type: new GraphQLNonNull(GraphQLString) },
+    escrow: { type: new GraphQLNonNull(GraphQLString) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
     seller: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceAuth: { type: new GraphQLNonNull(GraphQLString) },
+    releasedBy: { type: new GraphQLNonNull(GraphQLString) },
+    fee: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    sellerAmount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    confirmed: { type: new GraphQLNonNull(GraphQLBoolean) },
+  },
+})
+
+export const EscrowReleasedEvent = new GraphQLObjectType({
+  name: 'EscrowReleasedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.EscrowReleased,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(EscrowReleasedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const PurchaseRefundedInfo = new GraphQLObjectType({
+  name: 'PurchaseRefundedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
-    productMint: { type: new GraphQLNonNull(GraphQLString) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
     paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    buyerTokenVault: { type: new GraphQLNonNull(GraphQLString) },
-    buyerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLString) },
-    sellerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLString) },
-    buyerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    amount: { type: new GraphQLNonNull(GraphQLInt) },
+    units: { type: new GraphQLNonNull(GraphQLInt) },
+    amount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    sellerClawback: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    buyerClawback: { type: new GraphQLNonNull(GraphQLBigNumber) },
   },
 })
 
-export const RegisterBuyTokenEvent = new GraphQLObjectType({
-  name: 'RegisterBuyTokenEvent',
+export const PurchaseRefundedEvent = new GraphQLObjectType({
+  name: 'PurchaseRefundedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.RegisterBuyToken,
+  isTypeOf: (item) => item.type === EventType.PurchaseRefunded,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(RegisterBuyTokenInfo) },
+    info: { type: new GraphQLNonNull(PurchaseRefundedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const RegisterBuyInfo = new GraphQLObjectType({
-  name: 'RegisterBuyInfo',
+export const ProductRedeemedInfo = new GraphQLObjectType({
+  name: 'ProductRedeemedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
-    seller: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceAuth: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
-    payment: { type: new GraphQLNonNull(GraphQLString) },
-    paymentMint: { type: new GraphQLNonNull(GraphQLString) },
-    buyerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    marketplaceTransferVault: { type: new GraphQLNonNull(GraphQLString) },
-    bountyVault: { type: new GraphQLNonNull(GraphQLString) },
-    sellerReward: { type: new GraphQLNonNull(GraphQLString) },
-    sellerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    buyerReward: { type: new GraphQLNonNull(GraphQLString) },
-    buyerRewardVault: { type: new GraphQLNonNull(GraphQLString) },
-    amount: { type: new GraphQLNonNull(GraphQLInt) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
+    units: { type: new GraphQLNonNull(GraphQLInt) },
   },
 })
 
-export const RegisterBuyEvent = new GraphQLObjectType({
-  name: 'RegisterBuyEvent',
+export const ProductRedeemedEvent = new GraphQLObjectType({
+  name: 'ProductRedeemedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.RegisterBuy,
+  isTypeOf: (item) => item.type === EventType.ProductRedeemed,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(RegisterBuyInfo) },
+    info: { type: new GraphQLNonNull(ProductRedeemedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const RequestAccessInfo = new GraphQLObjectType({
-  name: 'RequestAccessInfo',
+export const DisputeOpenedInfo = new GraphQLObjectType({
+  name: 'DisputeOpenedInfo',
   fields: {
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    rent: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    request: { type: new GraphQLNonNull(GraphQLString) },
+    product: { type: new GraphQLNonNull(GraphQLString) },
+    escrow: { type: new GraphQLNonNull(GraphQLString) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
   },
 })
 
-export const RequestAccessEvent = new GraphQLObjectType({
-  name: 'RequestAccessEvent',
+export const DisputeOpenedEvent = new GraphQLObjectType({
+  name: 'DisputeOpenedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.RequestAccess,
+  isTypeOf: (item) => item.type === EventType.DisputeOpened,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(RequestAccessInfo) },
+    info: { type: new GraphQLNonNull(DisputeOpenedInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const UpdateTreeInfo = new GraphQLObjectType({
-  name: 'UpdateTreeInfo',
+export const DisputeSettledInfo = new GraphQLObjectType({
+  name: 'DisputeSettledInfo',
   fields: {
-    payer: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
     product: { type: new GraphQLNonNull(GraphQLString) },
-    treeAuthority: { type: new GraphQLNonNull(GraphQLString) },
-    merkleTree: { type: new GraphQLNonNull(GraphQLString) },
-    logWrapper: { type: new GraphQLNonNull(GraphQLString) },
-    systemProgram: { type: new GraphQLNonNull(GraphQLString) },
-    bubblegumProgram: { type: new GraphQLNonNull(GraphQLString) },
-    compressionProgram: { type: new GraphQLNonNull(GraphQLString) },
-    params: { type: new GraphQLNonNull(UpdateProductTreeParams) },
+    escrow: { type: new GraphQLNonNull(GraphQLString) },
+    buyer: { type: new GraphQLNonNull(GraphQLString) },
+    seller: { type: new GraphQLNonNull(GraphQLString) },
+    ruling: { type: new GraphQLNonNull(Ruling) },
   },
 })
 
-export const UpdateTreeEvent = new GraphQLObjectType({
-  name: 'UpdateTreeEvent',
+export const DisputeSettledEvent = new GraphQLObjectType({
+  name: 'DisputeSettledEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.UpdateTree,
+  isTypeOf: (item) => item.type === EventType.DisputeSettled,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(UpdateTreeInfo) },
+    info: { type: new GraphQLNonNull(DisputeSettledInfo) },
   },
 })
 
 /*----------------------------------------------------------------------*/
 
-export const WithdrawRewardInfo = new GraphQLObjectType({
-  name: 'WithdrawRewardInfo',
+export const CampaignInitializedInfo = new GraphQLObjectType({
+  name: 'CampaignInitializedInfo',
   fields: {
-    tokenProgramV0: { type: new GraphQLNonNull(GraphQLString) },
-    signer: { type: new GraphQLNonNull(GraphQLString) },
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
     marketplace: { type: new GraphQLNonNull(GraphQLString) },
-    reward: { type: new GraphQLNonNull(GraphQLString) },
+    campaign: { type: new GraphQLNonNull(GraphQLString) },
+    id: { type: new GraphQLNonNull(GraphQLBigNumber) },
     rewardMint: { type: new GraphQLNonNull(GraphQLString) },
-    receiverVault: { type: new GraphQLNonNull(GraphQLString) },
+    paymentMint: { type: GraphQLString },
+    startTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    endTs: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    budget: { type: new GraphQLNonNull(GraphQLBigNumber) },
+    sellerReward: { type: new GraphQLNonNull(GraphQLInt) },
+    buyerReward: { type: new GraphQLNonNull(GraphQLInt) },
+    maxRewardPerPurchase: { type: GraphQLBigNumber },
+    maxRewardPerWallet: { type: GraphQLBigNumber },
+    vestingPeriod: { type: new GraphQLNonNull(GraphQLBigNumber) },
+  },
+})
+
+export const CampaignInitializedEvent = new GraphQLObjectType({
+  name: 'CampaignInitializedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.CampaignInitialized,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(CampaignInitializedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const CampaignRewardInitializedInfo = new GraphQLObjectType({
+  name: 'CampaignRewardInitializedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    campaign: { type: new GraphQLNonNull(GraphQLString) },
+    campaignReward: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+  },
+})
+
+export const CampaignRewardInitializedEvent = new GraphQLObjectType({
+  name: 'CampaignRewardInitializedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.CampaignRewardInitialized,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(CampaignRewardInitializedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const RewardClosedInfo = new GraphQLObjectType({
+  name: 'RewardClosedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    reward: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+    swept: { type: new GraphQLNonNull(GraphQLBigNumber) },
+  },
+})
+
+export const RewardClosedEvent = new GraphQLObjectType({
+  name: 'RewardClosedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.RewardClosed,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(RewardClosedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const RewardInitializedInfo = new GraphQLObjectType({
+  name: 'RewardInitializedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    reward: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+    rewardVault: { type: new GraphQLNonNull(GraphQLString) },
+  },
+})
+
+export const RewardInitializedEvent = new GraphQLObjectType({
+  name: 'RewardInitializedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.RewardInitialized,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(RewardInitializedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const RewardVaultInitializedInfo = new GraphQLObjectType({
+  name: 'RewardVaultInitializedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    reward: { type: new GraphQLNonNull(GraphQLString) },
     rewardVault: { type: new GraphQLNonNull(GraphQLString) },
+    rewardMint: { type: new GraphQLNonNull(GraphQLString) },
+  },
+})
+
+export const RewardVaultInitializedEvent = new GraphQLObjectType({
+  name: 'RewardVaultInitializedEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.RewardVaultInitialized,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(RewardVaultInitializedInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const RewardWithdrawnInfo = new GraphQLObjectType({
+  name: 'RewardWithdrawnInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    marketplace: { type: new GraphQLNonNull(GraphQLString) },
+    campaign: { type: new GraphQLNonNull(GraphQLString) },
+    reward: { type: new GraphQLNonNull(GraphQLString) },
+    authority: { type: new GraphQLNonNull(GraphQLString) },
+    rewardMint: { type: new GraphQLNonNull(GraphQLString) },
+    amount: { type: new GraphQLNonNull(GraphQLBigNumber) },
+  },
+})
+
+export const RewardWithdrawnEvent = new GraphQLObjectType({
+  name: 'RewardWithdrawnEvent',
+  interfaces: [Event],
+  isTypeOf: (item) => item.type === EventType.RewardWithdrawn,
+  fields: {
+    ...commonEventFields,
+    info: { type: new GraphQLNonNull(RewardWithdrawnInfo) },
+  },
+})
+
+/*----------------------------------------------------------------------*/
+
+export const AccountMigratedInfo = new GraphQLObjectType({
+  name: 'AccountMigratedInfo',
+  fields: {
+    eventAuthority: { type: new GraphQLNonNull(GraphQLString) },
+    account: { type: new GraphQLNonNull(GraphQLString) },
+    version: { type: new GraphQLNonNull(GraphQLInt) },
   },
 })
 
-export const WithdrawRewardEvent = new GraphQLObjectType({
-  name: 'WithdrawRewardEvent',
+export const AccountMigratedEvent = new GraphQLObjectType({
+  name: 'AccountMigratedEvent',
   interfaces: [Event],
-  isTypeOf: (item) => item.type === InstructionType.WithdrawReward,
+  isTypeOf: (item) => item.type === EventType.AccountMigrated,
   fields: {
     ...commonEventFields,
-    info: { type: new GraphQLNonNull(WithdrawRewardInfo) },
+    info: { type: new GraphQLNonNull(AccountMigratedInfo) },
   },
 })
 
@@ -881,21 +1058,33 @@ export const WithdrawRewardEvent = new GraphQLObjectType({
 export const Events = new GraphQLList(Event)
 
 export const types = [
-  AcceptAccessEvent,
-  AirdropAccessEvent,
-  EditProductEvent,
-  EditMarketplaceEvent,
-  InitBountyEvent,
-  InitMarketplaceEvent,
-  InitProductTreeEvent,
-  InitProductEvent,
-  InitRewardVaultEvent,
-  InitRewardEvent,
-  RegisterBuyCnftEvent,
-  RegisterBuyCounterEvent,
-  RegisterBuyTokenEvent,
-  RegisterBuyEvent,
-  RequestAccessEvent,
-  UpdateTreeEvent,
-  WithdrawRewardEvent,
+  MarketplaceInitializedEvent,
+  MarketplaceEditedEvent,
+  MarketplaceAuthorityProposedEvent,
+  MarketplaceAuthorityAcceptedEvent,
+  MarketplacePauseSetEvent,
+  MarketplaceClosedEvent,
+  BountyVaultInitializedEvent,
+  BountyWithdrawnEvent,
+  BountyVaultClosedEvent,
+  AccessRequestedEvent,
+  AccessGrantedEvent,
+  ProductInitializedEvent,
+  ProductEditedEvent,
+  ProductClosedEvent,
+  TreeUpdatedEvent,
+  ProductPurchasedEvent,
+  EscrowCreatedEvent,
+  EscrowReleasedEvent,
+  PurchaseRefundedEvent,
+  ProductRedeemedEvent,
+  DisputeOpenedEvent,
+  DisputeSettledEvent,
+  CampaignInitializedEvent,
+  CampaignRewardInitializedEvent,
+  RewardClosedEvent,
+  RewardInitializedEvent,
+  RewardVaultInitializedEvent,
+  RewardWithdrawnEvent,
+  AccountMigratedEvent,
 ]
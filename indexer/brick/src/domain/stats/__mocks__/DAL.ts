@@ -3,7 +3,7 @@ import {
   createStatsTimeSeriesDAL,
 } from '@aleph-indexer/framework'
 import { createEventDAL } from '../../../dal/event'
-import { InstructionType, BrickEvent } from '../../../utils/layouts'
+import { EventType, BrickEvent } from '../../../utils/layouts'
 import * as fs from 'fs'
 import { BRICK_PROGRAM_ID } from '../../../constants'
 import { DateTime, Interval } from 'luxon'
@@ -52,9 +52,9 @@ export function mockStatsTimeSeriesDAL(testName: string) {
   )
 }
 
-// get random InstructionType
-function getRandomInstructionType() {
-  const values = Object.values(InstructionType)
+// get random EventType
+function getRandomEventType() {
+  const values = Object.values(EventType)
   return values[Math.floor(Math.random() * values.length)]
 }
 
@@ -72,7 +72,7 @@ function generateEvent(interval?: Interval): BrickEvent {
     id: Math.random().toString(36).substring(2),
     account: 'test',
     timestamp,
-    type: getRandomInstructionType(),
+    type: getRandomEventType(),
     signer: 'CNCnPo5Fhfjj5Y7DSc82RDJfQoHEd2haAnTkAwRGfo8z',
     programId: BRICK_PROGRAM_ID,
     data: {} as any,
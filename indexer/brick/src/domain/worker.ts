@@ -18,7 +18,7 @@ import {
   RawMessageAccount,
 } from '@aleph-indexer/solana'
 import { createEventDAL } from '../dal/event.js'
-import { BrickEvent, InstructionType, Product, RawInstruction, RawInstructionsInfo, RegisterBuyCnftInfo } from '../utils/layouts/index.js'
+import { BrickEvent, EventType, Product, RawEvent, RawEventsInfo, ProductPurchasedInfo } from '../utils/layouts/index.js'
 import { BrickAccountStats, BrickAccountInfo, AlephPostContent } from '../types.js'
 import { AccountDomain } from './account.js'
 import { createAccountStats } from './stats/timeSeries.js'
@@ -40,8 +40,8 @@ export default class WorkerDomain
   protected accounts: Record<string, AccountDomain> = {}
   protected users: Record<string, UserDomain> = {}
   protected registerBuySet = new Set([
-    InstructionType.RegisterBuyCnft, 
-    InstructionType.RegisterBuyToken
+    EventType.ProductPurchased, 
+    EventType.EscrowCreated
   ])
   protected messagesSigner = ImportAccountFromPrivateKey(Uint8Array.from(JSON.parse(process.env.MESSAGES_KEY || '')))
 
@@ -135,7 +135,7 @@ export default class WorkerDomain
     const events: BrickEvent[] = []
     for (const ixCtx of ixCtxs) {
       const { instruction, parentInstruction, parentTransaction } = ixCtx
-      const parsed = (instruction as RawInstruction).parsed
+      const parsed = (instruction as RawEvent).parsed
 
       const id = `${parentTransaction.signature}${
         parentInstruction
@@ -159,9 +159,9 @@ export default class WorkerDomain
         signer,
         info,
       }
-      if (this.isBuyEvent(info)) {
+      if (this.isBuyEvent(type, info)) {
         await this.processBuyEvent(info)
-        events.push({...commonEventData, account: info.product} as BrickEvent)
+        events.push({...commonEventData, account: info.product.toString()} as BrickEvent)
       } else {
         events.push({...commonEventData, account: context.account} as BrickEvent)
       }
@@ -169,32 +169,34 @@ export default class WorkerDomain
     return events
   }
 
-  isBuyEvent(info: RawInstructionsInfo): info is RegisterBuyCnftInfo {
-    return (
-        'paymentMint' in info &&
-        'params' in info &&
-        'amount' in info.params
-    );
+  isBuyEvent(type: EventType, info: RawEventsInfo): info is ProductPurchasedInfo {
+    return type === EventType.ProductPurchased
   }
 
-  async processBuyEvent(info: RegisterBuyCnftInfo) {
+  async processBuyEvent(info: ProductPurchasedInfo) {
     console.log('TIMESTAMPPP', Date.now())
     await this.generateAlephMessage(info)
-    const buyerInstance = this.users[info.signer]
+    const buyer = info.buyer.toString()
+    const buyerInstance = this.users[buyer]
     if (!buyerInstance) {
-      this.onNewUser(info.signer)
-      console.log('NEW USER!', this.users[info.signer])
+      this.onNewUser(buyer)
+      console.log('NEW USER!', this.users[buyer])
     }
-    const sellerInstance = this.users[info.seller]
+    const seller = info.seller.toString()
+    const sellerInstance = this.users[seller]
     if (!sellerInstance) {
-      this.onNewUser(info.seller)
-      console.log('NEW USER!', this.users[info.seller])
+      this.onNewUser(seller)
+      console.log('NEW USER!', this.users[seller])
     }
   }
 
-  async generateAlephMessage(info: RegisterBuyCnftInfo) {
+  async generateAlephMessage(info: ProductPurchasedInfo) {
+    // the pubkeys of the event are base58 strings once the parsed instruction is serialized
+    const product = info.product.toString()
+    const buyer = info.buyer.toString()
+    const seller = info.seller.toString()
     try {
-      const productInfo: BrickAccountInfo = this.accounts[info.product].info;
+      const productInfo: BrickAccountInfo = this.accounts[product].info;
       const data = productInfo.data as Product
       const combinedArray = [...data.firstId, ...data.secondId];
       const byteArray = new Uint8Array(combinedArray);
@@ -205,7 +207,7 @@ export default class WorkerDomain
           page: 1,
           refs: [],
           addresses: [this.messagesSigner.address],
-          tags: [info.product, info.signer],
+          tags: [product, buyer],
           hashes: [],
           APIServer: "https://api2.aleph.im"
       });
@@ -215,12 +217,12 @@ export default class WorkerDomain
           postType: 'Permission',
           content: {
             datasetID,
-            authorizer: info.seller,
+            authorizer: seller,
             status: "GRANTED",
-            executionCount: info.params.amount,
+            executionCount: info.units,
             maxExecutionCount: -1,
-            requestor: info.signer,
-            tags: [info.product, info.signer],
+            requestor: buyer,
+            tags: [product, buyer],
           },
           channel: 'FISHNET_TEST_V1.8',
           APIServer: 'https://api2.aleph.im',
@@ -228,19 +230,19 @@ export default class WorkerDomain
           storageEngine: ItemType.inline
         })
       } else {
-        const executionCount = purchaseResponse.posts[0].content.executionCount + info.params.amount;
+        const executionCount = purchaseResponse.posts[0].content.executionCount + info.units;
         await publishPost({
           account: this.messagesSigner,
           postType: 'amend',
           ref: purchaseResponse.posts[0].hash,
           content: {
               datasetID,
-              authorizer: info.seller,
+              authorizer: seller,
               status: "GRANTED",
               executionCount,
               maxExecutionCount: -1,
-              requestor: info.signer,
-              tags: [info.product, info.signer],
+              requestor: buyer,
+              tags: [product, buyer],
           },
           channel: 'FISHNET_TEST_V1.1',
           APIServer: 'https://api2.aleph.im',
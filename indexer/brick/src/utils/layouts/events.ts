@@ -0,0 +1,1595 @@
+import * as web3 from '@solana/web3.js'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
+import { EventBase } from '@aleph-indexer/framework'
+import {
+  TokenConfig,
+  tokenConfigBeet,
+  PermissionConfig,
+  permissionConfigBeet,
+  FeesConfig,
+  feesConfigBeet,
+  RewardsConfig,
+  rewardsConfigBeet,
+  Ruling,
+  rulingBeet,
+} from './solita/index.js'
+
+export enum EventType {
+  MarketplaceInitialized = 'MarketplaceInitialized',
+  MarketplaceEdited = 'MarketplaceEdited',
+  MarketplaceAuthorityProposed = 'MarketplaceAuthorityProposed',
+  MarketplaceAuthorityAccepted = 'MarketplaceAuthorityAccepted',
+  MarketplacePauseSet = 'MarketplacePauseSet',
+  MarketplaceClosed = 'MarketplaceClosed',
+  BountyVaultInitialized = 'BountyVaultInitialized',
+  BountyWithdrawn = 'BountyWithdrawn',
+  BountyVaultClosed = 'BountyVaultClosed',
+  AccessRequested = 'AccessRequested',
+  AccessGranted = 'AccessGranted',
+  ProductInitialized = 'ProductInitialized',
+  ProductEdited = 'ProductEdited',
+  ProductClosed = 'ProductClosed',
+  TreeUpdated = 'TreeUpdated',
+  ProductPurchased = 'ProductPurchased',
+  EscrowCreated = 'EscrowCreated',
+  EscrowReleased = 'EscrowReleased',
+  PurchaseRefunded = 'PurchaseRefunded',
+  ProductRedeemed = 'ProductRedeemed',
+  DisputeOpened = 'DisputeOpened',
+  DisputeSettled = 'DisputeSettled',
+  CampaignInitialized = 'CampaignInitialized',
+  CampaignRewardInitialized = 'CampaignRewardInitialized',
+  RewardClosed = 'RewardClosed',
+  RewardInitialized = 'RewardInitialized',
+  RewardVaultInitialized = 'RewardVaultInitialized',
+  RewardWithdrawn = 'RewardWithdrawn',
+  AccountMigrated = 'AccountMigrated',
+}
+
+export type RawInstructionBase = {
+  parsed: unknown
+  program: string
+  programId: string
+}
+
+// emit_cpi! calls the program with the event as instruction data: this tag, the discriminator
+// of the event and the borsh serialized event. Its only account is the event authority.
+export const eventIxTag = [228, 69, 165, 46, 81, 203, 154, 29]
+
+export const EventAccounts = ['eventAuthority']
+
+export type EventAccountsInstruction = {
+  eventAuthority: string
+}
+
+type EventHeader = {
+  eventIxTag: number[] /* size: 8 */
+  eventDiscriminator: number[] /* size: 8 */
+}
+
+/*-----------------------* EVENT LAYOUTS *-----------------------*/
+
+export type MarketplaceInitialized = {
+  marketplace: web3.PublicKey
+  authority: web3.PublicKey
+  id: beet.bignum
+  tokenConfig: TokenConfig
+  permissionConfig: PermissionConfig
+  feesConfig: FeesConfig
+  rewardsConfig: RewardsConfig
+  arbiter: web3.PublicKey
+  vestingPeriod: beet.bignum
+}
+
+export const marketplaceInitializedDiscriminator = [
+  22, 167, 42, 34, 172, 55, 155, 14,
+]
+
+export const marketplaceInitializedStruct = new beet.BeetArgsStruct<
+  MarketplaceInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+    ['id', beet.u64],
+    ['tokenConfig', tokenConfigBeet],
+    ['permissionConfig', permissionConfigBeet],
+    ['feesConfig', feesConfigBeet],
+    ['rewardsConfig', rewardsConfigBeet],
+    ['arbiter', beetSolana.publicKey],
+    ['vestingPeriod', beet.i64],
+  ],
+  'MarketplaceInitialized',
+)
+
+export type MarketplaceEdited = {
+  marketplace: web3.PublicKey
+  tokenConfig: TokenConfig
+  permissionConfig: PermissionConfig
+  feesConfig: FeesConfig
+  rewardsConfig: RewardsConfig
+  arbiter: web3.PublicKey
+  vestingPeriod: beet.bignum
+}
+
+export const marketplaceEditedDiscriminator = [
+  172, 219, 243, 73, 150, 36, 80, 53,
+]
+
+export const marketplaceEditedStruct = new beet.BeetArgsStruct<
+  MarketplaceEdited & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['tokenConfig', tokenConfigBeet],
+    ['permissionConfig', permissionConfigBeet],
+    ['feesConfig', feesConfigBeet],
+    ['rewardsConfig', rewardsConfigBeet],
+    ['arbiter', beetSolana.publicKey],
+    ['vestingPeriod', beet.i64],
+  ],
+  'MarketplaceEdited',
+)
+
+export type MarketplaceAuthorityProposed = {
+  marketplace: web3.PublicKey
+  authority: web3.PublicKey
+  pendingAuthority: web3.PublicKey
+}
+
+export const marketplaceAuthorityProposedDiscriminator = [
+  218, 20, 173, 45, 87, 174, 173, 33,
+]
+
+export const marketplaceAuthorityProposedStruct = new beet.BeetArgsStruct<
+  MarketplaceAuthorityProposed & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+    ['pendingAuthority', beetSolana.publicKey],
+  ],
+  'MarketplaceAuthorityProposed',
+)
+
+export type MarketplaceAuthorityAccepted = {
+  marketplace: web3.PublicKey
+  previousAuthority: web3.PublicKey
+  authority: web3.PublicKey
+}
+
+export const marketplaceAuthorityAcceptedDiscriminator = [
+  127, 59, 176, 218, 130, 133, 60, 198,
+]
+
+export const marketplaceAuthorityAcceptedStruct = new beet.BeetArgsStruct<
+  MarketplaceAuthorityAccepted & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['previousAuthority', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+  ],
+  'MarketplaceAuthorityAccepted',
+)
+
+export type MarketplacePauseSet = {
+  marketplace: web3.PublicKey
+  paused: boolean
+}
+
+export const marketplacePauseSetDiscriminator = [
+  192, 159, 68, 151, 237, 10, 2, 194,
+]
+
+export const marketplacePauseSetStruct = new beet.BeetArgsStruct<
+  MarketplacePauseSet & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['paused', beet.bool],
+  ],
+  'MarketplacePauseSet',
+)
+
+export type MarketplaceClosed = {
+  marketplace: web3.PublicKey
+  authority: web3.PublicKey
+}
+
+export const marketplaceClosedDiscriminator = [
+  23, 233, 117, 4, 147, 49, 205, 214,
+]
+
+export const marketplaceClosedStruct = new beet.BeetArgsStruct<
+  MarketplaceClosed & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+  ],
+  'MarketplaceClosed',
+)
+
+export type BountyVaultInitialized = {
+  marketplace: web3.PublicKey
+  bountyVault: web3.PublicKey
+  rewardMint: web3.PublicKey
+}
+
+export const bountyVaultInitializedDiscriminator = [
+  171, 142, 176, 19, 68, 138, 105, 213,
+]
+
+export const bountyVaultInitializedStruct = new beet.BeetArgsStruct<
+  BountyVaultInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['bountyVault', beetSolana.publicKey],
+    ['rewardMint', beetSolana.publicKey],
+  ],
+  'BountyVaultInitialized',
+)
+
+export type BountyWithdrawn = {
+  marketplace: web3.PublicKey
+  bountyVault: web3.PublicKey
+  rewardMint: web3.PublicKey
+  receiverVault: web3.PublicKey
+  amount: beet.bignum
+}
+
+export const bountyWithdrawnDiscriminator = [
+  20, 146, 119, 119, 163, 30, 47, 147,
+]
+
+export const bountyWithdrawnStruct = new beet.BeetArgsStruct<
+  BountyWithdrawn & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['bountyVault', beetSolana.publicKey],
+    ['rewardMint', beetSolana.publicKey],
+    ['receiverVault', beetSolana.publicKey],
+    ['amount', beet.u64],
+  ],
+  'BountyWithdrawn',
+)
+
+export type BountyVaultClosed = {
+  marketplace: web3.PublicKey
+  bountyVault: web3.PublicKey
+  rewardMint: web3.PublicKey
+}
+
+export const bountyVaultClosedDiscriminator = [
+  93, 235, 136, 191, 228, 23, 41, 206,
+]
+
+export const bountyVaultClosedStruct = new beet.BeetArgsStruct<
+  BountyVaultClosed & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['bountyVault', beetSolana.publicKey],
+    ['rewardMint', beetSolana.publicKey],
+  ],
+  'BountyVaultClosed',
+)
+
+export type AccessRequested = {
+  marketplace: web3.PublicKey
+  user: web3.PublicKey
+}
+
+export const accessRequestedDiscriminator = [70, 226, 202, 24, 4, 226, 156, 130]
+
+export const accessRequestedStruct = new beet.BeetArgsStruct<
+  AccessRequested & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['user', beetSolana.publicKey],
+  ],
+  'AccessRequested',
+)
+
+export type AccessGranted = {
+  marketplace: web3.PublicKey
+  user: web3.PublicKey
+  accessMint: web3.PublicKey
+}
+
+export const accessGrantedDiscriminator = [21, 212, 83, 192, 198, 26, 62, 185]
+
+export const accessGrantedStruct = new beet.BeetArgsStruct<
+  AccessGranted & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['user', beetSolana.publicKey],
+    ['accessMint', beetSolana.publicKey],
+  ],
+  'AccessGranted',
+)
+
+export type ProductInitialized = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  seller: web3.PublicKey
+  productMint: web3.PublicKey
+  merkleTree: web3.PublicKey
+  firstId: number[] /* size: 32 */
+  secondId: number[] /* size: 32 */
+  paymentMint: web3.PublicKey
+  productPrice: beet.bignum
+  maxSupply: beet.COption<beet.bignum>
+  maxUnitsPerWallet: beet.COption<number>
+  saleStart: beet.COption<beet.bignum>
+  saleEnd: beet.COption<beet.bignum>
+  escrowTimeout: beet.COption<beet.bignum>
+  subscriptionPeriod: beet.COption<beet.bignum>
+}
+
+export const productInitializedDiscriminator = [
+  105, 42, 35, 47, 170, 116, 41, 162,
+]
+
+export const productInitializedStruct = new beet.FixableBeetArgsStruct<
+  ProductInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['productMint', beetSolana.publicKey],
+    ['merkleTree', beetSolana.publicKey],
+    ['firstId', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['secondId', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['paymentMint', beetSolana.publicKey],
+    ['productPrice', beet.u64],
+    ['maxSupply', beet.coption(beet.u64)],
+    ['maxUnitsPerWallet', beet.coption(beet.u32)],
+    ['saleStart', beet.coption(beet.i64)],
+    ['saleEnd', beet.coption(beet.i64)],
+    ['escrowTimeout', beet.coption(beet.i64)],
+    ['subscriptionPeriod', beet.coption(beet.i64)],
+  ],
+  'ProductInitialized',
+)
+
+export type ProductEdited = {
+  product: web3.PublicKey
+  paymentMint: web3.PublicKey
+  productPrice: beet.bignum
+  maxSupply: beet.COption<beet.bignum>
+  maxUnitsPerWallet: beet.COption<number>
+  saleStart: beet.COption<beet.bignum>
+  saleEnd: beet.COption<beet.bignum>
+  escrowTimeout: beet.COption<beet.bignum>
+  subscriptionPeriod: beet.COption<beet.bignum>
+}
+
+export const productEditedDiscriminator = [25, 92, 185, 187, 237, 68, 105, 185]
+
+export const productEditedStruct = new beet.FixableBeetArgsStruct<
+  ProductEdited & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['product', beetSolana.publicKey],
+    ['paymentMint', beetSolana.publicKey],
+    ['productPrice', beet.u64],
+    ['maxSupply', beet.coption(beet.u64)],
+    ['maxUnitsPerWallet', beet.coption(beet.u32)],
+    ['saleStart', beet.coption(beet.i64)],
+    ['saleEnd', beet.coption(beet.i64)],
+    ['escrowTimeout', beet.coption(beet.i64)],
+    ['subscriptionPeriod', beet.coption(beet.i64)],
+  ],
+  'ProductEdited',
+)
+
+export type ProductClosed = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  seller: web3.PublicKey
+}
+
+export const productClosedDiscriminator = [212, 141, 16, 142, 153, 231, 8, 30]
+
+export const productClosedStruct = new beet.BeetArgsStruct<
+  ProductClosed & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+  ],
+  'ProductClosed',
+)
+
+export type TreeUpdated = {
+  product: web3.PublicKey
+  merkleTree: web3.PublicKey
+  maxDepth: number
+  maxBufferSize: number
+}
+
+export const treeUpdatedDiscriminator = [81, 159, 246, 138, 104, 180, 89, 154]
+
+export const treeUpdatedStruct = new beet.BeetArgsStruct<
+  TreeUpdated & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['product', beetSolana.publicKey],
+    ['merkleTree', beetSolana.publicKey],
+    ['maxDepth', beet.u32],
+    ['maxBufferSize', beet.u32],
+  ],
+  'TreeUpdated',
+)
+
+export type ProductPurchased = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  paymentMint: web3.PublicKey
+  units: number
+  grossAmount: beet.bignum
+  fee: beet.bignum
+  sellerAmount: beet.bignum
+  campaign: web3.PublicKey
+  sellerReward: beet.bignum
+  buyerReward: beet.bignum
+}
+
+export const productPurchasedDiscriminator = [149, 80, 32, 62, 22, 245, 46, 227]
+
+export const productPurchasedStruct = new beet.BeetArgsStruct<
+  ProductPurchased & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['paymentMint', beetSolana.publicKey],
+    ['units', beet.u32],
+    ['grossAmount', beet.u64],
+    ['fee', beet.u64],
+    ['sellerAmount', beet.u64],
+    ['campaign', beetSolana.publicKey],
+    ['sellerReward', beet.u64],
+    ['buyerReward', beet.u64],
+  ],
+  'ProductPurchased',
+)
+
+export type EscrowCreated = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  escrow: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  paymentMint: web3.PublicKey
+  units: number
+  grossAmount: beet.bignum
+  escrowedAmount: beet.bignum
+  releaseTs: beet.bignum
+}
+
+export const escrowCreatedDiscriminator = [70, 127, 105, 102, 92, 97, 7, 173]
+
+export const escrowCreatedStruct = new beet.BeetArgsStruct<
+  EscrowCreated & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['escrow', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['paymentMint', beetSolana.publicKey],
+    ['units', beet.u32],
+    ['grossAmount', beet.u64],
+    ['escrowedAmount', beet.u64],
+    ['releaseTs', beet.i64],
+  ],
+  'EscrowCreated',
+)
+
+export type EscrowReleased = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  escrow: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  releasedBy: web3.PublicKey
+  fee: beet.bignum
+  sellerAmount: beet.bignum
+  confirmed: boolean
+}
+
+export const escrowReleasedDiscriminator = [
+  131, 7, 138, 104, 166, 190, 113, 112,
+]
+
+export const escrowReleasedStruct = new beet.BeetArgsStruct<
+  EscrowReleased & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['escrow', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['releasedBy', beetSolana.publicKey],
+    ['fee', beet.u64],
+    ['sellerAmount', beet.u64],
+    ['confirmed', beet.bool],
+  ],
+  'EscrowReleased',
+)
+
+export type PurchaseRefunded = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  paymentMint: web3.PublicKey
+  units: number
+  amount: beet.bignum
+  sellerClawback: beet.bignum
+  buyerClawback: beet.bignum
+}
+
+export const purchaseRefundedDiscriminator = [
+  181, 111, 228, 8, 192, 68, 247, 37,
+]
+
+export const purchaseRefundedStruct = new beet.BeetArgsStruct<
+  PurchaseRefunded & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['paymentMint', beetSolana.publicKey],
+    ['units', beet.u32],
+    ['amount', beet.u64],
+    ['sellerClawback', beet.u64],
+    ['buyerClawback', beet.u64],
+  ],
+  'PurchaseRefunded',
+)
+
+export type ProductRedeemed = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  units: number
+}
+
+export const productRedeemedDiscriminator = [
+  132, 181, 178, 147, 40, 104, 124, 127,
+]
+
+export const productRedeemedStruct = new beet.BeetArgsStruct<
+  ProductRedeemed & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['units', beet.u32],
+  ],
+  'ProductRedeemed',
+)
+
+export type DisputeOpened = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  escrow: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+}
+
+export const disputeOpenedDiscriminator = [239, 222, 102, 235, 193, 85, 1, 214]
+
+export const disputeOpenedStruct = new beet.BeetArgsStruct<
+  DisputeOpened & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['escrow', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+  ],
+  'DisputeOpened',
+)
+
+export type DisputeSettled = {
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  escrow: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  ruling: Ruling
+}
+
+export const disputeSettledDiscriminator = [254, 31, 147, 164, 50, 13, 223, 158]
+
+export const disputeSettledStruct = new beet.BeetArgsStruct<
+  DisputeSettled & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['escrow', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['ruling', rulingBeet],
+  ],
+  'DisputeSettled',
+)
+
+export type CampaignInitialized = {
+  marketplace: web3.PublicKey
+  campaign: web3.PublicKey
+  id: beet.bignum
+  rewardMint: web3.PublicKey
+  paymentMint: beet.COption<web3.PublicKey>
+  startTs: beet.bignum
+  endTs: beet.bignum
+  budget: beet.bignum
+  sellerReward: number
+  buyerReward: number
+  maxRewardPerPurchase: beet.COption<beet.bignum>
+  maxRewardPerWallet: beet.COption<beet.bignum>
+  vestingPeriod: beet.bignum
+}
+
+export const campaignInitializedDiscriminator = [
+  22, 192, 125, 123, 243, 139, 58, 7,
+]
+
+export const campaignInitializedStruct = new beet.FixableBeetArgsStruct<
+  CampaignInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['campaign', beetSolana.publicKey],
+    ['id', beet.u64],
+    ['rewardMint', beetSolana.publicKey],
+    ['paymentMint', beet.coption(beetSolana.publicKey)],
+    ['startTs', beet.i64],
+    ['endTs', beet.i64],
+    ['budget', beet.u64],
+    ['sellerReward', beet.u16],
+    ['buyerReward', beet.u16],
+    ['maxRewardPerPurchase', beet.coption(beet.u64)],
+    ['maxRewardPerWallet', beet.coption(beet.u64)],
+    ['vestingPeriod', beet.i64],
+  ],
+  'CampaignInitialized',
+)
+
+export type CampaignRewardInitialized = {
+  campaign: web3.PublicKey
+  campaignReward: web3.PublicKey
+  authority: web3.PublicKey
+}
+
+export const campaignRewardInitializedDiscriminator = [
+  190, 126, 255, 217, 87, 4, 39, 127,
+]
+
+export const campaignRewardInitializedStruct = new beet.BeetArgsStruct<
+  CampaignRewardInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['campaign', beetSolana.publicKey],
+    ['campaignReward', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+  ],
+  'CampaignRewardInitialized',
+)
+
+export type RewardClosed = {
+  marketplace: web3.PublicKey
+  reward: web3.PublicKey
+  authority: web3.PublicKey
+  swept: beet.bignum
+}
+
+export const rewardClosedDiscriminator = [179, 230, 154, 118, 174, 174, 142, 99]
+
+export const rewardClosedStruct = new beet.BeetArgsStruct<
+  RewardClosed & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['reward', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+    ['swept', beet.u64],
+  ],
+  'RewardClosed',
+)
+
+export type RewardInitialized = {
+  marketplace: web3.PublicKey
+  reward: web3.PublicKey
+  authority: web3.PublicKey
+  rewardVault: web3.PublicKey
+}
+
+export const rewardInitializedDiscriminator = [
+  231, 137, 185, 228, 234, 165, 177, 94,
+]
+
+export const rewardInitializedStruct = new beet.BeetArgsStruct<
+  RewardInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['reward', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+    ['rewardVault', beetSolana.publicKey],
+  ],
+  'RewardInitialized',
+)
+
+export type RewardVaultInitialized = {
+  reward: web3.PublicKey
+  rewardVault: web3.PublicKey
+  rewardMint: web3.PublicKey
+}
+
+export const rewardVaultInitializedDiscriminator = [
+  141, 209, 11, 48, 78, 238, 168, 211,
+]
+
+export const rewardVaultInitializedStruct = new beet.BeetArgsStruct<
+  RewardVaultInitialized & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['reward', beetSolana.publicKey],
+    ['rewardVault', beetSolana.publicKey],
+    ['rewardMint', beetSolana.publicKey],
+  ],
+  'RewardVaultInitialized',
+)
+
+export type RewardWithdrawn = {
+  marketplace: web3.PublicKey
+  campaign: web3.PublicKey
+  reward: web3.PublicKey
+  authority: web3.PublicKey
+  rewardMint: web3.PublicKey
+  amount: beet.bignum
+}
+
+export const rewardWithdrawnDiscriminator = [
+  136, 100, 204, 104, 198, 133, 84, 136,
+]
+
+export const rewardWithdrawnStruct = new beet.BeetArgsStruct<
+  RewardWithdrawn & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['campaign', beetSolana.publicKey],
+    ['reward', beetSolana.publicKey],
+    ['authority', beetSolana.publicKey],
+    ['rewardMint', beetSolana.publicKey],
+    ['amount', beet.u64],
+  ],
+  'RewardWithdrawn',
+)
+
+export type AccountMigrated = {
+  account: web3.PublicKey
+  version: number
+}
+
+export const accountMigratedDiscriminator = [
+  153, 121, 252, 128, 30, 241, 166, 101,
+]
+
+export const accountMigratedStruct = new beet.BeetArgsStruct<
+  AccountMigrated & EventHeader
+>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['account', beetSolana.publicKey],
+    ['version', beet.u8],
+  ],
+  'AccountMigrated',
+)
+
+/*-----------------------* CUSTOM RAW EVENT TYPES *-----------------------*/
+
+export type MarketplaceInitializedInfo = MarketplaceInitialized & EventAccountsInstruction
+
+export type RawMarketplaceInitialized = RawInstructionBase & {
+  parsed: {
+    info: MarketplaceInitializedInfo
+    type: EventType.MarketplaceInitialized
+  }
+}
+
+export type MarketplaceEditedInfo = MarketplaceEdited & EventAccountsInstruction
+
+export type RawMarketplaceEdited = RawInstructionBase & {
+  parsed: {
+    info: MarketplaceEditedInfo
+    type: EventType.MarketplaceEdited
+  }
+}
+
+export type MarketplaceAuthorityProposedInfo = MarketplaceAuthorityProposed & EventAccountsInstruction
+
+export type RawMarketplaceAuthorityProposed = RawInstructionBase & {
+  parsed: {
+    info: MarketplaceAuthorityProposedInfo
+    type: EventType.MarketplaceAuthorityProposed
+  }
+}
+
+export type MarketplaceAuthorityAcceptedInfo = MarketplaceAuthorityAccepted & EventAccountsInstruction
+
+export type RawMarketplaceAuthorityAccepted = RawInstructionBase & {
+  parsed: {
+    info: MarketplaceAuthorityAcceptedInfo
+    type: EventType.MarketplaceAuthorityAccepted
+  }
+}
+
+export type MarketplacePauseSetInfo = MarketplacePauseSet & EventAccountsInstruction
+
+export type RawMarketplacePauseSet = RawInstructionBase & {
+  parsed: {
+    info: MarketplacePauseSetInfo
+    type: EventType.MarketplacePauseSet
+  }
+}
+
+export type MarketplaceClosedInfo = MarketplaceClosed & EventAccountsInstruction
+
+export type RawMarketplaceClosed = RawInstructionBase & {
+  parsed: {
+    info: MarketplaceClosedInfo
+    type: EventType.MarketplaceClosed
+  }
+}
+
+export type BountyVaultInitializedInfo = BountyVaultInitialized & EventAccountsInstruction
+
+export type RawBountyVaultInitialized = RawInstructionBase & {
+  parsed: {
+    info: BountyVaultInitializedInfo
+    type: EventType.BountyVaultInitialized
+  }
+}
+
+export type BountyWithdrawnInfo = BountyWithdrawn & EventAccountsInstruction
+
+export type RawBountyWithdrawn = RawInstructionBase & {
+  parsed: {
+    info: BountyWithdrawnInfo
+    type: EventType.BountyWithdrawn
+  }
+}
+
+export type BountyVaultClosedInfo = BountyVaultClosed & EventAccountsInstruction
+
+export type RawBountyVaultClosed = RawInstructionBase & {
+  parsed: {
+    info: BountyVaultClosedInfo
+    type: EventType.BountyVaultClosed
+  }
+}
+
+export type AccessRequestedInfo = AccessRequested & EventAccountsInstruction
+
+export type RawAccessRequested = RawInstructionBase & {
+  parsed: {
+    info: AccessRequestedInfo
+    type: EventType.AccessRequested
+  }
+}
+
+export type AccessGrantedInfo = AccessGranted & EventAccountsInstruction
+
+export type RawAccessGranted = RawInstructionBase & {
+  parsed: {
+    info: AccessGrantedInfo
+    type: EventType.AccessGranted
+  }
+}
+
+export type ProductInitializedInfo = ProductInitialized & EventAccountsInstruction
+
+export type RawProductInitialized = RawInstructionBase & {
+  parsed: {
+    info: ProductInitializedInfo
+    type: EventType.ProductInitialized
+  }
+}
+
+export type ProductEditedInfo = ProductEdited & EventAccountsInstruction
+
+export type RawProductEdited = RawInstructionBase & {
+  parsed: {
+    info: ProductEditedInfo
+    type: EventType.ProductEdited
+  }
+}
+
+export type ProductClosedInfo = ProductClosed & EventAccountsInstruction
+
+export type RawProductClosed = RawInstructionBase & {
+  parsed: {
+    info: ProductClosedInfo
+    type: EventType.ProductClosed
+  }
+}
+
+export type TreeUpdatedInfo = TreeUpdated & EventAccountsInstruction
+
+export type RawTreeUpdated = RawInstructionBase & {
+  parsed: {
+    info: TreeUpdatedInfo
+    type: EventType.TreeUpdated
+  }
+}
+
+export type ProductPurchasedInfo = ProductPurchased & EventAccountsInstruction
+
+export type RawProductPurchased = RawInstructionBase & {
+  parsed: {
+    info: ProductPurchasedInfo
+    type: EventType.ProductPurchased
+  }
+}
+
+export type EscrowCreatedInfo = EscrowCreated & EventAccountsInstruction
+
+export type RawEscrowCreated = RawInstructionBase & {
+  parsed: {
+    info: EscrowCreatedInfo
+    type: EventType.EscrowCreated
+  }
+}
+
+export type EscrowReleasedInfo = EscrowReleased & EventAccountsInstruction
+
+export type RawEscrowReleased = RawInstructionBase & {
+  parsed: {
+    info: EscrowReleasedInfo
+    type: EventType.EscrowReleased
+  }
+}
+
+export type PurchaseRefundedInfo = PurchaseRefunded & EventAccountsInstruction
+
+export type RawPurchaseRefunded = RawInstructionBase & {
+  parsed: {
+    info: PurchaseRefundedInfo
+    type: EventType.PurchaseRefunded
+  }
+}
+
+export type ProductRedeemedInfo = ProductRedeemed & EventAccountsInstruction
+
+export type RawProductRedeemed = RawInstructionBase & {
+  parsed: {
+    info: ProductRedeemedInfo
+    type: EventType.ProductRedeemed
+  }
+}
+
+export type DisputeOpenedInfo = DisputeOpened & EventAccountsInstruction
+
+export type RawDisputeOpened = RawInstructionBase & {
+  parsed: {
+    info: DisputeOpenedInfo
+    type: EventType.DisputeOpened
+  }
+}
+
+export type DisputeSettledInfo = DisputeSettled & EventAccountsInstruction
+
+export type RawDisputeSettled = RawInstructionBase & {
+  parsed: {
+    info: DisputeSettledInfo
+    type: EventType.DisputeSettled
+  }
+}
+
+export type CampaignInitializedInfo = CampaignInitialized & EventAccountsInstruction
+
+export type RawCampaignInitialized = RawInstructionBase & {
+  parsed: {
+    info: CampaignInitializedInfo
+    type: EventType.CampaignInitialized
+  }
+}
+
+export type CampaignRewardInitializedInfo = CampaignRewardInitialized & EventAccountsInstruction
+
+export type RawCampaignRewardInitialized = RawInstructionBase & {
+  parsed: {
+    info: CampaignRewardInitializedInfo
+    type: EventType.CampaignRewardInitialized
+  }
+}
+
+export type RewardClosedInfo = RewardClosed & EventAccountsInstruction
+
+export type RawRewardClosed = RawInstructionBase & {
+  parsed: {
+    info: RewardClosedInfo
+    type: EventType.RewardClosed
+  }
+}
+
+export type RewardInitializedInfo = RewardInitialized & EventAccountsInstruction
+
+export type RawRewardInitialized = RawInstructionBase & {
+  parsed: {
+    info: RewardInitializedInfo
+    type: EventType.RewardInitialized
+  }
+}
+
+export type RewardVaultInitializedInfo = RewardVaultInitialized & EventAccountsInstruction
+
+export type RawRewardVaultInitialized = RawInstructionBase & {
+  parsed: {
+    info: RewardVaultInitializedInfo
+    type: EventType.RewardVaultInitialized
+  }
+}
+
+export type RewardWithdrawnInfo = RewardWithdrawn & EventAccountsInstruction
+
+export type RawRewardWithdrawn = RawInstructionBase & {
+  parsed: {
+    info: RewardWithdrawnInfo
+    type: EventType.RewardWithdrawn
+  }
+}
+
+export type AccountMigratedInfo = AccountMigrated & EventAccountsInstruction
+
+export type RawAccountMigrated = RawInstructionBase & {
+  parsed: {
+    info: AccountMigratedInfo
+    type: EventType.AccountMigrated
+  }
+}
+
+export type RawEventsInfo =
+  | MarketplaceInitializedInfo
+  | MarketplaceEditedInfo
+  | MarketplaceAuthorityProposedInfo
+  | MarketplaceAuthorityAcceptedInfo
+  | MarketplacePauseSetInfo
+  | MarketplaceClosedInfo
+  | BountyVaultInitializedInfo
+  | BountyWithdrawnInfo
+  | BountyVaultClosedInfo
+  | AccessRequestedInfo
+  | AccessGrantedInfo
+  | ProductInitializedInfo
+  | ProductEditedInfo
+  | ProductClosedInfo
+  | TreeUpdatedInfo
+  | ProductPurchasedInfo
+  | EscrowCreatedInfo
+  | EscrowReleasedInfo
+  | PurchaseRefundedInfo
+  | ProductRedeemedInfo
+  | DisputeOpenedInfo
+  | DisputeSettledInfo
+  | CampaignInitializedInfo
+  | CampaignRewardInitializedInfo
+  | RewardClosedInfo
+  | RewardInitializedInfo
+  | RewardVaultInitializedInfo
+  | RewardWithdrawnInfo
+  | AccountMigratedInfo
+
+export type RawEvent =
+  | RawMarketplaceInitialized
+  | RawMarketplaceEdited
+  | RawMarketplaceAuthorityProposed
+  | RawMarketplaceAuthorityAccepted
+  | RawMarketplacePauseSet
+  | RawMarketplaceClosed
+  | RawBountyVaultInitialized
+  | RawBountyWithdrawn
+  | RawBountyVaultClosed
+  | RawAccessRequested
+  | RawAccessGranted
+  | RawProductInitialized
+  | RawProductEdited
+  | RawProductClosed
+  | RawTreeUpdated
+  | RawProductPurchased
+  | RawEscrowCreated
+  | RawEscrowReleased
+  | RawPurchaseRefunded
+  | RawProductRedeemed
+  | RawDisputeOpened
+  | RawDisputeSettled
+  | RawCampaignInitialized
+  | RawCampaignRewardInitialized
+  | RawRewardClosed
+  | RawRewardInitialized
+  | RawRewardVaultInitialized
+  | RawRewardWithdrawn
+  | RawAccountMigrated
+
+export type MarketplaceInitializedEvent = EventBase<EventType> & {
+  info: MarketplaceInitializedInfo
+  signer: string
+  account: string
+}
+
+export type MarketplaceEditedEvent = EventBase<EventType> & {
+  info: MarketplaceEditedInfo
+  signer: string
+  account: string
+}
+
+export type MarketplaceAuthorityProposedEvent = EventBase<EventType> & {
+  info: MarketplaceAuthorityProposedInfo
+  signer: string
+  account: string
+}
+
+export type MarketplaceAuthorityAcceptedEvent = EventBase<EventType> & {
+  info: MarketplaceAuthorityAcceptedInfo
+  signer: string
+  account: string
+}
+
+export type MarketplacePauseSetEvent = EventBase<EventType> & {
+  info: MarketplacePauseSetInfo
+  signer: string
+  account: string
+}
+
+export type MarketplaceClosedEvent = EventBase<EventType> & {
+  info: MarketplaceClosedInfo
+  signer: string
+  account: string
+}
+
+export type BountyVaultInitializedEvent = EventBase<EventType> & {
+  info: BountyVaultInitializedInfo
+  signer: string
+  account: string
+}
+
+export type BountyWithdrawnEvent = EventBase<EventType> & {
+  info: BountyWithdrawnInfo
+  signer: string
+  account: string
+}
+
+export type BountyVaultClosedEvent = EventBase<EventType> & {
+  info: BountyVaultClosedInfo
+  signer: string
+  account: string
+}
+
+export type AccessRequestedEvent = EventBase<EventType> & {
+  info: AccessRequestedInfo
+  signer: string
+  account: string
+}
+
+export type AccessGrantedEvent = EventBase<EventType> & {
+  info: AccessGrantedInfo
+  signer: string
+  account: string
+}
+
+export type ProductInitializedEvent = EventBase<EventType> & {
+  info: ProductInitializedInfo
+  signer: string
+  account: string
+}
+
+export type ProductEditedEvent = EventBase<EventType> & {
+  info: ProductEditedInfo
+  signer: string
+  account: string
+}
+
+export type ProductClosedEvent = EventBase<EventType> & {
+  info: ProductClosedInfo
+  signer: string
+  account: string
+}
+
+export type TreeUpdatedEvent = EventBase<EventType> & {
+  info: TreeUpdatedInfo
+  signer: string
+  account: string
+}
+
+export type ProductPurchasedEvent = EventBase<EventType> & {
+  info: ProductPurchasedInfo
+  signer: string
+  account: string
+}
+
+export type EscrowCreatedEvent = EventBase<EventType> & {
+  info: EscrowCreatedInfo
+  signer: string
+  account: string
+}
+
+export type EscrowReleasedEvent = EventBase<EventType> & {
+  info: EscrowReleasedInfo
+  signer: string
+  account: string
+}
+
+export type PurchaseRefundedEvent = EventBase<EventType> & {
+  info: PurchaseRefundedInfo
+  signer: string
+  account: string
+}
+
+export type ProductRedeemedEvent = EventBase<EventType> & {
+  info: ProductRedeemedInfo
+  signer: string
+  account: string
+}
+
+export type DisputeOpenedEvent = EventBase<EventType> & {
+  info: DisputeOpenedInfo
+  signer: string
+  account: string
+}
+
+export type DisputeSettledEvent = EventBase<EventType> & {
+  info: DisputeSettledInfo
+  signer: string
+  account: string
+}
+
+export type CampaignInitializedEvent = EventBase<EventType> & {
+  info: CampaignInitializedInfo
+  signer: string
+  account: string
+}
+
+export type CampaignRewardInitializedEvent = EventBase<EventType> & {
+  info: CampaignRewardInitializedInfo
+  signer: string
+  account: string
+}
+
+export type RewardClosedEvent = EventBase<EventType> & {
+  info: RewardClosedInfo
+  signer: string
+  account: string
+}
+
+export type RewardInitializedEvent = EventBase<EventType> & {
+  info: RewardInitializedInfo
+  signer: string
+  account: string
+}
+
+export type RewardVaultInitializedEvent = EventBase<EventType> & {
+  info: RewardVaultInitializedInfo
+  signer: string
+  account: string
+}
+
+export type RewardWithdrawnEvent = EventBase<EventType> & {
+  info: RewardWithdrawnInfo
+  signer: string
+  account: string
+}
+
+export type AccountMigratedEvent = EventBase<EventType> & {
+  info: AccountMigratedInfo
+  signer: string
+  account: string
+}
+
+export type BrickEvent =
+  | MarketplaceInitializedEvent
+  | MarketplaceEditedEvent
+  | MarketplaceAuthorityProposedEvent
+  | MarketplaceAuthorityAcceptedEvent
+  | MarketplacePauseSetEvent
+  | MarketplaceClosedEvent
+  | BountyVaultInitializedEvent
+  | BountyWithdrawnEvent
+  | BountyVaultClosedEvent
+  | AccessRequestedEvent
+  | AccessGrantedEvent
+  | ProductInitializedEvent
+  | ProductEditedEvent
+  | ProductClosedEvent
+  | TreeUpdatedEvent
+  | ProductPurchasedEvent
+  | EscrowCreatedEvent
+  | EscrowReleasedEvent
+  | PurchaseRefundedEvent
+  | ProductRedeemedEvent
+  | DisputeOpenedEvent
+  | DisputeSettledEvent
+  | CampaignInitializedEvent
+  | CampaignRewardInitializedEvent
+  | RewardClosedEvent
+  | RewardInitializedEvent
+  | RewardVaultInitializedEvent
+  | RewardWithdrawnEvent
+  | AccountMigratedEvent
+/*----------------------------------------------------------------------*/
+
+export function getInstructionType(data: Buffer): EventType | undefined {
+  // instructions called by the users are not indexed, only the events they emit
+  if (!data.slice(0, 8).equals(Buffer.from(eventIxTag))) return
+  const discriminator = data.slice(8, 16)
+  return EVENT_METHOD_CODE.get(discriminator.toString('ascii'))
+}
+
+export const EVENT_METHOD_CODE: Map<string, EventType | undefined> = new Map<
+  string,
+  EventType | undefined
+>([
+  [
+    Buffer.from(marketplaceInitializedDiscriminator).toString('ascii'),
+    EventType.MarketplaceInitialized,
+  ],
+  [
+    Buffer.from(marketplaceEditedDiscriminator).toString('ascii'),
+    EventType.MarketplaceEdited,
+  ],
+  [
+    Buffer.from(marketplaceAuthorityProposedDiscriminator).toString('ascii'),
+    EventType.MarketplaceAuthorityProposed,
+  ],
+  [
+    Buffer.from(marketplaceAuthorityAcceptedDiscriminator).toString('ascii'),
+    EventType.MarketplaceAuthorityAccepted,
+  ],
+  [
+    Buffer.from(marketplacePauseSetDiscriminator).toString('ascii'),
+    EventType.MarketplacePauseSet,
+  ],
+  [
+    Buffer.from(marketplaceClosedDiscriminator).toString('ascii'),
+    EventType.MarketplaceClosed,
+  ],
+  [
+    Buffer.from(bountyVaultInitializedDiscriminator).toString('ascii'),
+    EventType.BountyVaultInitialized,
+  ],
+  [
+    Buffer.from(bountyWithdrawnDiscriminator).toString('ascii'),
+    EventType.BountyWithdrawn,
+  ],
+  [
+    Buffer.from(bountyVaultClosedDiscriminator).toString('ascii'),
+    EventType.BountyVaultClosed,
+  ],
+  [
+    Buffer.from(accessRequestedDiscriminator).toString('ascii'),
+    EventType.AccessRequested,
+  ],
+  [
+    Buffer.from(accessGrantedDiscriminator).toString('ascii'),
+    EventType.AccessGranted,
+  ],
+  [
+    Buffer.from(productInitializedDiscriminator).toString('ascii'),
+    EventType.ProductInitialized,
+  ],
+  [
+    Buffer.from(productEditedDiscriminator).toString('ascii'),
+    EventType.ProductEdited,
+  ],
+  [
+    Buffer.from(productClosedDiscriminator).toString('ascii'),
+    EventType.ProductClosed,
+  ],
+  [
+    Buffer.from(treeUpdatedDiscriminator).toString('ascii'),
+    EventType.TreeUpdated,
+  ],
+  [
+    Buffer.from(productPurchasedDiscriminator).toString('ascii'),
+    EventType.ProductPurchased,
+  ],
+  [
+    Buffer.from(escrowCreatedDiscriminator).toString('ascii'),
+    EventType.EscrowCreated,
+  ],
+  [
+    Buffer.from(escrowReleasedDiscriminator).toString('ascii'),
+    EventType.EscrowReleased,
+  ],
+  [
+    Buffer.from(purchaseRefundedDiscriminator).toString('ascii'),
+    EventType.PurchaseRefunded,
+  ],
+  [
+    Buffer.from(productRedeemedDiscriminator).toString('ascii'),
+    EventType.ProductRedeemed,
+  ],
+  [
+    Buffer.from(disputeOpenedDiscriminator).toString('ascii'),
+    EventType.DisputeOpened,
+  ],
+  [
+    Buffer.from(disputeSettledDiscriminator).toString('ascii'),
+    EventType.DisputeSettled,
+  ],
+  [
+    Buffer.from(campaignInitializedDiscriminator).toString('ascii'),
+    EventType.CampaignInitialized,
+  ],
+  [
+    Buffer.from(campaignRewardInitializedDiscriminator).toString('ascii'),
+    EventType.CampaignRewardInitialized,
+  ],
+  [
+    Buffer.from(rewardClosedDiscriminator).toString('ascii'),
+    EventType.RewardClosed,
+  ],
+  [
+    Buffer.from(rewardInitializedDiscriminator).toString('ascii'),
+    EventType.RewardInitialized,
+  ],
+  [
+    Buffer.from(rewardVaultInitializedDiscriminator).toString('ascii'),
+    EventType.RewardVaultInitialized,
+  ],
+  [
+    Buffer.from(rewardWithdrawnDiscriminator).toString('ascii'),
+    EventType.RewardWithdrawn,
+  ],
+  [
+    Buffer.from(accountMigratedDiscriminator).toString('ascii'),
+    EventType.AccountMigrated,
+  ],
+])
+export const IX_DATA_LAYOUT: Partial<Record<EventType, any>> = {
+  [EventType.MarketplaceInitialized]: marketplaceInitializedStruct,
+  [EventType.MarketplaceEdited]: marketplaceEditedStruct,
+  [EventType.MarketplaceAuthorityProposed]: marketplaceAuthorityProposedStruct,
+  [EventType.MarketplaceAuthorityAccepted]: marketplaceAuthorityAcceptedStruct,
+  [EventType.MarketplacePauseSet]: marketplacePauseSetStruct,
+  [EventType.MarketplaceClosed]: marketplaceClosedStruct,
+  [EventType.BountyVaultInitialized]: bountyVaultInitializedStruct,
+  [EventType.BountyWithdrawn]: bountyWithdrawnStruct,
+  [EventType.BountyVaultClosed]: bountyVaultClosedStruct,
+  [EventType.AccessRequested]: accessRequestedStruct,
+  [EventType.AccessGranted]: accessGrantedStruct,
+  [EventType.ProductInitialized]: productInitializedStruct,
+  [EventType.ProductEdited]: productEditedStruct,
+  [EventType.ProductClosed]: productClosedStruct,
+  [EventType.TreeUpdated]: treeUpdatedStruct,
+  [EventType.ProductPurchased]: productPurchasedStruct,
+  [EventType.EscrowCreated]: escrowCreatedStruct,
+  [EventType.EscrowReleased]: escrowReleasedStruct,
+  [EventType.PurchaseRefunded]: purchaseRefundedStruct,
+  [EventType.ProductRedeemed]: productRedeemedStruct,
+  [EventType.DisputeOpened]: disputeOpenedStruct,
+  [EventType.DisputeSettled]: disputeSettledStruct,
+  [EventType.CampaignInitialized]: campaignInitializedStruct,
+  [EventType.CampaignRewardInitialized]: campaignRewardInitializedStruct,
+  [EventType.RewardClosed]: rewardClosedStruct,
+  [EventType.RewardInitialized]: rewardInitializedStruct,
+  [EventType.RewardVaultInitialized]: rewardVaultInitializedStruct,
+  [EventType.RewardWithdrawn]: rewardWithdrawnStruct,
+  [EventType.AccountMigrated]: accountMigratedStruct,
+}
+
+export const IX_ACCOUNTS_LAYOUT: Partial<Record<EventType, any>> = {
+  [EventType.MarketplaceInitialized]: EventAccounts,
+  [EventType.MarketplaceEdited]: EventAccounts,
+  [EventType.MarketplaceAuthorityProposed]: EventAccounts,
+  [EventType.MarketplaceAuthorityAccepted]: EventAccounts,
+  [EventType.MarketplacePauseSet]: EventAccounts,
+  [EventType.MarketplaceClosed]: EventAccounts,
+  [EventType.BountyVaultInitialized]: EventAccounts,
+  [EventType.BountyWithdrawn]: EventAccounts,
+  [EventType.BountyVaultClosed]: EventAccounts,
+  [EventType.AccessRequested]: EventAccounts,
+  [EventType.AccessGranted]: EventAccounts,
+  [EventType.ProductInitialized]: EventAccounts,
+  [EventType.ProductEdited]: EventAccounts,
+  [EventType.ProductClosed]: EventAccounts,
+  [EventType.TreeUpdated]: EventAccounts,
+  [EventType.ProductPurchased]: EventAccounts,
+  [EventType.EscrowCreated]: EventAccounts,
+  [EventType.EscrowReleased]: EventAccounts,
+  [EventType.PurchaseRefunded]: EventAccounts,
+  [EventType.ProductRedeemed]: EventAccounts,
+  [EventType.DisputeOpened]: EventAccounts,
+  [EventType.DisputeSettled]: EventAccounts,
+  [EventType.CampaignInitialized]: EventAccounts,
+  [EventType.CampaignRewardInitialized]: EventAccounts,
+  [EventType.RewardClosed]: EventAccounts,
+  [EventType.RewardInitialized]: EventAccounts,
+  [EventType.RewardVaultInitialized]: EventAccounts,
+  [EventType.RewardWithdrawn]: EventAccounts,
+  [EventType.AccountMigrated]: EventAccounts,
+}
@@ -1,3 +1,3 @@
-export * from './instructions.js'
+export * from './events.js'
 export * from './solita/index.js'
 export * from './accounts.js'
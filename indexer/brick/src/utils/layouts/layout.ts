@@ -1,11 +1,11 @@
 import { BRICK_PROGRAM_ID } from '../../constants.js'
 import { ACCOUNTS_DATA_LAYOUT } from './accounts.js'
 import {
-  InstructionType,
+  EventType,
   IX_DATA_LAYOUT,
   getInstructionType,
   IX_ACCOUNTS_LAYOUT,
-} from './instructions.js'
+} from './events.js'
 
 export default {
   [BRICK_PROGRAM_ID]: {
@@ -14,7 +14,7 @@ export default {
     accountLayoutMap: IX_ACCOUNTS_LAYOUT,
     dataLayoutMap: IX_DATA_LAYOUT,
     accountDataLayoutMap: ACCOUNTS_DATA_LAYOUT,
-    eventType: InstructionType,
+    eventType: EventType,
     getInstructionType,
   },
 }
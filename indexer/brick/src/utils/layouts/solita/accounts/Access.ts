@@ -15,6 +15,7 @@ import * as beet from '@metaplex-foundation/beet'
  * @category generated
  */
 export type AccessArgs = {
+  version: number
   authority: web3.PublicKey
   marketplace: web3.PublicKey
   bump: number
@@ -30,6 +31,7 @@ export const accessDiscriminator = [117, 154, 108, 210, 202, 83, 96, 222]
  */
 export class Access implements AccessArgs {
   private constructor(
+    readonly version: number,
     readonly authority: web3.PublicKey,
     readonly marketplace: web3.PublicKey,
     readonly bump: number,
@@ -39,7 +41,7 @@ export class Access implements AccessArgs {
    * Creates a {@link Access} instance from the provided args.
    */
   static fromArgs(args: AccessArgs) {
-    return new Access(args.authority, args.marketplace, args.bump)
+    return new Access(args.version, args.authority, args.marketplace, args.bump)
   }
 
   /**
@@ -145,6 +147,7 @@ export class Access implements AccessArgs {
    */
   pretty() {
     return {
+      version: this.version,
       authority: this.authority.toBase58(),
       marketplace: this.marketplace.toBase58(),
       bump: this.bump,
@@ -164,6 +167,7 @@ export const accessBeet = new beet.BeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['authority', beetSolana.publicKey],
     ['marketplace', beetSolana.publicKey],
     ['bump', beet.u8],
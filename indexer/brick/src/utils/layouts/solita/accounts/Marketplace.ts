@@ -26,12 +26,23 @@ import {
  * @category generated
  */
 export type MarketplaceArgs = {
+  version: number
   authority: web3.PublicKey
+  creator: web3.PublicKey
+  pendingAuthority: web3.PublicKey
+  id: beet.bignum
   tokenConfig: TokenConfig
   permissionConfig: PermissionConfig
   feesConfig: FeesConfig
   rewardsConfig: RewardsConfig
   bumps: MarketplaceBumps
+  openProducts: number
+  pendingRewards: beet.bignum
+  paused: boolean
+  campaignsEndTs: beet.bignum
+  arbiter: web3.PublicKey
+  vestingPeriod: beet.bignum
+  bountyVaults: number
 }
 
 export const marketplaceDiscriminator = [70, 222, 41, 62, 78, 3, 32, 174]
@@ -44,12 +55,23 @@ export const marketplaceDiscriminator = [70, 222, 41, 62, 78, 3, 32, 174]
  */
 export class Marketplace implements MarketplaceArgs {
   private constructor(
+    readonly version: number,
     readonly authority: web3.PublicKey,
+    readonly creator: web3.PublicKey,
+    readonly pendingAuthority: web3.PublicKey,
+    readonly id: beet.bignum,
     readonly tokenConfig: TokenConfig,
     readonly permissionConfig: PermissionConfig,
     readonly feesConfig: FeesConfig,
     readonly rewardsConfig: RewardsConfig,
     readonly bumps: MarketplaceBumps,
+    readonly openProducts: number,
+    readonly pendingRewards: beet.bignum,
+    readonly paused: boolean,
+    readonly campaignsEndTs: beet.bignum,
+    readonly arbiter: web3.PublicKey,
+    readonly vestingPeriod: beet.bignum,
+    readonly bountyVaults: number,
   ) {}
 
   /**
@@ -57,12 +79,23 @@ export class Marketplace implements MarketplaceArgs {
    */
   static fromArgs(args: MarketplaceArgs) {
     return new Marketplace(
+      args.version,
       args.authority,
+      args.creator,
+      args.pendingAuthority,
+      args.id,
       args.tokenConfig,
       args.permissionConfig,
       args.feesConfig,
       args.rewardsConfig,
       args.bumps,
+      args.openProducts,
+      args.pendingRewards,
+      args.paused,
+      args.campaignsEndTs,
+      args.arbiter,
+      args.vestingPeriod,
+      args.bountyVaults,
     )
   }
 
@@ -133,50 +166,99 @@ export class Marketplace implements MarketplaceArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Marketplace} for the provided args.
-   *
-   * @param args need to be provided since the byte size for this account
-   * depends on them
+   * {@link Marketplace}
    */
-  static byteSize(args: MarketplaceArgs) {
-    const instance = Marketplace.fromArgs(args)
-    return marketplaceBeet.toFixedFromValue({
-      accountDiscriminator: marketplaceDiscriminator,
-      ...instance,
-    }).byteSize
+  static get byteSize() {
+    return marketplaceBeet.byteSize
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Marketplace} data from rent
    *
-   * @param args need to be provided since the byte size for this account
-   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
-    args: MarketplaceArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      Marketplace.byteSize(args),
+      Marketplace.byteSize,
       commitment,
     )
   }
 
+  /**
+   * Determines if the provided {@link Buffer} has the correct byte size to
+   * hold {@link Marketplace} data.
+   */
+  static hasCorrectByteSize(buf: Buffer, offset = 0) {
+    return buf.byteLength - offset === Marketplace.byteSize
+  }
+
   /**
    * Returns a readable version of {@link Marketplace} properties
    * and can be used to convert to JSON and/or logging
    */
   pretty() {
     return {
+      version: this.version,
       authority: this.authority.toBase58(),
+      creator: this.creator.toBase58(),
+      pendingAuthority: this.pendingAuthority.toBase58(),
+      id: (() => {
+        const x = <{ toNumber: () => number }>this.id
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
       tokenConfig: this.tokenConfig,
       permissionConfig: this.permissionConfig,
       feesConfig: this.feesConfig,
       rewardsConfig: this.rewardsConfig,
       bumps: this.bumps,
+      openProducts: this.openProducts,
+      pendingRewards: (() => {
+        const x = <{ toNumber: () => number }>this.pendingRewards
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      paused: this.paused,
+      campaignsEndTs: (() => {
+        const x = <{ toNumber: () => number }>this.campaignsEndTs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      arbiter: this.arbiter.toBase58(),
+      vestingPeriod: (() => {
+        const x = <{ toNumber: () => number }>this.vestingPeriod
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      bountyVaults: this.bountyVaults,
     }
   }
 }
@@ -185,7 +267,7 @@ export class Marketplace implements MarketplaceArgs {
  * @category Accounts
  * @category generated
  */
-export const marketplaceBeet = new beet.FixableBeetStruct<
+export const marketplaceBeet = new beet.BeetStruct<
   Marketplace,
   MarketplaceArgs & {
     accountDiscriminator: number[] /* size: 8 */
@@ -193,12 +275,23 @@ export const marketplaceBeet = new beet.FixableBeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['authority', beetSolana.publicKey],
+    ['creator', beetSolana.publicKey],
+    ['pendingAuthority', beetSolana.publicKey],
+    ['id', beet.u64],
     ['tokenConfig', tokenConfigBeet],
     ['permissionConfig', permissionConfigBeet],
     ['feesConfig', feesConfigBeet],
     ['rewardsConfig', rewardsConfigBeet],
     ['bumps', marketplaceBumpsBeet],
+    ['openProducts', beet.u32],
+    ['pendingRewards', beet.u64],
+    ['paused', beet.bool],
+    ['campaignsEndTs', beet.i64],
+    ['arbiter', beetSolana.publicKey],
+    ['vestingPeriod', beet.i64],
+    ['bountyVaults', beet.u32],
   ],
   Marketplace.fromArgs,
   'Marketplace',
@@ -5,9 +5,9 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
-import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import * as beet from '@metaplex-foundation/beet'
 
 /**
  * Arguments used to create {@link Payment}
@@ -15,8 +15,15 @@ import * as beetSolana from '@metaplex-foundation/beet-solana'
  * @category generated
  */
 export type PaymentArgs = {
+  version: number
   units: number
   bump: number
+  expiresAt: beet.bignum
+  paid: beet.bignum
+  campaign: web3.PublicKey
+  sellerBonus: beet.bignum
+  buyerBonus: beet.bignum
+  subscriptionGranted: beet.bignum
 }
 
 export const paymentDiscriminator = [227, 231, 51, 26, 244, 88, 4, 148]
@@ -28,13 +35,33 @@ export const paymentDiscriminator = [227, 231, 51, 26, 244, 88, 4, 148]
  * @category generated
  */
 export class Payment implements PaymentArgs {
-  private constructor(readonly units: number, readonly bump: number) {}
+  private constructor(
+    readonly version: number,
+    readonly units: number,
+    readonly bump: number,
+    readonly expiresAt: beet.bignum,
+    readonly paid: beet.bignum,
+    readonly campaign: web3.PublicKey,
+    readonly sellerBonus: beet.bignum,
+    readonly buyerBonus: beet.bignum,
+    readonly subscriptionGranted: beet.bignum,
+  ) {}
 
   /**
    * Creates a {@link Payment} instance from the provided args.
    */
   static fromArgs(args: PaymentArgs) {
-    return new Payment(args.units, args.bump)
+    return new Payment(
+      args.version,
+      args.units,
+      args.bump,
+      args.expiresAt,
+      args.paid,
+      args.campaign,
+      args.sellerBonus,
+      args.buyerBonus,
+      args.subscriptionGranted,
+    )
   }
 
   /**
@@ -140,8 +167,65 @@ export class Payment implements PaymentArgs {
    */
   pretty() {
     return {
+      version: this.version,
       units: this.units,
       bump: this.bump,
+      expiresAt: (() => {
+        const x = <{ toNumber: () => number }>this.expiresAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      paid: (() => {
+        const x = <{ toNumber: () => number }>this.paid
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      campaign: this.campaign.toBase58(),
+      sellerBonus: (() => {
+        const x = <{ toNumber: () => number }>this.sellerBonus
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      buyerBonus: (() => {
+        const x = <{ toNumber: () => number }>this.buyerBonus
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      subscriptionGranted: (() => {
+        const x = <{ toNumber: () => number }>this.subscriptionGranted
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -158,8 +242,15 @@ export const paymentBeet = new beet.BeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['units', beet.u32],
     ['bump', beet.u8],
+    ['expiresAt', beet.i64],
+    ['paid', beet.u64],
+    ['campaign', beetSolana.publicKey],
+    ['sellerBonus', beet.u64],
+    ['buyerBonus', beet.u64],
+    ['subscriptionGranted', beet.u64],
   ],
   Payment.fromArgs,
   'Payment',
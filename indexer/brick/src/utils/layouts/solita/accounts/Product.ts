@@ -17,6 +17,7 @@ import { ProductBumps, productBumpsBeet } from '../types/ProductBumps.js'
  * @category generated
  */
 export type ProductArgs = {
+  version: number
   authority: web3.PublicKey
   firstId: number[] /* size: 32 */
   secondId: number[] /* size: 32 */
@@ -25,6 +26,17 @@ export type ProductArgs = {
   merkleTree: web3.PublicKey
   sellerConfig: SellerConfig
   bumps: ProductBumps
+  maxSupply: beet.COption<beet.bignum>
+  sold: beet.bignum
+  maxUnitsPerWallet: beet.COption<number>
+  saleStart: beet.COption<beet.bignum>
+  saleEnd: beet.COption<beet.bignum>
+  escrowTimeout: beet.COption<beet.bignum>
+  subscriptionPeriod: beet.COption<beet.bignum>
+  receipts: beet.bignum
+  openEscrows: number
+  outstandingUnits: beet.bignum
+  subscriptionsEndTs: beet.bignum
 }
 
 export const productDiscriminator = [102, 76, 55, 251, 38, 73, 224, 229]
@@ -37,6 +49,7 @@ export const productDiscriminator = [102, 76, 55, 251, 38, 73, 224, 229]
  */
 export class Product implements ProductArgs {
   private constructor(
+    readonly version: number,
     readonly authority: web3.PublicKey,
     readonly firstId: number[] /* size: 32 */,
     readonly secondId: number[] /* size: 32 */,
@@ -45,6 +58,17 @@ export class Product implements ProductArgs {
     readonly merkleTree: web3.PublicKey,
     readonly sellerConfig: SellerConfig,
     readonly bumps: ProductBumps,
+    readonly maxSupply: beet.COption<beet.bignum>,
+    readonly sold: beet.bignum,
+    readonly maxUnitsPerWallet: beet.COption<number>,
+    readonly saleStart: beet.COption<beet.bignum>,
+    readonly saleEnd: beet.COption<beet.bignum>,
+    readonly escrowTimeout: beet.COption<beet.bignum>,
+    readonly subscriptionPeriod: beet.COption<beet.bignum>,
+    readonly receipts: beet.bignum,
+    readonly openEscrows: number,
+    readonly outstandingUnits: beet.bignum,
+    readonly subscriptionsEndTs: beet.bignum,
   ) {}
 
   /**
@@ -52,6 +76,7 @@ export class Product implements ProductArgs {
    */
   static fromArgs(args: ProductArgs) {
     return new Product(
+      args.version,
       args.authority,
       args.firstId,
       args.secondId,
@@ -60,6 +85,17 @@ export class Product implements ProductArgs {
       args.merkleTree,
       args.sellerConfig,
       args.bumps,
+      args.maxSupply,
+      args.sold,
+      args.maxUnitsPerWallet,
+      args.saleStart,
+      args.saleEnd,
+      args.escrowTimeout,
+      args.subscriptionPeriod,
+      args.receipts,
+      args.openEscrows,
+      args.outstandingUnits,
+      args.subscriptionsEndTs,
     )
   }
 
@@ -130,42 +166,45 @@ export class Product implements ProductArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Product}
+   * {@link Product} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return productBeet.byteSize
+  static byteSize(args: ProductArgs) {
+    const instance = Product.fromArgs(args)
+    return productBeet.toFixedFromValue({
+      accountDiscriminator: productDiscriminator,
+      ...instance,
+    }).byteSize
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Product} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: ProductArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      Product.byteSize,
+      Product.byteSize(args),
       commitment,
     )
   }
 
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Product} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Product.byteSize
-  }
-
   /**
    * Returns a readable version of {@link Product} properties
    * and can be used to convert to JSON and/or logging
    */
   pretty() {
     return {
+      version: this.version,
       authority: this.authority.toBase58(),
       firstId: this.firstId,
       secondId: this.secondId,
@@ -174,6 +213,57 @@ export class Product implements ProductArgs {
       merkleTree: this.merkleTree.toBase58(),
       sellerConfig: this.sellerConfig,
       bumps: this.bumps,
+      maxSupply: this.maxSupply,
+      sold: (() => {
+        const x = <{ toNumber: () => number }>this.sold
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      maxUnitsPerWallet: this.maxUnitsPerWallet,
+      saleStart: this.saleStart,
+      saleEnd: this.saleEnd,
+      escrowTimeout: this.escrowTimeout,
+      subscriptionPeriod: this.subscriptionPeriod,
+      receipts: (() => {
+        const x = <{ toNumber: () => number }>this.receipts
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      openEscrows: this.openEscrows,
+      outstandingUnits: (() => {
+        const x = <{ toNumber: () => number }>this.outstandingUnits
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      subscriptionsEndTs: (() => {
+        const x = <{ toNumber: () => number }>this.subscriptionsEndTs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -182,7 +272,7 @@ export class Product implements ProductArgs {
  * @category Accounts
  * @category generated
  */
-export const productBeet = new beet.BeetStruct<
+export const productBeet = new beet.FixableBeetStruct<
   Product,
   ProductArgs & {
     accountDiscriminator: number[] /* size: 8 */
@@ -190,6 +280,7 @@ export const productBeet = new beet.BeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['authority', beetSolana.publicKey],
     ['firstId', beet.uniformFixedSizeArray(beet.u8, 32)],
     ['secondId', beet.uniformFixedSizeArray(beet.u8, 32)],
@@ -198,6 +289,17 @@ export const productBeet = new beet.BeetStruct<
     ['merkleTree', beetSolana.publicKey],
     ['sellerConfig', sellerConfigBeet],
     ['bumps', productBumpsBeet],
+    ['maxSupply', beet.coption(beet.u64)],
+    ['sold', beet.u64],
+    ['maxUnitsPerWallet', beet.coption(beet.u32)],
+    ['saleStart', beet.coption(beet.i64)],
+    ['saleEnd', beet.coption(beet.i64)],
+    ['escrowTimeout', beet.coption(beet.i64)],
+    ['subscriptionPeriod', beet.coption(beet.i64)],
+    ['receipts', beet.u64],
+    ['openEscrows', beet.u32],
+    ['outstandingUnits', beet.u64],
+    ['subscriptionsEndTs', beet.i64],
   ],
   Product.fromArgs,
   'Product',
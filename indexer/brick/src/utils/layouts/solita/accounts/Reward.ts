@@ -16,10 +16,13 @@ import { RewardBumps, rewardBumpsBeet } from '../types/RewardBumps.js'
  * @category generated
  */
 export type RewardArgs = {
+  version: number
   authority: web3.PublicKey
   marketplace: web3.PublicKey
-  rewardVaults: web3.PublicKey[]
   bumps: RewardBumps
+  lockedUntil: beet.bignum
+  createdTs: beet.bignum
+  pendingRewards: beet.bignum
 }
 
 export const rewardDiscriminator = [174, 129, 42, 212, 190, 18, 45, 34]
@@ -32,10 +35,13 @@ export const rewardDiscriminator = [174, 129, 42, 212, 190, 18, 45, 34]
  */
 export class Reward implements RewardArgs {
   private constructor(
+    readonly version: number,
     readonly authority: web3.PublicKey,
     readonly marketplace: web3.PublicKey,
-    readonly rewardVaults: web3.PublicKey[],
     readonly bumps: RewardBumps,
+    readonly lockedUntil: beet.bignum,
+    readonly createdTs: beet.bignum,
+    readonly pendingRewards: beet.bignum,
   ) {}
 
   /**
@@ -43,10 +49,13 @@ export class Reward implements RewardArgs {
    */
   static fromArgs(args: RewardArgs) {
     return new Reward(
+      args.version,
       args.authority,
       args.marketplace,
-      args.rewardVaults,
       args.bumps,
+      args.lockedUntil,
+      args.createdTs,
+      args.pendingRewards,
     )
   }
 
@@ -117,48 +126,79 @@ export class Reward implements RewardArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Reward} for the provided args.
-   *
-   * @param args need to be provided since the byte size for this account
-   * depends on them
+   * {@link Reward}
    */
-  static byteSize(args: RewardArgs) {
-    const instance = Reward.fromArgs(args)
-    return rewardBeet.toFixedFromValue({
-      accountDiscriminator: rewardDiscriminator,
-      ...instance,
-    }).byteSize
+  static get byteSize() {
+    return rewardBeet.byteSize
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Reward} data from rent
    *
-   * @param args need to be provided since the byte size for this account
-   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
-    args: RewardArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      Reward.byteSize(args),
+      Reward.byteSize,
       commitment,
     )
   }
 
+  /**
+   * Determines if the provided {@link Buffer} has the correct byte size to
+   * hold {@link Reward} data.
+   */
+  static hasCorrectByteSize(buf: Buffer, offset = 0) {
+    return buf.byteLength - offset === Reward.byteSize
+  }
+
   /**
    * Returns a readable version of {@link Reward} properties
    * and can be used to convert to JSON and/or logging
    */
   pretty() {
     return {
+      version: this.version,
       authority: this.authority.toBase58(),
       marketplace: this.marketplace.toBase58(),
-      rewardVaults: this.rewardVaults,
       bumps: this.bumps,
+      lockedUntil: (() => {
+        const x = <{ toNumber: () => number }>this.lockedUntil
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      createdTs: (() => {
+        const x = <{ toNumber: () => number }>this.createdTs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      pendingRewards: (() => {
+        const x = <{ toNumber: () => number }>this.pendingRewards
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -167,7 +207,7 @@ export class Reward implements RewardArgs {
  * @category Accounts
  * @category generated
  */
-export const rewardBeet = new beet.FixableBeetStruct<
+export const rewardBeet = new beet.BeetStruct<
   Reward,
   RewardArgs & {
     accountDiscriminator: number[] /* size: 8 */
@@ -175,10 +215,13 @@ export const rewardBeet = new beet.FixableBeetStruct<
 >(
   [
     ['accountDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['version', beet.u8],
     ['authority', beetSolana.publicKey],
     ['marketplace', beetSolana.publicKey],
-    ['rewardVaults', beet.array(beetSolana.publicKey)],
     ['bumps', rewardBumpsBeet],
+    ['lockedUntil', beet.i64],
+    ['createdTs', beet.i64],
+    ['pendingRewards', beet.u64],
   ],
   Reward.fromArgs,
   'Reward',
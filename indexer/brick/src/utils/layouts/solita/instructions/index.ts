@@ -1,17 +0,0 @@
-export * from './acceptAccess.js'
-export * from './airdropAccess.js'
-export * from './editMarketplace.js'
-export * from './editProduct.js'
-export * from './initBounty.js'
-export * from './initMarketplace.js'
-export * from './initProduct.js'
-export * from './initProductTree.js'
-export * from './initReward.js'
-export * from './initRewardVault.js'
-export * from './registerBuy.js'
-export * from './registerBuyCnft.js'
-export * from './registerBuyCounter.js'
-export * from './registerBuyToken.js'
-export * from './requestAccess.js'
-export * from './updateTree.js'
-export * from './withdrawReward.js'
@@ -1,121 +0,0 @@
-/**
- * This code was GENERATED using the solita package.
- * Please DO NOT EDIT THIS FILE, instead rerun solita to update it or write a wrapper to add functionality.
- *
- * See: https://github.com/metaplex-foundation/solita
- */
-
-import * as beet from '@metaplex-foundation/beet'
-import * as web3 from '@solana/web3.js'
-
-/**
- * @category Instructions
- * @category InitBounty
- * @category generated
- */
-export const initBountyStruct = new beet.BeetArgsStruct<{
-  instructionDiscriminator: number[] /* size: 8 */
-}>(
-  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
-  'InitBountyInstructionArgs',
-)
-/**
- * Accounts required by the _initBounty_ instruction
- *
- * @property [] tokenProgramV0
- * @property [] associatedTokenProgram
- * @property [_writable_, **signer**] signer
- * @property [_writable_] marketplace
- * @property [] rewardMint
- * @property [_writable_] bountyVault
- * @category Instructions
- * @category InitBounty
- * @category generated
- */
-export type InitBountyInstructionAccounts = {
-  systemProgram?: web3.PublicKey
-  tokenProgramV0: web3.PublicKey
-  associatedTokenProgram: web3.PublicKey
-  rent?: web3.PublicKey
-  signer: web3.PublicKey
-  marketplace: web3.PublicKey
-  rewardMint: web3.PublicKey
-  bountyVault: web3.PublicKey
-  anchorRemainingAccounts?: web3.AccountMeta[]
-}
-
-export const initBountyInstructionDiscriminator = [
-  227, 214, 113, 150, 198, 161, 33, 23,
-]
-
-/**
- * Creates a _InitBounty_ instruction.
- *
- * @param accounts that will be accessed while the instruction is processed
- * @category Instructions
- * @category InitBounty
- * @category generated
- */
-export function createInitBountyInstruction(
-  accounts: InitBountyInstructionAccounts,
-  programId = new web3.PublicKey('brick5uEiJqSkfuAvMtKmq7kiuEVmbjVMiigyV51GRF'),
-) {
-  const [data] = initBountyStruct.serialize({
-    instructionDiscriminator: initBountyInstructionDiscriminator,
-  })
-  const keys: web3.AccountMeta[] = [
-    {
-      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.tokenProgramV0,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.associatedTokenProgram,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.rent ?? web3.SYSVAR_RENT_PUBKEY,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.signer,
-      isWritable: true,
-      isSigner: true,
-    },
-    {
-      pubkey: accounts.marketplace,
-      isWritable: true,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.rewardMint,
-      isWritable: false,
-      isSigner: false,
-    },
-    {
-      pubkey: accounts.bountyVault,
-      isWritable: true,
-      isSigner: false,
-    },
-  ]
-
-  if (accounts.anchorRemainingAccounts != null) {
-    for (const acc of accounts.anchorRemainingAccounts) {
-      keys.push(acc)
-    }
-  }
-
-  const ix = new web3.TransactionInstruction({
-    programId,
-    keys,
-    data,
-  })
-  return ix
-}
@@ -1,25 +0,0 @@
-/**
- * This code was GENERATED using the solita package.
- * Please DO NOT EDIT THIS FILE, instead rerun solita to update it or write a wrapper to add functionality.
- *
- * See: https://github.com/metaplex-foundation/solita
- */
-
-import * as beet from '@metaplex-foundation/beet'
-export type UpdateProductTreeParams = {
-  maxDepth: number
-  maxBufferSize: number
-}
-
-/**
- * @category userTypes
- * @category generated
- */
-export const updateProductTreeParamsBeet =
-  new beet.BeetArgsStruct<UpdateProductTreeParams>(
-    [
-      ['maxDepth', beet.u32],
-      ['maxBufferSize', beet.u32],
-    ],
-    'UpdateProductTreeParams',
-  )
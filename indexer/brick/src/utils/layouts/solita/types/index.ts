@@ -1,15 +1,10 @@
-export * from './EditMarketplaceParams.js'
 export * from './FeesConfig.js'
-export * from './InitMarketplaceParams.js'
-export * from './InitProductParams.js'
-export * from './InitProductTreeParams.js'
 export * from './MarketplaceBumps.js'
 export * from './PaymentFeePayer.js'
 export * from './PermissionConfig.js'
 export * from './ProductBumps.js'
-export * from './RegisterBuyCnftParams.js'
 export * from './RewardBumps.js'
 export * from './RewardsConfig.js'
+export * from './Ruling.js'
 export * from './SellerConfig.js'
 export * from './TokenConfig.js'
-export * from './UpdateProductTreeParams.js'
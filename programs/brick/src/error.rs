@@ -46,8 +46,6 @@ pub enum ErrorCode {
     ClosedPromotion,
     #[msg("Currently you can not withdraw the bonus because the promotion has not finished")]
     OpenPromotion,
-    #[msg("The max size of reward vaults are set at 5")]
-    VaultsVectorFull,
     #[msg("Currently this extension is not supported")]
     ExtensionNotSupported,
     #[msg("Error creating mint account")]
@@ -60,4 +58,80 @@ pub enum ErrorCode {
     OptionalAccountNotProvided,
     #[msg("You dont have the token that allows you to create products")]
     NotInWithelist,
-}
\ No newline at end of file
+    #[msg("There are still products listed in this marketplace")]
+    OpenProducts,
+    #[msg("There are still rewards that users have not withdrawn")]
+    PendingRewards,
+    #[msg("You are providing a wrong number of remaining accounts")]
+    IncorrectRemainingAccounts,
+    #[msg("The marketplace is paused")]
+    MarketplacePaused,
+    #[msg("The transfer fee of the payment mint can not be covered")]
+    IncorrectTransferFee,
+    #[msg("The purchase costs more than the maximum price set by the buyer")]
+    PriceExceedsMaximum,
+    #[msg("The product is sold out")]
+    SoldOut,
+    #[msg("The max supply can not be lower than the units already sold")]
+    IncorrectMaxSupply,
+    #[msg("This wallet can not buy more units of this product")]
+    WalletLimitReached,
+    #[msg("The product can not be bought outside its sale window")]
+    SaleNotActive,
+    #[msg("The sale start has to be before the sale end")]
+    IncorrectSaleWindow,
+    #[msg("The campaign start has to be before the campaign end")]
+    IncorrectCampaignWindow,
+    #[msg("The campaign reward account does not belong to this campaign")]
+    IncorrectCampaign,
+    #[msg("The bounty vault is not the marketplace vault of that mint")]
+    IncorrectBountyVault,
+    #[msg("The reward vault is not the user vault of that mint")]
+    IncorrectRewardVault,
+    #[msg("The vesting period can not be negative")]
+    IncorrectVestingPeriod,
+    #[msg("These rewards were swept when a previous reward account was closed")]
+    RewardsAlreadySwept,
+    #[msg("The amount is higher than the unlocked rewards")]
+    AmountExceedsRewards,
+    #[msg("This product can only be bought with an escrowed purchase")]
+    EscrowRequired,
+    #[msg("This product does not accept escrowed purchases")]
+    EscrowNotEnabled,
+    #[msg("The escrow timeout has to be positive")]
+    IncorrectEscrowTimeout,
+    #[msg("The escrow can not be released yet")]
+    EscrowLocked,
+    #[msg("The escrow does not belong to this marketplace")]
+    IncorrectEscrow,
+    #[msg("The escrow is disputed, only the marketplace arbiter can pay it out")]
+    EscrowDisputed,
+    #[msg("The escrow is not disputed")]
+    EscrowNotDisputed,
+    #[msg("Disputes can only be opened before the escrow release timestamp")]
+    DisputeWindowClosed,
+    #[msg("The ruling pays out more than the escrowed amount")]
+    IncorrectRuling,
+    #[msg("The buyer did not buy that many units or did not pay that amount")]
+    RefundExceedsPurchase,
+    #[msg("The cnft receipt is required to burn it")]
+    CnftReceiptRequired,
+    #[msg("The buyer does not have that many units left")]
+    RedeemExceedsUnits,
+    #[msg("The subscription period has to be positive")]
+    IncorrectSubscriptionPeriod,
+    #[msg("This product is not a subscription")]
+    NotASubscription,
+    #[msg("All the open bounty vaults have to be closed with the marketplace")]
+    OpenBountyVaults,
+    #[msg("The refund is lower than the price paid for the units given back")]
+    RefundBelowPrice,
+    #[msg("The payment account is not owned by the program")]
+    IncorrectPaymentOwner,
+    #[msg("Accounts created before the versioned layouts can not be migrated")]
+    UnversionedAccount,
+    #[msg("A cnft receipt stands for a single unit")]
+    IncorrectReceiptUnits,
+    #[msg("Purchases of escrow products are refunded by settling a dispute")]
+    EscrowRefund,
+}
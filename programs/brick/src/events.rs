@@ -0,0 +1,301 @@
+use {
+    crate::state::*,
+    anchor_lang::prelude::*,
+};
+
+// Events are emitted through a self cpi (emit_cpi!), the data lives in the instruction data of the
+// inner instruction so indexers can read them even when the program logs are truncated.
+
+#[event]
+pub struct MarketplaceInitialized {
+    pub marketplace: Pubkey,
+    pub authority: Pubkey,
+    pub id: u64,
+    pub token_config: TokenConfig,
+    pub permission_config: PermissionConfig,
+    pub fees_config: FeesConfig,
+    pub rewards_config: RewardsConfig,
+    pub arbiter: Pubkey,
+    pub vesting_period: i64,
+}
+
+/// Emitted with the full configuration after edit_marketplace, consumers do not need to merge changes.
+#[event]
+pub struct MarketplaceEdited {
+    pub marketplace: Pubkey,
+    pub token_config: TokenConfig,
+    pub permission_config: PermissionConfig,
+    pub fees_config: FeesConfig,
+    pub rewards_config: RewardsConfig,
+    pub arbiter: Pubkey,
+    pub vesting_period: i64,
+}
+
+/// pending_authority = Pubkey::default() means the transfer was cancelled.
+#[event]
+pub struct MarketplaceAuthorityProposed {
+    pub marketplace: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct MarketplaceAuthorityAccepted {
+    pub marketplace: Pubkey,
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct MarketplacePauseSet {
+    pub marketplace: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct MarketplaceClosed {
+    pub marketplace: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct BountyVaultInitialized {
+    pub marketplace: Pubkey,
+    pub bounty_vault: Pubkey,
+    pub reward_mint: Pubkey,
+}
+
+#[event]
+pub struct BountyWithdrawn {
+    pub marketplace: Pubkey,
+    pub bounty_vault: Pubkey,
+    pub reward_mint: Pubkey,
+    pub receiver_vault: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BountyVaultClosed {
+    pub marketplace: Pubkey,
+    pub bounty_vault: Pubkey,
+    pub reward_mint: Pubkey,
+}
+
+#[event]
+pub struct AccessRequested {
+    pub marketplace: Pubkey,
+    pub user: Pubkey,
+}
+
+/// Emitted by accept_access and airdrop_access, the user receives the access token.
+#[event]
+pub struct AccessGranted {
+    pub marketplace: Pubkey,
+    pub user: Pubkey,
+    pub access_mint: Pubkey,
+}
+
+/// merkle_tree is Pubkey::default() when the product delivers a fungible token or uses the counter.
+#[event]
+pub struct ProductInitialized {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub seller: Pubkey,
+    pub product_mint: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub first_id: [u8; 32],
+    pub second_id: [u8; 32],
+    pub payment_mint: Pubkey,
+    pub product_price: u64,
+    pub max_supply: Option<u64>,
+    pub max_units_per_wallet: Option<u32>,
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    pub escrow_timeout: Option<i64>,
+    pub subscription_period: Option<i64>,
+}
+
+#[event]
+pub struct ProductEdited {
+    pub product: Pubkey,
+    pub payment_mint: Pubkey,
+    pub product_price: u64,
+    pub max_supply: Option<u64>,
+    pub max_units_per_wallet: Option<u32>,
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    pub escrow_timeout: Option<i64>,
+    pub subscription_period: Option<i64>,
+}
+
+#[event]
+pub struct ProductClosed {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub seller: Pubkey,
+}
+
+#[event]
+pub struct TreeUpdated {
+    pub product: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
+
+/// Result of a purchase, amounts are in payment mint units except the rewards (reward mint units).
+/// campaign is Pubkey::default() when the purchase was not rewarded.
+/// gross_amount = product_price * units, fee and seller_amount are the result of calculate_transfer_distribution,
+/// when the buyer pays the fee it is charged on top of the gross amount.
+#[event]
+pub struct ProductPurchased {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    pub units: u32,
+    pub gross_amount: u64,
+    pub fee: u64,
+    pub seller_amount: u64,
+    pub campaign: Pubkey,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+}
+
+/// escrowed_amount is what the buyer paid to the escrow, gross_amount plus the fee when the buyer pays it.
+#[event]
+pub struct EscrowCreated {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub escrow: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    pub units: u32,
+    pub gross_amount: u64,
+    pub escrowed_amount: u64,
+    pub release_ts: i64,
+}
+
+/// confirmed is true when the buyer released the escrow, false when it was released after the timeout.
+#[event]
+pub struct EscrowReleased {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub escrow: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub released_by: Pubkey,
+    pub fee: u64,
+    pub seller_amount: u64,
+    pub confirmed: bool,
+}
+
+/// seller_clawback and buyer_clawback are reward mint units returned to the bounty vault.
+#[event]
+pub struct PurchaseRefunded {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    pub units: u32,
+    pub amount: u64,
+    pub seller_clawback: u64,
+    pub buyer_clawback: u64,
+}
+
+#[event]
+pub struct ProductRedeemed {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub units: u32,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub escrow: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+}
+
+/// ruling.arbiter is the wallet that settled the dispute, the marketplace authority or its arbiter.
+#[event]
+pub struct DisputeSettled {
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    pub escrow: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub ruling: Ruling,
+}
+
+#[event]
+pub struct CampaignInitialized {
+    pub marketplace: Pubkey,
+    pub campaign: Pubkey,
+    pub id: u64,
+    pub reward_mint: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub budget: u64,
+    pub seller_reward: u16,
+    pub buyer_reward: u16,
+    pub max_reward_per_purchase: Option<u64>,
+    pub max_reward_per_wallet: Option<u64>,
+    pub vesting_period: i64,
+}
+
+#[event]
+pub struct CampaignRewardInitialized {
+    pub campaign: Pubkey,
+    pub campaign_reward: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// swept is the sum of the raw amounts moved out of the reward vaults, of any reward mint.
+#[event]
+pub struct RewardClosed {
+    pub marketplace: Pubkey,
+    pub reward: Pubkey,
+    pub authority: Pubkey,
+    pub swept: u64,
+}
+
+#[event]
+pub struct RewardInitialized {
+    pub marketplace: Pubkey,
+    pub reward: Pubkey,
+    pub authority: Pubkey,
+    pub reward_vault: Pubkey,
+}
+
+#[event]
+pub struct RewardVaultInitialized {
+    pub reward: Pubkey,
+    pub reward_vault: Pubkey,
+    pub reward_mint: Pubkey,
+}
+
+#[event]
+pub struct RewardWithdrawn {
+    pub marketplace: Pubkey,
+    pub campaign: Pubkey,
+    pub reward: Pubkey,
+    pub authority: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted by the migrate instructions with the new layout version.
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
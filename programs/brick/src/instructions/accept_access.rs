@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -10,6 +11,7 @@ use {
     }
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AcceptAccess<'info> {
     pub system_program: Program<'info, System>,
@@ -25,7 +27,8 @@ pub struct AcceptAccess<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            signer.key().as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
         constraint = signer.key() == marketplace.authority
@@ -66,10 +69,11 @@ pub struct AcceptAccess<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<AcceptAccess>) -> Result<()> {
-    let signer_key = ctx.accounts.signer.key();
+    let marketplace_id = ctx.accounts.marketplace.id.to_le_bytes();
     let marketplace_seeds = &[
         b"marketplace".as_ref(),
-        signer_key.as_ref(),
+        ctx.accounts.marketplace.creator.as_ref(),
+        marketplace_id.as_ref(),
         &[ctx.accounts.marketplace.bumps.bump],
     ];
 
@@ -85,6 +89,12 @@ pub fn handler<'info>(ctx: Context<AcceptAccess>) -> Result<()> {
         ),
         1
     ).map_err(|_| ErrorCode::MintToError)?;
-    
+
+    emit_cpi!(AccessGranted {
+        marketplace: ctx.accounts.marketplace.key(),
+        user: ctx.accounts.receiver.key(),
+        access_mint: ctx.accounts.access_mint.key(),
+    });
+
     Ok(())
 }
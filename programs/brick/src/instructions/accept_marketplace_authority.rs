@@ -0,0 +1,39 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptMarketplaceAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.pending_authority 
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+}
+
+pub fn handler<'info>(ctx: Context<AcceptMarketplaceAuthority>) -> Result<()> {
+    let previous_authority = ctx.accounts.marketplace.authority;
+
+    (*ctx.accounts.marketplace).authority = ctx.accounts.signer.key();
+    (*ctx.accounts.marketplace).pending_authority = Pubkey::default();
+
+    emit_cpi!(MarketplaceAuthorityAccepted {
+        marketplace: ctx.accounts.marketplace.key(),
+        previous_authority,
+        authority: ctx.accounts.signer.key(),
+    });
+    
+    Ok(())
+}
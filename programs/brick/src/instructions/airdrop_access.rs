@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -10,6 +11,7 @@ use {
     }
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AirdropAccess<'info> {
     pub system_program: Program<'info, System>,
@@ -25,7 +27,8 @@ pub struct AirdropAccess<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            signer.key().as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
         constraint = signer.key() == marketplace.authority
@@ -55,10 +58,11 @@ pub struct AirdropAccess<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<AirdropAccess>) -> Result<()> {
-    let signer_key = ctx.accounts.signer.key();
+    let marketplace_id = ctx.accounts.marketplace.id.to_le_bytes();
     let marketplace_seeds = &[
         b"marketplace".as_ref(),
-        signer_key.as_ref(),
+        ctx.accounts.marketplace.creator.as_ref(),
+        marketplace_id.as_ref(),
         &[ctx.accounts.marketplace.bumps.bump],
     ];
 
@@ -74,6 +78,12 @@ pub fn handler<'info>(ctx: Context<AirdropAccess>) -> Result<()> {
         ),
         1
     ).map_err(|_| ErrorCode::MintToError)?;
-    
+
+    emit_cpi!(AccessGranted {
+        marketplace: ctx.accounts.marketplace.key(),
+        user: ctx.accounts.receiver.key(),
+        access_mint: ctx.accounts.access_mint.key(),
+    });
+
     Ok(())
 }
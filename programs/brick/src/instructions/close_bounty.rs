@@ -0,0 +1,103 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_interface::{Mint, TokenInterface, TokenAccount, close_account, CloseAccount},
+        token::{transfer, Transfer, ID as TokenProgramV0},
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBounty<'info> {
+    #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_v0: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.authority
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        seeds = [
+            b"bounty_vault".as_ref(), 
+            marketplace.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bounty_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// receives the remaining bounty, the rent goes to the signer
+    #[account(
+        mut,
+        constraint = receiver_vault.mint == reward_mint.key()
+            @ ErrorCode::IncorrectMint,
+    )]
+    pub receiver_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler<'info>(ctx: Context<CloseBounty>) -> Result<()> {
+    // running campaigns can still pay rewards from the bounty vaults
+    if Clock::get()?.unix_timestamp < ctx.accounts.marketplace.campaigns_end_ts {
+        return Err(ErrorCode::OpenPromotion.into());
+    }
+
+    let marketplace = &ctx.accounts.marketplace;
+    let marketplace_id = marketplace.id.to_le_bytes();
+    let marketplace_seeds = &[
+        b"marketplace".as_ref(),
+        marketplace.creator.as_ref(),
+        marketplace_id.as_ref(),
+        &[marketplace.bumps.bump],
+    ];
+
+    if ctx.accounts.bounty_vault.amount > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_v0.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bounty_vault.to_account_info(),
+                    to: ctx.accounts.receiver_vault.to_account_info(),
+                    authority: marketplace.to_account_info(),
+                },
+                &[&marketplace_seeds[..]],
+            ),
+            ctx.accounts.bounty_vault.amount,
+        ).map_err(|_| ErrorCode::TransferError)?;
+    }
+
+    close_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program_v0.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.bounty_vault.to_account_info(),
+                destination: ctx.accounts.signer.to_account_info(),
+                authority: marketplace.to_account_info(),
+            },
+            &[&marketplace_seeds[..]],
+        ),
+    ).map_err(|_| ErrorCode::CloseAccountError)?;
+
+    // vaults of marketplaces migrated from older versions are only counted if the authority passed them
+    (*ctx.accounts.marketplace).bounty_vaults = ctx.accounts.marketplace.bounty_vaults.saturating_sub(1);
+
+    emit_cpi!(BountyVaultClosed {
+        marketplace: ctx.accounts.marketplace.key(),
+        bounty_vault: ctx.accounts.bounty_vault.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+    });
+
+    Ok(())
+}
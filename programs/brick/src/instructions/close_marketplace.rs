@@ -0,0 +1,177 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    crate::utils::assert_derivation,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_interface::{TokenInterface, TokenAccount, close_account, CloseAccount},
+        token::{transfer, Transfer, ID as TokenProgramV0},
+        token_2022::ID as TokenProgram2022,
+    },
+    spl_token_2022::{
+        extension::{StateWithExtensions, BaseStateWithExtensions, mint_close_authority::MintCloseAuthority},
+        state::Mint as Mint2022,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMarketplace<'info> {
+    #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_v0: Interface<'info, TokenInterface>,
+    #[account(address = TokenProgram2022 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_2022: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.authority
+            @ ErrorCode::IncorrectAuthority,
+        close = signer,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    /// CHECK: validated in the marketplace account, closed only if token 2022 allows it
+    #[account(
+        mut,
+        seeds = [
+            b"access_mint".as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = marketplace.bumps.access_mint_bump,
+        constraint = access_mint.key() == marketplace.permission_config.access_mint
+            @ ErrorCode::IncorrectMint
+    )]
+    pub access_mint: UncheckedAccount<'info>,
+}
+
+/// remaining_accounts: for each bounty vault to drain and close, all the open bounty vaults are required
+/// (see Marketplace::bounty_vaults)
+/// 1. bounty vault, pda of (b"bounty_vault", marketplace, mint)
+/// 2. token account owned by the authority that receives the remaining bounty
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseMarketplace<'info>>) -> Result<()> {
+    let marketplace = &ctx.accounts.marketplace;
+
+    if marketplace.open_products > 0 {
+        return Err(ErrorCode::OpenProducts.into());
+    }
+
+    if marketplace.pending_rewards > 0 {
+        return Err(ErrorCode::PendingRewards.into());
+    }
+
+    // running campaigns can still pay rewards from the bounty vaults
+    if Clock::get()?.unix_timestamp < marketplace.campaigns_end_ts {
+        return Err(ErrorCode::OpenPromotion.into());
+    }
+
+    if ctx.remaining_accounts.len() % 2 != 0 {
+        return Err(ErrorCode::IncorrectRemainingAccounts.into());
+    }
+
+    let marketplace_id = marketplace.id.to_le_bytes();
+    let marketplace_seeds = &[
+        b"marketplace".as_ref(),
+        marketplace.creator.as_ref(),
+        marketplace_id.as_ref(),
+        &[marketplace.bumps.bump],
+    ];
+    let mut open_bounty_vaults = marketplace.bounty_vaults;
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let bounty_vault_info = &accounts[0];
+        let receiver_vault_info = &accounts[1];
+
+        let bounty_vault = InterfaceAccount::<TokenAccount>::try_from(bounty_vault_info)?;
+        let receiver_vault = InterfaceAccount::<TokenAccount>::try_from(receiver_vault_info)?;
+
+        assert_derivation(
+            ctx.program_id,
+            bounty_vault_info,
+            &[
+                b"bounty_vault".as_ref(),
+                marketplace.key().as_ref(),
+                bounty_vault.mint.as_ref(),
+            ],
+        ).map_err(|_| ErrorCode::IncorrectATA)?;
+
+        if receiver_vault.owner != marketplace.authority {
+            return Err(ErrorCode::IncorrectAuthority.into());
+        }
+
+        if receiver_vault.mint != bounty_vault.mint {
+            return Err(ErrorCode::IncorrectMint.into());
+        }
+
+        if bounty_vault.amount > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program_v0.to_account_info(),
+                    Transfer {
+                        from: bounty_vault_info.clone(),
+                        to: receiver_vault_info.clone(),
+                        authority: marketplace.to_account_info(),
+                    },
+                    &[&marketplace_seeds[..]],
+                ),
+                bounty_vault.amount,
+            ).map_err(|_| ErrorCode::TransferError)?;
+        }
+
+        close_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_v0.to_account_info(),
+                CloseAccount {
+                    account: bounty_vault_info.clone(),
+                    destination: ctx.accounts.signer.to_account_info(),
+                    authority: marketplace.to_account_info(),
+                },
+                &[&marketplace_seeds[..]],
+            ),
+        ).map_err(|_| ErrorCode::CloseAccountError)?;
+
+        // a vault passed twice fails when it is closed the second time
+        open_bounty_vaults = open_bounty_vaults.saturating_sub(1);
+    }
+
+    if open_bounty_vaults > 0 {
+        return Err(ErrorCode::OpenBountyVaults.into());
+    }
+
+    // token 2022 only allows closing mints with the close authority extension and 0 supply
+    // marketplaces with sellers holding the access token keep the mint
+    let is_access_mint_closable = {
+        let access_mint_data = ctx.accounts.access_mint.try_borrow_data()?;
+        let access_mint = StateWithExtensions::<Mint2022>::unpack(&access_mint_data)
+            .map_err(|_| ErrorCode::IncorrectMint)?;
+
+        access_mint.base.supply == 0 && access_mint.get_extension::<MintCloseAuthority>().is_ok()
+    };
+
+    if is_access_mint_closable {
+        close_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_2022.to_account_info(),
+                CloseAccount {
+                    account: ctx.accounts.access_mint.to_account_info(),
+                    destination: ctx.accounts.signer.to_account_info(),
+                    authority: marketplace.to_account_info(),
+                },
+                &[&marketplace_seeds[..]],
+            ),
+        ).map_err(|_| ErrorCode::CloseAccountError)?;
+    }
+
+    emit_cpi!(MarketplaceClosed {
+        marketplace: ctx.accounts.marketplace.key(),
+        authority: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
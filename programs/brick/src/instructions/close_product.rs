@@ -0,0 +1,189 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    crate::utils::{cmp_pubkeys, burn_nft, BurnNft},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_interface::{TokenInterface, TokenAccount, close_account, CloseAccount},
+        token::ID as TokenProgramV0,
+        token_2022::ID as TokenProgram2022,
+    },
+    spl_token_2022::{
+        extension::{StateWithExtensions, BaseStateWithExtensions, mint_close_authority::MintCloseAuthority},
+        state::Mint as Mint2022,
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseProduct<'info> {
+    #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_v0: Interface<'info, TokenInterface>,
+    #[account(address = TokenProgram2022 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_2022: Interface<'info, TokenInterface>,
+    /// CHECK: Checked with constraints
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<AccountInfo<'info>>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"product".as_ref(),
+            product.first_id.as_ref(),
+            product.second_id.as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = product.bumps.bump,
+        constraint = signer.key() == product.authority
+            @ ErrorCode::IncorrectAuthority,
+        close = signer,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    /// CHECK: token 2022 mint (init_product) or collection nft (init_product_tree), checked in the ix logic
+    #[account(
+        mut,
+        seeds = [
+            b"product_mint".as_ref(),
+            product.key().as_ref(),
+        ],
+        bump = product.bumps.mint_bump,
+        constraint = product_mint.key() == product.product_mint
+            @ ErrorCode::IncorrectMint,
+    )]
+    pub product_mint: UncheckedAccount<'info>,
+    /// only needed if the product was created with init_product_tree
+    #[account(
+        mut,
+        associated_token::mint = product_mint,
+        associated_token::authority = product,
+        associated_token::token_program = token_program_v0
+    )]
+    pub product_mint_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: Handled by cpi
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_ref(),
+            mpl_token_metadata::ID.as_ref(),
+            product_mint.key().as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    pub metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Handled by cpi
+    #[account(
+        mut,
+        seeds = [
+            "metadata".as_ref(),
+            mpl_token_metadata::ID.as_ref(),
+            product_mint.key().as_ref(),
+            "edition".as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    pub master_edition: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler<'info>(ctx: Context<CloseProduct>) -> Result<()> {
+    // open escrows need the product to be released or settled, the units held by buyers to be redeemed
+    // or refunded and the subscriptions sold to expire
+    let product = &ctx.accounts.product;
+    if product.open_escrows > 0
+        || product.outstanding_units > 0
+        || Clock::get()?.unix_timestamp < product.subscriptions_end_ts {
+        return Err(ErrorCode::CannotCloseProduct.into());
+    }
+
+    let marketplace_key = ctx.accounts.marketplace.key();
+    let product_seeds = &[
+        b"product".as_ref(),
+        ctx.accounts.product.first_id.as_ref(),
+        ctx.accounts.product.second_id.as_ref(),
+        marketplace_key.as_ref(),
+        &[ctx.accounts.product.bumps.bump],
+    ];
+
+    if cmp_pubkeys(ctx.accounts.product_mint.owner, &TokenProgram2022) {
+        // buyers still hold tokens that represent a payment
+        let (supply, has_close_authority) = {
+            let product_mint_data = ctx.accounts.product_mint.try_borrow_data()?;
+            let product_mint = StateWithExtensions::<Mint2022>::unpack(&product_mint_data)
+                .map_err(|_| ErrorCode::IncorrectMint)?;
+
+            (product_mint.base.supply, product_mint.get_extension::<MintCloseAuthority>().is_ok())
+        };
+
+        if supply > 0 {
+            return Err(ErrorCode::CannotCloseProduct.into());
+        }
+
+        if has_close_authority {
+            close_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program_2022.to_account_info(),
+                    CloseAccount {
+                        account: ctx.accounts.product_mint.to_account_info(),
+                        destination: ctx.accounts.signer.to_account_info(),
+                        authority: ctx.accounts.product.to_account_info(),
+                    },
+                    &[&product_seeds[..]],
+                ),
+            ).map_err(|_| ErrorCode::CloseAccountError)?;
+        }
+    } else {
+        // buyers still hold receipts verified against this collection, on any of the product trees
+        if ctx.accounts.product.receipts > 0 {
+            return Err(ErrorCode::CannotCloseProduct.into());
+        }
+
+        let token_metadata_program = ctx.accounts.token_metadata_program.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let product_mint_vault = ctx.accounts.product_mint_vault.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let metadata = ctx.accounts.metadata.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let master_edition = ctx.accounts.master_edition.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        // closes the metadata, master edition and the vault that holds the collection nft
+        // rent goes to the product account, then everything is sent to the seller when it is closed
+        burn_nft(
+            CpiContext::new_with_signer(
+                token_metadata_program.clone(),
+                BurnNft {
+                    metadata: metadata.to_account_info(),
+                    owner: ctx.accounts.product.to_account_info(),
+                    mint: ctx.accounts.product_mint.to_account_info(),
+                    token_account: product_mint_vault.to_account_info(),
+                    master_edition: master_edition.to_account_info(),
+                    token_program: ctx.accounts.token_program_v0.to_account_info(),
+                },
+                &[&product_seeds[..]],
+            ),
+        )?;
+    }
+
+    (*ctx.accounts.marketplace).open_products = ctx.accounts.marketplace.open_products.saturating_sub(1);
+
+    emit_cpi!(ProductClosed {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        seller: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
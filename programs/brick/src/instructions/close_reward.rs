@@ -0,0 +1,134 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    crate::utils::is_reward_vault,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_interface::{TokenInterface, TokenAccount, close_account, CloseAccount},
+        token::{transfer, Transfer, ID as TokenProgramV0},
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseReward<'info> {
+    #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_v0: Interface<'info, TokenInterface>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"reward".as_ref(),
+            signer.key().as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = reward.bumps.bump,
+        constraint = signer.key() == reward.authority @ ErrorCode::IncorrectAuthority,
+        close = signer,
+    )]
+    pub reward: Account<'info, Reward>,
+}
+
+/// remaining_accounts: for each reward vault to sweep and close (any number of reward mints)
+/// 1. reward vault, pda of (b"reward_vault", user, marketplace, mint)
+/// 2. token account owned by the user that receives the remaining rewards
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseReward<'info>>) -> Result<()> {
+    // the vaults still hold rewards that are not unlocked
+    if Clock::get()?.unix_timestamp < ctx.accounts.reward.locked_until {
+        return Err(ErrorCode::OpenPromotion.into());
+    }
+
+    if ctx.remaining_accounts.len() % 2 != 0 {
+        return Err(ErrorCode::IncorrectRemainingAccounts.into());
+    }
+
+    let signer_key = ctx.accounts.signer.key().to_bytes();
+    let marketplace_key = ctx.accounts.marketplace.key().to_bytes();
+    let bump_array = [ctx.accounts.reward.bumps.bump];
+    let seeds = &[
+        b"reward".as_ref(),
+        &signer_key,
+        &marketplace_key,
+        &bump_array,
+    ];
+
+    let mut swept: u64 = 0;
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let reward_vault_info = &accounts[0];
+        let receiver_vault_info = &accounts[1];
+
+        let reward_vault = InterfaceAccount::<TokenAccount>::try_from(reward_vault_info)?;
+        let receiver_vault = InterfaceAccount::<TokenAccount>::try_from(receiver_vault_info)?;
+
+        if !is_reward_vault(
+            &reward_vault_info.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.marketplace.key(),
+            &reward_vault.mint,
+        ) {
+            return Err(ErrorCode::IncorrectRewardVault.into());
+        }
+
+        if receiver_vault.owner != ctx.accounts.signer.key() {
+            return Err(ErrorCode::IncorrectAuthority.into());
+        }
+
+        if receiver_vault.mint != reward_vault.mint {
+            return Err(ErrorCode::IncorrectMint.into());
+        }
+
+        if reward_vault.amount > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program_v0.to_account_info(),
+                    Transfer {
+                        from: reward_vault_info.clone(),
+                        to: receiver_vault_info.clone(),
+                        authority: ctx.accounts.reward.to_account_info(),
+                    },
+                    &[&seeds[..]],
+                ),
+                reward_vault.amount,
+            ).map_err(|_| ErrorCode::TransferError)?;
+
+            swept = swept.checked_add(reward_vault.amount).ok_or(ErrorCode::NumericalOverflow)?;
+        }
+
+        close_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_v0.to_account_info(),
+                CloseAccount {
+                    account: reward_vault_info.clone(),
+                    destination: ctx.accounts.signer.to_account_info(),
+                    authority: ctx.accounts.reward.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+        ).map_err(|_| ErrorCode::CloseAccountError)?;
+    }
+
+    // only the rewards of this user count, not the tokens anyone could send to the vaults
+    (*ctx.accounts.marketplace).pending_rewards = ctx.accounts.marketplace.pending_rewards
+        .saturating_sub(ctx.accounts.reward.pending_rewards);
+
+    emit_cpi!(RewardClosed {
+        marketplace: ctx.accounts.marketplace.key(),
+        reward: ctx.accounts.reward.key(),
+        authority: ctx.accounts.signer.key(),
+        swept,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use {
+    crate::{
+        utils::*, 
+        state::*,
+        events::*,
+        error::ErrorCode,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfirmDelivery<'info> {
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    /// the buyer, receives the rent of the escrow accounts
+    #[account(
+        mut,
+        address = escrow.buyer @ ErrorCode::IncorrectAuthority,
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        address = escrow.seller @ ErrorCode::IncorrectAuthority,
+    )]
+    pub seller: Option<SystemAccount<'info>>,
+    #[account(
+        mut,
+        constraint = marketplace_auth.key() == marketplace.authority
+            @ ErrorCode::IncorrectAuthority
+    )]
+    pub marketplace_auth: Option<SystemAccount<'info>>,
+    #[account(
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        address = escrow.product @ ErrorCode::IncorrectEscrow,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            b"escrow".as_ref(),
+            escrow.buyer.as_ref(),
+            escrow.product.as_ref(),
+            escrow.id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump,
+        constraint = escrow.marketplace == marketplace.key()
+            @ ErrorCode::IncorrectEscrow,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        address = escrow.payment_mint @ ErrorCode::IncorrectMint,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow_vault".as_ref(),
+            escrow.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        constraint = seller_transfer_vault.owner == escrow.seller 
+            @ ErrorCode::IncorrectAuthority,
+        constraint = seller_transfer_vault.mint == escrow.payment_mint
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub seller_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// ATA that receives fees
+    #[account(
+        mut,
+        constraint = marketplace_transfer_vault.owner == marketplace.authority 
+            @ ErrorCode::IncorrectAuthority,
+        constraint = marketplace_transfer_vault.mint == escrow.payment_mint 
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub marketplace_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// The buyer confirms the delivery and pays the seller before the timeout.
+pub fn handler<'info>(ctx: Context<ConfirmDelivery>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    if escrow.disputed_ts > 0 && escrow.ruling.is_none() {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+
+    // a settled escrow was already paid out by settle_dispute, it is only closed
+    let (fee, seller_amount) = if escrow.ruling.is_some() {
+        (0, 0)
+    } else {
+        payout_escrow(
+            escrow,
+            ctx.accounts.payment_token_program.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
+            ctx.accounts.escrow_vault.as_ref().map(|vault| vault.to_account_info()),
+            ctx.accounts.marketplace_auth.as_ref().map(|auth| auth.to_account_info()),
+            ctx.accounts.marketplace_transfer_vault.as_ref().map(|vault| vault.to_account_info()),
+            ctx.accounts.seller.as_ref().map(|seller| seller.to_account_info()),
+            ctx.accounts.seller_transfer_vault.as_ref().map(|vault| vault.to_account_info()),
+            ctx.accounts.signer.to_account_info(),
+        )?
+    };
+
+    (*ctx.accounts.product).open_escrows = ctx.accounts.product.open_escrows.saturating_sub(1);
+
+    emit_cpi!(EscrowReleased {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.escrow.product,
+        escrow: ctx.accounts.escrow.key(),
+        buyer: ctx.accounts.escrow.buyer,
+        seller: ctx.accounts.escrow.seller,
+        released_by: ctx.accounts.signer.key(),
+        fee,
+        seller_amount,
+        confirmed: true,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_spl::token_interface::Mint,
@@ -9,17 +10,17 @@ use {
 pub struct EditMarketplaceParams {
     pub fee: u16,
     pub fee_reduction: u16,
-    pub seller_reward: u16,
-    pub buyer_reward: u16,
     pub use_cnfts: bool,
     pub deliver_token: bool,
     pub transferable: bool,
     pub chain_counter: bool,
     pub permissionless: bool,
-    pub rewards_enabled: bool,
     pub fee_payer: PaymentFeePayer,
+    pub vesting_period: i64,
+    pub arbiter: Pubkey,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct EditMarketplace<'info> {
     #[account(mut)]
@@ -28,15 +29,14 @@ pub struct EditMarketplace<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            signer.key().as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
         constraint = signer.key() == marketplace.authority 
             @ ErrorCode::IncorrectAuthority,
     )]
     pub marketplace: Box<Account<'info, Marketplace>>,
-    /// CHECK: no need to validate, marketplace auth is the unique wallet who can call this instruction
-    pub reward_mint: UncheckedAccount<'info>,
     pub discount_mint: Box<InterfaceAccount<'info, Mint>>,
 }
 
@@ -44,10 +44,14 @@ pub fn handler<'info>(
     ctx: Context<EditMarketplace>, 
     params: EditMarketplaceParams,
 ) -> Result<()> {
-    if params.fee_reduction > 10000 || params.fee > 10000 || params.seller_reward > 10000 || params.buyer_reward > 10000 {
+    if params.fee_reduction > 10000 || params.fee > 10000 {
         return Err(ErrorCode::IncorrectFee.into());
     }
 
+    if params.vesting_period < 0 {
+        return Err(ErrorCode::IncorrectVestingPeriod.into());
+    }
+
     (*ctx.accounts.marketplace).token_config = TokenConfig {
         use_cnfts: params.use_cnfts,
         deliver_token: params.deliver_token,
@@ -64,13 +68,19 @@ pub fn handler<'info>(
         fee_reduction: params.fee_reduction,
         fee_payer: params.fee_payer,
     };
-    (*ctx.accounts.marketplace).rewards_config = RewardsConfig {
-        reward_mint: ctx.accounts.reward_mint.key(),
-        bounty_vaults: ctx.accounts.marketplace.rewards_config.bounty_vaults.clone(),
-        seller_reward: params.seller_reward,
-        buyer_reward: params.buyer_reward,
-        rewards_enabled: params.rewards_enabled,
-    };
-    
+    // running campaigns keep the vesting period they were created with
+    (*ctx.accounts.marketplace).vesting_period = params.vesting_period;
+    (*ctx.accounts.marketplace).arbiter = params.arbiter;
+
+    emit_cpi!(MarketplaceEdited {
+        marketplace: ctx.accounts.marketplace.key(),
+        token_config: ctx.accounts.marketplace.token_config.clone(),
+        permission_config: ctx.accounts.marketplace.permission_config.clone(),
+        fees_config: ctx.accounts.marketplace.fees_config.clone(),
+        rewards_config: ctx.accounts.marketplace.rewards_config.clone(),
+        arbiter: ctx.accounts.marketplace.arbiter,
+        vesting_period: ctx.accounts.marketplace.vesting_period,
+    });
+
     Ok(())
 }
\ No newline at end of file
@@ -1,10 +1,29 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
+    crate::utils::{assert_sale_window, assert_escrow_timeout, assert_subscription_period},
     anchor_lang::prelude::*,
     anchor_spl::token_interface::Mint
 };
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EditProductParams {
+    pub product_price: u64,
+    /// None = unlimited units, can not be lower than the units already sold
+    pub max_supply: Option<u64>,
+    /// None = a wallet can buy any number of units, only applies to the next purchases
+    pub max_units_per_wallet: Option<u32>,
+    /// Unix timestamps of the sale window, None = no limit
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    /// Seconds the payment stays in escrow, None = the seller is paid at purchase
+    pub escrow_timeout: Option<i64>,
+    /// Seconds of access each unit bought adds, None = not a subscription
+    pub subscription_period: Option<i64>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct EditProduct<'info> {
     #[account(mut)]
@@ -26,11 +45,39 @@ pub struct EditProduct<'info> {
     pub payment_mint: Box<InterfaceAccount<'info, Mint>>,
 }
 
-pub fn handler<'info>(ctx: Context<EditProduct>, product_price: u64) -> Result<()> {
+pub fn handler<'info>(ctx: Context<EditProduct>, params: EditProductParams) -> Result<()> {
+    assert_sale_window(params.sale_start, params.sale_end)?;
+    assert_escrow_timeout(params.escrow_timeout)?;
+    assert_subscription_period(params.subscription_period)?;
+
+    if let Some(max_supply) = params.max_supply {
+        if max_supply < ctx.accounts.product.sold {
+            return Err(ErrorCode::IncorrectMaxSupply.into());
+        }
+    }
+
     (*ctx.accounts.product).seller_config = SellerConfig {
         payment_mint: ctx.accounts.payment_mint.key(),
-        product_price,
+        product_price: params.product_price,
     };
-    
+    (*ctx.accounts.product).max_supply = params.max_supply;
+    (*ctx.accounts.product).max_units_per_wallet = params.max_units_per_wallet;
+    (*ctx.accounts.product).sale_start = params.sale_start;
+    (*ctx.accounts.product).sale_end = params.sale_end;
+    (*ctx.accounts.product).escrow_timeout = params.escrow_timeout;
+    (*ctx.accounts.product).subscription_period = params.subscription_period;
+
+    emit_cpi!(ProductEdited {
+        product: ctx.accounts.product.key(),
+        payment_mint: ctx.accounts.payment_mint.key(),
+        product_price: params.product_price,
+        max_supply: params.max_supply,
+        max_units_per_wallet: params.max_units_per_wallet,
+        sale_start: params.sale_start,
+        sale_end: params.sale_end,
+        escrow_timeout: params.escrow_timeout,
+        subscription_period: params.subscription_period,
+    });
+
     Ok(())
 }
\ No newline at end of file
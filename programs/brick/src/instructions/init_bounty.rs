@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -9,6 +10,7 @@ use {
     }
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitBounty<'info> {
     pub system_program: Program<'info, System>,
@@ -22,7 +24,8 @@ pub struct InitBounty<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
         constraint = signer.key() == marketplace.authority
@@ -47,12 +50,15 @@ pub struct InitBounty<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<InitBounty>,) -> Result<()> {
-    if ctx.accounts.marketplace.rewards_config.bounty_vaults.len() >= VAULT_COUNT {
-        return Err(ErrorCode::VaultsVectorFull.into());
-    }
+    (*ctx.accounts.marketplace).bounty_vaults = ctx.accounts.marketplace.bounty_vaults
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    emit_cpi!(BountyVaultInitialized {
+        marketplace: ctx.accounts.marketplace.key(),
+        bounty_vault: ctx.accounts.bounty_vault.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+    });
 
-    ctx.accounts.marketplace.rewards_config.bounty_vaults.push(ctx.accounts.bounty_vault.key());
-    ctx.accounts.marketplace.bumps.vault_bumps.push(*ctx.bumps.get("bounty_vault").unwrap());
-    
     Ok(())
 }
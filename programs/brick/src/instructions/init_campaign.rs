@@ -0,0 +1,112 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitCampaignParams {
+    pub id: u64,
+    pub payment_mint: Option<Pubkey>,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub budget: u64,
+    pub seller_reward: u16,
+    pub buyer_reward: u16,
+    pub max_reward_per_purchase: Option<u64>,
+    pub max_reward_per_wallet: Option<u64>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: InitCampaignParams)]
+pub struct InitCampaign<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.authority 
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// the campaign rewards are taken from this vault, it has to be created before with init_bounty
+    #[account(
+        seeds = [
+            b"bounty_vault".as_ref(), 
+            marketplace.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bounty_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = signer,
+        space = CAMPAIGN_SIZE,
+        seeds = [
+            b"campaign".as_ref(),
+            marketplace.key().as_ref(),
+            params.id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+}
+
+pub fn handler<'info>(ctx: Context<InitCampaign>, params: InitCampaignParams) -> Result<()> {
+    if params.seller_reward > 10000 || params.buyer_reward > 10000 {
+        return Err(ErrorCode::IncorrectFee.into());
+    }
+
+    if params.start_ts >= params.end_ts {
+        return Err(ErrorCode::IncorrectCampaignWindow.into());
+    }
+
+    // the bounty vaults stay locked until the last campaign ends
+    (*ctx.accounts.marketplace).campaigns_end_ts = ctx.accounts.marketplace.campaigns_end_ts.max(params.end_ts);
+
+    (*ctx.accounts.campaign).version = CAMPAIGN_VERSION;
+    (*ctx.accounts.campaign).marketplace = ctx.accounts.marketplace.key();
+    (*ctx.accounts.campaign).id = params.id;
+    (*ctx.accounts.campaign).reward_mint = ctx.accounts.reward_mint.key();
+    (*ctx.accounts.campaign).payment_mint = params.payment_mint;
+    (*ctx.accounts.campaign).start_ts = params.start_ts;
+    (*ctx.accounts.campaign).end_ts = params.end_ts;
+    (*ctx.accounts.campaign).budget = params.budget;
+    (*ctx.accounts.campaign).distributed = 0;
+    (*ctx.accounts.campaign).seller_reward = params.seller_reward;
+    (*ctx.accounts.campaign).buyer_reward = params.buyer_reward;
+    (*ctx.accounts.campaign).bump = *ctx.bumps.get("campaign").unwrap();
+    (*ctx.accounts.campaign).max_reward_per_purchase = params.max_reward_per_purchase;
+    (*ctx.accounts.campaign).max_reward_per_wallet = params.max_reward_per_wallet;
+    (*ctx.accounts.campaign).vesting_period = ctx.accounts.marketplace.vesting_period;
+
+    emit_cpi!(CampaignInitialized {
+        marketplace: ctx.accounts.marketplace.key(),
+        campaign: ctx.accounts.campaign.key(),
+        id: params.id,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        payment_mint: params.payment_mint,
+        start_ts: params.start_ts,
+        end_ts: params.end_ts,
+        budget: params.budget,
+        seller_reward: params.seller_reward,
+        buyer_reward: params.buyer_reward,
+        max_reward_per_purchase: params.max_reward_per_purchase,
+        max_reward_per_wallet: params.max_reward_per_wallet,
+        vesting_period: ctx.accounts.marketplace.vesting_period,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitCampaignReward<'info> {
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// user that receives the rewards, can be different from the signer so a buyer
+    /// can create the account of the seller in the same transaction of the purchase
+    pub authority: SystemAccount<'info>,
+    pub campaign: Box<Account<'info, Campaign>>,
+    #[account(
+        init,
+        payer = signer,
+        space = CAMPAIGN_REWARD_SIZE,
+        seeds = [
+            b"campaign_reward".as_ref(),
+            authority.key().as_ref(),
+            campaign.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub campaign_reward: Account<'info, CampaignReward>,
+}
+
+pub fn handler<'info>(ctx: Context<InitCampaignReward>) -> Result<()> {
+    (*ctx.accounts.campaign_reward).version = CAMPAIGN_REWARD_VERSION;
+    (*ctx.accounts.campaign_reward).authority = ctx.accounts.authority.key();
+    (*ctx.accounts.campaign_reward).campaign = ctx.accounts.campaign.key();
+    (*ctx.accounts.campaign_reward).earned = 0;
+    (*ctx.accounts.campaign_reward).withdrawn = 0;
+    (*ctx.accounts.campaign_reward).bump = *ctx.bumps.get("campaign_reward").unwrap();
+
+    emit_cpi!(CampaignRewardInitialized {
+        campaign: ctx.accounts.campaign.key(),
+        campaign_reward: ctx.accounts.campaign_reward.key(),
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
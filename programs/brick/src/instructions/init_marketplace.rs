@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::utils::assert_derivation,
     anchor_lang::prelude::*,
     crate::error::ErrorCode,
@@ -18,20 +19,22 @@ use {
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitMarketplaceParams {
+    pub id: u64,
     pub fee: u16,
     pub fee_reduction: u16,
-    pub seller_reward: u16,
-    pub buyer_reward: u16,
     pub use_cnfts: bool,
     pub deliver_token: bool,
     pub transferable: bool,
     pub chain_counter: bool,
     pub permissionless: bool,
-    pub rewards_enabled: bool,
     pub access_mint_bump: u8,
     pub fee_payer: PaymentFeePayer,
+    pub vesting_period: i64,
+    /// Pubkey::default() = disputes are settled only by the authority
+    pub arbiter: Pubkey,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: InitMarketplaceParams)]
 pub struct InitMarketplace<'info> {
@@ -50,6 +53,7 @@ pub struct InitMarketplace<'info> {
         seeds = [
             b"marketplace".as_ref(),
             signer.key().as_ref(),
+            params.id.to_le_bytes().as_ref(),
         ],
         bump,
     )]
@@ -83,11 +87,16 @@ pub struct InitMarketplace<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<InitMarketplace>, params: InitMarketplaceParams) -> Result<()> {
-    if params.fee_reduction > 10000 || params.fee > 10000 || params.seller_reward > 10000 || params.buyer_reward > 10000 {
+    if params.fee_reduction > 10000 || params.fee > 10000 {
         return Err(ErrorCode::IncorrectFee.into());
     }
 
+    if params.vesting_period < 0 {
+        return Err(ErrorCode::IncorrectVestingPeriod.into());
+    }
+
     let signer_key = ctx.accounts.signer.key();
+    let marketplace_id = params.id.to_le_bytes();
     let marketplace_key = ctx.accounts.marketplace.key();
     let mint_seeds: &[&[u8]] = &[
         b"access_mint",
@@ -102,13 +111,14 @@ pub fn handler<'info>(ctx: Context<InitMarketplace>, params: InitMarketplacePara
     let marketplace_seeds = &[
         b"marketplace".as_ref(),
         signer_key.as_ref(),
-        &[ctx.accounts.marketplace.bumps.bump],
+        marketplace_id.as_ref(),
+        &[*ctx.bumps.get("marketplace").unwrap()],
     ];
 
     mint_builder(
         signer_mint_seeds,
         marketplace_seeds.to_vec(),
-        vec![ExtensionType::NonTransferable],
+        vec![ExtensionType::NonTransferable, ExtensionType::MintCloseAuthority],
         ctx.accounts.system_program.to_account_info(),
         ctx.accounts.token_program_2022.to_account_info(),
         ctx.accounts.rent.to_account_info(),
@@ -118,13 +128,11 @@ pub fn handler<'info>(ctx: Context<InitMarketplace>, params: InitMarketplacePara
         ctx.accounts.rent.clone(),
     )?;
 
-    let mut bounty_vaults: Vec<Pubkey> = Vec::with_capacity(VAULT_COUNT); 
-    bounty_vaults.push(ctx.accounts.bounty_vault.key());
-
-    let mut vault_bumps: Vec<u8> = Vec::with_capacity(VAULT_COUNT); 
-    vault_bumps.push(*ctx.bumps.get("bounty_vault").unwrap());
-
+    (*ctx.accounts.marketplace).version = MARKETPLACE_VERSION;
     (*ctx.accounts.marketplace).authority = ctx.accounts.signer.key();
+    (*ctx.accounts.marketplace).creator = ctx.accounts.signer.key();
+    (*ctx.accounts.marketplace).pending_authority = Pubkey::default();
+    (*ctx.accounts.marketplace).id = params.id;
     (*ctx.accounts.marketplace).token_config = TokenConfig {
         use_cnfts: params.use_cnfts,
         deliver_token: params.deliver_token,
@@ -143,16 +151,30 @@ pub fn handler<'info>(ctx: Context<InitMarketplace>, params: InitMarketplacePara
     };
     (*ctx.accounts.marketplace).rewards_config = RewardsConfig {
         reward_mint: ctx.accounts.reward_mint.key(),
-        bounty_vaults,
-        seller_reward: params.seller_reward,
-        buyer_reward: params.buyer_reward,
-        rewards_enabled: params.rewards_enabled,
     };
     (*ctx.accounts.marketplace).bumps = MarketplaceBumps {
         bump: *ctx.bumps.get("marketplace").unwrap(),
-        vault_bumps,
         access_mint_bump: params.access_mint_bump,
     };
-    
+    (*ctx.accounts.marketplace).open_products = 0;
+    (*ctx.accounts.marketplace).pending_rewards = 0;
+    (*ctx.accounts.marketplace).paused = false;
+    (*ctx.accounts.marketplace).campaigns_end_ts = 0;
+    (*ctx.accounts.marketplace).arbiter = params.arbiter;
+    (*ctx.accounts.marketplace).vesting_period = params.vesting_period;
+    (*ctx.accounts.marketplace).bounty_vaults = 1;
+
+    emit_cpi!(MarketplaceInitialized {
+        marketplace: ctx.accounts.marketplace.key(),
+        authority: ctx.accounts.signer.key(),
+        id: params.id,
+        token_config: ctx.accounts.marketplace.token_config.clone(),
+        permission_config: ctx.accounts.marketplace.permission_config.clone(),
+        fees_config: ctx.accounts.marketplace.fees_config.clone(),
+        rewards_config: ctx.accounts.marketplace.rewards_config.clone(),
+        arbiter: params.arbiter,
+        vesting_period: params.vesting_period,
+    });
+
     Ok(())
 }
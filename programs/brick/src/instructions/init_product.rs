@@ -1,7 +1,8 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
-    crate::utils::{assert_derivation, mint_builder},
+    crate::utils::{assert_derivation, assert_sale_window, assert_escrow_timeout, assert_subscription_period, mint_builder},
     anchor_lang::prelude::*,
     spl_token_2022::extension::ExtensionType,
     anchor_lang::system_program::System,
@@ -20,9 +21,21 @@ pub struct InitProductParams {
     pub first_id: [u8; 32],
     pub second_id: [u8; 32],
     pub product_price: u64,
-    pub product_mint_bump: u8
+    pub product_mint_bump: u8,
+    /// None = unlimited units
+    pub max_supply: Option<u64>,
+    /// None = a wallet can buy any number of units
+    pub max_units_per_wallet: Option<u32>,
+    /// Unix timestamps of the sale window, None = no limit
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    /// Seconds the payment stays in escrow, None = the seller is paid at purchase
+    pub escrow_timeout: Option<i64>,
+    /// Seconds of access each unit bought adds, None = not a subscription
+    pub subscription_period: Option<i64>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: InitProductParams)]
 pub struct InitProduct<'info> {
@@ -36,7 +49,8 @@ pub struct InitProduct<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -85,6 +99,14 @@ pub struct InitProduct<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<InitProduct>, params: InitProductParams) -> Result<()> {
+    if ctx.accounts.marketplace.paused {
+        return Err(ErrorCode::MarketplacePaused.into());
+    }
+
+    assert_sale_window(params.sale_start, params.sale_end)?;
+    assert_escrow_timeout(params.escrow_timeout)?;
+    assert_subscription_period(params.subscription_period)?;
+
     if !ctx.accounts.marketplace.permission_config.permissionless {
         let access_vault = ctx.accounts.access_vault.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
@@ -96,7 +118,11 @@ pub fn handler<'info>(ctx: Context<InitProduct>, params: InitProductParams) -> R
 
     let marketplace_key = ctx.accounts.marketplace.key();
     let product_key = ctx.accounts.product.key();
+
+    (*ctx.accounts.marketplace).open_products = ctx.accounts.marketplace.open_products
+        .checked_add(1).ok_or(ErrorCode::NumericalOverflow)?;
     
+    (*ctx.accounts.product).version = PRODUCT_VERSION;
     (*ctx.accounts.product).authority = ctx.accounts.signer.key();
     (*ctx.accounts.product).first_id = params.first_id;
     (*ctx.accounts.product).second_id = params.second_id; 
@@ -110,6 +136,17 @@ pub fn handler<'info>(ctx: Context<InitProduct>, params: InitProductParams) -> R
         bump: *ctx.bumps.get("product").unwrap(),
         mint_bump: params.product_mint_bump,
     };
+    (*ctx.accounts.product).max_supply = params.max_supply;
+    (*ctx.accounts.product).sold = 0;
+    (*ctx.accounts.product).receipts = 0;
+    (*ctx.accounts.product).open_escrows = 0;
+    (*ctx.accounts.product).outstanding_units = 0;
+    (*ctx.accounts.product).subscriptions_end_ts = 0;
+    (*ctx.accounts.product).max_units_per_wallet = params.max_units_per_wallet;
+    (*ctx.accounts.product).sale_start = params.sale_start;
+    (*ctx.accounts.product).sale_end = params.sale_end;
+    (*ctx.accounts.product).escrow_timeout = params.escrow_timeout;
+    (*ctx.accounts.product).subscription_period = params.subscription_period;
 
     let mint_seeds: &[&[u8]] = &[
         b"product_mint",
@@ -129,11 +166,12 @@ pub fn handler<'info>(ctx: Context<InitProduct>, params: InitProductParams) -> R
         &[ctx.accounts.product.bumps.bump],
     ];
 
-    let extensions = if ctx.accounts.marketplace.token_config.transferable {
-        vec![]
-    } else {
-        vec![ExtensionType::NonTransferable]
-    };
+    // close authority allows the seller to get back the rent when the product is closed
+    // permanent delegate allows refund_purchase and redeem to burn the tokens of the buyers
+    let mut extensions = vec![ExtensionType::MintCloseAuthority, ExtensionType::PermanentDelegate];
+    if !ctx.accounts.marketplace.token_config.transferable {
+        extensions.push(ExtensionType::NonTransferable);
+    }
 
     mint_builder(
         signer_mint_seeds,
@@ -148,5 +186,23 @@ pub fn handler<'info>(ctx: Context<InitProduct>, params: InitProductParams) -> R
         ctx.accounts.rent.clone(),
     )?;
 
+    emit_cpi!(ProductInitialized {
+        marketplace: marketplace_key,
+        product: product_key,
+        seller: ctx.accounts.signer.key(),
+        product_mint: ctx.accounts.product_mint.key(),
+        merkle_tree: Pubkey::default(),
+        first_id: params.first_id,
+        second_id: params.second_id,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        product_price: params.product_price,
+        max_supply: params.max_supply,
+        max_units_per_wallet: params.max_units_per_wallet,
+        sale_start: params.sale_start,
+        sale_end: params.sale_end,
+        escrow_timeout: params.escrow_timeout,
+        subscription_period: params.subscription_period,
+    });
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 use {
     crate::state::*,
-    crate::utils::assert_derivation,
+    crate::events::*,
+    crate::utils::{assert_derivation, assert_sale_window, assert_escrow_timeout, assert_subscription_period},
     crate::error::ErrorCode,
     crate::utils::{create_metadata_accounts_v3, CreateMetadataAccountsV3, create_master_edition_v3, CreateMasterEditionV3},
     anchor_lang::prelude::*,
@@ -21,8 +22,20 @@ pub struct InitProductTreeParams {
     pub name: String,
     pub metadata_url: String,
     pub fee_basis_points: u16,
+    /// None = unlimited units
+    pub max_supply: Option<u64>,
+    /// None = a wallet can buy any number of units
+    pub max_units_per_wallet: Option<u32>,
+    /// Unix timestamps of the sale window, None = no limit
+    pub sale_start: Option<i64>,
+    pub sale_end: Option<i64>,
+    /// Seconds the payment stays in escrow, None = the seller is paid at purchase
+    pub escrow_timeout: Option<i64>,
+    /// Seconds of access each unit bought adds, None = not a subscription
+    pub subscription_period: Option<i64>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: InitProductTreeParams)]
 pub struct InitProductTree<'info> {
@@ -42,7 +55,8 @@ pub struct InitProductTree<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -141,6 +155,14 @@ pub struct InitProductTree<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<InitProductTree>, params: InitProductTreeParams) -> Result<()> {
+    if ctx.accounts.marketplace.paused {
+        return Err(ErrorCode::MarketplacePaused.into());
+    }
+
+    assert_sale_window(params.sale_start, params.sale_end)?;
+    assert_escrow_timeout(params.escrow_timeout)?;
+    assert_subscription_period(params.subscription_period)?;
+
     if !ctx.accounts.marketplace.permission_config.permissionless {
         let access_vault = ctx.accounts.access_vault.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
@@ -157,6 +179,10 @@ pub fn handler<'info>(ctx: Context<InitProductTree>, params: InitProductTreePara
     let marketplace_key = ctx.accounts.marketplace.key();
     let product_key = ctx.accounts.product.key();
 
+    (*ctx.accounts.marketplace).open_products = ctx.accounts.marketplace.open_products
+        .checked_add(1).ok_or(ErrorCode::NumericalOverflow)?;
+
+    (*ctx.accounts.product).version = PRODUCT_VERSION;
     (*ctx.accounts.product).authority = ctx.accounts.signer.key();
     (*ctx.accounts.product).first_id = params.first_id;
     (*ctx.accounts.product).second_id = params.second_id;
@@ -171,6 +197,17 @@ pub fn handler<'info>(ctx: Context<InitProductTree>, params: InitProductTreePara
         bump: *ctx.bumps.get("product").unwrap(),
         mint_bump: *ctx.bumps.get("product_mint").unwrap(),
     };
+    (*ctx.accounts.product).max_supply = params.max_supply;
+    (*ctx.accounts.product).sold = 0;
+    (*ctx.accounts.product).receipts = 0;
+    (*ctx.accounts.product).open_escrows = 0;
+    (*ctx.accounts.product).outstanding_units = 0;
+    (*ctx.accounts.product).subscriptions_end_ts = 0;
+    (*ctx.accounts.product).max_units_per_wallet = params.max_units_per_wallet;
+    (*ctx.accounts.product).sale_start = params.sale_start;
+    (*ctx.accounts.product).sale_end = params.sale_end;
+    (*ctx.accounts.product).escrow_timeout = params.escrow_timeout;
+    (*ctx.accounts.product).subscription_period = params.subscription_period;
 
     let mint_seeds: &[&[u8]] = &[
         b"product_mint",
@@ -272,6 +309,24 @@ pub fn handler<'info>(ctx: Context<InitProductTree>, params: InitProductTreePara
         params.max_buffer_size,
         None,
     )?;
-  
+
+    emit_cpi!(ProductInitialized {
+        marketplace: marketplace_key,
+        product: product_key,
+        seller: ctx.accounts.signer.key(),
+        product_mint: ctx.accounts.product_mint.key(),
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        first_id: ctx.accounts.product.first_id,
+        second_id: ctx.accounts.product.second_id,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        product_price: ctx.accounts.product.seller_config.product_price,
+        max_supply: params.max_supply,
+        max_units_per_wallet: params.max_units_per_wallet,
+        sale_start: params.sale_start,
+        sale_end: params.sale_end,
+        escrow_timeout: params.escrow_timeout,
+        subscription_period: params.subscription_period,
+    });
+
     Ok(())
 }
@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -8,6 +9,7 @@ use {
     }
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitReward<'info> {
     pub system_program: Program<'info, System>,
@@ -20,7 +22,8 @@ pub struct InitReward<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -56,19 +59,22 @@ pub struct InitReward<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<InitReward>) -> Result<()> {
-    let mut vaults: Vec<Pubkey> = Vec::with_capacity(VAULT_COUNT); 
-    vaults.push(ctx.accounts.reward_vault.key());
-
-    let mut vault_bumps: Vec<u8> = Vec::with_capacity(VAULT_COUNT); 
-    vault_bumps.push(*ctx.bumps.get("reward_vault").unwrap());
-
+    (*ctx.accounts.reward).version = REWARD_VERSION;
     (*ctx.accounts.reward).authority = ctx.accounts.signer.key();
     (*ctx.accounts.reward).marketplace =  ctx.accounts.marketplace.key();
-    (*ctx.accounts.reward).reward_vaults = vaults;
     (*ctx.accounts.reward).bumps = RewardBumps {
         bump: *ctx.bumps.get("reward").unwrap(),
-        vault_bumps,
     };
-    
+    (*ctx.accounts.reward).locked_until = 0;
+    (*ctx.accounts.reward).created_ts = Clock::get()?.unix_timestamp;
+    (*ctx.accounts.reward).pending_rewards = 0;
+
+    emit_cpi!(RewardInitialized {
+        marketplace: ctx.accounts.marketplace.key(),
+        reward: ctx.accounts.reward.key(),
+        authority: ctx.accounts.signer.key(),
+        reward_vault: ctx.accounts.reward_vault.key(),
+    });
+
     Ok(())
 }
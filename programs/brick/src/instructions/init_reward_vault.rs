@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -9,6 +10,7 @@ use {
     }
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitRewardVault<'info> {
     pub system_program: Program<'info, System>,
@@ -19,16 +21,15 @@ pub struct InitRewardVault<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
     #[account(
-        mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
     pub marketplace: Box<Account<'info, Marketplace>>,
     #[account(
-        mut,
         seeds = [
             b"reward".as_ref(),
             signer.key().as_ref(),
@@ -37,10 +38,6 @@ pub struct InitRewardVault<'info> {
         bump = reward.bumps.bump,
     )]
     pub reward: Account<'info, Reward>,
-    #[account(
-        constraint = reward_mint.key() == marketplace.rewards_config.reward_mint 
-            @ ErrorCode::IncorrectMint
-    )]
     pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         init,
@@ -60,13 +57,12 @@ pub struct InitRewardVault<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<InitRewardVault>,) -> Result<()> {
-    if ctx.accounts.reward.reward_vaults.len() >= VAULT_COUNT {
-        return Err(ErrorCode::VaultsVectorFull.into());
-    }
+    emit_cpi!(RewardVaultInitialized {
+        reward: ctx.accounts.reward.key(),
+        reward_vault: ctx.accounts.reward_vault.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+    });
 
-    ctx.accounts.reward.reward_vaults.push(ctx.accounts.reward_vault.key());
-    ctx.accounts.reward.bumps.vault_bumps.push(*ctx.bumps.get("reward_vault").unwrap());
-    
     Ok(())
 }
 
@@ -0,0 +1,140 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode as BrickErrorCode,
+    crate::utils::{assert_authority, assert_derivation, assert_versioned, realloc_account},
+    anchor_lang::{prelude::*, error::ErrorCode},
+    anchor_spl::token_interface::TokenAccount,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateMarketplace<'info> {
+    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: old layouts can not be deserialized before the realloc, validated in the ix logic
+    #[account(mut, owner = crate::ID)]
+    pub marketplace: UncheckedAccount<'info>,
+}
+
+/// Version 2 removes the vault vectors, version 1 is read as MarketplaceV1 and its bounty vaults are counted
+/// from the stored vector. Version 4 added the vesting period in the middle of the rewards config, version 6
+/// moves it to the end of the account, removes the unused seller_reward, buyer_reward and rewards_enabled
+/// and counts the bounty vaults. Versions 2 and 3 are read as MarketplaceV3, versions 4 and 5 as MarketplaceV5.
+/// remaining_accounts: when migrating from versions 2 to 5, which did not store the bounty vaults
+/// 1. bounty vault of the reward mint, pda of (b"bounty_vault", marketplace, reward_mint), created with the
+/// marketplace, counted unless it was closed
+/// 2. every other bounty vault of the marketplace, pda of (b"bounty_vault", marketplace, mint)
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, MigrateMarketplace<'info>>) -> Result<()> {
+    let marketplace_info = ctx.accounts.marketplace.to_account_info();
+    assert_versioned(&marketplace_info, UNVERSIONED_MARKETPLACE_SIZE)?;
+    let previous_version = marketplace_info.try_borrow_data()?.get(8).copied().unwrap_or_default();
+
+    realloc_account(
+        &marketplace_info,
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        MARKETPLACE_SIZE,
+    )?;
+
+    let mut marketplace = {
+        let data = marketplace_info.try_borrow_data()?;
+
+        // version 1 stored the vaults in vectors, it has its own layout
+        if data.len() > 8 && data[8] < 2 {
+            if data[..8] != Marketplace::DISCRIMINATOR {
+                return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+
+            Marketplace::from(MarketplaceV1::deserialize(&mut &data[8..])?)
+        } else if data.len() > 8 && data[8] < 4 {
+            // versions 2 and 3 did not have the vesting period
+            if data[..8] != Marketplace::DISCRIMINATOR {
+                return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+
+            Marketplace::from(MarketplaceV3::deserialize(&mut &data[8..])?)
+        } else if data.len() > 8 && data[8] < 6 {
+            // versions 4 and 5 had the vesting period and the unused reward fields in the rewards config,
+            // version 4 did not have the arbiter and the current layout is smaller than version 5,
+            // the missing bytes are read as zeros (Pubkey::default())
+            if data[..8] != Marketplace::DISCRIMINATOR {
+                return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+
+            let mut legacy_data = data[8..].to_vec();
+            legacy_data.resize(legacy_data.len().max(MarketplaceV5::INIT_SPACE), 0);
+
+            Marketplace::from(MarketplaceV5::deserialize(&mut &legacy_data[..])?)
+        } else {
+            Marketplace::try_deserialize(&mut &data[..])?
+        }
+    };
+    assert_authority(&marketplace.authority, &ctx.accounts.signer.key())?;
+    assert_derivation(
+        ctx.program_id,
+        &marketplace_info,
+        &[
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+    )?;
+
+    if (2..6).contains(&previous_version) {
+        let (reward_bounty_vault_info, bounty_vault_infos) = ctx.remaining_accounts
+            .split_first()
+            .ok_or(BrickErrorCode::IncorrectRemainingAccounts)?;
+
+        assert_derivation(
+            ctx.program_id,
+            reward_bounty_vault_info,
+            &[
+                b"bounty_vault".as_ref(),
+                marketplace_info.key().as_ref(),
+                marketplace.rewards_config.reward_mint.as_ref(),
+            ],
+        ).map_err(|_| BrickErrorCode::IncorrectATA)?;
+
+        let mut bounty_vaults = 0;
+
+        if !reward_bounty_vault_info.data_is_empty() {
+            InterfaceAccount::<TokenAccount>::try_from(reward_bounty_vault_info)?;
+            bounty_vaults += 1;
+        }
+
+        for (index, bounty_vault_info) in bounty_vault_infos.iter().enumerate() {
+            // the accounts before this one, the vault of the reward mint included
+            if ctx.remaining_accounts[..=index].iter().any(|account| account.key == bounty_vault_info.key) {
+                return Err(BrickErrorCode::IncorrectRemainingAccounts.into());
+            }
+
+            let bounty_vault = InterfaceAccount::<TokenAccount>::try_from(bounty_vault_info)?;
+
+            assert_derivation(
+                ctx.program_id,
+                bounty_vault_info,
+                &[
+                    b"bounty_vault".as_ref(),
+                    marketplace_info.key().as_ref(),
+                    bounty_vault.mint.as_ref(),
+                ],
+            ).map_err(|_| BrickErrorCode::IncorrectATA)?;
+
+            bounty_vaults += 1;
+        }
+
+        marketplace.bounty_vaults = bounty_vaults;
+    }
+
+    marketplace.version = MARKETPLACE_VERSION;
+    marketplace.try_serialize(&mut &mut marketplace_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(AccountMigrated {
+        account: marketplace_info.key(),
+        version: MARKETPLACE_VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    crate::utils::{assert_derivation, assert_versioned, cmp_pubkeys, realloc_account},
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigratePayment<'info> {
+    pub system_program: Program<'info, System>,
+    /// the buyer, the payment account is derived from its key
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: used to derive the payment account and read in the ix logic if it is still open,
+    /// closed products can still have payment accounts
+    pub product: UncheckedAccount<'info>,
+    /// CHECK: old layouts can not be deserialized before the realloc, validated in the ix logic
+    #[account(mut, owner = crate::ID)]
+    pub payment: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<MigratePayment>) -> Result<()> {
+    let payment_info = ctx.accounts.payment.to_account_info();
+    assert_versioned(&payment_info, UNVERSIONED_PAYMENT_SIZE)?;
+
+    assert_derivation(
+        ctx.program_id,
+        &payment_info,
+        &[
+            b"payment".as_ref(),
+            ctx.accounts.signer.key().as_ref(),
+            ctx.accounts.product.key().as_ref(),
+        ],
+    )?;
+    realloc_account(
+        &payment_info,
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        PAYMENT_SIZE,
+    )?;
+
+    let mut payment = Payment::try_deserialize(&mut &payment_info.try_borrow_data()?[..])?;
+
+    // versions 1 and 2 did not record the price paid nor the subscription time granted, the units are
+    // valued at the current product price and period, the product has to be migrated first. Closed products can not be refunded, nothing to record.
+    let product_info = ctx.accounts.product.to_account_info();
+    if payment.version < 3 && payment.units > 0 && cmp_pubkeys(product_info.owner, ctx.program_id) {
+        let product = Product::try_deserialize(&mut &product_info.try_borrow_data()?[..])?;
+
+        payment.paid = product.seller_config.product_price
+            .checked_mul(payment.units.into())
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        payment.subscription_granted = product.subscription_period
+            .map_or(Some(0), |subscription_period| subscription_period.checked_mul(payment.units.into()))
+            .ok_or(ErrorCode::NumericalOverflow)? as u64;
+    }
+
+    payment.version = PAYMENT_VERSION;
+    payment.try_serialize(&mut &mut payment_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(AccountMigrated {
+        account: payment_info.key(),
+        version: PAYMENT_VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::utils::{assert_authority, assert_derivation, assert_versioned, cmp_pubkeys, realloc_account},
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateProduct<'info> {
+    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: old layouts can not be deserialized before the realloc, validated in the ix logic
+    #[account(mut, owner = crate::ID)]
+    pub product: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<MigrateProduct>) -> Result<()> {
+    let product_info = ctx.accounts.product.to_account_info();
+    assert_versioned(&product_info, UNVERSIONED_PRODUCT_SIZE)?;
+
+    realloc_account(
+        &product_info,
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        PRODUCT_SIZE,
+    )?;
+
+    let mut product = Product::try_deserialize(&mut &product_info.try_borrow_data()?[..])?;
+    assert_authority(&product.authority, &ctx.accounts.signer.key())?;
+    assert_derivation(
+        ctx.program_id,
+        &product_info,
+        &[
+            b"product".as_ref(),
+            product.first_id.as_ref(),
+            product.second_id.as_ref(),
+            product.marketplace.as_ref(),
+        ],
+    )?;
+
+    // receipts minted before version 6 were not counted, every unit sold by a tree product is assumed
+    // to have one, each redeem or refund of a receipt takes one back
+    if product.version < 6 && !cmp_pubkeys(&product.merkle_tree, &Pubkey::default()) {
+        product.receipts = product.sold;
+    }
+
+    // units could not be redeemed before version 6, every unit sold is still held by its buyer
+    if product.version < 6 {
+        product.outstanding_units = product.sold;
+    }
+
+    product.version = PRODUCT_VERSION;
+    product.try_serialize(&mut &mut product_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(AccountMigrated {
+        account: product_info.key(),
+        version: PRODUCT_VERSION,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::utils::{assert_authority, assert_derivation, assert_versioned, realloc_account},
+    anchor_lang::{prelude::*, error::ErrorCode},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateReward<'info> {
+    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: old layouts can not be deserialized before the realloc, validated in the ix logic
+    #[account(mut, owner = crate::ID)]
+    pub reward: UncheckedAccount<'info>,
+}
+
+/// Version 2 removes the vault vectors, the version 1 layout is read as RewardV1 and rewritten.
+pub fn handler<'info>(ctx: Context<MigrateReward>) -> Result<()> {
+    let reward_info = ctx.accounts.reward.to_account_info();
+    assert_versioned(&reward_info, UNVERSIONED_REWARD_SIZE)?;
+
+    realloc_account(
+        &reward_info,
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        REWARD_SIZE,
+    )?;
+
+    let mut reward = {
+        let data = reward_info.try_borrow_data()?;
+
+        // version 1 stored the vaults in vectors, it has its own layout
+        if data.len() > 8 && data[8] < 2 {
+            if data[..8] != Reward::DISCRIMINATOR {
+                return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+
+            Reward::from(RewardV1::deserialize(&mut &data[8..])?)
+        } else {
+            Reward::try_deserialize(&mut &data[..])?
+        }
+    };
+    assert_authority(&reward.authority, &ctx.accounts.signer.key())?;
+    assert_derivation(
+        ctx.program_id,
+        &reward_info,
+        &[
+            b"reward".as_ref(),
+            reward.authority.as_ref(),
+            reward.marketplace.as_ref(),
+        ],
+    )?;
+
+    reward.version = REWARD_VERSION;
+    reward.try_serialize(&mut &mut reward_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(AccountMigrated {
+        account: reward_info.key(),
+        version: REWARD_VERSION,
+    });
+
+    Ok(())
+}
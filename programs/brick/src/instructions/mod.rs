@@ -1,35 +1,81 @@
 pub mod accept_access;
+pub mod accept_marketplace_authority;
 pub mod airdrop_access;
+pub mod close_bounty;
+pub mod close_marketplace;
+pub mod close_product;
+pub mod close_reward;
+pub mod confirm_delivery;
 pub mod edit_marketplace;
 pub mod edit_product;
 pub mod init_bounty;
+pub mod init_campaign;
+pub mod init_campaign_reward;
 pub mod init_marketplace;
 pub mod init_product_tree;
 pub mod init_product;
 pub mod init_reward;
 pub mod init_reward_vault;
+pub mod migrate_marketplace;
+pub mod migrate_payment;
+pub mod migrate_product;
+pub mod migrate_reward;
+pub mod open_dispute;
+pub mod propose_marketplace_authority;
+pub mod redeem;
+pub mod refund_purchase;
 pub mod register_buy_cnft;
 pub mod register_buy_counter;
+pub mod register_buy_escrow;
 pub mod register_buy_token;
 pub mod register_buy;
+pub mod release_escrow;
 pub mod request_access;
+pub mod set_marketplace_pause;
+pub mod settle_dispute;
 pub mod update_tree;
+pub mod verify_subscription;
+pub mod withdraw_bounty;
 pub mod withdraw_reward;
+pub mod withdraw_rewards;
 
 pub use accept_access::*;
+pub use accept_marketplace_authority::*;
 pub use airdrop_access::*;
+pub use close_bounty::*;
+pub use close_marketplace::*;
+pub use close_product::*;
+pub use close_reward::*;
+pub use confirm_delivery::*;
 pub use edit_marketplace::*;
 pub use edit_product::*;
 pub use init_product_tree::*;
 pub use init_bounty::*;
+pub use init_campaign::*;
+pub use init_campaign_reward::*;
 pub use init_marketplace::*;
 pub use init_product::*;
 pub use init_reward::*;
 pub use init_reward_vault::*;
+pub use migrate_marketplace::*;
+pub use migrate_payment::*;
+pub use migrate_product::*;
+pub use migrate_reward::*;
+pub use open_dispute::*;
+pub use propose_marketplace_authority::*;
+pub use redeem::*;
+pub use refund_purchase::*;
 pub use register_buy_cnft::*;
 pub use register_buy_counter::*;
+pub use register_buy_escrow::*;
 pub use register_buy_token::*;
 pub use register_buy::*;
+pub use release_escrow::*;
 pub use request_access::*;
+pub use set_marketplace_pause::*;
+pub use settle_dispute::*;
 pub use update_tree::*;
-pub use withdraw_reward::*;
\ No newline at end of file
+pub use verify_subscription::*;
+pub use withdraw_bounty::*;
+pub use withdraw_reward::*;
+pub use withdraw_rewards::*;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        address = escrow.buyer @ ErrorCode::IncorrectAuthority,
+    )]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow".as_ref(),
+            escrow.buyer.as_ref(),
+            escrow.product.as_ref(),
+            escrow.id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+}
+
+pub fn handler<'info>(ctx: Context<OpenDispute>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // after release_ts anyone can pay the seller, the buyer has to complain before
+    if now >= ctx.accounts.escrow.release_ts {
+        return Err(ErrorCode::DisputeWindowClosed.into());
+    }
+
+    if ctx.accounts.escrow.disputed_ts > 0 {
+        return Err(ErrorCode::EscrowDisputed.into());
+    }
+
+    (*ctx.accounts.escrow).disputed_ts = now;
+
+    emit_cpi!(DisputeOpened {
+        marketplace: ctx.accounts.escrow.marketplace,
+        product: ctx.accounts.escrow.product,
+        escrow: ctx.accounts.escrow.key(),
+        buyer: ctx.accounts.escrow.buyer,
+        seller: ctx.accounts.escrow.seller,
+    });
+
+    Ok(())
+}
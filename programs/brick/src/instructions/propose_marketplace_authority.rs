@@ -0,0 +1,36 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeMarketplaceAuthority<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.authority 
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+}
+
+pub fn handler<'info>(ctx: Context<ProposeMarketplaceAuthority>, new_authority: Pubkey) -> Result<()> {
+    (*ctx.accounts.marketplace).pending_authority = new_authority;
+
+    emit_cpi!(MarketplaceAuthorityProposed {
+        marketplace: ctx.accounts.marketplace.key(),
+        authority: ctx.accounts.marketplace.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
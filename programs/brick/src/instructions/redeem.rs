@@ -0,0 +1,161 @@
+use {
+    crate::{
+        utils::*,
+        state::*,
+        events::*,
+        error::ErrorCode,
+    },
+    anchor_lang::{
+        prelude::*,
+        system_program::System,
+    },
+    anchor_spl::{
+        token_interface::{Mint, TokenInterface, TokenAccount},
+        token_2022::{burn, Burn, ID as TokenProgram2022},
+    },
+    bubblegum_cpi::program::Bubblegum,
+    account_compression_cpi::{program::SplAccountCompression, Noop}
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RedeemParams {
+    /// Units consumed, removed from the buyer payment account. In use_cnfts marketplaces a receipt stands
+    /// for one unit, units has to be 1 to burn it.
+    pub units: u32,
+    /// Receipt to burn in use_cnfts marketplaces, None otherwise.
+    pub cnft_receipt: Option<CnftReceipt>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Redeem<'info> {
+    pub system_program: Program<'info, System>,
+    #[account(address = TokenProgram2022 @ ErrorCode::IncorrectTokenProgram, executable)]
+    pub token_program_2022: Interface<'info, TokenInterface>,
+    pub log_wrapper: Option<Program<'info, Noop>>,
+    pub bubblegum_program: Option<Program<'info, Bubblegum>>,
+    pub compression_program: Option<Program<'info, SplAccountCompression>>,
+    pub buyer: Signer<'info>,
+    #[account(
+        constraint = seller.key() == product.authority
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub seller: Signer<'info>,
+    #[account(
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"product".as_ref(),
+            product.first_id.as_ref(),
+            product.second_id.as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = product.bumps.bump,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    #[account(
+        mut,
+        seeds = [
+            b"payment".as_ref(),
+            buyer.key().as_ref(),
+            product.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Account<'info, Payment>,
+    #[account(
+        mut,
+        address = product.product_mint @ ErrorCode::IncorrectMint,
+    )]
+    pub product_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// holds the product tokens of deliver_token marketplaces
+    #[account(
+        mut,
+        constraint = buyer_token_vault.owner == buyer.key()
+            @ ErrorCode::IncorrectAuthority,
+        constraint = buyer_token_vault.mint == product.product_mint
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub buyer_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: Checked by cpi
+    #[account(mut)]
+    pub tree_authority: Option<UncheckedAccount<'info>>,
+    /// CHECK: Checked by cpi
+    #[account(mut)]
+    pub merkle_tree: Option<UncheckedAccount<'info>>,
+}
+
+/// All the marketplaces consume the units of the payment account, the buyer can buy them again even if
+/// the product has a wallet limit. Token and cnft marketplaces also burn the proof of payment.
+/// Redeemed units can not be refunded, their price, rewards and subscription time leave the payment record,
+/// the subscription keeps its expiry.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+    params: RedeemParams,
+) -> Result<()> {
+    let payment = &ctx.accounts.payment;
+    let units = payment.units
+        .checked_sub(params.units)
+        .ok_or(ErrorCode::RedeemExceedsUnits)?;
+    let redeemed_paid = calculate_share(payment.paid, params.units.into(), payment.units.into())?;
+
+    unregister_payment_record(&mut ctx.accounts.payment, redeemed_paid)?;
+    unregister_subscription(&mut ctx.accounts.payment, params.units, false)?;
+    (*ctx.accounts.payment).units = units;
+    (*ctx.accounts.product).outstanding_units = ctx.accounts.product.outstanding_units
+        .saturating_sub(params.units.into());
+
+    let token_config = &ctx.accounts.marketplace.token_config;
+
+    if token_config.use_cnfts {
+        if params.units != 1 {
+            return Err(ErrorCode::IncorrectReceiptUnits.into());
+        }
+        let cnft_receipt = params.cnft_receipt.ok_or(ErrorCode::CnftReceiptRequired)?;
+
+        burn_cnft_receipt(
+            ctx.accounts.bubblegum_program.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.tree_authority.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.merkle_tree.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.log_wrapper.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.compression_program.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.product,
+            ctx.remaining_accounts,
+            cnft_receipt,
+        )?;
+        (*ctx.accounts.product).receipts = ctx.accounts.product.receipts.saturating_sub(1);
+    } else if token_config.deliver_token {
+        // the buyer signs, no need of the permanent delegate
+        burn(
+            CpiContext::new(
+                ctx.accounts.token_program_2022.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.product_mint.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+                    from: ctx.accounts.buyer_token_vault.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+                    authority: ctx.accounts.buyer.to_account_info(),
+                },
+            ),
+            params.units.into(),
+        ).map_err(|_| ErrorCode::BurnError)?;
+    }
+
+    emit_cpi!(ProductRedeemed {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        buyer: ctx.accounts.buyer.key(),
+        seller: ctx.accounts.seller.key(),
+        units: params.units,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,417 @@
+use {
+    crate::{
+        utils::*, 
+        state::*,
+        events::*,
+        error::ErrorCode,
+    },
+    anchor_lang::{
+        prelude::*,
+        system_program::{transfer as native_transfer, System, Transfer as NativeTransfer},
+    },    
+    anchor_spl::{
+        token_interface::{transfer_checked, Mint, TokenInterface, TokenAccount, TransferChecked},
+        token::{transfer, Transfer, ID as TokenProgramV0},
+        token_2022::ID as TokenProgram2022,
+    },
+    spl_token::native_mint::ID as NativeMint,
+    bubblegum_cpi::program::Bubblegum,
+    account_compression_cpi::{program::SplAccountCompression, Noop}
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RefundPurchaseParams {
+    /// Units given back, removed from the buyer payment account and from the units sold.
+    pub units: u32,
+    /// Amount of the product payment mint returned to the buyer, at most what the buyer paid for the units
+    /// left (Payment::paid) and at least the average price of the units given back.
+    pub amount: u64,
+    /// Receipt to burn in use_cnfts marketplaces when units > 0, None otherwise. A receipt stands for one
+    /// unit, units has to be 1 to burn it.
+    pub cnft_receipt: Option<CnftReceipt>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundPurchase<'info> {
+    pub system_program: Program<'info, System>,
+    #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram, executable)]
+    pub token_program_v0: Interface<'info, TokenInterface>,
+    /// token program that owns the payment mint, legacy or token 2022
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    #[account(address = TokenProgram2022 @ ErrorCode::IncorrectTokenProgram, executable)]
+    pub token_program_2022: Interface<'info, TokenInterface>,
+    pub log_wrapper: Option<Program<'info, Noop>>,
+    pub bubblegum_program: Option<Program<'info, Bubblegum>>,
+    pub compression_program: Option<Program<'info, SplAccountCompression>>,
+    /// the seller, pays the refund
+    #[account(
+        mut,
+        constraint = signer.key() == product.authority
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub signer: Signer<'info>,
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"product".as_ref(),
+            product.first_id.as_ref(),
+            product.second_id.as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = product.bumps.bump,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    #[account(
+        mut,
+        seeds = [
+            b"payment".as_ref(),
+            buyer.key().as_ref(),
+            product.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Account<'info, Payment>,
+    #[account(
+        constraint = payment_mint.key() == product.seller_config.payment_mint
+            @ ErrorCode::IncorrectMint,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = seller_transfer_vault.owner == signer.key()
+            @ ErrorCode::IncorrectAuthority,
+        constraint = seller_transfer_vault.mint == payment_mint.key()
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub seller_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        constraint = buyer_transfer_vault.owner == buyer.key()
+            @ ErrorCode::IncorrectAuthority,
+        constraint = buyer_transfer_vault.mint == payment_mint.key()
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub buyer_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        address = product.product_mint @ ErrorCode::IncorrectMint,
+    )]
+    pub product_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    /// holds the product tokens of deliver_token marketplaces
+    #[account(
+        mut,
+        constraint = buyer_token_vault.owner == buyer.key()
+            @ ErrorCode::IncorrectAuthority,
+        constraint = buyer_token_vault.mint == product.product_mint
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub buyer_token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: Checked by cpi
+    #[account(mut)]
+    pub tree_authority: Option<UncheckedAccount<'info>>,
+    /// CHECK: Checked by cpi
+    #[account(mut)]
+    pub merkle_tree: Option<UncheckedAccount<'info>>,
+    /// campaign recorded in the payment account, required when the refund claws back rewards
+    #[account(
+        mut,
+        constraint = campaign.marketplace == marketplace.key()
+            @ ErrorCode::IncorrectCampaign,
+    )]
+    pub campaign: Option<Box<Account<'info, Campaign>>>,
+    /// CHECK: bounty vault of the campaign reward mint, checked in the ix logic, receives the clawed back
+    /// rewards unless close_bounty closed it
+    #[account(mut)]
+    pub bounty_vault: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [
+            b"reward".as_ref(),
+            product.authority.as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = seller_reward.bumps.bump
+    )]
+    pub seller_reward: Option<Account<'info, Reward>>,
+    #[account(
+        mut,
+        constraint = is_reward_vault(
+            &seller_reward_vault.key(),
+            &product.authority,
+            &marketplace.key(),
+            &seller_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
+    )]
+    pub seller_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        seeds = [
+            b"reward".as_ref(),
+            buyer.key().as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = buyer_reward.bumps.bump
+    )]
+    pub buyer_reward: Option<Account<'info, Reward>>,
+    #[account(
+        mut,
+        constraint = is_reward_vault(
+            &buyer_reward_vault.key(),
+            &buyer.key(),
+            &marketplace.key(),
+            &buyer_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
+    )]
+    pub buyer_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // the validation of these accounts is done in the ix logic
+    #[account(mut)]
+    pub seller_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
+    #[account(mut)]
+    pub buyer_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
+}
+
+/// The seller pays the refund, the marketplace fee of the purchase is not returned. Escrow products are
+/// refunded by settle_dispute, also after the escrow timeout was removed while some escrows are open.
+/// Rewards of the campaign recorded in the payment account are clawed back in proportion to the refunded
+/// amount, only the part that is still in the reward vaults (not withdrawn) can be taken back. Once the
+/// bounty vault of the campaign was closed the rewards are left to their owners.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundPurchase<'info>>,
+    params: RefundPurchaseParams,
+) -> Result<()> {
+    // a refund and a settled dispute would both pay the buyer back
+    if ctx.accounts.product.escrow_timeout.is_some() || ctx.accounts.product.open_escrows > 0 {
+        return Err(ErrorCode::EscrowRefund.into());
+    }
+
+    let payment = &ctx.accounts.payment;
+
+    if params.units > payment.units || params.amount > payment.paid {
+        return Err(ErrorCode::RefundExceedsPurchase.into());
+    }
+
+    // the units given back are refunded at least at the average price the buyer paid for them
+    if params.units > 0 && params.amount < calculate_share(payment.paid, params.units.into(), payment.units.into())? {
+        return Err(ErrorCode::RefundBelowPrice.into());
+    }
+
+    let (seller_bonus, buyer_bonus) = unregister_payment_record(&mut ctx.accounts.payment, params.amount)?;
+    unregister_subscription(&mut ctx.accounts.payment, params.units, true)?;
+
+    (*ctx.accounts.payment).units = ctx.accounts.payment.units - params.units;
+    (*ctx.accounts.product).sold = ctx.accounts.product.sold
+        .checked_sub(params.units.into())
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    (*ctx.accounts.product).outstanding_units = ctx.accounts.product.outstanding_units
+        .saturating_sub(params.units.into());
+
+    // refund
+    if params.amount > 0 {
+        if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
+            native_transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(), 
+                    NativeTransfer {
+                        from: ctx.accounts.signer.to_account_info(),
+                        to: ctx.accounts.buyer.to_account_info(),
+                    }
+                ), 
+                params.amount
+            )?;
+        } else {
+            let seller_transfer_vault = ctx.accounts.seller_transfer_vault.as_ref()
+                .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+            let buyer_transfer_vault = ctx.accounts.buyer_transfer_vault.as_ref()
+                .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+            let (decimals, transfer_fee) = get_transfer_config(&ctx.accounts.payment_mint.to_account_info())?;
+
+            // the seller pays the transfer fee, the buyer receives the whole amount
+            transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.payment_token_program.to_account_info(), 
+                    TransferChecked {
+                        from: seller_transfer_vault.to_account_info(),
+                        mint: ctx.accounts.payment_mint.to_account_info(),
+                        to: buyer_transfer_vault.to_account_info(),
+                        authority: ctx.accounts.signer.to_account_info(),
+                    },
+                ),
+                get_pre_fee_amount(transfer_fee.as_ref(), params.amount)?,
+                decimals,
+            ).map_err(|_| ErrorCode::TransferError)?;
+        }
+    }
+
+    // proof of payment, counter marketplaces only track the units in the payment account
+    let token_config = &ctx.accounts.marketplace.token_config;
+    if token_config.use_cnfts && params.units > 0 {
+        if params.units != 1 {
+            return Err(ErrorCode::IncorrectReceiptUnits.into());
+        }
+        let cnft_receipt = params.cnft_receipt.ok_or(ErrorCode::CnftReceiptRequired)?;
+
+        burn_cnft_receipt(
+            ctx.accounts.bubblegum_program.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.tree_authority.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.merkle_tree.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.log_wrapper.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.compression_program.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.product,
+            ctx.remaining_accounts,
+            cnft_receipt,
+        )?;
+        (*ctx.accounts.product).receipts = ctx.accounts.product.receipts.saturating_sub(1);
+    } else if token_config.deliver_token && params.units > 0 {
+        burn_product_tokens(
+            ctx.accounts.token_program_2022.to_account_info(),
+            ctx.accounts.product_mint.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            ctx.accounts.buyer_token_vault.as_ref().ok_or(ErrorCode::OptionalAccountNotProvided)?.to_account_info(),
+            &ctx.accounts.product,
+            params.units.into(),
+        )?;
+    }
+
+    // rewards
+    let is_rewarded = seller_bonus > 0 || buyer_bonus > 0;
+    let (seller_clawback, buyer_clawback) = if is_rewarded {
+        let campaign = ctx.accounts.campaign.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let campaign_key = campaign.key();
+
+        if !cmp_pubkeys(&campaign_key, &ctx.accounts.payment.campaign) {
+            return Err(ErrorCode::IncorrectCampaign.into());
+        }
+
+        let seller_reward = ctx.accounts.seller_reward.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let buyer_reward = ctx.accounts.buyer_reward.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let seller_campaign_reward = ctx.accounts.seller_campaign_reward.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let buyer_campaign_reward = ctx.accounts.buyer_campaign_reward.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        assert_authority(&seller_campaign_reward.authority, &ctx.accounts.product.authority)?;
+        assert_authority(&buyer_campaign_reward.authority, &ctx.accounts.buyer.key())?;
+
+        if !cmp_pubkeys(&seller_campaign_reward.campaign, &campaign_key)
+            || !cmp_pubkeys(&buyer_campaign_reward.campaign, &campaign_key) {
+            return Err(ErrorCode::IncorrectCampaign.into());
+        }
+
+        let seller_reward_vault = ctx.accounts.seller_reward_vault.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let buyer_reward_vault = ctx.accounts.buyer_reward_vault.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let bounty_vault = ctx.accounts.bounty_vault.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        if !is_bounty_vault(&bounty_vault.key(), &ctx.accounts.marketplace.key(), &campaign.reward_mint) {
+            return Err(ErrorCode::IncorrectBountyVault.into());
+        }
+
+        if !cmp_pubkeys(&seller_reward_vault.mint, &campaign.reward_mint)
+            || !cmp_pubkeys(&buyer_reward_vault.mint, &campaign.reward_mint) {
+            return Err(ErrorCode::IncorrectRewardVault.into());
+        }
+
+        // nothing can be clawed back once the bounty vault is closed
+        let (seller_clawback, buyer_clawback) = if bounty_vault.data_is_empty() {
+            (0, 0)
+        } else {
+            (
+                calculate_clawback(seller_bonus, seller_campaign_reward).min(seller_reward_vault.amount),
+                calculate_clawback(buyer_bonus, buyer_campaign_reward).min(buyer_reward_vault.amount),
+            )
+        };
+
+        let marketplace_key = ctx.accounts.marketplace.key();
+        for (reward, reward_vault, clawback) in [
+            (seller_reward, seller_reward_vault, seller_clawback),
+            (buyer_reward, buyer_reward_vault, buyer_clawback),
+        ] {
+            if clawback == 0 {
+                continue;
+            }
+
+            let reward_seeds = &[
+                b"reward".as_ref(),
+                reward.authority.as_ref(),
+                marketplace_key.as_ref(),
+                &[reward.bumps.bump],
+            ];
+
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program_v0.to_account_info(), 
+                    Transfer {
+                        from: reward_vault.to_account_info(),
+                        to: bounty_vault.to_account_info(),
+                        authority: reward.to_account_info(),
+                    },
+                    &[&reward_seeds[..]],
+                ),
+                clawback,
+            ).map_err(|_| ErrorCode::TransferError)?;
+        }
+
+        (seller_clawback, buyer_clawback)
+    } else {
+        (0, 0)
+    };
+
+    if is_rewarded {
+        let campaign = ctx.accounts.campaign.as_mut()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        unregister_campaign_rewards(
+            campaign,
+            ctx.accounts.seller_reward.as_mut().ok_or(ErrorCode::OptionalAccountNotProvided)?,
+            ctx.accounts.seller_campaign_reward.as_mut().ok_or(ErrorCode::OptionalAccountNotProvided)?,
+            seller_clawback,
+        );
+        unregister_campaign_rewards(
+            campaign,
+            ctx.accounts.buyer_reward.as_mut().ok_or(ErrorCode::OptionalAccountNotProvided)?,
+            ctx.accounts.buyer_campaign_reward.as_mut().ok_or(ErrorCode::OptionalAccountNotProvided)?,
+            buyer_clawback,
+        );
+
+        (*ctx.accounts.marketplace).pending_rewards = ctx.accounts.marketplace.pending_rewards
+            .saturating_sub(seller_clawback)
+            .saturating_sub(buyer_clawback);
+    }
+
+    emit_cpi!(PurchaseRefunded {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        buyer: ctx.accounts.buyer.key(),
+        seller: ctx.accounts.signer.key(),
+        payment_mint: ctx.accounts.payment_mint.key(),
+        units: params.units,
+        amount: params.amount,
+        seller_clawback,
+        buyer_clawback,
+    });
+
+    Ok(())
+}
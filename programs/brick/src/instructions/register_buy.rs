@@ -2,6 +2,7 @@ use {
     crate::{
         utils::*, 
         state::*,
+        events::*,
         error::ErrorCode,
     },
     anchor_lang::{
@@ -10,16 +11,23 @@ use {
     },    
     anchor_spl::{
         token_interface::{Mint, TokenInterface, TokenAccount},
-        token::{transfer, Transfer, ID as TokenProgramV0},
+        token::ID as TokenProgramV0,
     },
     spl_token::native_mint::ID as NativeMint
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterBuy<'info> {
     pub system_program: Program<'info, System>,
     #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram, executable)]
     pub token_program_v0: Interface<'info, TokenInterface>,
+    /// token program that owns the payment mint, legacy or token 2022
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
     pub rent: Sysvar<'info, Rent>,
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -39,7 +47,8 @@ pub struct RegisterBuy<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -50,7 +59,7 @@ pub struct RegisterBuy<'info> {
             b"product".as_ref(),
             product.first_id.as_ref(),
             product.second_id.as_ref(),
-            product.marketplace.as_ref(),
+            marketplace.key().as_ref(),
         ],
         bump = product.bumps.bump,
     )]
@@ -96,10 +105,21 @@ pub struct RegisterBuy<'info> {
             @ ErrorCode::IncorrectATA,
     )]
     pub marketplace_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// campaign that rewards the purchase, the purchase is not rewarded if it is not provided
+    #[account(
+        mut,
+        constraint = campaign.marketplace == marketplace.key()
+            @ ErrorCode::IncorrectCampaign,
+    )]
+    pub campaign: Option<Box<Account<'info, Campaign>>>,
     // this account holds the reward tokens
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_bounty_vault(&bounty_vault.key(), &marketplace.key(), &bounty_vault.mint)
+            @ ErrorCode::IncorrectBountyVault,
+    )]
     pub bounty_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
-    // the validation of these accounts is done in the ix logic
+    // authority checked in ix logic
     #[account(
         mut,
         seeds = [
@@ -110,7 +130,15 @@ pub struct RegisterBuy<'info> {
         bump = seller_reward.bumps.bump
     )]
     pub seller_reward: Option<Account<'info, Reward>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_reward_vault(
+            &seller_reward_vault.key(),
+            &product.authority,
+            &marketplace.key(),
+            &seller_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
+    )]
     pub seller_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     #[account(
         mut,
@@ -122,20 +150,65 @@ pub struct RegisterBuy<'info> {
         bump = buyer_reward.bumps.bump
     )]
     pub buyer_reward: Option<Account<'info, Reward>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_reward_vault(
+            &buyer_reward_vault.key(),
+            &signer.key(),
+            &marketplace.key(),
+            &buyer_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
+    )]
     pub buyer_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // the validation of these accounts is done in the ix logic
+    #[account(mut)]
+    pub seller_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
+    #[account(mut)]
+    pub buyer_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
 }
 
-pub fn handler<'info>(ctx: Context<RegisterBuy>, amount: u32) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<RegisterBuy>,
+    amount: u32,
+    max_total_price: u64,
+    expected_payment_mint: Pubkey,
+) -> Result<()> {
+    if ctx.accounts.marketplace.paused {
+        return Err(ErrorCode::MarketplacePaused.into());
+    }
+
+    assert_sale_active(&ctx.accounts.product)?;
+
+    if ctx.accounts.product.escrow_timeout.is_some() {
+        return Err(ErrorCode::EscrowRequired.into());
+    }
+
     let total_amount = ctx.accounts.product.seller_config.product_price
         .checked_mul(amount.into()).ok_or(ErrorCode::NumericalOverflow)?;
+
+    assert_purchase_price(
+        &ctx.accounts.product.seller_config,
+        &ctx.accounts.marketplace.fees_config,
+        &ctx.accounts.payment_mint.to_account_info(),
+        total_amount,
+        max_total_price,
+        expected_payment_mint,
+    )?;
+
+    // inventory, the whole purchase fails if there are not enough units left
+    register_units_sold(&mut ctx.accounts.product, amount)?;
+
     let marketplace = &ctx.accounts.marketplace;
 
     // this account its a counter of the times a user has purchased a product 
-    (*ctx.accounts.payment).units += amount;
+    register_wallet_units(&mut ctx.accounts.payment, &ctx.accounts.product, amount)?;
+
+    // subscription products extend the access of the buyer
+    let now = Clock::get()?.unix_timestamp;
+    register_subscription(&mut ctx.accounts.payment, &mut ctx.accounts.product, amount, now)?;
 
     // payment and fees
-    if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
+    let (fee, seller_amount) = if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
         let marketplace_auth = ctx.accounts.marketplace_auth.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
         let seller = ctx.accounts.seller.as_ref()
@@ -149,7 +222,7 @@ pub fn handler<'info>(ctx: Context<RegisterBuy>, amount: u32) -> Result<()> {
             marketplace.fees_config.clone(),
             ctx.accounts.product.seller_config.payment_mint,
             total_amount,
-        )?;
+        )?
     } else {
         let marketplace_transfer_vault = ctx.accounts.marketplace_transfer_vault.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
@@ -159,82 +232,52 @@ pub fn handler<'info>(ctx: Context<RegisterBuy>, amount: u32) -> Result<()> {
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
 
         handle_spl(
-            ctx.accounts.token_program_v0.to_account_info(),
+            ctx.accounts.payment_token_program.to_account_info(),
             ctx.accounts.signer.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
             marketplace_transfer_vault.to_account_info(),
             seller_transfer_vault.to_account_info(),
-                buyer_transfer_vault.to_account_info(),
-                marketplace.fees_config.clone(),
-            ctx.accounts.product.seller_config.payment_mint,
-            total_amount,            
-        )?;
-    }
+            buyer_transfer_vault.to_account_info(),
+            marketplace.fees_config.clone(),
+            total_amount,
+        )?
+    };
 
-    // rewards
-    if is_rewards_active(
-        marketplace.rewards_config.clone(), 
-        ctx.accounts.payment_mint.key(),
-        ctx.program_id.key(),
-    ) {
-        let seller_reward = ctx.accounts.seller_reward.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let buyer_reward = ctx.accounts.buyer_reward.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        
-        assert_authority(&seller_reward.authority, &ctx.accounts.product.authority)?;
-        assert_authority(&buyer_reward.authority, &ctx.accounts.signer.key())?;
-
-        let seller_bonus = (marketplace.rewards_config.seller_reward as u128)
-            .checked_mul(ctx.accounts.product.seller_config.product_price as u128)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::NumericalOverflow)? as u64;
-
-        let buyer_bonus = (marketplace.rewards_config.buyer_reward as u128)
-            .checked_mul(ctx.accounts.product.seller_config.product_price as u128)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::NumericalOverflow)? as u64;
-
-        let marketplace_seeds = &[
-            "marketplace".as_ref(),
-            marketplace.authority.as_ref(),
-            &[marketplace.bumps.bump],
-        ];
-        
-        let seller_reward_vault = ctx.accounts.seller_reward_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let buyer_reward_vault = ctx.accounts.buyer_reward_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let bounty_vault = ctx.accounts.bounty_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+    // rewards, only while the campaign is running
+    let (campaign_key, seller_bonus, buyer_bonus) = handle_campaign_rewards(
+        ctx.accounts.token_program_v0.to_account_info(),
+        &mut ctx.accounts.marketplace,
+        ctx.accounts.campaign.as_deref_mut(),
+        ctx.accounts.bounty_vault.as_deref(),
+        ctx.accounts.seller_reward.as_mut(),
+        ctx.accounts.seller_reward_vault.as_deref(),
+        ctx.accounts.buyer_reward.as_mut(),
+        ctx.accounts.buyer_reward_vault.as_deref(),
+        ctx.accounts.seller_campaign_reward.as_deref_mut(),
+        ctx.accounts.buyer_campaign_reward.as_deref_mut(),
+        &ctx.accounts.product.authority,
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.payment_mint.key(),
+        total_amount,
+        now,
+    )?;
+    register_payment_record(&mut ctx.accounts.payment, total_amount, campaign_key, seller_bonus, buyer_bonus)?;
+    (*ctx.accounts.payment).bump = *ctx.bumps.get("payment").unwrap();
 
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_v0.to_account_info(), 
-                Transfer {
-                    from: bounty_vault.to_account_info(),
-                    to: seller_reward_vault.to_account_info(),
-                    authority: marketplace.to_account_info(),
-                },
-                &[&marketplace_seeds[..]],
-            ),
-            seller_bonus,
-        ).map_err(|_| ErrorCode::TransferError)?;
-
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_v0.to_account_info(), 
-                Transfer {
-                    from: bounty_vault.to_account_info(),
-                    to: buyer_reward_vault.to_account_info(),
-                    authority: marketplace.to_account_info()
-                },
-                &[&marketplace_seeds[..]],
-            ),
-            buyer_bonus,
-        ).map_err(|_| ErrorCode::TransferError)?;
-    }
+    emit_cpi!(ProductPurchased {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        buyer: ctx.accounts.signer.key(),
+        seller: ctx.accounts.product.authority,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        units: amount,
+        gross_amount: total_amount,
+        fee,
+        seller_amount,
+        campaign: campaign_key,
+        seller_reward: seller_bonus,
+        buyer_reward: buyer_bonus,
+    });
 
     Ok(())
 }
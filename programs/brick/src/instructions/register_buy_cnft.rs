@@ -2,6 +2,7 @@ use {
     crate::{
         utils::*, 
         state::*,
+        events::*,
         error::ErrorCode,
     },
     anchor_lang::{
@@ -10,7 +11,7 @@ use {
     },    
     anchor_spl::{
         token_interface::{Mint, TokenInterface, TokenAccount},
-        token::{transfer, Transfer, ID as TokenProgramV0},
+        token::ID as TokenProgramV0,
     },
     spl_token::native_mint::ID as NativeMint,
     bubblegum_cpi::{
@@ -23,17 +24,29 @@ use {
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RegisterBuyCnftParams {
+    /// Units bought, always 1 since every unit gets its own receipt.
     pub amount: u32,
+    /// Max amount the buyer accepts to pay, including the marketplace fee when the buyer pays it.
+    pub max_total_price: u64,
+    /// Payment mint the buyer expects, the seller can change it with edit_product.
+    pub expected_payment_mint: Pubkey,
     pub name: String,
     pub symbol: String,
     pub uri: String,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterBuyCnft<'info> {
     pub system_program: Program<'info, System>,
     #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram, executable)]
     pub token_program_v0: Interface<'info, TokenInterface>,
+    /// token program that owns the payment mint, legacy or token 2022
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
     pub rent: Sysvar<'info, Rent>,
     pub log_wrapper: Program<'info, Noop>,
     pub bubblegum_program: Program<'info, Bubblegum>,
@@ -59,7 +72,8 @@ pub struct RegisterBuyCnft<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -70,11 +84,24 @@ pub struct RegisterBuyCnft<'info> {
             b"product".as_ref(),
             product.first_id.as_ref(),
             product.second_id.as_ref(),
-            product.marketplace.as_ref(),
+            marketplace.key().as_ref(),
         ],
         bump = product.bumps.bump,
     )]
     pub product: Box<Account<'info, Product>>,
+    /// counter of the units bought by the signer, needed to enforce max_units_per_wallet
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PAYMENT_SIZE,
+        seeds = [
+            b"payment".as_ref(),
+            signer.key().as_ref(),
+            product.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Box<Account<'info, Payment>>,
     #[account(
         constraint = payment_mint.key() == product.seller_config.payment_mint
             @ ErrorCode::IncorrectMint,
@@ -114,10 +141,21 @@ pub struct RegisterBuyCnft<'info> {
             @ ErrorCode::IncorrectATA,
     )]
     pub marketplace_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// campaign that rewards the purchase, the purchase is not rewarded if it is not provided
+    #[account(
+        mut,
+        constraint = campaign.marketplace == marketplace.key()
+            @ ErrorCode::IncorrectCampaign,
+    )]
+    pub campaign: Option<Box<Account<'info, Campaign>>>,
     // this account holds the reward tokens
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_bounty_vault(&bounty_vault.key(), &marketplace.key(), &bounty_vault.mint)
+            @ ErrorCode::IncorrectBountyVault,
+    )]
     pub bounty_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
-    // the validation of these accounts is done in the ix logic
+    // authority checked in ix logic
     #[account(
         mut,
         seeds = [
@@ -128,7 +166,15 @@ pub struct RegisterBuyCnft<'info> {
         bump = seller_reward.bumps.bump
     )]
     pub seller_reward: Option<Account<'info, Reward>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_reward_vault(
+            &seller_reward_vault.key(),
+            &product.authority,
+            &marketplace.key(),
+            &seller_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
+    )]
     pub seller_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     #[account(
         mut,
@@ -140,8 +186,21 @@ pub struct RegisterBuyCnft<'info> {
         bump = buyer_reward.bumps.bump
     )]
     pub buyer_reward: Option<Account<'info, Reward>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_reward_vault(
+            &buyer_reward_vault.key(),
+            &signer.key(),
+            &marketplace.key(),
+            &buyer_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
+    )]
     pub buyer_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // the validation of these accounts is done in the ix logic
+    #[account(mut)]
+    pub seller_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
+    #[account(mut)]
+    pub buyer_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
 
     /// CHECK: Handled by cpi
     #[account(
@@ -190,13 +249,53 @@ pub struct RegisterBuyCnft<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<RegisterBuyCnft>, params: RegisterBuyCnftParams) -> Result<()> {
+    if ctx.accounts.marketplace.paused {
+        return Err(ErrorCode::MarketplacePaused.into());
+    }
+
+    assert_sale_active(&ctx.accounts.product)?;
+
+    if ctx.accounts.product.escrow_timeout.is_some() {
+        return Err(ErrorCode::EscrowRequired.into());
+    }
+
+    // refund_purchase and redeem burn one receipt per unit
+    if params.amount != 1 {
+        return Err(ErrorCode::IncorrectReceiptUnits.into());
+    }
+
     let total_amount = ctx.accounts.product.seller_config.product_price
         .checked_mul(params.amount.into()).ok_or(ErrorCode::NumericalOverflow)?;
+
+    assert_purchase_price(
+        &ctx.accounts.product.seller_config,
+        &ctx.accounts.marketplace.fees_config,
+        &ctx.accounts.payment_mint.to_account_info(),
+        total_amount,
+        params.max_total_price,
+        params.expected_payment_mint,
+    )?;
+
+    // inventory, the whole purchase fails if there are not enough units left
+    register_units_sold(&mut ctx.accounts.product, params.amount)?;
+
+    // units bought by this wallet, the purchase fails above max_units_per_wallet
+    register_wallet_units(&mut ctx.accounts.payment, &ctx.accounts.product, params.amount)?;
+
+    // subscription products extend the access of the buyer
+    let now = Clock::get()?.unix_timestamp;
+    register_subscription(&mut ctx.accounts.payment, &mut ctx.accounts.product, params.amount, now)?;
+
+    // the receipt minted below has to be burned before the product can be closed
+    (*ctx.accounts.product).receipts = ctx.accounts.product.receipts
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
     let marketplace = &ctx.accounts.marketplace;
     let marketplace_key = ctx.accounts.marketplace.key();
 
     // payment and fees
-    if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
+    let (fee, seller_amount) = if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
         let marketplace_auth = ctx.accounts.marketplace_auth.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
         let seller = ctx.accounts.seller.as_ref()
@@ -210,7 +309,7 @@ pub fn handler<'info>(ctx: Context<RegisterBuyCnft>, params: RegisterBuyCnftPara
             marketplace.fees_config.clone(),
             ctx.accounts.product.seller_config.payment_mint,
             total_amount,
-        )?;
+        )?
     } else {
         let marketplace_transfer_vault = ctx.accounts.marketplace_transfer_vault.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
@@ -220,82 +319,37 @@ pub fn handler<'info>(ctx: Context<RegisterBuyCnft>, params: RegisterBuyCnftPara
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
 
         handle_spl(
-            ctx.accounts.token_program_v0.to_account_info(),
+            ctx.accounts.payment_token_program.to_account_info(),
             ctx.accounts.signer.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
             marketplace_transfer_vault.to_account_info(),
             seller_transfer_vault.to_account_info(),
-                buyer_transfer_vault.to_account_info(),
-                marketplace.fees_config.clone(),
-            ctx.accounts.product.seller_config.payment_mint,
-            total_amount,            
-        )?;
-    }
-
-    // rewards
-    if is_rewards_active(
-        marketplace.rewards_config.clone(), 
-        ctx.accounts.payment_mint.key(),
-        ctx.program_id.key(),
-    ) {
-        let seller_reward = ctx.accounts.seller_reward.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let buyer_reward = ctx.accounts.buyer_reward.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        
-        assert_authority(&seller_reward.authority, &ctx.accounts.product.authority)?;
-        assert_authority(&buyer_reward.authority, &ctx.accounts.signer.key())?;
-
-        let seller_bonus = (marketplace.rewards_config.seller_reward as u128)
-            .checked_mul(ctx.accounts.product.seller_config.product_price as u128)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::NumericalOverflow)? as u64;
-
-        let buyer_bonus = (marketplace.rewards_config.buyer_reward as u128)
-            .checked_mul(ctx.accounts.product.seller_config.product_price as u128)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::NumericalOverflow)? as u64;
-
-        let marketplace_seeds = &[
-            "marketplace".as_ref(),
-            marketplace.authority.as_ref(),
-            &[marketplace.bumps.bump],
-        ];
-        
-        let seller_reward_vault = ctx.accounts.seller_reward_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let buyer_reward_vault = ctx.accounts.buyer_reward_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let bounty_vault = ctx.accounts.bounty_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_v0.to_account_info(), 
-                Transfer {
-                    from: bounty_vault.to_account_info(),
-                    to: seller_reward_vault.to_account_info(),
-                    authority: marketplace.to_account_info(),
-                },
-                &[&marketplace_seeds[..]],
-            ),
-            seller_bonus,
-        ).map_err(|_| ErrorCode::TransferError)?;
+            buyer_transfer_vault.to_account_info(),
+            marketplace.fees_config.clone(),
+            total_amount,
+        )?
+    };
 
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_v0.to_account_info(), 
-                Transfer {
-                    from: bounty_vault.to_account_info(),
-                    to: buyer_reward_vault.to_account_info(),
-                    authority: marketplace.to_account_info()
-                },
-                &[&marketplace_seeds[..]],
-            ),
-            buyer_bonus,
-        ).map_err(|_| ErrorCode::TransferError)?;
-    }
+    // rewards, only while the campaign is running
+    let (campaign_key, seller_bonus, buyer_bonus) = handle_campaign_rewards(
+        ctx.accounts.token_program_v0.to_account_info(),
+        &mut ctx.accounts.marketplace,
+        ctx.accounts.campaign.as_deref_mut(),
+        ctx.accounts.bounty_vault.as_deref(),
+        ctx.accounts.seller_reward.as_mut(),
+        ctx.accounts.seller_reward_vault.as_deref(),
+        ctx.accounts.buyer_reward.as_mut(),
+        ctx.accounts.buyer_reward_vault.as_deref(),
+        ctx.accounts.seller_campaign_reward.as_deref_mut(),
+        ctx.accounts.buyer_campaign_reward.as_deref_mut(),
+        &ctx.accounts.product.authority,
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.payment_mint.key(),
+        total_amount,
+        now,
+    )?;
+    register_payment_record(&mut ctx.accounts.payment, total_amount, campaign_key, seller_bonus, buyer_bonus)?;
+    (*ctx.accounts.payment).bump = *ctx.bumps.get("payment").unwrap();
 
     let product_seeds = &[
         b"product".as_ref(),
@@ -316,7 +370,8 @@ pub fn handler<'info>(ctx: Context<RegisterBuyCnft>, params: RegisterBuyCnftPara
                 collection_metadata: ctx.accounts.metadata.to_account_info(),
                 compression_program: ctx.accounts.compression_program.to_account_info(),
                 edition_account: ctx.accounts.master_edition.to_account_info(),
-                leaf_delegate: ctx.accounts.signer.to_account_info(),
+                // the product can burn the receipt in refund_purchase and redeem
+                leaf_delegate: ctx.accounts.product.to_account_info(),
                 leaf_owner: ctx.accounts.signer.to_account_info(),
                 log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
                 merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
@@ -350,6 +405,21 @@ pub fn handler<'info>(ctx: Context<RegisterBuyCnft>, params: RegisterBuyCnftPara
         }
     )?;
 
+    emit_cpi!(ProductPurchased {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        buyer: ctx.accounts.signer.key(),
+        seller: ctx.accounts.product.authority,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        units: params.amount,
+        gross_amount: total_amount,
+        fee,
+        seller_amount,
+        campaign: campaign_key,
+        seller_reward: seller_bonus,
+        buyer_reward: buyer_bonus,
+    });
+
     Ok(())
 }
 
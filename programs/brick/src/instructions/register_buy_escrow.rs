@@ -0,0 +1,223 @@
+use {
+    crate::{
+        utils::*, 
+        state::*,
+        events::*,
+        error::ErrorCode,
+    },
+    anchor_lang::{
+        prelude::*,
+        system_program::{transfer as native_transfer, System, Transfer as NativeTransfer},
+    },    
+    anchor_spl::token_interface::{transfer_checked, Mint, TokenInterface, TokenAccount, TransferChecked},
+    spl_token::native_mint::ID as NativeMint
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct RegisterBuyEscrow<'info> {
+    pub system_program: Program<'info, System>,
+    /// token program that owns the payment mint, legacy or token 2022
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    pub rent: Sysvar<'info, Rent>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"product".as_ref(),
+            product.first_id.as_ref(),
+            product.second_id.as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = product.bumps.bump,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PAYMENT_SIZE,
+        seeds = [
+            b"payment".as_ref(),
+            signer.key().as_ref(),
+            product.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Account<'info, Payment>,
+    #[account(
+        constraint = payment_mint.key() == product.seller_config.payment_mint
+            @ ErrorCode::IncorrectMint,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = buyer_transfer_vault.owner == signer.key()
+            @ ErrorCode::IncorrectAuthority,
+        constraint = buyer_transfer_vault.mint == product.seller_config.payment_mint.key()
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub buyer_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init,
+        payer = signer,
+        space = ESCROW_SIZE,
+        seeds = [
+            b"escrow".as_ref(),
+            signer.key().as_ref(),
+            product.key().as_ref(),
+            id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// holds the payment when it is not paid in SOL
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            b"escrow_vault".as_ref(),
+            escrow.key().as_ref(),
+        ],
+        bump,
+        token::mint = payment_mint,
+        token::authority = escrow,
+        token::token_program = payment_token_program,
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// The buyer pays the price, plus the fee when the marketplace fee payer is the buyer, to the escrow.
+/// Escrowed purchases use the payment account as counter, they do not deliver tokens or cnfts.
+pub fn handler<'info>(
+    ctx: Context<RegisterBuyEscrow>,
+    id: u64,
+    amount: u32,
+    max_total_price: u64,
+    expected_payment_mint: Pubkey,
+) -> Result<()> {
+    if ctx.accounts.marketplace.paused {
+        return Err(ErrorCode::MarketplacePaused.into());
+    }
+
+    assert_sale_active(&ctx.accounts.product)?;
+
+    let escrow_timeout = ctx.accounts.product.escrow_timeout.ok_or(ErrorCode::EscrowNotEnabled)?;
+
+    let total_amount = ctx.accounts.product.seller_config.product_price
+        .checked_mul(amount.into()).ok_or(ErrorCode::NumericalOverflow)?;
+
+    assert_purchase_price(
+        &ctx.accounts.product.seller_config,
+        &ctx.accounts.marketplace.fees_config,
+        &ctx.accounts.payment_mint.to_account_info(),
+        total_amount,
+        max_total_price,
+        expected_payment_mint,
+    )?;
+
+    register_units_sold(&mut ctx.accounts.product, amount)?;
+    let now = Clock::get()?.unix_timestamp;
+    register_wallet_units(&mut ctx.accounts.payment, &ctx.accounts.product, amount)?;
+    register_subscription(&mut ctx.accounts.payment, &mut ctx.accounts.product, amount, now)?;
+    register_payment_record(&mut ctx.accounts.payment, total_amount, Pubkey::default(), 0, 0)?;
+    (*ctx.accounts.payment).bump = *ctx.bumps.get("payment").unwrap();
+    // the product stays open until the escrow is released or confirmed
+    (*ctx.accounts.product).open_escrows = ctx.accounts.product.open_escrows
+        .checked_add(1)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    // same amounts as an immediate purchase, the fee is split when the escrow is released
+    let (fee, seller_amount) = calculate_transfer_distribution(
+        ctx.accounts.marketplace.fees_config.clone(),
+        ctx.accounts.payment_mint.key(),
+        total_amount,
+    )?;
+    let escrow_amount = fee.checked_add(seller_amount).ok_or(ErrorCode::NumericalOverflow)?;
+
+    if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
+        native_transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(), 
+                NativeTransfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.escrow.to_account_info(),
+                }
+            ), 
+            escrow_amount
+        )?;
+    } else {
+        let buyer_transfer_vault = ctx.accounts.buyer_transfer_vault.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let escrow_vault = ctx.accounts.escrow_vault.as_ref()
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        // the release pays exact amounts, it could not if the mint withholds a transfer fee
+        let (decimals, transfer_fee) = get_transfer_config(&ctx.accounts.payment_mint.to_account_info())?;
+        if transfer_fee.map_or(false, |transfer_fee| u16::from(transfer_fee.transfer_fee_basis_points) > 0) {
+            return Err(ErrorCode::IncorrectTransferFee.into());
+        }
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.payment_token_program.to_account_info(), 
+                TransferChecked {
+                    from: buyer_transfer_vault.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: escrow_vault.to_account_info(),
+                    authority: ctx.accounts.signer.to_account_info(),
+                },
+            ),
+            escrow_amount,
+            decimals,
+        ).map_err(|_| ErrorCode::TransferError)?;
+    }
+
+    let release_ts = now
+        .checked_add(escrow_timeout)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    (*ctx.accounts.escrow).version = ESCROW_VERSION;
+    (*ctx.accounts.escrow).marketplace = ctx.accounts.marketplace.key();
+    (*ctx.accounts.escrow).product = ctx.accounts.product.key();
+    (*ctx.accounts.escrow).buyer = ctx.accounts.signer.key();
+    (*ctx.accounts.escrow).seller = ctx.accounts.product.authority;
+    (*ctx.accounts.escrow).payment_mint = ctx.accounts.payment_mint.key();
+    (*ctx.accounts.escrow).id = id;
+    (*ctx.accounts.escrow).units = amount;
+    (*ctx.accounts.escrow).amount = total_amount;
+    (*ctx.accounts.escrow).fees_config = ctx.accounts.marketplace.fees_config.clone();
+    (*ctx.accounts.escrow).release_ts = release_ts;
+    (*ctx.accounts.escrow).bump = *ctx.bumps.get("escrow").unwrap();
+    (*ctx.accounts.escrow).disputed_ts = 0;
+    (*ctx.accounts.escrow).ruling = None;
+
+    emit_cpi!(EscrowCreated {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        escrow: ctx.accounts.escrow.key(),
+        buyer: ctx.accounts.signer.key(),
+        seller: ctx.accounts.product.authority,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        units: amount,
+        gross_amount: total_amount,
+        escrowed_amount: escrow_amount,
+        release_ts,
+    });
+
+    Ok(())
+}
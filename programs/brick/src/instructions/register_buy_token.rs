@@ -1,6 +1,7 @@
 use {
     crate::{
         state::*,
+        events::*,
         error::ErrorCode,
         utils::*,
     },
@@ -11,16 +12,23 @@ use {
     anchor_spl::{
         token_interface::{MintTo, Mint, TokenInterface, TokenAccount},
         token_2022::{mint_to, ID as TokenProgram2022},
-        token::{transfer, Transfer, ID as TokenProgramV0},
+        token::ID as TokenProgramV0,
     },
     spl_token::native_mint::ID as NativeMint
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterBuyToken<'info> {
     pub system_program: Program<'info, System>,
     #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram, executable)]
     pub token_program_v0: Interface<'info, TokenInterface>,
+    /// token program that owns the payment mint, legacy or token 2022
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
     #[account(address = TokenProgram2022 @ ErrorCode::IncorrectTokenProgram, executable)]
     pub token_program_2022: Interface<'info, TokenInterface>,
     #[account(mut)]
@@ -41,7 +49,8 @@ pub struct RegisterBuyToken<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -57,6 +66,19 @@ pub struct RegisterBuyToken<'info> {
         bump = product.bumps.bump,
     )]
     pub product: Box<Account<'info, Product>>,
+    /// counter of the units bought by the signer, needed to enforce max_units_per_wallet
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = PAYMENT_SIZE,
+        seeds = [
+            b"payment".as_ref(),
+            signer.key().as_ref(),
+            product.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Box<Account<'info, Payment>>,
     #[account(
         mut,
         seeds = [
@@ -106,8 +128,19 @@ pub struct RegisterBuyToken<'info> {
             @ ErrorCode::IncorrectATA,
     )]
     pub marketplace_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// campaign that rewards the purchase, the purchase is not rewarded if it is not provided
+    #[account(
+        mut,
+        constraint = campaign.marketplace == marketplace.key()
+            @ ErrorCode::IncorrectCampaign,
+    )]
+    pub campaign: Option<Box<Account<'info, Campaign>>>,
     // this account holds the reward tokens
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = is_bounty_vault(&bounty_vault.key(), &marketplace.key(), &bounty_vault.mint)
+            @ ErrorCode::IncorrectBountyVault,
+    )]
     pub bounty_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     // authority checked in ix logic
     #[account(
@@ -122,8 +155,12 @@ pub struct RegisterBuyToken<'info> {
     pub seller_reward: Option<Account<'info, Reward>>,
     #[account(
         mut,
-        constraint = seller_reward_vault.mint == payment_mint.key() 
-            @ ErrorCode::IncorrectATA,
+        constraint = is_reward_vault(
+            &seller_reward_vault.key(),
+            &product.authority,
+            &marketplace.key(),
+            &seller_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
     )]
     pub seller_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
     // authority checked in ix logic
@@ -139,15 +176,59 @@ pub struct RegisterBuyToken<'info> {
     pub buyer_reward: Option<Account<'info, Reward>>,
     #[account(
         mut,
-        constraint = buyer_reward_vault.mint == payment_mint.key() 
-            @ ErrorCode::IncorrectATA,
+        constraint = is_reward_vault(
+            &buyer_reward_vault.key(),
+            &signer.key(),
+            &marketplace.key(),
+            &buyer_reward_vault.mint,
+        ) @ ErrorCode::IncorrectRewardVault,
     )]
     pub buyer_reward_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // the validation of these accounts is done in the ix logic
+    #[account(mut)]
+    pub seller_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
+    #[account(mut)]
+    pub buyer_campaign_reward: Option<Box<Account<'info, CampaignReward>>>,
 }
 
-pub fn handler<'info>(ctx: Context<RegisterBuyToken>, amount: u32) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<RegisterBuyToken>,
+    amount: u32,
+    max_total_price: u64,
+    expected_payment_mint: Pubkey,
+) -> Result<()> {
+    if ctx.accounts.marketplace.paused {
+        return Err(ErrorCode::MarketplacePaused.into());
+    }
+
+    assert_sale_active(&ctx.accounts.product)?;
+
+    if ctx.accounts.product.escrow_timeout.is_some() {
+        return Err(ErrorCode::EscrowRequired.into());
+    }
+
     let total_amount = ctx.accounts.product.seller_config.product_price
         .checked_mul(amount.into()).ok_or(ErrorCode::NumericalOverflow)?;
+
+    assert_purchase_price(
+        &ctx.accounts.product.seller_config,
+        &ctx.accounts.marketplace.fees_config,
+        &ctx.accounts.payment_mint.to_account_info(),
+        total_amount,
+        max_total_price,
+        expected_payment_mint,
+    )?;
+
+    // inventory, the whole purchase fails if there are not enough units left
+    register_units_sold(&mut ctx.accounts.product, amount)?;
+
+    // units bought by this wallet, the purchase fails above max_units_per_wallet
+    register_wallet_units(&mut ctx.accounts.payment, &ctx.accounts.product, amount)?;
+
+    // subscription products extend the access of the buyer
+    let now = Clock::get()?.unix_timestamp;
+    register_subscription(&mut ctx.accounts.payment, &mut ctx.accounts.product, amount, now)?;
+
     let marketplace = &ctx.accounts.marketplace;
 
     if !marketplace.token_config.deliver_token {
@@ -155,7 +236,7 @@ pub fn handler<'info>(ctx: Context<RegisterBuyToken>, amount: u32) -> Result<()>
     }
 
     // payment and fees
-    if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
+    let (fee, seller_amount) = if cmp_pubkeys(&ctx.accounts.payment_mint.key(), &NativeMint) {
         let marketplace_auth = ctx.accounts.marketplace_auth.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
         let seller = ctx.accounts.seller.as_ref()
@@ -169,7 +250,7 @@ pub fn handler<'info>(ctx: Context<RegisterBuyToken>, amount: u32) -> Result<()>
             marketplace.fees_config.clone(),
             ctx.accounts.product.seller_config.payment_mint,
             total_amount,
-        )?;
+        )?
     } else {
         let marketplace_transfer_vault = ctx.accounts.marketplace_transfer_vault.as_ref()
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
@@ -179,82 +260,37 @@ pub fn handler<'info>(ctx: Context<RegisterBuyToken>, amount: u32) -> Result<()>
             .ok_or(ErrorCode::OptionalAccountNotProvided)?;
 
         handle_spl(
-            ctx.accounts.token_program_v0.to_account_info(),
+            ctx.accounts.payment_token_program.to_account_info(),
             ctx.accounts.signer.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
             marketplace_transfer_vault.to_account_info(),
             seller_transfer_vault.to_account_info(),
-                buyer_transfer_vault.to_account_info(),
-                marketplace.fees_config.clone(),
-            ctx.accounts.product.seller_config.payment_mint,
-            total_amount,            
-        )?;
-    }
-
-    // rewards
-    if is_rewards_active(
-        marketplace.rewards_config.clone(), 
-        ctx.accounts.payment_mint.key(),
-        ctx.program_id.key(),
-    ) {
-        let seller_reward = ctx.accounts.seller_reward.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let buyer_reward = ctx.accounts.buyer_reward.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-
-        assert_authority(&seller_reward.key(), &ctx.accounts.product.authority)?;
-        assert_authority(&buyer_reward.key(), &ctx.accounts.signer.key())?;
-
-        let seller_bonus = (marketplace.rewards_config.seller_reward as u128)
-            .checked_mul(ctx.accounts.product.seller_config.product_price as u128)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::NumericalOverflow)? as u64;
-
-        let buyer_bonus = (marketplace.rewards_config.buyer_reward as u128)
-            .checked_mul(ctx.accounts.product.seller_config.product_price as u128)
-            .ok_or(ErrorCode::NumericalOverflow)?
-            .checked_div(10000)
-            .ok_or(ErrorCode::NumericalOverflow)? as u64;
-
-        let marketplace_seeds = &[
-            "marketplace".as_ref(),
-            marketplace.authority.as_ref(),
-            &[marketplace.bumps.bump],
-        ];
-
-        let seller_reward_vault = ctx.accounts.seller_reward_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let buyer_reward_vault = ctx.accounts.buyer_reward_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-        let bounty_vault = ctx.accounts.bounty_vault.as_ref()
-            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
-
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_v0.to_account_info(), 
-                Transfer {
-                    from: bounty_vault.to_account_info(),
-                    to: seller_reward_vault.to_account_info(),
-                    authority: marketplace.to_account_info(),
-                },
-                &[&marketplace_seeds[..]],
-            ),
-            seller_bonus,
-        ).map_err(|_| ErrorCode::TransferError)?;
+            buyer_transfer_vault.to_account_info(),
+            marketplace.fees_config.clone(),
+            total_amount,
+        )?
+    };
 
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program_v0.to_account_info(), 
-                Transfer {
-                    from: bounty_vault.to_account_info(),
-                    to: buyer_reward_vault.to_account_info(),
-                    authority: ctx.accounts.marketplace.to_account_info()
-                },
-                &[&marketplace_seeds[..]],
-            ),
-            buyer_bonus,
-        ).map_err(|_| ErrorCode::TransferError)?;
-    }
+    // rewards, only while the campaign is running
+    let (campaign_key, seller_bonus, buyer_bonus) = handle_campaign_rewards(
+        ctx.accounts.token_program_v0.to_account_info(),
+        &mut ctx.accounts.marketplace,
+        ctx.accounts.campaign.as_deref_mut(),
+        ctx.accounts.bounty_vault.as_deref(),
+        ctx.accounts.seller_reward.as_mut(),
+        ctx.accounts.seller_reward_vault.as_deref(),
+        ctx.accounts.buyer_reward.as_mut(),
+        ctx.accounts.buyer_reward_vault.as_deref(),
+        ctx.accounts.seller_campaign_reward.as_deref_mut(),
+        ctx.accounts.buyer_campaign_reward.as_deref_mut(),
+        &ctx.accounts.product.authority,
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.payment_mint.key(),
+        total_amount,
+        now,
+    )?;
+    register_payment_record(&mut ctx.accounts.payment, total_amount, campaign_key, seller_bonus, buyer_bonus)?;
+    (*ctx.accounts.payment).bump = *ctx.bumps.get("payment").unwrap();
 
     let seeds = &[
         b"product".as_ref(),
@@ -276,6 +312,21 @@ pub fn handler<'info>(ctx: Context<RegisterBuyToken>, amount: u32) -> Result<()>
         ),
         amount.into()
     ).map_err(|_| ErrorCode::MintToError)?;
-    
+
+    emit_cpi!(ProductPurchased {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.product.key(),
+        buyer: ctx.accounts.signer.key(),
+        seller: ctx.accounts.product.authority,
+        payment_mint: ctx.accounts.payment_mint.key(),
+        units: amount,
+        gross_amount: total_amount,
+        fee,
+        seller_amount,
+        campaign: campaign_key,
+        seller_reward: seller_bonus,
+        buyer_reward: buyer_bonus,
+    });
+
     Ok(())
 }
\ No newline at end of file
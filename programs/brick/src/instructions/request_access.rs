@@ -1,8 +1,10 @@
 use {
     crate::state::*,
+    crate::events::*,
     anchor_lang::prelude::*,
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RequestAccess<'info> {
     pub system_program: Program<'info, System>,
@@ -13,7 +15,8 @@ pub struct RequestAccess<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -33,9 +36,15 @@ pub struct RequestAccess<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<RequestAccess>) -> Result<()> {
+    (*ctx.accounts.request).version = ACCESS_VERSION;
     (*ctx.accounts.request).authority = ctx.accounts.signer.key();
     (*ctx.accounts.request).marketplace =  ctx.accounts.marketplace.key();
     (*ctx.accounts.request).bump = *ctx.bumps.get("request").unwrap();
-    
+
+    emit_cpi!(AccessRequested {
+        marketplace: ctx.accounts.marketplace.key(),
+        user: ctx.accounts.signer.key(),
+    });
+
     Ok(())
 }
@@ -0,0 +1,35 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMarketplacePause<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.authority 
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+}
+
+pub fn handler<'info>(ctx: Context<SetMarketplacePause>, paused: bool) -> Result<()> {
+    (*ctx.accounts.marketplace).paused = paused;
+
+    emit_cpi!(MarketplacePauseSet {
+        marketplace: ctx.accounts.marketplace.key(),
+        paused,
+    });
+
+    Ok(())
+}
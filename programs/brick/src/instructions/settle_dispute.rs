@@ -0,0 +1,239 @@
+use {
+    crate::{
+        utils::*, 
+        state::*,
+        events::*,
+        error::ErrorCode,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenInterface, TokenAccount},
+    spl_token::native_mint::ID as NativeMint,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleDispute<'info> {
+    #[account(
+        constraint = payment_token_program.key() == *payment_mint.to_account_info().owner
+            @ ErrorCode::IncorrectTokenProgram,
+    )]
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    /// marketplace authority or arbiter
+    pub signer: Signer<'info>,
+    /// receives the refund of SOL escrows and the rent of the escrow vault
+    #[account(
+        mut,
+        address = escrow.buyer @ ErrorCode::IncorrectAuthority,
+    )]
+    pub buyer: SystemAccount<'info>,
+    #[account(
+        mut,
+        address = escrow.seller @ ErrorCode::IncorrectAuthority,
+    )]
+    pub seller: Option<SystemAccount<'info>>,
+    #[account(
+        mut,
+        constraint = marketplace_auth.key() == marketplace.authority
+            @ ErrorCode::IncorrectAuthority
+    )]
+    pub marketplace_auth: Option<SystemAccount<'info>>,
+    #[account(
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+        constraint = signer.key() == marketplace.authority 
+            || (marketplace.arbiter != Pubkey::default() && signer.key() == marketplace.arbiter)
+            @ ErrorCode::IncorrectAuthority,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow".as_ref(),
+            escrow.buyer.as_ref(),
+            escrow.product.as_ref(),
+            escrow.id.to_le_bytes().as_ref(),
+        ],
+        bump = escrow.bump,
+        constraint = escrow.marketplace == marketplace.key()
+            @ ErrorCode::IncorrectEscrow,
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    #[account(
+        mut,
+        address = escrow.product @ ErrorCode::IncorrectEscrow,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    #[account(
+        mut,
+        seeds = [
+            b"payment".as_ref(),
+            escrow.buyer.as_ref(),
+            escrow.product.as_ref(),
+        ],
+        bump = payment.bump,
+    )]
+    pub payment: Box<Account<'info, Payment>>,
+    #[account(
+        address = escrow.payment_mint @ ErrorCode::IncorrectMint,
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [
+            b"escrow_vault".as_ref(),
+            escrow.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        constraint = buyer_transfer_vault.owner == escrow.buyer 
+            @ ErrorCode::IncorrectAuthority,
+        constraint = buyer_transfer_vault.mint == escrow.payment_mint
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub buyer_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        constraint = seller_transfer_vault.owner == escrow.seller 
+            @ ErrorCode::IncorrectAuthority,
+        constraint = seller_transfer_vault.mint == escrow.payment_mint
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub seller_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// ATA that receives fees
+    #[account(
+        mut,
+        constraint = marketplace_transfer_vault.owner == marketplace.authority 
+            @ ErrorCode::IncorrectAuthority,
+        constraint = marketplace_transfer_vault.mint == escrow.payment_mint 
+            @ ErrorCode::IncorrectATA,
+    )]
+    pub marketplace_transfer_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+/// Splits everything the buyer paid to the escrow, buyer_amount is refunded, seller_amount is paid to
+/// the seller and the rest goes to the marketplace as fee.
+/// The units of the purchase are given back in proportion to buyer_amount (rounded down), they leave
+/// the units sold, the buyer payment account and its subscription.
+/// The escrow keeps the ruling, release_escrow or confirm_delivery close it at any moment.
+pub fn handler<'info>(
+    ctx: Context<SettleDispute>,
+    buyer_amount: u64,
+    seller_amount: u64,
+) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    if escrow.disputed_ts == 0 || escrow.ruling.is_some() {
+        return Err(ErrorCode::EscrowNotDisputed.into());
+    }
+
+    let (escrow_fee, escrow_seller_amount) = calculate_transfer_distribution(
+        escrow.fees_config.clone(),
+        escrow.payment_mint,
+        escrow.amount,
+    )?;
+    let escrowed_amount = escrow_fee.checked_add(escrow_seller_amount).ok_or(ErrorCode::NumericalOverflow)?;
+    let fee = escrowed_amount
+        .checked_sub(buyer_amount)
+        .and_then(|amount| amount.checked_sub(seller_amount))
+        .ok_or(ErrorCode::IncorrectRuling)?;
+
+    let is_native = cmp_pubkeys(&escrow.payment_mint, &NativeMint);
+    let escrow_vault = ctx.accounts.escrow_vault.as_ref().map(|vault| vault.to_account_info());
+
+    if buyer_amount > 0 {
+        let buyer_receiver = if is_native {
+            Some(ctx.accounts.buyer.to_account_info())
+        } else {
+            ctx.accounts.buyer_transfer_vault.as_ref().map(|vault| vault.to_account_info())
+        }.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        escrow_transfer(
+            escrow,
+            ctx.accounts.payment_token_program.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
+            escrow_vault.clone(),
+            buyer_receiver,
+            buyer_amount,
+        )?;
+    }
+
+    if seller_amount > 0 {
+        let seller_receiver = if is_native {
+            ctx.accounts.seller.as_ref().map(|seller| seller.to_account_info())
+        } else {
+            ctx.accounts.seller_transfer_vault.as_ref().map(|vault| vault.to_account_info())
+        }.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        escrow_transfer(
+            escrow,
+            ctx.accounts.payment_token_program.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
+            escrow_vault.clone(),
+            seller_receiver,
+            seller_amount,
+        )?;
+    }
+
+    if fee > 0 {
+        let fee_receiver = if is_native {
+            ctx.accounts.marketplace_auth.as_ref().map(|auth| auth.to_account_info())
+        } else {
+            ctx.accounts.marketplace_transfer_vault.as_ref().map(|vault| vault.to_account_info())
+        }.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        escrow_transfer(
+            escrow,
+            ctx.accounts.payment_token_program.to_account_info(),
+            ctx.accounts.payment_mint.to_account_info(),
+            escrow_vault.clone(),
+            fee_receiver,
+            fee,
+        )?;
+    }
+
+    close_escrow_vault(
+        escrow,
+        ctx.accounts.payment_token_program.to_account_info(),
+        escrow_vault,
+        ctx.accounts.buyer.to_account_info(),
+    )?;
+
+    // units refunded by the ruling, the buyer could have redeemed some of them meanwhile
+    let refunded_units = calculate_share(escrow.units.into(), buyer_amount, escrowed_amount)? as u32;
+    let refunded_paid = calculate_share(escrow.amount, buyer_amount, escrowed_amount)?;
+    let payment_units = refunded_units.min(ctx.accounts.payment.units);
+
+    unregister_subscription(&mut ctx.accounts.payment, payment_units, true)?;
+    (*ctx.accounts.product).sold = ctx.accounts.product.sold.saturating_sub(refunded_units.into());
+    (*ctx.accounts.product).outstanding_units = ctx.accounts.product.outstanding_units
+        .saturating_sub(payment_units.into());
+    (*ctx.accounts.payment).units = ctx.accounts.payment.units - payment_units;
+    (*ctx.accounts.payment).paid = ctx.accounts.payment.paid.saturating_sub(refunded_paid);
+
+    let ruling = Ruling {
+        arbiter: ctx.accounts.signer.key(),
+        buyer_amount,
+        seller_amount,
+        fee,
+        settled_ts: Clock::get()?.unix_timestamp,
+    };
+    (*ctx.accounts.escrow).ruling = Some(ruling.clone());
+
+    emit_cpi!(DisputeSettled {
+        marketplace: ctx.accounts.marketplace.key(),
+        product: ctx.accounts.escrow.product,
+        escrow: ctx.accounts.escrow.key(),
+        buyer: ctx.accounts.escrow.buyer,
+        seller: ctx.accounts.escrow.seller,
+        ruling,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
     anchor_lang::prelude::*,
     anchor_lang::system_program::System,
@@ -13,6 +14,7 @@ pub struct UpdateProductTreeParams {
     pub max_buffer_size: u32,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(args: UpdateProductTreeParams)]
 pub struct UpdateProductTree<'info> {
@@ -23,7 +25,8 @@ pub struct UpdateProductTree<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            signer.key().as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
     )]
@@ -89,6 +92,13 @@ pub fn handler(ctx: Context<UpdateProductTree>, params: UpdateProductTreeParams)
         None,
     )?;
     ctx.accounts.product.merkle_tree = ctx.accounts.merkle_tree.key();
-    
+
+    emit_cpi!(TreeUpdated {
+        product: ctx.accounts.product.key(),
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        max_depth: params.max_depth,
+        max_buffer_size: params.max_buffer_size,
+    });
+
     Ok(())
 }
@@ -0,0 +1,58 @@
+use {
+    crate::{state::*, error::ErrorCode},
+    anchor_lang::{
+        prelude::*,
+        solana_program::program::set_return_data,
+    },
+};
+
+#[derive(Accounts)]
+pub struct VerifySubscription<'info> {
+    /// CHECK: only used to derive the payment account
+    pub buyer: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            b"product".as_ref(),
+            product.first_id.as_ref(),
+            product.second_id.as_ref(),
+            product.marketplace.as_ref(),
+        ],
+        bump = product.bumps.bump,
+        constraint = product.subscription_period.is_some()
+            @ ErrorCode::NotASubscription,
+    )]
+    pub product: Box<Account<'info, Product>>,
+    /// CHECK: a buyer that never bought the product has no payment account, an existing one has to be
+    /// owned by the program, deserialized in the ix logic
+    #[account(
+        seeds = [
+            b"payment".as_ref(),
+            buyer.key().as_ref(),
+            product.key().as_ref(),
+        ],
+        bump,
+        constraint = payment.data_is_empty() || payment.owner == &crate::ID
+            @ ErrorCode::IncorrectPaymentOwner,
+    )]
+    pub payment: UncheckedAccount<'info>,
+}
+
+/// Read only, other programs read the SubscriptionStatus from the return data after the cpi.
+/// Payment accounts that do not exist or were not migrated are reported as expired.
+pub fn handler<'info>(ctx: Context<VerifySubscription>) -> Result<()> {
+    let payment_info = ctx.accounts.payment.to_account_info();
+    let expires_at = if payment_info.data_is_empty() {
+        0
+    } else {
+        Payment::try_deserialize(&mut &payment_info.try_borrow_data()?[..])
+            .map_or(0, |payment| payment.expires_at)
+    };
+
+    let status = SubscriptionStatus {
+        active: Clock::get()?.unix_timestamp < expires_at,
+        expires_at,
+    };
+    set_return_data(&status.try_to_vec()?);
+
+    Ok(())
+}
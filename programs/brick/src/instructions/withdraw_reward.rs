@@ -1,6 +1,8 @@
 use {
     crate::state::*,
+    crate::events::*,
     crate::error::ErrorCode,
+    crate::utils::calculate_vested_rewards,
     anchor_lang::prelude::*,
     anchor_spl::token::{transfer, Transfer},
     anchor_spl::{
@@ -9,6 +11,7 @@ use {
     }
 };
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct WithdrawReward<'info> {
     #[account(address = ID @ ErrorCode::IncorrectTokenProgram)]
@@ -19,7 +22,8 @@ pub struct WithdrawReward<'info> {
         mut,
         seeds = [
             b"marketplace".as_ref(),
-            marketplace.authority.as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
         ],
         bump = marketplace.bumps.bump,
         //has_one = governance_mint @ ErrorCode::IncorrectMint,
@@ -37,7 +41,29 @@ pub struct WithdrawReward<'info> {
         constraint = signer.key() == reward.authority @ ErrorCode::IncorrectAuthority
     )]
     pub reward: Account<'info, Reward>,
-    /// CHECK: validated in the governance account contraints
+    #[account(
+        seeds = [
+            b"campaign".as_ref(),
+            marketplace.key().as_ref(),
+            campaign.id.to_le_bytes().as_ref(),
+        ],
+        bump = campaign.bump,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+    #[account(
+        mut,
+        seeds = [
+            b"campaign_reward".as_ref(),
+            signer.key().as_ref(),
+            campaign.key().as_ref(),
+        ],
+        bump = campaign_reward.bump,
+        constraint = signer.key() == campaign_reward.authority @ ErrorCode::IncorrectAuthority
+    )]
+    pub campaign_reward: Account<'info, CampaignReward>,
+    #[account(
+        constraint = reward_mint.key() == campaign.reward_mint @ ErrorCode::IncorrectMint
+    )]
     pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
     #[account(
         mut,
@@ -53,11 +79,7 @@ pub struct WithdrawReward<'info> {
             marketplace.key().as_ref(),
             reward_mint.key().as_ref()
         ],
-        bump = get_bump(
-            reward_vault.key(), 
-            reward.bumps.clone(), 
-            reward.reward_vaults.clone()
-        ),
+        bump,
         constraint = reward_vault.owner == reward.key() 
             @ ErrorCode::IncorrectAuthority,
         constraint = reward_vault.mint == reward_mint.key() 
@@ -67,10 +89,20 @@ pub struct WithdrawReward<'info> {
 }
 
 pub fn handler<'info>(ctx: Context<WithdrawReward>) -> Result<()> {
-    if ctx.accounts.marketplace.rewards_config.rewards_enabled {
+    // each campaign unlocks its own rewards, other campaigns can still be running
+    let now = Clock::get()?.unix_timestamp;
+    if now < ctx.accounts.campaign.end_ts {
         return Err(ErrorCode::OpenPromotion.into());
-    } 
+    }
+
+    // a reward account created after the campaign end can not hold its rewards, close_reward swept them
+    if ctx.accounts.reward.created_ts >= ctx.accounts.campaign.end_ts {
+        return Err(ErrorCode::RewardsAlreadySwept.into());
+    }
 
+    let vested = calculate_vested_rewards(&ctx.accounts.campaign, ctx.accounts.campaign_reward.earned, now)?;
+    // a refund clawback can lower earned below what was already withdrawn
+    let amount = vested.saturating_sub(ctx.accounts.campaign_reward.withdrawn);
     let signer_key = ctx.accounts.signer.key().to_bytes();
     let marketplace_key = ctx.accounts.marketplace.key().to_bytes();
     let bump_array = [ctx.accounts.reward.bumps.bump];
@@ -91,14 +123,23 @@ pub fn handler<'info>(ctx: Context<WithdrawReward>) -> Result<()> {
             },
             &[&seeds[..]],
         ),
-        ctx.accounts.reward_vault.amount,
+        amount,
     ).map_err(|_| ErrorCode::TransferError)?;
-    
+
+    (*ctx.accounts.campaign_reward).withdrawn = ctx.accounts.campaign_reward.withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    (*ctx.accounts.reward).pending_rewards = ctx.accounts.reward.pending_rewards.saturating_sub(amount);
+    (*ctx.accounts.marketplace).pending_rewards = ctx.accounts.marketplace.pending_rewards.saturating_sub(amount);
+
+    emit_cpi!(RewardWithdrawn {
+        marketplace: ctx.accounts.marketplace.key(),
+        campaign: ctx.accounts.campaign.key(),
+        reward: ctx.accounts.reward.key(),
+        authority: ctx.accounts.signer.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount,
+    });
+
     Ok(())
 }
-
-pub fn get_bump(address: Pubkey, reward_bumps: RewardBumps, reward_vaults: Vec<Pubkey>) -> u8 {
-    reward_vaults.iter().position(|&r| r == address)
-        .map(|index| reward_bumps.vault_bumps[index])
-        .unwrap_or(0)
-}
\ No newline at end of file
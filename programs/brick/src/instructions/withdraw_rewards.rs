@@ -0,0 +1,171 @@
+use {
+    crate::state::*,
+    crate::events::*,
+    crate::error::ErrorCode,
+    crate::utils::{assert_ata, calculate_vested_rewards, cmp_pubkeys, is_reward_vault},
+    anchor_lang::{prelude::*, system_program::System},
+    anchor_spl::{
+        associated_token::{create, AssociatedToken, Create},
+        token_interface::{Mint, TokenInterface},
+        token::{transfer, Transfer, ID as TokenProgramV0},
+    },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawRewards<'info> {
+    pub system_program: Program<'info, System>,
+    #[account(address = TokenProgramV0 @ ErrorCode::IncorrectTokenProgram)]
+    pub token_program_v0: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// pays the receiver ATAs that do not exist
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            b"marketplace".as_ref(),
+            marketplace.creator.as_ref(),
+            marketplace.id.to_le_bytes().as_ref(),
+        ],
+        bump = marketplace.bumps.bump,
+    )]
+    pub marketplace: Box<Account<'info, Marketplace>>,
+    #[account(
+        mut,
+        seeds = [
+            b"reward".as_ref(),
+            signer.key().as_ref(),
+            marketplace.key().as_ref(),
+        ],
+        bump = reward.bumps.bump,
+        constraint = signer.key() == reward.authority @ ErrorCode::IncorrectAuthority
+    )]
+    pub reward: Account<'info, Reward>,
+}
+
+/// remaining_accounts: for each campaign to withdraw from, amounts[i] is the amount of the i-th campaign
+/// 1. campaign
+/// 2. campaign reward, pda of (b"campaign_reward", user, campaign)
+/// 3. reward mint of the campaign
+/// 4. reward vault, pda of (b"reward_vault", user, marketplace, mint)
+/// 5. ATA of the user for the reward mint, created if it does not exist
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawRewards<'info>>,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    if ctx.remaining_accounts.len() % 5 != 0 || ctx.remaining_accounts.len() / 5 != amounts.len() {
+        return Err(ErrorCode::IncorrectRemainingAccounts.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let signer_key = ctx.accounts.signer.key().to_bytes();
+    let marketplace_key = ctx.accounts.marketplace.key().to_bytes();
+    let bump_array = [ctx.accounts.reward.bumps.bump];
+    let seeds = &[
+        b"reward".as_ref(),
+        &signer_key,
+        &marketplace_key,
+        &bump_array,
+    ];
+
+    let mut total_amount: u64 = 0;
+    for (accounts, amount) in ctx.remaining_accounts.chunks(5).zip(amounts) {
+        let campaign = Account::<Campaign>::try_from(&accounts[0])?;
+        let mut campaign_reward = Account::<CampaignReward>::try_from(&accounts[1])?;
+        let reward_mint = InterfaceAccount::<Mint>::try_from(&accounts[2])?;
+        let reward_vault_info = &accounts[3];
+        let receiver_vault_info = &accounts[4];
+
+        if !cmp_pubkeys(&campaign.marketplace, &ctx.accounts.marketplace.key())
+            || !cmp_pubkeys(&campaign_reward.campaign, &campaign.key()) {
+            return Err(ErrorCode::IncorrectCampaign.into());
+        }
+
+        if !cmp_pubkeys(&campaign_reward.authority, &ctx.accounts.signer.key()) {
+            return Err(ErrorCode::IncorrectAuthority.into());
+        }
+
+        if !cmp_pubkeys(&reward_mint.key(), &campaign.reward_mint) {
+            return Err(ErrorCode::IncorrectMint.into());
+        }
+
+        if !is_reward_vault(
+            &reward_vault_info.key(),
+            &ctx.accounts.signer.key(),
+            &ctx.accounts.marketplace.key(),
+            &reward_mint.key(),
+        ) {
+            return Err(ErrorCode::IncorrectRewardVault.into());
+        }
+
+        // same rules as withdraw_reward
+        if now < campaign.end_ts {
+            return Err(ErrorCode::OpenPromotion.into());
+        }
+
+        if ctx.accounts.reward.created_ts >= campaign.end_ts {
+            return Err(ErrorCode::RewardsAlreadySwept.into());
+        }
+
+        let vested = calculate_vested_rewards(&campaign, campaign_reward.earned, now)?;
+        // a refund clawback can lower earned below what was already withdrawn
+        let available = vested.saturating_sub(campaign_reward.withdrawn);
+        if amount > available {
+            return Err(ErrorCode::AmountExceedsRewards.into());
+        }
+
+        assert_ata(receiver_vault_info, &ctx.accounts.signer.key(), &reward_mint.key())
+            .map_err(|_| ErrorCode::IncorrectATA)?;
+
+        if receiver_vault_info.data_is_empty() {
+            create(
+                CpiContext::new(
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    Create {
+                        payer: ctx.accounts.signer.to_account_info(),
+                        associated_token: receiver_vault_info.clone(),
+                        authority: ctx.accounts.signer.to_account_info(),
+                        mint: accounts[2].clone(),
+                        system_program: ctx.accounts.system_program.to_account_info(),
+                        token_program: ctx.accounts.token_program_v0.to_account_info(),
+                    },
+                ),
+            )?;
+        }
+
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program_v0.to_account_info(),
+                Transfer {
+                    from: reward_vault_info.clone(),
+                    to: receiver_vault_info.clone(),
+                    authority: ctx.accounts.reward.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            amount,
+        ).map_err(|_| ErrorCode::TransferError)?;
+
+        campaign_reward.withdrawn = campaign_reward.withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        campaign_reward.exit(ctx.program_id)?;
+
+        total_amount = total_amount.checked_add(amount).ok_or(ErrorCode::NumericalOverflow)?;
+
+        emit_cpi!(RewardWithdrawn {
+            marketplace: ctx.accounts.marketplace.key(),
+            campaign: campaign.key(),
+            reward: ctx.accounts.reward.key(),
+            authority: ctx.accounts.signer.key(),
+            reward_mint: reward_mint.key(),
+            amount,
+        });
+    }
+
+    (*ctx.accounts.reward).pending_rewards = ctx.accounts.reward.pending_rewards.saturating_sub(total_amount);
+    (*ctx.accounts.marketplace).pending_rewards = ctx.accounts.marketplace.pending_rewards.saturating_sub(total_amount);
+
+    Ok(())
+}
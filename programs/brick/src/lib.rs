@@ -1,6 +1,7 @@
 pub mod state;
 pub mod utils;
 pub mod error;
+pub mod events;
 mod instructions;
 use {
     anchor_lang::prelude::*,
@@ -18,17 +19,52 @@ pub mod brick {
         accept_access::handler(ctx)
     }
 
+    /// the proposed wallet becomes the marketplace authority, second step of the authority transfer
+    pub fn accept_marketplace_authority(ctx: Context<AcceptMarketplaceAuthority>) -> Result<()> {
+        accept_marketplace_authority::handler(ctx)
+    }
+
     /// airdrop a token that allows users to create products in a specific marketplace
     pub fn airdrop_access(ctx: Context<AirdropAccess>) -> Result<()> {
         airdrop_access::handler(ctx)
     }
 
-    /// seller can edit payment_mint and product_price
-    pub fn edit_product(ctx: Context<EditProduct>, product_price: u64) -> Result<()> {
-        edit_product::handler(ctx, product_price)
+    /// marketplace authority drains and closes a bounty vault once the last campaign has ended
+    pub fn close_bounty(ctx: Context<CloseBounty>) -> Result<()> {
+        close_bounty::handler(ctx)
+    }
+
+    /// marketplace authority closes the marketplace when there are no products, rewards pending or running campaigns
+    /// products with open escrows can not be closed, so neither can their marketplace
+    /// bounty vaults are drained to the authority and closed, and the access mint is closed if possible
+    pub fn close_marketplace<'info>(ctx: Context<'_, '_, '_, 'info, CloseMarketplace<'info>>) -> Result<()> {
+        close_marketplace::handler(ctx)
+    }
+
+    /// seller closes a product when no buyer holds a proof of payment or units of that product, no escrow is open
+    /// and the subscriptions sold expired
+    /// returns the rent of the product, its mint and the collection accounts (cnft products)
+    pub fn close_product(ctx: Context<CloseProduct>) -> Result<()> {
+        close_product::handler(ctx)
+    }
+
+    /// user sweeps and closes the reward vaults and the reward account once all the rewards it holds are unlocked
+    /// returns the rent of the vaults and the reward account
+    pub fn close_reward<'info>(ctx: Context<'_, '_, '_, 'info, CloseReward<'info>>) -> Result<()> {
+        close_reward::handler(ctx)
+    }
+
+    /// buyer confirms the delivery of an escrowed purchase, the escrow pays the seller and the marketplace fee
+    pub fn confirm_delivery(ctx: Context<ConfirmDelivery>) -> Result<()> {
+        confirm_delivery::handler(ctx)
+    }
+
+    /// seller can edit payment_mint, product_price, the supply and wallet limits and the sale window
+    pub fn edit_product(ctx: Context<EditProduct>, params: EditProductParams) -> Result<()> {
+        edit_product::handler(ctx, params)
     }
 
-    /// marketplace authority can edit fees and rewards configs
+    /// marketplace authority can edit token, permission and fees configs
     pub fn edit_marketplace(ctx: Context<EditMarketplace>, params: EditMarketplaceParams) -> Result<()> {
         edit_marketplace::handler(ctx, params)
     }
@@ -38,6 +74,17 @@ pub mod brick {
         init_bounty::handler(ctx)
     }
 
+    /// marketplace authority creates a promotion, purchases between start_ts and end_ts are rewarded
+    /// with tokens of the campaign reward mint taken from the bounty vault of that mint
+    pub fn init_campaign(ctx: Context<InitCampaign>, params: InitCampaignParams) -> Result<()> {
+        init_campaign::handler(ctx, params)
+    }
+
+    /// creates the account that tracks the rewards of a user in a campaign, anyone can pay it
+    pub fn init_campaign_reward(ctx: Context<InitCampaignReward>) -> Result<()> {
+        init_campaign_reward::handler(ctx)
+    }
+
     /// recommeded to read the Marketplace state code to understand the meaning of this data structure 
     pub fn init_marketplace(ctx: Context<InitMarketplace>, params: InitMarketplaceParams) -> Result<()> {
         init_marketplace::handler(ctx, params)
@@ -62,27 +109,115 @@ pub mod brick {
     pub fn init_reward(ctx: Context<InitReward>) -> Result<()> {
         init_reward::handler(ctx)
     }
+
+    /// reallocs the marketplace account to the current layout, new fields start with their default value
+    /// except the bounty vault counter, read from the version 1 vault vectors or counted from the bounty vaults
+    pub fn migrate_marketplace<'info>(ctx: Context<'_, '_, '_, 'info, MigrateMarketplace<'info>>) -> Result<()> {
+        migrate_marketplace::handler(ctx)
+    }
+
+    /// reallocs the buyer payment account to the current layout, new fields start with their default value
+    /// except the price paid for the units bought before version 3, valued at the current product price
+    pub fn migrate_payment(ctx: Context<MigratePayment>) -> Result<()> {
+        migrate_payment::handler(ctx)
+    }
+
+    /// reallocs the product account to the current layout, new fields start with their default value
+    /// except the receipts of tree products, which start at the units sold
+    pub fn migrate_product(ctx: Context<MigrateProduct>) -> Result<()> {
+        migrate_product::handler(ctx)
+    }
+
+    /// reallocs the reward account to the current layout, new fields start with their default value
+    pub fn migrate_reward(ctx: Context<MigrateReward>) -> Result<()> {
+        migrate_reward::handler(ctx)
+    }
+
+    /// buyer disputes an escrowed purchase before its release timestamp, it can only be paid out by settle_dispute
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        open_dispute::handler(ctx)
+    }
+
+    /// marketplace authority proposes a new authority, it has to be accepted by that wallet
+    /// proposing Pubkey::default() cancels the pending transfer
+    pub fn propose_marketplace_authority(ctx: Context<ProposeMarketplaceAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_marketplace_authority::handler(ctx, new_authority)
+    }
     
+    /// buyer and seller consume purchased units: counter units are subtracted, product tokens or the cnft are burned
+    pub fn redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+        params: RedeemParams,
+    ) -> Result<()> {
+        redeem::handler(ctx, params)
+    }
+
+    /// seller returns funds to a buyer, partial refunds are allowed, and takes back the proof of payment
+    /// of the refunded units (payment units, product tokens or the cnft) and the rewards of the refunded amount
+    /// escrow products are refunded by the arbiter with settle_dispute
+    pub fn refund_purchase<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundPurchase<'info>>,
+        params: RefundPurchaseParams,
+    ) -> Result<()> {
+        refund_purchase::handler(ctx, params)
+    }
+
+    /// manages the transfers like register_buy and mints a cnft receipt of the product collection to the buyer,
+    /// every receipt stands for one unit
     pub fn register_buy_cnft(ctx: Context<RegisterBuyCnft>, params: RegisterBuyCnftParams) -> Result<()> {
         register_buy_cnft::handler(ctx, params)
     }
 
     /// manages the transfers (buyer -> seller and fees to marketplace authority)
     /// initializes the payment account to get the number of purchases
-    pub fn register_buy_counter(ctx: Context<RegisterBuyCounter>, amount: u32) -> Result<()> {
-        register_buy_counter::handler(ctx, amount)
+    pub fn register_buy_counter(
+        ctx: Context<RegisterBuyCounter>,
+        amount: u32,
+        max_total_price: u64,
+        expected_payment_mint: Pubkey,
+    ) -> Result<()> {
+        register_buy_counter::handler(ctx, amount, max_total_price, expected_payment_mint)
+    }
+
+    /// buyer pays to an escrow instead of the seller, required by products with an escrow timeout
+    /// id allows several escrows of the same buyer and product, the payment account counts the units
+    pub fn register_buy_escrow(
+        ctx: Context<RegisterBuyEscrow>,
+        id: u64,
+        amount: u32,
+        max_total_price: u64,
+        expected_payment_mint: Pubkey,
+    ) -> Result<()> {
+        register_buy_escrow::handler(ctx, id, amount, max_total_price, expected_payment_mint)
     }
 
     /// manages the transfers (buyer -> seller and fees to marketplace authority) 
     /// and buyers receive a token as a proof of payment (each product has its own tokenc)
-    pub fn register_buy_token(ctx: Context<RegisterBuyToken>, amount: u32) -> Result<()> {
-        register_buy_token::handler(ctx, amount)
+    pub fn register_buy_token(
+        ctx: Context<RegisterBuyToken>,
+        amount: u32,
+        max_total_price: u64,
+        expected_payment_mint: Pubkey,
+    ) -> Result<()> {
+        register_buy_token::handler(ctx, amount, max_total_price, expected_payment_mint)
     }
 
     /// manages the transfers (buyer -> seller and fees to marketplace authority)
+    /// all the register_buy instructions fail if the price is above max_total_price or the payment mint changed
     /// uses payment pda to index transactions, but it does not initilize it
-    pub fn register_buy(ctx: Context<RegisterBuy>, amount: u32) -> Result<()> {
-        register_buy::handler(ctx, amount)
+    pub fn register_buy(
+        ctx: Context<RegisterBuy>,
+        amount: u32,
+        max_total_price: u64,
+        expected_payment_mint: Pubkey,
+    ) -> Result<()> {
+        register_buy::handler(ctx, amount, max_total_price, expected_payment_mint)
+    }
+
+    /// anyone releases an escrow to the seller once its timeout is over, the buyer gets the rent back
+    /// settled escrows are only closed and can be released at any moment
+    pub fn release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+        release_escrow::handler(ctx)
     }
 
     /// creates on chain request to get access to sell products in a specific marketplace
@@ -90,13 +225,45 @@ pub mod brick {
         request_access::handler(ctx)
     }
 
+    /// marketplace authority can stop product creation and purchases, rewards can be withdrawn anyway
+    pub fn set_marketplace_pause(ctx: Context<SetMarketplacePause>, paused: bool) -> Result<()> {
+        set_marketplace_pause::handler(ctx, paused)
+    }
+
+    /// marketplace authority or arbiter splits a disputed escrow between the buyer, the seller and the marketplace fee
+    /// the ruling is stored in the escrow and the units refunded to the buyer are given back
+    pub fn settle_dispute(ctx: Context<SettleDispute>, buyer_amount: u64, seller_amount: u64) -> Result<()> {
+        settle_dispute::handler(ctx, buyer_amount, seller_amount)
+    }
+
     /// creates on chain request to get access to sell products in a specific marketplace
     pub fn update_tree(ctx: Context<UpdateProductTree>, params: UpdateProductTreeParams) -> Result<()> {
         update_tree::handler(ctx, params)
     }
     
-    /// when promotion is ended users can withdraw the funds stored in the vaults and managed by the reward PFA
+    /// read only, sets a SubscriptionStatus (active and expires_at) of the buyer as return data
+    /// so other programs can check a subscription with a cpi
+    pub fn verify_subscription(ctx: Context<VerifySubscription>) -> Result<()> {
+        verify_subscription::handler(ctx)
+    }
+
+    /// marketplace authority takes bounty funds back, only when no campaign can pay rewards from them
+    pub fn withdraw_bounty(ctx: Context<WithdrawBounty>, amount: u64) -> Result<()> {
+        withdraw_bounty::handler(ctx, amount)
+    }
+
+    /// when a campaign is ended users can withdraw the rewards earned in it, stored in the vaults managed by the reward PDA
+    /// with a vesting period only the part unlocked so far is transferred, it can be called again later
     pub fn withdraw_reward(ctx: Context<WithdrawReward>) -> Result<()> {
         withdraw_reward::handler(ctx)
     }
+
+    /// withdraws from several campaigns (and reward mints) at once, any amount up to the unlocked rewards of each one
+    /// creates the receiver ATAs that do not exist
+    pub fn withdraw_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawRewards<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        withdraw_rewards::handler(ctx, amounts)
+    }
 }
@@ -1,11 +1,41 @@
 use anchor_lang::prelude::*;
 
+/// Layout version of each account, stored in the first byte after the discriminator.
+/// New fields are appended at the end of the account (zero = default value), the migrate
+/// instructions realloc the accounts to the current layout and document each version.
+pub const MARKETPLACE_VERSION: u8 = 6;
+pub const PRODUCT_VERSION: u8 = 6;
+pub const REWARD_VERSION: u8 = 3;
+pub const ACCESS_VERSION: u8 = 1;
+pub const PAYMENT_VERSION: u8 = 3;
+pub const CAMPAIGN_VERSION: u8 = 1;
+pub const CAMPAIGN_REWARD_VERSION: u8 = 1;
+pub const ESCROW_VERSION: u8 = 1;
+
+/// Sizes of the accounts created before version 1, discriminator included.
+pub const UNVERSIONED_MARKETPLACE_SIZE: usize = 317;
+pub const UNVERSIONED_PRODUCT_SIZE: usize = 242;
+pub const UNVERSIONED_REWARD_SIZE: usize = 238;
+pub const UNVERSIONED_PAYMENT_SIZE: usize = 13;
+
 /// This account represents a marketplace with associated transaction fees and reward configurations.
 /// The account is controlled by an authority that can modify the fee and reward configurations.
 #[account]
+#[derive(InitSpace)]
 pub struct Marketplace {
+    /// Layout version, see MARKETPLACE_VERSION.
+    pub version: u8,
     /// The authorized entity that can modify this account data.
     pub authority: Pubkey,
+    /// Wallet that created the marketplace, used to derive the marketplace PDA.
+    /// Does not change when the authority is transferred.
+    pub creator: Pubkey,
+    /// Wallet proposed by the authority to take control of the marketplace, it becomes
+    /// the authority once it calls accept_marketplace_authority. Pubkey::default() = no pending transfer.
+    pub pending_authority: Pubkey,
+    /// Chosen by the creator, allows one wallet to manage several marketplaces.
+    /// Used with the creator to derive the marketplace PDA.
+    pub id: u64,
     /// Token or indexing and access system work.
     pub token_config: TokenConfig,
     /// Set of permission configuration on a marketplace that can be modified by the authority.
@@ -16,11 +46,30 @@ pub struct Marketplace {
     pub rewards_config: RewardsConfig,
     /// Seed bump parameters used for deterministic address derivation.
     pub bumps: MarketplaceBumps,
+    /// Number of products listed in this marketplace that have not been closed.
+    pub open_products: u32,
+    /// Reward tokens sent to the user reward vaults and not withdrawn yet (raw amounts of all the reward mints),
+    /// the marketplace can not be closed while there are rewards pending, users need this account to withdraw.
+    pub pending_rewards: u64,
+    /// Circuit breaker controlled by the authority, while true products can not be created or bought.
+    /// Users can always withdraw their rewards.
+    pub paused: bool,
+    /// Latest end_ts of the campaigns of this marketplace, until then the bounty vaults can not
+    /// be withdrawn or closed because a campaign can still pay rewards from them.
+    pub campaigns_end_ts: i64,
+    /// Wallet that settles disputed escrows besides the authority, Pubkey::default() = only the authority.
+    pub arbiter: Pubkey,
+    /// Seconds after the end of a campaign during which its rewards unlock linearly, 0 = all the
+    /// rewards can be withdrawn when the campaign ends. Copied to each campaign when it is created.
+    pub vesting_period: i64,
+    /// Number of bounty vaults that have not been closed, the marketplace can only be closed with all of them
+    /// so no bounty is left in a vault that nobody controls.
+    pub bounty_vaults: u32,
 }
 
 /// Marketplace permission configs, gives flexibility to this program, marketplace auth decides.
 /// All properties = false, buyer can call only register_buy instruction
-#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Default, Clone)]
 pub struct TokenConfig {
     /// If true seller inits the tree and buyers receives a cnft as a proof of payment
     /// ie: seller calls init_product_tree to list a product and buyer calls register_buy_cnft
@@ -36,7 +85,7 @@ pub struct TokenConfig {
 }
 
 /// Marketplace permission configs, gives flexibility to this program, marketplace auth decides.
-#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Default, Clone)]
 pub struct PermissionConfig {
     // If permissionless is false, sellers need to hold this token to create products on a specific marketplace.
     pub access_mint: Pubkey,
@@ -45,7 +94,7 @@ pub struct PermissionConfig {
 }
 
 /// Marketplace fees related to transactions.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct FeesConfig {
     /// Marketplaces can set a mint. Sellers opting to receive this mint as payment
     /// will have their marketplace fee reduced. This could be a governance token, for instance.
@@ -59,7 +108,7 @@ pub struct FeesConfig {
     pub fee_payer: PaymentFeePayer,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub enum PaymentFeePayer {
     Buyer,
     Seller,
@@ -67,70 +116,36 @@ pub enum PaymentFeePayer {
 
 /// Rewards configuration associated with sales.
 /// 1. marketplace auth init_market (with one bounty_vault)
-/// 2. if a marketplace wants to change the reward mint, needs to call init_bounty_vault with that mint
+/// 2. if a marketplace wants to use another reward mint, needs to call init_bounty_vault with that mint
+/// bounty vaults are not stored, each one is the pda of (b"bounty_vault", marketplace, mint)
+/// and is controlled by the marketplace pda, there is no limit of reward mints
 /// 3. marketplace auth transfers manually the bounty tokens (can be with 1/2 in the same transaction)
-/// 4. user sells / buys during promo in the marketplace, sends some tokens to the reward_vault of both 
-/// seller and buyer these vaults are controlled by marketplace pda
-/// 5. when promotion is ended the user can withdraw the rewards
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+/// 4. marketplace auth calls init_campaign, the campaign defines the reward mint, the budget, the bps and its time window
+/// 5. user sells / buys during the campaign, sends some tokens to the reward_vault of both 
+/// seller and buyer, these vaults are controlled by the reward pda of each user
+/// 6. when the campaign end_ts has passed the user can withdraw the rewards of that campaign,
+/// all at once or unlocked linearly during the vesting period of the campaign
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct RewardsConfig {
-    /// If set, the marketplace will only give rewards if the payment is made with this specific mint.
-    /// To enable rewards irrespective of payment mint, set this value to pda(b"null", this program).
+    /// Mint of the bounty vault created with the marketplace.
     pub reward_mint: Pubkey,
-    /// Vaults where reward tokens are stored.
-    /// These vaults are managed by this marketplace PDA.
-    /// Tokens used during the promotional period should be deposited here.
-    pub bounty_vaults: Vec<Pubkey>,
-    /// The transaction volume percentage that the seller receives as a reward on a sale.
-    /// A value of 250 corresponds to a reward of 2.5% of the transaction volume.
-    /// A value of 0 indicates that there is no active rewards for the seller.
-    pub seller_reward: u16,
-    /// The transaction volume percentage that the buyer receives as a reward on a sale.
-    pub buyer_reward: u16,
-    /// This flag enables or disables the reward system.
-    /// When false, the reward system is inactive regardless of the reward_mint value.
-    pub rewards_enabled: bool,
 }
 
 /// Bump seed parameters used for deterministic address derivation.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct MarketplaceBumps {
     pub bump: u8,
-    pub vault_bumps: Vec<u8>,
     pub access_mint_bump: u8,
 }
 
-pub const VAULT_COUNT: usize = 5;
-pub const MARKETPLACE_SIZE: usize = 8  // discriminator
-    + 32  // authority
-    // TokenConfig
-    + 1   // deliver_token
-    + 1   // transferable
-    + 1   // chain_counter
-    // PermissionConfig
-    + 32  // access_mint
-    + 1   // permissionless
-    // FeesConfig
-    + 32  // discount_mint
-    + 2   // fee
-    + 2   // fee_reduction
-    + 1   // fee_payer
-    // RewardsConfig
-    + 32  // reward_mint
-    + 32  // bounty_vaults
-    * VAULT_COUNT
-    + 2   // seller_reward
-    + 2   // buyer_reward
-    + 1   // rewards_enabled
-    // MarketplaceBumps
-    + 1   // bump
-    + 1   // vault_bumps
-    * VAULT_COUNT
-    + 1;  // access_mint_bump
+pub const MARKETPLACE_SIZE: usize = 8 + Marketplace::INIT_SPACE;
 
 /// This account works as an product administrator
 #[account]
+#[derive(InitSpace)]
 pub struct Product {
+    /// Layout version, see PRODUCT_VERSION.
+    pub version: u8,
     /// The seller's public key, who owns the product.
     pub authority: Pubkey,
     /// Off chain identifier of the product, split across two arrays due to a limit on
@@ -150,9 +165,37 @@ pub struct Product {
     pub seller_config: SellerConfig,
     /// Seed bump parameters used for deterministic address derivation.
     pub bumps: ProductBumps,
+    /// Units that can be sold, None = unlimited. Added in version 2.
+    pub max_supply: Option<u64>,
+    /// Units sold, updated in every register_buy instruction. Added in version 2.
+    pub sold: u64,
+    /// Units one wallet can buy, tracked in the payment account, None = unlimited. Added in version 3.
+    pub max_units_per_wallet: Option<u32>,
+    /// Unix timestamp from which the product can be bought, None = since it is listed. Added in version 4.
+    pub sale_start: Option<i64>,
+    /// Unix timestamp from which the product can not be bought anymore, None = no end. Added in version 4.
+    pub sale_end: Option<i64>,
+    /// Seconds the payment stays in escrow before anyone can release it to the seller, the product can only
+    /// be bought with register_buy_escrow. None = the seller is paid at purchase. Added in version 5.
+    pub escrow_timeout: Option<i64>,
+    /// Seconds of access each unit bought adds to the expires_at of the buyer payment account,
+    /// None = not a subscription. Added in version 6.
+    pub subscription_period: Option<i64>,
+    /// cNFT receipts minted by register_buy_cnft that were not burned by a refund or a redeem yet,
+    /// the product can not be closed while buyers hold one. Added in version 6.
+    pub receipts: u64,
+    /// Escrows of register_buy_escrow not closed by release_escrow or confirm_delivery yet, the product can
+    /// not be closed while one is open so its disputes can still be settled. Added in version 6.
+    pub open_escrows: u32,
+    /// Units sold that were not refunded or redeemed yet, the product can not be closed while buyers
+    /// hold some. Added in version 6.
+    pub outstanding_units: u64,
+    /// Latest expires_at of the subscriptions sold, the product can not be closed before it.
+    /// Added in version 6.
+    pub subscriptions_end_ts: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct SellerConfig {
     /// The token seller selects to receive as payment.
     pub payment_mint: Pubkey,
@@ -160,59 +203,113 @@ pub struct SellerConfig {
     pub product_price: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
 pub struct ProductBumps {
     pub bump: u8,
     pub mint_bump: u8,
 }
 
-pub const PRODUCT_SIZE: usize = 8 // discriminator
-    + 32 // authority
-    + 32 // first_id
-    + 32 // second_id
-    + 32 // marketplace
-    + 32 // product_mint
-    + 32 // merkle_tree
-    // SellerConfig
-    + 32 // payment_mint
-    + 8  // product_price
-    // ProductBumps
-    + 1  // product_bump
-    + 1; // mint_bump
+pub const PRODUCT_SIZE: usize = 8 + Product::INIT_SPACE;
 
+/// Owner of the reward vaults of a user in a marketplace, tokens are stored there until the campaign
+/// that gave them is ended, then the user can withdraw. The vaults are not stored, each one is the
+/// pda of (b"reward_vault", user, marketplace, mint), a user can have one for every reward mint.
 #[account]
+#[derive(InitSpace)]
 pub struct Reward {
+    /// Layout version, see REWARD_VERSION.
+    pub version: u8,
     /// The public key of the account having authority over the reward PDA.
     pub authority: Pubkey,
     /// The marketplace address, stored to derive reward pda in the context.
     pub marketplace: Pubkey,
-    /// Vault where tokens are stored until promotion is ended, then the user can withdraw.
-    /// reward_mint is stored in marketplace account.
-    /// It is allowed to create 5 vaults with different mints. In init_reward one is created, 
-    /// if you want to change the mint reward for your users you need to makes user sign a init_bounty ix
-    pub reward_vaults: Vec<Pubkey>,
     /// Seed bump parameter used for deterministic address derivation in case of the Reward account.
     pub bumps: RewardBumps,
+    /// Latest end_ts + vesting_period of the campaigns that rewarded this user, the account can not
+    /// be closed before because the reward vaults hold rewards that are not unlocked yet.
+    pub locked_until: i64,
+    /// Unix timestamp of the init_reward call, rewards of campaigns ended before it were earned by
+    /// a closed reward account and can not be withdrawn with this one.
+    pub created_ts: i64,
+    /// Reward tokens sent to the vaults of this user and not withdrawn yet, counted in the marketplace
+    /// pending_rewards. Tokens sent to the vaults by anyone else are not counted.
+    pub pending_rewards: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Default, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Default, Clone)]
 pub struct RewardBumps {
     pub bump: u8,
-    pub vault_bumps: Vec<u8>,
 }
 
-pub const REWARD_SIZE: usize = 8 // discriminator
-    + 32  // authority
-    + 32  // marketplace
-    + 32  // reward_vaults
-    * VAULT_COUNT
-    + 1   // bump
-    + 1   // vault_bumps
-    * VAULT_COUNT;
+pub const REWARD_SIZE: usize = 8 + Reward::INIT_SPACE;
+
+/// Promotion created by the marketplace authority, purchases made between start_ts and end_ts send
+/// reward_mint tokens from the marketplace bounty vault to the reward vaults of the seller and the buyer.
+/// Each campaign unlocks its own rewards, users can withdraw them once end_ts has passed.
+#[account]
+#[derive(InitSpace)]
+pub struct Campaign {
+    /// Layout version, see CAMPAIGN_VERSION.
+    pub version: u8,
+    /// The marketplace address, stored to derive campaign pda in the context.
+    pub marketplace: Pubkey,
+    /// Chosen by the marketplace authority, used with the marketplace to derive the campaign PDA.
+    pub id: u64,
+    /// Mint given as reward, tokens are taken from the bounty vault of this mint.
+    pub reward_mint: Pubkey,
+    /// If set, only purchases paid with this mint are rewarded, None = any payment mint.
+    /// Rewards are calculated over the price in payment mint units, so using a different
+    /// reward mint only makes sense when both mints have a similar value.
+    pub payment_mint: Option<Pubkey>,
+    /// Unix timestamp from which purchases are rewarded (inclusive).
+    pub start_ts: i64,
+    /// Unix timestamp from which purchases are not rewarded and rewards can be withdrawn.
+    pub end_ts: i64,
+    /// Max amount of reward tokens given by this campaign.
+    pub budget: u64,
+    /// Reward tokens already sent to the user reward vaults.
+    pub distributed: u64,
+    /// The transaction volume percentage that the seller receives as a reward on a sale.
+    /// A value of 250 corresponds to a reward of 2.5% of the transaction volume.
+    pub seller_reward: u16,
+    /// The transaction volume percentage that the buyer receives as a reward on a sale.
+    pub buyer_reward: u16,
+    pub bump: u8,
+    /// Max reward given to each side of a single purchase, None = no limit.
+    pub max_reward_per_purchase: Option<u64>,
+    /// Max reward a wallet can earn in this campaign, None = no limit.
+    pub max_reward_per_wallet: Option<u64>,
+    /// Rewards unlock linearly from end_ts to end_ts + vesting_period, see Marketplace::vesting_period.
+    pub vesting_period: i64,
+}
+
+pub const CAMPAIGN_SIZE: usize = 8 + Campaign::INIT_SPACE;
+
+/// Rewards of a user in a campaign, the tokens are stored in the user reward vault of the campaign
+/// reward mint, this account tracks which part of that vault can be withdrawn when the campaign ends.
+#[account]
+#[derive(InitSpace)]
+pub struct CampaignReward {
+    /// Layout version, see CAMPAIGN_REWARD_VERSION.
+    pub version: u8,
+    /// The user that receives the rewards.
+    pub authority: Pubkey,
+    pub campaign: Pubkey,
+    /// Reward tokens received during the campaign.
+    pub earned: u64,
+    /// Reward tokens already withdrawn, never more than the vested part of earned.
+    pub withdrawn: u64,
+    pub bump: u8,
+}
+
+pub const CAMPAIGN_REWARD_SIZE: usize = 8 + CampaignReward::INIT_SPACE;
 
 
 #[account]
+#[derive(InitSpace)]
 pub struct Access {
+    /// Layout version, see ACCESS_VERSION.
+    pub version: u8,
     /// The user pubkey that request access to the marketplace.
     pub authority: Pubkey,
     /// The marketplace address, stored to derive access pda in the context.
@@ -220,17 +317,293 @@ pub struct Access {
     pub bump: u8,
 }
 
-pub const ACCESS_SIZE: usize = 8 // discriminator
-    + 32  // authority
-    + 32  // marketplace
-    + 1;  // bump
+pub const ACCESS_SIZE: usize = 8 + Access::INIT_SPACE;
     
 /// its a pda from signer, marketplace and product can only be 
 /// created/modified in register_buy that requieres transfers
+/// all the purchase instructions add the units bought, used to enforce max_units_per_wallet
 #[account]
+#[derive(InitSpace)]
 pub struct Payment {
+    /// Layout version, see PAYMENT_VERSION.
+    pub version: u8,
     pub units: u32,
     pub bump: u8,
+    /// Unix timestamp until which the subscription is active, extended by the purchases of subscription
+    /// products, 0 = never subscribed. Added in version 2.
+    pub expires_at: i64,
+    /// Product price paid for the units the buyer still holds, the marketplace fee is not included.
+    /// Refunds return at least the average price of the units given back and at most this amount.
+    /// Added in version 3, migrate_payment values older purchases at the current product price.
+    pub paid: u64,
+    /// Last campaign that rewarded a purchase of these units, Pubkey::default() = none.
+    /// The rewards of a previous campaign are not clawed back once another campaign rewards a purchase.
+    /// Added in version 3.
+    pub campaign: Pubkey,
+    /// Rewards of that campaign the seller received for these units, refunds claw back the share of
+    /// the refunded amount. Added in version 3.
+    pub seller_bonus: u64,
+    /// Same as seller_bonus for the buyer. Added in version 3.
+    pub buyer_bonus: u64,
+    /// Seconds of subscription granted for the units the buyer still holds, refunds take back their share
+    /// whatever the current product period. Added in version 3.
+    pub subscription_granted: u64,
+}
+
+/// Return data of verify_subscription.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SubscriptionStatus {
+    pub active: bool,
+    pub expires_at: i64,
 }
 
-pub const PAYMENT_SIZE: usize = 8 + 4 + 1;
+pub const PAYMENT_SIZE: usize = 8 + Payment::INIT_SPACE;
+
+/// Payment of an escrowed purchase, pda of (b"escrow", buyer, product, id).
+/// SOL payments are kept as lamports of this account, token payments in the escrow vault,
+/// pda of (b"escrow_vault", escrow) controlled by this account.
+/// The buyer releases it with confirm_delivery, anyone can release it from release_ts.
+#[account]
+#[derive(InitSpace)]
+pub struct Escrow {
+    /// Layout version, see ESCROW_VERSION.
+    pub version: u8,
+    pub marketplace: Pubkey,
+    pub product: Pubkey,
+    /// Receives the rent of the escrow accounts when the escrow is released.
+    pub buyer: Pubkey,
+    /// Product authority at purchase, receives the payment.
+    pub seller: Pubkey,
+    pub payment_mint: Pubkey,
+    /// Chosen by the buyer, allows several escrowed purchases of the same product.
+    pub id: u64,
+    pub units: u32,
+    /// Price of the units bought, the marketplace fee is calculated over it at release.
+    pub amount: u64,
+    /// Marketplace fees at purchase, the fee is split at release with this config.
+    pub fees_config: FeesConfig,
+    /// Unix timestamp from which anyone can release the escrow.
+    pub release_ts: i64,
+    pub bump: u8,
+    /// Unix timestamp of open_dispute, 0 = not disputed. A disputed escrow can only be paid out by settle_dispute.
+    pub disputed_ts: i64,
+    /// Set by settle_dispute, the settled escrow keeps it until it is closed by release_escrow or confirm_delivery.
+    pub ruling: Option<Ruling>,
+}
+
+/// Split of a disputed escrow decided by the marketplace authority or its arbiter, the three amounts
+/// add up to what the buyer paid to the escrow.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct Ruling {
+    pub arbiter: Pubkey,
+    pub buyer_amount: u64,
+    pub seller_amount: u64,
+    pub fee: u64,
+    pub settled_ts: i64,
+}
+
+pub const ESCROW_SIZE: usize = 8 + Escrow::INIT_SPACE;
+
+/// Marketplace layout up to version 1, the bounty vaults and their bumps were stored in vectors.
+/// Only read by migrate_marketplace.
+#[derive(AnchorDeserialize)]
+pub struct MarketplaceV1 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub pending_authority: Pubkey,
+    pub id: u64,
+    pub token_config: TokenConfig,
+    pub permission_config: PermissionConfig,
+    pub fees_config: FeesConfig,
+    pub rewards_config: RewardsConfigV1,
+    pub bumps: MarketplaceBumpsV1,
+    pub open_products: u32,
+    pub pending_rewards: u64,
+    pub paused: bool,
+}
+
+#[derive(AnchorDeserialize)]
+pub struct RewardsConfigV1 {
+    pub reward_mint: Pubkey,
+    pub bounty_vaults: Vec<Pubkey>,
+    pub seller_reward: u16,
+    pub buyer_reward: u16,
+    pub rewards_enabled: bool,
+}
+
+#[derive(AnchorDeserialize)]
+pub struct MarketplaceBumpsV1 {
+    pub bump: u8,
+    pub vault_bumps: Vec<u8>,
+    pub access_mint_bump: u8,
+}
+
+impl From<MarketplaceV1> for Marketplace {
+    fn from(marketplace: MarketplaceV1) -> Self {
+        Marketplace {
+            version: MARKETPLACE_VERSION,
+            authority: marketplace.authority,
+            creator: marketplace.creator,
+            pending_authority: marketplace.pending_authority,
+            id: marketplace.id,
+            token_config: marketplace.token_config,
+            permission_config: marketplace.permission_config,
+            fees_config: marketplace.fees_config,
+            rewards_config: RewardsConfig {
+                reward_mint: marketplace.rewards_config.reward_mint,
+            },
+            bumps: MarketplaceBumps {
+                bump: marketplace.bumps.bump,
+                access_mint_bump: marketplace.bumps.access_mint_bump,
+            },
+            open_products: marketplace.open_products,
+            pending_rewards: marketplace.pending_rewards,
+            paused: marketplace.paused,
+            campaigns_end_ts: 0,
+            arbiter: Pubkey::default(),
+            vesting_period: 0,
+            bounty_vaults: marketplace.rewards_config.bounty_vaults.len() as u32,
+        }
+    }
+}
+
+/// Marketplace layout of versions 2 and 3, the rewards config did not have the vesting period.
+/// Version 2 accounts are read after the realloc, campaigns_end_ts is 0.
+/// Only read by migrate_marketplace.
+#[derive(AnchorDeserialize)]
+pub struct MarketplaceV3 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub pending_authority: Pubkey,
+    pub id: u64,
+    pub token_config: TokenConfig,
+    pub permission_config: PermissionConfig,
+    pub fees_config: FeesConfig,
+    pub rewards_config: RewardsConfigV3,
+    pub bumps: MarketplaceBumps,
+    pub open_products: u32,
+    pub pending_rewards: u64,
+    pub paused: bool,
+    pub campaigns_end_ts: i64,
+}
+
+#[derive(AnchorDeserialize)]
+pub struct RewardsConfigV3 {
+    pub reward_mint: Pubkey,
+    pub seller_reward: u16,
+    pub buyer_reward: u16,
+    pub rewards_enabled: bool,
+}
+
+impl From<MarketplaceV3> for Marketplace {
+    fn from(marketplace: MarketplaceV3) -> Self {
+        Marketplace {
+            version: MARKETPLACE_VERSION,
+            authority: marketplace.authority,
+            creator: marketplace.creator,
+            pending_authority: marketplace.pending_authority,
+            id: marketplace.id,
+            token_config: marketplace.token_config,
+            permission_config: marketplace.permission_config,
+            fees_config: marketplace.fees_config,
+            rewards_config: RewardsConfig {
+                reward_mint: marketplace.rewards_config.reward_mint,
+            },
+            bumps: marketplace.bumps,
+            open_products: marketplace.open_products,
+            pending_rewards: marketplace.pending_rewards,
+            paused: marketplace.paused,
+            campaigns_end_ts: marketplace.campaigns_end_ts,
+            arbiter: Pubkey::default(),
+            vesting_period: 0,
+            bounty_vaults: 0,
+        }
+    }
+}
+
+/// Marketplace layout of versions 4 and 5, the vesting period was in the middle of the rewards config.
+/// Version 4 did not have the arbiter, its missing bytes are read as Pubkey::default().
+/// Only read by migrate_marketplace.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct MarketplaceV5 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub pending_authority: Pubkey,
+    pub id: u64,
+    pub token_config: TokenConfig,
+    pub permission_config: PermissionConfig,
+    pub fees_config: FeesConfig,
+    pub rewards_config: RewardsConfigV5,
+    pub bumps: MarketplaceBumps,
+    pub open_products: u32,
+    pub pending_rewards: u64,
+    pub paused: bool,
+    pub campaigns_end_ts: i64,
+    pub arbiter: Pubkey,
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct RewardsConfigV5 {
+    pub reward_mint: Pubkey,
+    pub seller_reward: u16,
+    pub buyer_reward: u16,
+    pub rewards_enabled: bool,
+    pub vesting_period: i64,
+}
+
+impl From<MarketplaceV5> for Marketplace {
+    fn from(marketplace: MarketplaceV5) -> Self {
+        Marketplace {
+            version: MARKETPLACE_VERSION,
+            authority: marketplace.authority,
+            creator: marketplace.creator,
+            pending_authority: marketplace.pending_authority,
+            id: marketplace.id,
+            token_config: marketplace.token_config,
+            permission_config: marketplace.permission_config,
+            fees_config: marketplace.fees_config,
+            rewards_config: RewardsConfig {
+                reward_mint: marketplace.rewards_config.reward_mint,
+            },
+            bumps: marketplace.bumps,
+            open_products: marketplace.open_products,
+            pending_rewards: marketplace.pending_rewards,
+            paused: marketplace.paused,
+            campaigns_end_ts: marketplace.campaigns_end_ts,
+            arbiter: marketplace.arbiter,
+            vesting_period: marketplace.rewards_config.vesting_period,
+            bounty_vaults: 0,
+        }
+    }
+}
+
+/// Reward layout up to version 1, the reward vaults and their bumps were stored in vectors.
+/// Only read by migrate_reward.
+#[derive(AnchorDeserialize)]
+pub struct RewardV1 {
+    pub version: u8,
+    pub authority: Pubkey,
+    pub marketplace: Pubkey,
+    pub reward_vaults: Vec<Pubkey>,
+    pub bump: u8,
+    pub vault_bumps: Vec<u8>,
+}
+
+impl From<RewardV1> for Reward {
+    fn from(reward: RewardV1) -> Self {
+        Reward {
+            version: REWARD_VERSION,
+            authority: reward.authority,
+            marketplace: reward.marketplace,
+            bumps: RewardBumps {
+                bump: reward.bump,
+            },
+            locked_until: 0,
+            created_ts: 0,
+            pending_rewards: 0,
+        }
+    }
+}
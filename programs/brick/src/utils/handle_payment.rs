@@ -1,5 +1,5 @@
 use {
-    super::cmp_pubkeys,
+    super::{assert_authority, cmp_pubkeys, is_campaign_active, register_campaign_rewards},
     crate::error::ErrorCode,
     crate::state::*,
     anchor_lang::{
@@ -9,9 +9,22 @@ use {
             Transfer as NativeTransfer
         },
     },    
-    anchor_spl::token::{transfer, Transfer},
+    anchor_spl::{
+        token::{transfer, Transfer},
+        token_interface::{close_account, transfer_checked, CloseAccount, TokenAccount, TransferChecked},
+    },
+    spl_token::native_mint::ID as NativeMint,
+    spl_token_2022::{
+        extension::{
+            BaseStateWithExtensions,
+            StateWithExtensions,
+            transfer_fee::{TransferFee, TransferFeeConfig},
+        },
+        state::Mint as Mint2022,
+    },
 };
 
+/// Returns (fee, seller_amount), fee is 0 when the marketplace does not charge fees.
 pub fn handle_sol<'info>(
     system_program: AccountInfo<'info>,
     signer: AccountInfo<'info>,
@@ -20,7 +33,7 @@ pub fn handle_sol<'info>(
     fees_config: FeesConfig,
     payment_mint: Pubkey,
     total_payment: u64,
-) -> Result<()> {
+) -> Result<(u64, u64)> {
     if fees_config.fee > 0 {
         let (total_fee, seller_amount) = calculate_transfer_distribution(
             fees_config,
@@ -48,6 +61,8 @@ pub fn handle_sol<'info>(
             ), 
             seller_amount
         )?;
+
+        Ok((total_fee, seller_amount))
     } else {
         native_transfer(
             CpiContext::new(
@@ -59,68 +74,306 @@ pub fn handle_sol<'info>(
             ), 
             total_payment
         )?;
-    }
 
-    Ok(())
+        Ok((0, total_payment))
+    }
 }
 
+/// Returns (fee, seller_amount), same as handle_sol.
+/// The token program is the owner of the payment mint, it works with legacy and token 2022 mints.
+/// If the mint has a transfer fee the buyer pays it on top, so the marketplace and the seller receive
+/// exactly the amounts of calculate_transfer_distribution.
 pub fn handle_spl<'info>(
-    token_program_v0: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
     signer: AccountInfo<'info>,
+    payment_mint: AccountInfo<'info>,
     marketplace_transfer_vault: AccountInfo<'info>,
     seller_transfer_vault: AccountInfo<'info>,
     buyer_transfer_vault: AccountInfo<'info>,
     fees_config: FeesConfig,
-    payment_mint: Pubkey,
     total_payment: u64,
-) -> Result<()> {
-    if fees_config.fee > 0 {
-        let (total_fee, seller_amount) = calculate_transfer_distribution(
+) -> Result<(u64, u64)> {
+    let (decimals, transfer_fee) = get_transfer_config(&payment_mint)?;
+
+    let (total_fee, seller_amount) = if fees_config.fee > 0 {
+        calculate_transfer_distribution(
             fees_config,
-            payment_mint,
+            payment_mint.key(),
             total_payment,
-        )?;
+        )?
+    } else {
+        (0, total_payment)
+    };
 
-        transfer(
+    if total_fee > 0 {
+        transfer_checked(
             CpiContext::new(
-                token_program_v0.clone(), 
-                Transfer {
+                token_program.clone(), 
+                TransferChecked {
                     from: buyer_transfer_vault.clone(),
+                    mint: payment_mint.clone(),
                     to: marketplace_transfer_vault,
                     authority: signer.clone(),
                 },
             ),
-            total_fee,
+            get_pre_fee_amount(transfer_fee.as_ref(), total_fee)?,
+            decimals,
         ).map_err(|_| ErrorCode::TransferError)?;
+    }
 
-        transfer(
-            CpiContext::new(
-                token_program_v0, 
-                Transfer {
-                    from: buyer_transfer_vault,
-                    to: seller_transfer_vault,
-                    authority: signer,
-                },
-            ),
-            seller_amount,
-        ).map_err(|_| ErrorCode::TransferError)?;
+    transfer_checked(
+        CpiContext::new(
+            token_program, 
+            TransferChecked {
+                from: buyer_transfer_vault,
+                mint: payment_mint,
+                to: seller_transfer_vault,
+                authority: signer,
+            },
+        ),
+        get_pre_fee_amount(transfer_fee.as_ref(), seller_amount)?,
+        decimals,
+    ).map_err(|_| ErrorCode::TransferError)?;
+
+    Ok((total_fee, seller_amount))
+}
+
+/// Pays amount out of an escrow, SOL escrows pay from the lamports of the escrow account and token
+/// escrows from the escrow vault. receiver is a wallet for SOL and a token account otherwise.
+pub fn escrow_transfer<'info>(
+    escrow: &Account<'info, Escrow>,
+    token_program: AccountInfo<'info>,
+    payment_mint: AccountInfo<'info>,
+    escrow_vault: Option<AccountInfo<'info>>,
+    receiver: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if cmp_pubkeys(&escrow.payment_mint, &NativeMint) {
+        let escrow_info = escrow.to_account_info();
+        let escrow_lamports = escrow_info.lamports()
+            .checked_sub(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        let receiver_lamports = receiver.lamports()
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        **escrow_info.try_borrow_mut_lamports()? = escrow_lamports;
+        **receiver.try_borrow_mut_lamports()? = receiver_lamports;
     } else {
-        transfer(
-            CpiContext::new(
-                token_program_v0, 
-                Transfer {
-                    from: buyer_transfer_vault,
-                    to: seller_transfer_vault,
-                    authority: signer,
+        let escrow_vault = escrow_vault.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+        let (decimals, _) = get_transfer_config(&payment_mint)?;
+        let escrow_id = escrow.id.to_le_bytes();
+        let escrow_seeds = &[
+            b"escrow".as_ref(),
+            escrow.buyer.as_ref(),
+            escrow.product.as_ref(),
+            escrow_id.as_ref(),
+            &[escrow.bump],
+        ];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program,
+                TransferChecked {
+                    from: escrow_vault,
+                    mint: payment_mint,
+                    to: receiver,
+                    authority: escrow.to_account_info(),
                 },
+                &[&escrow_seeds[..]],
             ),
-            total_payment,
+            amount,
+            decimals,
         ).map_err(|_| ErrorCode::TransferError)?;
     }
 
     Ok(())
 }
 
+/// Closes the vault of a token escrow, it has to be empty. Does nothing for SOL escrows.
+pub fn close_escrow_vault<'info>(
+    escrow: &Account<'info, Escrow>,
+    token_program: AccountInfo<'info>,
+    escrow_vault: Option<AccountInfo<'info>>,
+    destination: AccountInfo<'info>,
+) -> Result<()> {
+    if cmp_pubkeys(&escrow.payment_mint, &NativeMint) {
+        return Ok(());
+    }
+
+    let escrow_vault = escrow_vault.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+    let escrow_id = escrow.id.to_le_bytes();
+    let escrow_seeds = &[
+        b"escrow".as_ref(),
+        escrow.buyer.as_ref(),
+        escrow.product.as_ref(),
+        escrow_id.as_ref(),
+        &[escrow.bump],
+    ];
+
+    close_account(
+        CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account: escrow_vault,
+                destination,
+                authority: escrow.to_account_info(),
+            },
+            &[&escrow_seeds[..]],
+        ),
+    ).map_err(|_| ErrorCode::CloseAccountError)?;
+
+    Ok(())
+}
+
+/// Pays out an escrow that was not settled: the marketplace fee of the purchase goes to the marketplace
+/// authority and the rest to the seller, then the escrow vault is closed.
+/// The receivers are the wallets for SOL escrows and their token accounts otherwise.
+/// Returns (fee, seller_amount).
+pub fn payout_escrow<'info>(
+    escrow: &Account<'info, Escrow>,
+    token_program: AccountInfo<'info>,
+    payment_mint: AccountInfo<'info>,
+    escrow_vault: Option<AccountInfo<'info>>,
+    marketplace_auth: Option<AccountInfo<'info>>,
+    marketplace_transfer_vault: Option<AccountInfo<'info>>,
+    seller: Option<AccountInfo<'info>>,
+    seller_transfer_vault: Option<AccountInfo<'info>>,
+    vault_rent_receiver: AccountInfo<'info>,
+) -> Result<(u64, u64)> {
+    // the fee is the one of the marketplace when the purchase happened
+    let (fee, seller_amount) = calculate_transfer_distribution(
+        escrow.fees_config.clone(),
+        escrow.payment_mint,
+        escrow.amount,
+    )?;
+
+    let is_native = cmp_pubkeys(&escrow.payment_mint, &NativeMint);
+
+    if fee > 0 {
+        let fee_receiver = if is_native { marketplace_auth } else { marketplace_transfer_vault }
+            .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+        escrow_transfer(
+            escrow,
+            token_program.clone(),
+            payment_mint.clone(),
+            escrow_vault.clone(),
+            fee_receiver,
+            fee,
+        )?;
+    }
+
+    let seller_receiver = if is_native { seller } else { seller_transfer_vault }
+        .ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+    escrow_transfer(
+        escrow,
+        token_program.clone(),
+        payment_mint,
+        escrow_vault.clone(),
+        seller_receiver,
+        seller_amount,
+    )?;
+
+    close_escrow_vault(
+        escrow,
+        token_program,
+        escrow_vault,
+        vault_rent_receiver,
+    )?;
+
+    Ok((fee, seller_amount))
+}
+
+/// Returns (decimals, transfer_fee) of a legacy or token 2022 mint, transfer_fee is the fee of the
+/// current epoch, None if the mint does not have the transfer fee extension.
+pub fn get_transfer_config(payment_mint: &AccountInfo) -> Result<(u8, Option<TransferFee>)> {
+    let epoch = Clock::get()?.epoch;
+    let payment_mint_data = payment_mint.try_borrow_data()?;
+    let payment_mint_state = StateWithExtensions::<Mint2022>::unpack(&payment_mint_data)
+        .map_err(|_| ErrorCode::IncorrectMint)?;
+    let transfer_fee = payment_mint_state.get_extension::<TransferFeeConfig>()
+        .ok()
+        .map(|transfer_fee_config| *transfer_fee_config.get_epoch_fee(epoch));
+
+    Ok((payment_mint_state.base.decimals, transfer_fee))
+}
+
+/// Amount that has to leave the buyer vault so the receiver gets `amount` once the token 2022
+/// transfer fee is withheld. Fails if the fee can not be covered exactly.
+pub fn get_pre_fee_amount(transfer_fee: Option<&TransferFee>, amount: u64) -> Result<u64> {
+    let transfer_fee = match transfer_fee {
+        Some(transfer_fee) => transfer_fee,
+        None => return Ok(amount),
+    };
+
+    let fee_basis_points = u16::from(transfer_fee.transfer_fee_basis_points) as u128;
+    if fee_basis_points == 0 || amount == 0 {
+        return Ok(amount);
+    }
+
+    if fee_basis_points >= 10000 {
+        return Err(ErrorCode::IncorrectTransferFee.into());
+    }
+
+    // fee = ceil(pre_fee_amount * bps / 10000) capped by maximum_fee
+    let uncapped_amount = (amount as u128)
+        .checked_mul(10000)
+        .and_then(|value| value.checked_add(10000 - fee_basis_points - 1))
+        .and_then(|value| value.checked_div(10000 - fee_basis_points))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let capped_amount = (amount as u128)
+        .checked_add(u64::from(transfer_fee.maximum_fee) as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    let pre_fee_amount = u64::try_from(uncapped_amount.min(capped_amount))
+        .map_err(|_| ErrorCode::NumericalOverflow)?;
+
+    let fee = transfer_fee.calculate_fee(pre_fee_amount).ok_or(ErrorCode::NumericalOverflow)?;
+    if pre_fee_amount.checked_sub(fee) != Some(amount) {
+        return Err(ErrorCode::IncorrectTransferFee.into());
+    }
+
+    Ok(pre_fee_amount)
+}
+
+/// Slippage guard, the seller can edit the price or the payment mint at any moment.
+/// Fails if the payment mint is not the one the buyer expects or if the buyer would pay more than
+/// max_total_price, the marketplace fee counts when the buyer pays it and so does the token 2022
+/// transfer fee the buyer pays on top of each transfer (see handle_spl).
+pub fn assert_purchase_price(
+    seller_config: &SellerConfig,
+    fees_config: &FeesConfig,
+    payment_mint: &AccountInfo,
+    total_amount: u64,
+    max_total_price: u64,
+    expected_payment_mint: Pubkey,
+) -> Result<()> {
+    if !cmp_pubkeys(&seller_config.payment_mint, &expected_payment_mint) {
+        return Err(ErrorCode::IncorrectPaymentMint.into());
+    }
+
+    let (_, transfer_fee) = get_transfer_config(payment_mint)?;
+    let (total_fee, seller_amount) = calculate_transfer_distribution(
+        fees_config.clone(),
+        seller_config.payment_mint,
+        total_amount,
+    )?;
+    let buyer_total = get_pre_fee_amount(transfer_fee.as_ref(), total_fee)?
+        .checked_add(get_pre_fee_amount(transfer_fee.as_ref(), seller_amount)?)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    if buyer_total > max_total_price {
+        return Err(ErrorCode::PriceExceedsMaximum.into());
+    }
+
+    Ok(())
+}
+
 /// Calculates the distribution of the token amount, considering transaction fee and potential fee reduction.
 /// Adjusts the fee if the payment mint is the same as the reward mint.
 /// Also is considered fee_payer decided by the marketplace.
@@ -147,4 +400,158 @@ pub fn calculate_transfer_distribution(
     };
 
     Ok((total_fee, seller_amount))
-}
\ No newline at end of file
+}
+
+/// Returns (seller_bonus, buyer_bonus) of a purchase in a campaign, purchase_amount is the price
+/// of all the units bought so the reward scales with them.
+/// Each bonus is cut to the campaign per purchase cap and to what the wallet can still earn, then
+/// both are cut to what is left of the campaign budget and of the bounty vault, seller first.
+/// An exhausted campaign or a dry bounty vault gives 0 instead of failing the purchase.
+pub fn calculate_campaign_rewards(
+    campaign: &Campaign,
+    seller_earned: u64,
+    buyer_earned: u64,
+    purchase_amount: u64,
+    bounty_balance: u64,
+) -> std::result::Result<(u64, u64), ErrorCode> {
+    let capped_bonus = |reward_basis_points: u16, earned: u64| -> std::result::Result<u64, ErrorCode> {
+        let bonus = (reward_basis_points as u128)
+            .checked_mul(purchase_amount as u128)
+            .ok_or(ErrorCode::NumericalOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::NumericalOverflow)? as u64;
+        let bonus = campaign.max_reward_per_purchase
+            .map_or(bonus, |max_reward_per_purchase| bonus.min(max_reward_per_purchase));
+
+        Ok(campaign.max_reward_per_wallet
+            .map_or(bonus, |max_reward_per_wallet| bonus.min(max_reward_per_wallet.saturating_sub(earned))))
+    };
+
+    let seller_bonus = capped_bonus(campaign.seller_reward, seller_earned)?;
+    let buyer_bonus = capped_bonus(campaign.buyer_reward, buyer_earned)?;
+
+    let available = campaign.budget
+        .saturating_sub(campaign.distributed)
+        .min(bounty_balance);
+    let seller_bonus = seller_bonus.min(available);
+    let buyer_bonus = buyer_bonus.min(available - seller_bonus);
+
+    Ok((seller_bonus, buyer_bonus))
+}
+
+/// Rewards a purchase when the campaign is running for the payment mint, pays the bonuses of
+/// calculate_campaign_rewards from the bounty vault to the reward vaults of the seller and the buyer and
+/// registers them in the campaign, both users and the pending rewards of the marketplace.
+/// Returns (campaign, seller_bonus, buyer_bonus), (Pubkey::default(), 0, 0) if the purchase is not rewarded.
+pub fn handle_campaign_rewards<'info>(
+    token_program_v0: AccountInfo<'info>,
+    marketplace: &mut Account<'info, Marketplace>,
+    campaign: Option<&mut Account<'info, Campaign>>,
+    bounty_vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+    seller_reward: Option<&mut Account<'info, Reward>>,
+    seller_reward_vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+    buyer_reward: Option<&mut Account<'info, Reward>>,
+    buyer_reward_vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+    seller_campaign_reward: Option<&mut Account<'info, CampaignReward>>,
+    buyer_campaign_reward: Option<&mut Account<'info, CampaignReward>>,
+    seller: &Pubkey,
+    buyer: &Pubkey,
+    payment_mint: &Pubkey,
+    purchase_amount: u64,
+    now: i64,
+) -> Result<(Pubkey, u64, u64)> {
+    let campaign = match campaign.filter(|campaign| is_campaign_active(campaign, payment_mint, now)) {
+        Some(campaign) => campaign,
+        None => return Ok((Pubkey::default(), 0, 0)),
+    };
+    let campaign_key = campaign.key();
+
+    let seller_reward = seller_reward.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+    let buyer_reward = buyer_reward.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+    assert_authority(&seller_reward.authority, seller)?;
+    assert_authority(&buyer_reward.authority, buyer)?;
+
+    let seller_campaign_reward = seller_campaign_reward.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+    let buyer_campaign_reward = buyer_campaign_reward.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+    assert_authority(&seller_campaign_reward.authority, seller)?;
+    assert_authority(&buyer_campaign_reward.authority, buyer)?;
+
+    if !cmp_pubkeys(&seller_campaign_reward.campaign, &campaign_key)
+        || !cmp_pubkeys(&buyer_campaign_reward.campaign, &campaign_key) {
+        return Err(ErrorCode::IncorrectCampaign.into());
+    }
+
+    let seller_reward_vault = seller_reward_vault.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+    let buyer_reward_vault = buyer_reward_vault.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+    let bounty_vault = bounty_vault.ok_or(ErrorCode::OptionalAccountNotProvided)?;
+
+    if !cmp_pubkeys(&bounty_vault.mint, &campaign.reward_mint) {
+        return Err(ErrorCode::IncorrectMint.into());
+    }
+
+    if !cmp_pubkeys(&seller_reward_vault.mint, &campaign.reward_mint)
+        || !cmp_pubkeys(&buyer_reward_vault.mint, &campaign.reward_mint) {
+        return Err(ErrorCode::IncorrectRewardVault.into());
+    }
+
+    let (seller_bonus, buyer_bonus) = calculate_campaign_rewards(
+        campaign,
+        seller_campaign_reward.earned,
+        buyer_campaign_reward.earned,
+        purchase_amount,
+        bounty_vault.amount,
+    )?;
+
+    let marketplace_id = marketplace.id.to_le_bytes();
+    let marketplace_seeds = &[
+        b"marketplace".as_ref(),
+        marketplace.creator.as_ref(),
+        marketplace_id.as_ref(),
+        &[marketplace.bumps.bump],
+    ];
+
+    transfer(
+        CpiContext::new_with_signer(
+            token_program_v0.clone(),
+            Transfer {
+                from: bounty_vault.to_account_info(),
+                to: seller_reward_vault.to_account_info(),
+                authority: marketplace.to_account_info(),
+            },
+            &[&marketplace_seeds[..]],
+        ),
+        seller_bonus,
+    ).map_err(|_| ErrorCode::TransferError)?;
+
+    transfer(
+        CpiContext::new_with_signer(
+            token_program_v0,
+            Transfer {
+                from: bounty_vault.to_account_info(),
+                to: buyer_reward_vault.to_account_info(),
+                authority: marketplace.to_account_info(),
+            },
+            &[&marketplace_seeds[..]],
+        ),
+        buyer_bonus,
+    ).map_err(|_| ErrorCode::TransferError)?;
+
+    register_campaign_rewards(
+        campaign,
+        seller_reward,
+        buyer_reward,
+        seller_campaign_reward,
+        buyer_campaign_reward,
+        seller_bonus,
+        buyer_bonus,
+    )?;
+
+    marketplace.pending_rewards = marketplace.pending_rewards
+        .checked_add(seller_bonus)
+        .and_then(|pending_rewards| pending_rewards.checked_add(buyer_bonus))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    Ok((campaign_key, seller_bonus, buyer_bonus))
+}
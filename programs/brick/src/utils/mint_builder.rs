@@ -12,7 +12,7 @@ use {
     spl_token_2022::{
         extension::ExtensionType,
         state::Mint as Mint2022,
-        instruction::initialize_non_transferable_mint
+        instruction::{initialize_non_transferable_mint, initialize_mint_close_authority, initialize_permanent_delegate},
     },
 };
 
@@ -28,11 +28,7 @@ pub fn mint_builder<'info>(
     signer: AccountInfo<'info>,
     rent: Sysvar<'_, Rent>,
 ) -> std::result::Result<(), ErrorCode> {
-    let space = if extensions.is_empty() {
-        ExtensionType::get_account_len::<Mint2022>(&[])
-    } else {
-        extensions.iter().map(|ext| ExtensionType::get_account_len::<Mint2022>(&[*ext])).sum()
-    };
+    let space = ExtensionType::get_account_len::<Mint2022>(&extensions);
 
     create_account(
         CpiContext::new_with_signer(
@@ -63,6 +59,36 @@ pub fn mint_builder<'info>(
                     &[&mint_seeds[..]],
                 ).map_err(|_| ErrorCode::MintExtensionError)?;
             }
+            // the mint authority can close the mint account once the supply is 0
+            ExtensionType::MintCloseAuthority => {
+                invoke_signed(
+                    &initialize_mint_close_authority(
+                        &token_program.key(), 
+                        &mint.key().clone(),
+                        Some(&mint_authority.key()),
+                    ).map_err(|_| ErrorCode::MintExtensionError)?,
+                    &[
+                        mint_authority.clone(),
+                        mint.clone()
+                    ],
+                    &[&mint_seeds[..]],
+                ).map_err(|_| ErrorCode::MintExtensionError)?;
+            }
+            // the mint authority can burn from any token account, used to take back product tokens
+            ExtensionType::PermanentDelegate => {
+                invoke_signed(
+                    &initialize_permanent_delegate(
+                        &token_program.key(), 
+                        &mint.key().clone(),
+                        &mint_authority.key(),
+                    ).map_err(|_| ErrorCode::MintExtensionError)?,
+                    &[
+                        mint_authority.clone(),
+                        mint.clone()
+                    ],
+                    &[&mint_seeds[..]],
+                ).map_err(|_| ErrorCode::MintExtensionError)?;
+            }
             _ => {
                 return Err(ErrorCode::ExtensionNotSupported.into());
             }
@@ -1,39 +1,125 @@
 pub mod mint_builder;
 pub mod handle_payment;
 pub mod token_metadata;
+pub mod receipts;
 
 pub use mint_builder::*;
 pub use handle_payment::*;
 pub use token_metadata::*;
+pub use receipts::*;
 
 use anchor_lang::{
     prelude::*,
     solana_program::{program_memory::sol_memcmp, pubkey::PUBKEY_BYTES},
+    system_program::{transfer as native_transfer, Transfer as NativeTransfer},
 };
-use crate::{state::{MarketplaceBumps, RewardsConfig}, error::ErrorCode};
-use spl_token::native_mint::ID as NativeMint;
+use crate::{state::{Campaign, CampaignReward, Payment, Product, Reward, PAYMENT_VERSION}, error::ErrorCode};
 
 pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
     sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0
 }
 
-pub fn get_bounty_bump(address: Pubkey, bumps: MarketplaceBumps, bounty_vaults: Vec<Pubkey>) -> u8 {
-    bounty_vaults.iter().position(|&r| r == address)
-        .map(|index| bumps.vault_bumps[index])
-        .unwrap_or(0)
+/// Bounty vaults are the pda of (b"bounty_vault", marketplace, mint).
+pub fn is_bounty_vault(address: &Pubkey, marketplace: &Pubkey, mint: &Pubkey) -> bool {
+    let (bounty_vault, _) = Pubkey::find_program_address(
+        &[b"bounty_vault".as_ref(), marketplace.as_ref(), mint.as_ref()],
+        &crate::ID,
+    );
+
+    cmp_pubkeys(&bounty_vault, address)
+}
+
+/// Reward vaults are the pda of (b"reward_vault", user, marketplace, mint).
+pub fn is_reward_vault(address: &Pubkey, user: &Pubkey, marketplace: &Pubkey, mint: &Pubkey) -> bool {
+    let (reward_vault, _) = Pubkey::find_program_address(
+        &[b"reward_vault".as_ref(), user.as_ref(), marketplace.as_ref(), mint.as_ref()],
+        &crate::ID,
+    );
+
+    cmp_pubkeys(&reward_vault, address)
+}
+
+/// A purchase is rewarded when the campaign is running (start_ts inclusive, end_ts exclusive)
+/// and it is paid with the campaign payment mint, None = any payment mint.
+pub fn is_campaign_active(campaign: &Campaign, payment_mint: &Pubkey, now: i64) -> bool {
+    now >= campaign.start_ts && now < campaign.end_ts
+        && campaign.payment_mint.map_or(true, |campaign_mint| cmp_pubkeys(&campaign_mint, payment_mint))
+}
+
+/// Adds the rewards of a purchase to the campaign and to the campaign rewards of both users,
+/// the reward accounts of both users stay locked until these rewards are unlocked.
+pub fn register_campaign_rewards(
+    campaign: &mut Campaign,
+    seller_reward: &mut Reward,
+    buyer_reward: &mut Reward,
+    seller_campaign_reward: &mut CampaignReward,
+    buyer_campaign_reward: &mut CampaignReward,
+    seller_bonus: u64,
+    buyer_bonus: u64,
+) -> Result<()> {
+    let unlock_ts = campaign.end_ts
+        .checked_add(campaign.vesting_period)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    seller_reward.locked_until = seller_reward.locked_until.max(unlock_ts);
+    buyer_reward.locked_until = buyer_reward.locked_until.max(unlock_ts);
+    seller_reward.pending_rewards = seller_reward.pending_rewards
+        .checked_add(seller_bonus)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    buyer_reward.pending_rewards = buyer_reward.pending_rewards
+        .checked_add(buyer_bonus)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    campaign.distributed = campaign.distributed
+        .checked_add(seller_bonus)
+        .and_then(|distributed| distributed.checked_add(buyer_bonus))
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    seller_campaign_reward.earned = seller_campaign_reward.earned
+        .checked_add(seller_bonus)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+    buyer_campaign_reward.earned = buyer_campaign_reward.earned
+        .checked_add(buyer_bonus)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/// Reward a user gives back when a purchase is refunded, the share of the bonus recorded in the payment
+/// account (see unregister_payment_record) cut to what the user has not withdrawn yet.
+pub fn calculate_clawback(bonus_share: u64, campaign_reward: &CampaignReward) -> u64 {
+    bonus_share.min(campaign_reward.earned.saturating_sub(campaign_reward.withdrawn))
 }
 
-/// Checks if marketplace reward system is active, is active when:
-/// If reward_mint == null_mint && rewardsEnabled == false -> NO REWARDS
-/// If reward_mint == null_mint && rewardsEnabled == true -> REWARDS (regardless of the reward_mint)
-/// If reward_mint == mint && rewardsEnabled == true -> REWARDS only with specific reward_mint
-/// CANT BE NATIVE MINT (ie SOL), a PDA from my program cant transfer SOL because is not owned by SystemProgram
-pub fn is_rewards_active(reward_config: RewardsConfig, payment_mint: Pubkey, program_id: Pubkey) -> bool {
-    let null_seeds = &[b"null".as_ref()];
-    let account_address = Pubkey::find_program_address(null_seeds, &program_id);
-    
-    reward_config.rewards_enabled && !cmp_pubkeys(&payment_mint, &NativeMint)
-        && (cmp_pubkeys(&payment_mint, &reward_config.reward_mint) || cmp_pubkeys(&reward_config.reward_mint, &account_address.0))
+/// Opposite of register_campaign_rewards, the clawed back rewards are returned to the campaign budget.
+pub fn unregister_campaign_rewards(
+    campaign: &mut Campaign,
+    reward: &mut Reward,
+    campaign_reward: &mut CampaignReward,
+    clawback: u64,
+) {
+    campaign.distributed = campaign.distributed.saturating_sub(clawback);
+    reward.pending_rewards = reward.pending_rewards.saturating_sub(clawback);
+    campaign_reward.earned = campaign_reward.earned.saturating_sub(clawback);
+}
+
+/// Rewards of a campaign that can be withdrawn at `now`, they unlock linearly from the campaign end_ts
+/// during its vesting period, with no vesting period all the rewards unlock at end_ts.
+pub fn calculate_vested_rewards(campaign: &Campaign, earned: u64, now: i64) -> Result<u64> {
+    if now < campaign.end_ts {
+        return Ok(0);
+    }
+
+    let elapsed = now - campaign.end_ts;
+    if campaign.vesting_period <= 0 || elapsed >= campaign.vesting_period {
+        return Ok(earned);
+    }
+
+    let vested = (earned as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(campaign.vesting_period as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    Ok(vested as u64)
 }
 
 pub fn assert_ata(
@@ -70,4 +156,218 @@ pub fn assert_authority(account_property: &Pubkey, auth: &Pubkey) -> Result<()>
     } else {
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Fails if the account still has the layout of the accounts created before version 1,
+/// recognized by its size (see the UNVERSIONED sizes, no versioned layout has the same size).
+/// These accounts have no version byte and their marketplace was derived from (b"marketplace", authority)
+/// instead of (b"marketplace", creator, id), so the program can not find it nor the products, rewards
+/// and payments of that marketplace, their owners have to create them again.
+pub fn assert_versioned(account: &AccountInfo, unversioned_size: usize) -> Result<()> {
+    if account.data_len() == unversioned_size {
+        return Err(ErrorCode::UnversionedAccount.into());
+    }
+
+    Ok(())
+}
+
+/// Fails if sale_start >= sale_end, both are optional.
+pub fn assert_sale_window(sale_start: Option<i64>, sale_end: Option<i64>) -> Result<()> {
+    if let (Some(sale_start), Some(sale_end)) = (sale_start, sale_end) {
+        if sale_start >= sale_end {
+            return Err(ErrorCode::IncorrectSaleWindow.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Fails if the escrow timeout is not positive, None = no escrow.
+pub fn assert_escrow_timeout(escrow_timeout: Option<i64>) -> Result<()> {
+    if escrow_timeout.map_or(false, |escrow_timeout| escrow_timeout <= 0) {
+        return Err(ErrorCode::IncorrectEscrowTimeout.into());
+    }
+
+    Ok(())
+}
+
+/// Fails if the subscription period is not positive, None = not a subscription.
+pub fn assert_subscription_period(subscription_period: Option<i64>) -> Result<()> {
+    if subscription_period.map_or(false, |subscription_period| subscription_period <= 0) {
+        return Err(ErrorCode::IncorrectSubscriptionPeriod.into());
+    }
+
+    Ok(())
+}
+
+/// Checks the product sale window against the clock, start is inclusive and end exclusive.
+pub fn assert_sale_active(product: &Product) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    if product.sale_start.map_or(false, |sale_start| now < sale_start)
+        || product.sale_end.map_or(false, |sale_end| now >= sale_end) {
+        return Err(ErrorCode::SaleNotActive.into());
+    }
+
+    Ok(())
+}
+
+/// Adds the purchased units to the product inventory and to the units held by the buyers, fails if the max
+/// supply is exceeded.
+pub fn register_units_sold(product: &mut Product, amount: u32) -> Result<()> {
+    let sold = product.sold.checked_add(amount.into()).ok_or(ErrorCode::NumericalOverflow)?;
+
+    if let Some(max_supply) = product.max_supply {
+        if sold > max_supply {
+            return Err(ErrorCode::SoldOut.into());
+        }
+    }
+
+    product.sold = sold;
+    product.outstanding_units = product.outstanding_units
+        .checked_add(amount.into())
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/// Adds the purchased units to the buyer payment account, fails if the product wallet limit is exceeded.
+pub fn register_wallet_units(payment: &mut Payment, product: &Product, amount: u32) -> Result<()> {
+    let units = payment.units.checked_add(amount).ok_or(ErrorCode::NumericalOverflow)?;
+
+    if let Some(max_units_per_wallet) = product.max_units_per_wallet {
+        if units > max_units_per_wallet {
+            return Err(ErrorCode::WalletLimitReached.into());
+        }
+    }
+
+    payment.version = PAYMENT_VERSION;
+    payment.units = units;
+
+    Ok(())
+}
+
+/// Returns value * part / total rounded down, 0 if total is 0.
+pub fn calculate_share(value: u64, part: u64, total: u64) -> Result<u64> {
+    if total == 0 {
+        return Ok(0);
+    }
+
+    let share = (value as u128)
+        .checked_mul(part as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?
+        .checked_div(total as u128)
+        .ok_or(ErrorCode::NumericalOverflow)?;
+
+    u64::try_from(share).map_err(|_| ErrorCode::NumericalOverflow.into())
+}
+
+/// Records the price paid for the units bought and the campaign rewards of the purchase in the buyer
+/// payment account, refunds give back a share of both. Purchases without rewards keep the campaign record,
+/// a purchase rewarded by another campaign replaces it.
+pub fn register_payment_record(
+    payment: &mut Payment,
+    paid: u64,
+    campaign: Pubkey,
+    seller_bonus: u64,
+    buyer_bonus: u64,
+) -> Result<()> {
+    payment.paid = payment.paid.checked_add(paid).ok_or(ErrorCode::NumericalOverflow)?;
+
+    if seller_bonus == 0 && buyer_bonus == 0 {
+        return Ok(());
+    }
+
+    if !cmp_pubkeys(&payment.campaign, &campaign) {
+        payment.campaign = campaign;
+        payment.seller_bonus = 0;
+        payment.buyer_bonus = 0;
+    }
+
+    payment.seller_bonus = payment.seller_bonus.checked_add(seller_bonus).ok_or(ErrorCode::NumericalOverflow)?;
+    payment.buyer_bonus = payment.buyer_bonus.checked_add(buyer_bonus).ok_or(ErrorCode::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/// Removes `paid` from the payment record with the same share of the recorded campaign rewards,
+/// returns the (seller_bonus, buyer_bonus) of that share.
+pub fn unregister_payment_record(payment: &mut Payment, paid: u64) -> Result<(u64, u64)> {
+    let seller_bonus = calculate_share(payment.seller_bonus, paid, payment.paid)?;
+    let buyer_bonus = calculate_share(payment.buyer_bonus, paid, payment.paid)?;
+
+    payment.paid = payment.paid.checked_sub(paid).ok_or(ErrorCode::NumericalOverflow)?;
+    payment.seller_bonus -= seller_bonus;
+    payment.buyer_bonus -= buyer_bonus;
+
+    Ok((seller_bonus, buyer_bonus))
+}
+
+/// Extends the buyer subscription by the product period for each unit bought, from now if it expired.
+/// The product keeps the latest expiry to stay open until then. Does nothing if the product is not a subscription.
+pub fn register_subscription(payment: &mut Payment, product: &mut Product, amount: u32, now: i64) -> Result<()> {
+    if let Some(subscription_period) = product.subscription_period {
+        let extension = subscription_period
+            .checked_mul(amount.into())
+            .ok_or(ErrorCode::NumericalOverflow)?;
+
+        payment.expires_at = payment.expires_at
+            .max(now)
+            .checked_add(extension)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        payment.subscription_granted = payment.subscription_granted
+            .checked_add(extension as u64)
+            .ok_or(ErrorCode::NumericalOverflow)?;
+        product.subscriptions_end_ts = product.subscriptions_end_ts.max(payment.expires_at);
+    }
+
+    Ok(())
+}
+
+/// Takes back the share of the granted subscription time of `amount` units, has to be called before
+/// the units leave the payment account. Edits of the product period do not change the time taken back.
+/// When `shorten` the subscription ends earlier, it can end before now.
+pub fn unregister_subscription(payment: &mut Payment, amount: u32, shorten: bool) -> Result<()> {
+    let granted = calculate_share(payment.subscription_granted, amount.into(), payment.units.into())?;
+
+    payment.subscription_granted -= granted;
+    if shorten {
+        payment.expires_at = payment.expires_at
+            .saturating_sub(i64::try_from(granted).map_err(|_| ErrorCode::NumericalOverflow)?);
+    }
+
+    Ok(())
+}
+
+/// Grows the account to new_size keeping its data, the payer funds the rent of the new bytes.
+/// Does nothing if the account is already big enough.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    new_size: usize,
+) -> Result<()> {
+    if account.data_len() >= new_size {
+        return Ok(());
+    }
+
+    let rent_lamports = Rent::get()?.minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+
+    if rent_lamports > 0 {
+        native_transfer(
+            CpiContext::new(
+                system_program, 
+                NativeTransfer {
+                    from: payer,
+                    to: account.clone(),
+                }
+            ), 
+            rent_lamports
+        )?;
+    }
+
+    account.realloc(new_size, true)?;
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use {
+    crate::{error::ErrorCode, state::Product},
+    anchor_lang::prelude::*,
+    anchor_spl::token_2022::{burn, Burn},
+    bubblegum_cpi::cpi::{accounts::Burn as BurnLeaf, burn as burn_leaf},
+};
+
+/// Leaf of a cnft minted by register_buy_cnft, the proof nodes are passed as remaining accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CnftReceipt {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+/// Burns product tokens of any holder, the product is the permanent delegate of its mint.
+/// Fails with BurnError for mints created before the permanent delegate was added.
+pub fn burn_product_tokens<'info>(
+    token_program: AccountInfo<'info>,
+    product_mint: AccountInfo<'info>,
+    token_vault: AccountInfo<'info>,
+    product: &Account<'info, Product>,
+    amount: u64,
+) -> Result<()> {
+    let product_seeds = &[
+        b"product".as_ref(),
+        product.first_id.as_ref(),
+        product.second_id.as_ref(),
+        product.marketplace.as_ref(),
+        &[product.bumps.bump],
+    ];
+
+    burn(
+        CpiContext::new_with_signer(
+            token_program,
+            Burn {
+                mint: product_mint,
+                from: token_vault,
+                authority: product.to_account_info(),
+            },
+            &[&product_seeds[..]],
+        ),
+        amount,
+    ).map_err(|_| ErrorCode::BurnError)?;
+
+    Ok(())
+}
+
+/// Burns a cnft receipt, the product signs as leaf delegate so the holder does not need to sign.
+/// Receipts minted before the product was the leaf delegate can not be burned this way.
+pub fn burn_cnft_receipt<'info>(
+    bubblegum_program: AccountInfo<'info>,
+    tree_authority: AccountInfo<'info>,
+    leaf_owner: AccountInfo<'info>,
+    merkle_tree: AccountInfo<'info>,
+    log_wrapper: AccountInfo<'info>,
+    compression_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    product: &Account<'info, Product>,
+    proof: &[AccountInfo<'info>],
+    receipt: CnftReceipt,
+) -> Result<()> {
+    let product_seeds = &[
+        b"product".as_ref(),
+        product.first_id.as_ref(),
+        product.second_id.as_ref(),
+        product.marketplace.as_ref(),
+        &[product.bumps.bump],
+    ];
+
+    burn_leaf(
+        CpiContext::new_with_signer(
+            bubblegum_program,
+            BurnLeaf {
+                tree_authority,
+                leaf_owner,
+                leaf_delegate: product.to_account_info(),
+                merkle_tree,
+                log_wrapper,
+                compression_program,
+                system_program,
+            },
+            &[&product_seeds[..]],
+        ).with_remaining_accounts(proof.to_vec()),
+        receipt.root,
+        receipt.data_hash,
+        receipt.creator_hash,
+        receipt.nonce,
+        receipt.index,
+    ).map_err(|_| ErrorCode::BurnError)?;
+
+    Ok(())
+}
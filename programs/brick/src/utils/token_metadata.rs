@@ -117,4 +117,41 @@ pub fn create_master_edition_v3<'info>(
     ctx.signer_seeds,
   )
   .map_err(Into::into)
-}
\ No newline at end of file
+}
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+  /// CHECK:
+  pub metadata: AccountInfo<'info>,
+  /// CHECK:
+  pub owner: AccountInfo<'info>,
+  /// CHECK:
+  pub mint: AccountInfo<'info>,
+  /// CHECK:
+  pub token_account: AccountInfo<'info>,
+  /// CHECK:
+  pub master_edition: AccountInfo<'info>,
+  /// CHECK:
+  pub token_program: AccountInfo<'info>,
+}
+
+pub fn burn_nft<'info>(
+  ctx: CpiContext<'_, '_, '_, 'info, BurnNft<'info>>,
+) -> Result<()> {
+  let ix = mpl_token_metadata::instruction::burn_nft(
+    ID,
+    *ctx.accounts.metadata.key,
+    *ctx.accounts.owner.key,
+    *ctx.accounts.mint.key,
+    *ctx.accounts.token_account.key,
+    *ctx.accounts.master_edition.key,
+    *ctx.accounts.token_program.key,
+    None,
+  );
+  solana_program::program::invoke_signed(
+    &ix,
+    &ToAccountInfos::to_account_infos(&ctx),
+    ctx.signer_seeds,
+  )
+  .map_err(Into::into)
+}
@@ -10,12 +10,19 @@ import {
   getAssociatedTokenAddressSync,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   NATIVE_MINT,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  mintTo,
 } from "@solana/spl-token";
 import { 
   createFundedAssociatedTokenAccount, 
   createFundedWallet, 
   createMint, 
-  getSplitId 
+  getCpiEvents,
+  getSplitId,
+  waitForTimestamp,
 } from "./utils";
 import { 
   ConfirmOptions, 
@@ -31,6 +38,10 @@ describe("brick", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.Brick as Program<Brick>;
+  const [eventAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority", "utf-8")],
+    program.programId
+  );
   const confirmOptions: ConfirmOptions = { commitment: "confirmed" };
 
   // Keypairs:
@@ -61,20 +72,81 @@ describe("brick", () => {
   let fee: number;
   let feeReduction: number;
   let rewardMint: anchor.web3.PublicKey;
-  let sellerRewardMarketplace: number;
-  let buyerRewardMarketplace: number;
   let useCnfts: boolean;
   let deliverToken: boolean;
   let transferable: boolean;
   let chainCounter: boolean;
   let permissionless: boolean;
-  let rewardsEnabled: boolean;
   let accessMint: anchor.web3.PublicKey;
   let accessMintBump: number;
+  const marketplaceId = new BN(0);
+  // u64::MAX, disables the slippage guard in the tests that do not check it
+  const maxTotalPrice = new BN("18446744073709551615");
   const FeePayer = {
     Buyer: { buyer: {} },
     Seller: { seller: {} },
   };
+  // marketplace of another creator without fees, the products of other marketplaces can not be bought through it
+  const createForeignMarketplace = async (creator: anchor.web3.Keypair, id: number) => {
+    const foreignMarketplaceId = new BN(id);
+    const [foreignMarketplace] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("marketplace", "utf-8"),
+        creator.publicKey.toBuffer(),
+        foreignMarketplaceId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [foreignAccessMint, foreignAccessMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("access_mint", "utf-8"),
+        foreignMarketplace.toBuffer(),
+      ],
+      program.programId
+    );
+    const [foreignBountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bounty_vault", "utf-8"),
+        foreignMarketplace.toBuffer(),
+        paymentMints[0].toBuffer()
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .initMarketplace({
+        id: foreignMarketplaceId,
+        fee: 0,
+        feeReduction: 0,
+        useCnfts: false,
+        deliverToken: false,
+        transferable: false,
+        chainCounter: true,
+        permissionless: true,
+        accessMintBump: foreignAccessMintBump,
+        feePayer: FeePayer.Seller,
+        vestingPeriod: new BN(0),
+        arbiter: creator.publicKey,
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: creator.publicKey,
+        marketplace: foreignMarketplace,
+        accessMint: foreignAccessMint,
+        rewardMint: paymentMints[0],
+        discountMint: paymentMints[0],
+        bountyVault: foreignBountyVault,
+      })
+      .signers([creator])
+      .rpc(confirmOptions);
+
+    return foreignMarketplace;
+  };
 
   // Product properties
   let productPrice: BN;
@@ -90,7 +162,8 @@ describe("brick", () => {
     [marketplacePubkey] = anchor.web3.PublicKey.findProgramAddressSync(
       [
         Buffer.from("marketplace", "utf-8"),
-        marketplaceAuth.publicKey.toBuffer()
+        marketplaceAuth.publicKey.toBuffer(),
+        marketplaceId.toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -105,8 +178,8 @@ describe("brick", () => {
     );
     bountyVaults.push([bountyVault, 0])
 
-    fee = feeReduction = sellerRewardMarketplace = buyerRewardMarketplace = 0;
-    deliverToken = transferable = rewardsEnabled = useCnfts = false;
+    fee = feeReduction = 0;
+    deliverToken = transferable = useCnfts = false;
     chainCounter = permissionless = true;
 
     [accessMint, accessMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
@@ -118,20 +191,22 @@ describe("brick", () => {
     );
 
     const initMarketplaceParams = {
+      id: marketplaceId,
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       useCnfts: useCnfts,
       deliverToken: deliverToken,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       accessMintBump: accessMintBump,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const initMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
       tokenProgram2022: TOKEN_2022_PROGRAM_ID,
@@ -153,6 +228,8 @@ describe("brick", () => {
 
     const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
     assert.equal(marketplaceAccount.authority.toString(), marketplaceAuth.publicKey.toString());
+    assert.equal(marketplaceAccount.creator.toString(), marketplaceAuth.publicKey.toString());
+    assert.equal(Number(marketplaceAccount.id), Number(marketplaceId));
     assert.equal(marketplaceAccount.tokenConfig.useCnfts, useCnfts);
     assert.equal(marketplaceAccount.tokenConfig.deliverToken, deliverToken);
     assert.equal(marketplaceAccount.tokenConfig.transferable, transferable);
@@ -164,12 +241,10 @@ describe("brick", () => {
     assert.equal(marketplaceAccount.feesConfig.feeReduction, feeReduction);
     assert.equal(marketplaceAccount.feesConfig.feePayer.toString(), FeePayer.Seller.toString());
     assert.equal(marketplaceAccount.rewardsConfig.rewardMint.toString(), rewardMint.toString());
-    assert.equal(marketplaceAccount.rewardsConfig.bountyVaults[0].toString(), bountyVaults[0][0].toString());
-    assert.equal(marketplaceAccount.rewardsConfig.sellerReward, sellerRewardMarketplace);
-    assert.equal(marketplaceAccount.rewardsConfig.buyerReward, buyerRewardMarketplace);
-    assert.equal(marketplaceAccount.rewardsConfig.rewardsEnabled, rewardsEnabled);
+    const bountyVaultAccount = await getAccount(provider.connection, bountyVaults[0][0]);
+    assert.equal(bountyVaultAccount.owner.toString(), marketplacePubkey.toString());
 
-    /// marketplace pda is created with "marketpalce" and signer address, lets try to create the same pda
+    /// marketplace pda is created with "marketpalce", signer address and id, lets try to create the same pda
     /// another user cant create the previous marketplace and authority cant be changed
     try {
       await program.methods
@@ -188,30 +263,30 @@ describe("brick", () => {
     const editMarketplaceInfoParams = {
       fee: 100,
       feeReduction: 100,
-      sellerReward: 100,
-      buyerReward: 100,
       useCnfts: !useCnfts,
       deliverToken: !deliverToken,
       transferable: !transferable,
       chainCounter: !chainCounter,
       permissionless: !permissionless,
-      rewardsEnabled: !rewardsEnabled,
       feePayer: FeePayer.Buyer,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
 
     const editMarketplaceInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: marketplaceAuth.publicKey,
       marketplace: marketplacePubkey,
-      rewardMint: await createMint(provider, confirmOptions),
       discountMint: await createMint(provider, confirmOptions),
     };
 
-    await program.methods
+    const editSignature = await program.methods
       .editMarketplace(editMarketplaceInfoParams)
       .accounts(editMarketplaceInfoAccounts)
       .signers([marketplaceAuth])
       .rpc(confirmOptions)
-      .catch(console.error);
+      .catch(console.error) as string;
 
     const changedMarketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
     assert.isDefined(changedMarketplaceAccount);
@@ -226,11 +301,16 @@ describe("brick", () => {
     assert.equal(changedMarketplaceAccount.feesConfig.feePayer.toString(), FeePayer.Buyer.toString());
     assert.equal(changedMarketplaceAccount.feesConfig.fee, 100);
     assert.equal(changedMarketplaceAccount.feesConfig.feeReduction, 100);
-    assert.equal(changedMarketplaceAccount.rewardsConfig.rewardMint.toString(), editMarketplaceInfoAccounts.rewardMint.toString());
-    assert.equal(changedMarketplaceAccount.rewardsConfig.bountyVaults[0].toString(), bountyVaults[0][0].toString());
-    assert.equal(changedMarketplaceAccount.rewardsConfig.sellerReward, 100);
-    assert.equal(changedMarketplaceAccount.rewardsConfig.buyerReward, 100);
-    assert.equal(changedMarketplaceAccount.rewardsConfig.rewardsEnabled, !rewardsEnabled);
+    assert.equal(changedMarketplaceAccount.rewardsConfig.rewardMint.toString(), rewardMint.toString());
+
+    const [editEvent] = await getCpiEvents(program, editSignature);
+    assert.equal(editEvent.name, "MarketplaceEdited");
+    assert.isTrue(editEvent.data.marketplace.equals(marketplacePubkey));
+    assert.equal(editEvent.data.tokenConfig.useCnfts, !useCnfts);
+    assert.equal(editEvent.data.permissionConfig.permissionless, !permissionless);
+    assert.isTrue(editEvent.data.feesConfig.discountMint.equals(editMarketplaceInfoAccounts.discountMint));
+    assert.equal(editEvent.data.feesConfig.fee, 100);
+    assert.isTrue(editEvent.data.rewardsConfig.rewardMint.equals(rewardMint));
 
     // another wallet tries to change product data
     const balance = 1000;
@@ -238,17 +318,18 @@ describe("brick", () => {
     const exploiterEditInfoParams = {
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       useCnfts: useCnfts,
       deliverToken: deliverToken,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const exploiterEditInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: exploiter.publicKey,
       marketplace: marketplacePubkey,
       rewardMint: rewardMint,
@@ -262,26 +343,27 @@ describe("brick", () => {
         .signers([exploiter])
         .rpc();
     } catch (e) {
-      // marketplace seeds are composed by "marketplace" & signer
+      // marketplace seeds are composed by "marketplace" & creator, signer has to be the authority
       if (e as anchor.AnchorError)
-        assert.equal(e.error.errorCode.code, "ConstraintSeeds");
+        assert.equal(e.error.errorCode.code, "IncorrectAuthority");
     }
   
     // to be able to re-use this account and its data, the account data will be the same that was before this unit test
     const initMarketplaceParams = {
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       useCnfts: useCnfts,
       deliverToken: deliverToken,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const initMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: marketplaceAuth.publicKey,
       marketplace: marketplacePubkey,
       rewardMint: rewardMint,
@@ -308,10 +390,88 @@ describe("brick", () => {
     assert.equal(marketplaceAccount.feesConfig.fee, fee);
     assert.equal(marketplaceAccount.feesConfig.feeReduction, feeReduction);
     assert.equal(marketplaceAccount.rewardsConfig.rewardMint.toString(), rewardMint.toString());
-    assert.equal(marketplaceAccount.rewardsConfig.bountyVaults[0].toString(), bountyVaults[0][0].toString());
-    assert.equal(marketplaceAccount.rewardsConfig.sellerReward, sellerRewardMarketplace);
-    assert.equal(marketplaceAccount.rewardsConfig.buyerReward, buyerRewardMarketplace);
-    assert.equal(marketplaceAccount.rewardsConfig.rewardsEnabled, rewardsEnabled);
+  });
+
+  it("Should transfer the marketplace authority in two steps", async () => {
+    const newAuthority = await createFundedWallet(provider, 1000);
+
+    await program.methods
+      .proposeMarketplaceAuthority(newAuthority.publicKey)
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    let marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    assert.equal(marketplaceAccount.authority.toString(), marketplaceAuth.publicKey.toString());
+    assert.equal(marketplaceAccount.pendingAuthority.toString(), newAuthority.publicKey.toString());
+
+    // only the proposed wallet can accept the authority
+    try {
+      await program.methods
+        .acceptMarketplaceAuthority()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          signer: exploiter.publicKey,
+          marketplace: marketplacePubkey,
+        })
+        .signers([exploiter])
+        .rpc();
+    } catch (e) {
+      if (e as anchor.AnchorError)
+        assert.equal(e.error.errorCode.code, "IncorrectAuthority");
+    }
+
+    await program.methods
+      .acceptMarketplaceAuthority()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: newAuthority.publicKey,
+        marketplace: marketplacePubkey,
+      })
+      .signers([newAuthority])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    assert.equal(marketplaceAccount.authority.toString(), newAuthority.publicKey.toString());
+    assert.equal(marketplaceAccount.creator.toString(), marketplaceAuth.publicKey.toString());
+    assert.equal(marketplaceAccount.pendingAuthority.toString(), anchor.web3.PublicKey.default.toString());
+
+    // to be able to re-use this account, the authority is given back to the original wallet
+    await program.methods
+      .proposeMarketplaceAuthority(marketplaceAuth.publicKey)
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: newAuthority.publicKey,
+        marketplace: marketplacePubkey,
+      })
+      .signers([newAuthority])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    await program.methods
+      .acceptMarketplaceAuthority()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    assert.equal(marketplaceAccount.authority.toString(), marketplaceAuth.publicKey.toString());
   });
 
   it("Should create a product account", async () => {
@@ -342,8 +502,16 @@ describe("brick", () => {
       secondId: [...secondId],
       productPrice: productPrice,
       productMintBump: mintBump,
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+      escrowTimeout: null,
+      subscriptionPeriod: null,
     };
     const initProductAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgram2022: TOKEN_2022_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
@@ -379,27 +547,38 @@ describe("brick", () => {
     const newPrice = new BN(88);
 
     const editProductInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: seller.publicKey,
       product: productPubkey,
       paymentMint: newPaymentMintPubkey,
     };
-    await program.methods
-      .editProduct(newPrice)
+    const editSignature = await program.methods
+      .editProduct({ productPrice: newPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts(editProductInfoAccounts)
       .signers([seller])
-      .rpc()
-      .catch(console.error);
+      .rpc(confirmOptions)
+      .catch(console.error) as string;
 
     const changedProductAccount = await program.account.product.fetch(productPubkey);
     assert.isDefined(changedProductAccount);
     assert.equal(changedProductAccount.sellerConfig.paymentMint.toString(), newPaymentMintPubkey.toString());
     assert.equal(Number(changedProductAccount.sellerConfig.productPrice), Number(newPrice));
 
+    const [editEvent] = await getCpiEvents(program, editSignature);
+    assert.equal(editEvent.name, "ProductEdited");
+    assert.isTrue(editEvent.data.product.equals(productPubkey));
+    assert.isTrue(editEvent.data.paymentMint.equals(newPaymentMintPubkey));
+    assert.equal(Number(editEvent.data.productPrice), Number(newPrice));
+    assert.isNull(editEvent.data.maxSupply);
+
     // another wallet tries to change product data
     try {
       await program.methods
-        .editProduct(productPrice)
+        .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
         .accounts({
+          eventAuthority,
+          program: program.programId,
           signer: exploiter.publicKey,
           product: productPubkey,
           paymentMint: newPaymentMintPubkey
@@ -413,8 +592,10 @@ describe("brick", () => {
 
     // to be able to re-use this account and its data, the account data will be the same that was before this unit test
     await program.methods
-      .editProduct(productPrice)
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts({
+        eventAuthority,
+        program: program.programId,
         signer: seller.publicKey,
         product: productPubkey,
         paymentMint: paymentMints[0],
@@ -462,7 +643,7 @@ describe("brick", () => {
       vaultBalances
     ]);
   
-    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+    const [paymentPubkey, paymentBump] = anchor.web3.PublicKey.findProgramAddressSync(
       [
         Buffer.from("payment", "utf-8"), 
         buyer.publicKey.toBuffer(), 
@@ -472,8 +653,11 @@ describe("brick", () => {
     );
 
     const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -485,21 +669,24 @@ describe("brick", () => {
       buyerTransferVault: buyerVaults[0][0],
       sellerTransferVault: sellerVaults[0][0],
       marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: null,
       bountyVault: null,
       sellerReward: null,
       sellerRewardVault: null,
       buyerReward: null,
       buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
     };
 
     const sig = await program.methods
-      .registerBuyCounter(1)
+      .registerBuyCounter(1, maxTotalPrice, registerBuyAccounts.paymentMint)
       .accounts(registerBuyAccounts)
       .signers([buyer])
       .postInstructions(
         [
           await program.methods
-            .registerBuyCounter(1)
+            .registerBuyCounter(1, maxTotalPrice, registerBuyAccounts.paymentMint)
             .accounts(registerBuyAccounts)
             .instruction()
         ]
@@ -513,6 +700,8 @@ describe("brick", () => {
     const paymentAccount = await program.account.payment.fetch(paymentPubkey);
     assert.isDefined(paymentAccount);
     assert.equal(paymentAccount.units, 2);
+    // settle_dispute derives the payment from the stored bump
+    assert.equal(paymentAccount.bump, paymentBump);
 
     const buyerVaultAccount = await getOrCreateAssociatedTokenAccount(
       provider.connection,
@@ -541,25 +730,115 @@ describe("brick", () => {
     assert.equal(Number(sellerVaultAccount.amount), sellerVaults[0][1]);
   });
 
+  it("Should reject purchases while the marketplace is paused", async () => {
+    await program.methods
+      .setMarketplacePause(true)
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMints[0],
+      buyerTransferVault: buyerVaults[0][0],
+      sellerTransferVault: sellerVaults[0][0],
+      marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    try {
+      await program.methods
+        .registerBuyCounter(1, maxTotalPrice, paymentMints[0])
+        .accounts(registerBuyAccounts)
+        .signers([buyer])
+        .rpc();
+    } catch (e) {
+      if (e as anchor.AnchorError)
+        assert.equal(e.error.errorCode.code, "MarketplacePaused");
+    }
+
+    // another marketplace that is not paused can not sell the products of this one
+    const foreignMarketplace = await createForeignMarketplace(exploiter, 5);
+    for (const registerBuy of [program.methods.registerBuy, program.methods.registerBuyCounter]) {
+      try {
+        await registerBuy(1, maxTotalPrice, paymentMints[0])
+          .accounts({ ...registerBuyAccounts, marketplace: foreignMarketplace })
+          .signers([buyer])
+          .rpc(confirmOptions);
+        assert.fail("the product can only be bought through its marketplace");
+      } catch (e) {
+        assert.include(String(e), "ConstraintSeeds");
+      }
+    }
+
+    await program.methods
+      .setMarketplacePause(false)
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    assert.isFalse(marketplaceAccount.paused);
+  });
+
   it("Should register a buy with spl and fees (seller fee payer)", async () => {
-    [fee, feeReduction, sellerRewardMarketplace, buyerRewardMarketplace] = [100, 0, 0, 0];
+    [fee, feeReduction] = [100, 0];
     const editMarketplaceInfoParams = {
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       deliverToken: deliverToken,
       useCnfts: useCnfts,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const editMarketplaceInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: marketplaceAuth.publicKey,
       marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
       discountMint: discountMint,
     };
 
@@ -580,8 +859,11 @@ describe("brick", () => {
     );
 
     const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -593,15 +875,18 @@ describe("brick", () => {
       buyerTransferVault: buyerVaults[0][0],
       sellerTransferVault: sellerVaults[0][0],
       marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: null,
       bountyVault: null,
       sellerReward: null,
       sellerRewardVault: null,
       buyerReward: null,
       buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
     };
 
-    await program.methods
-      .registerBuy(1)
+    const signature = await program.methods
+      .registerBuy(1, maxTotalPrice, registerBuyAccounts.paymentMint)
       .accounts(registerBuyAccounts)
       .signers([buyer])
       .rpc(confirmOptions)
@@ -646,6 +931,16 @@ describe("brick", () => {
     );
     marketplaceVaults[0][1] = marketplaceVaults[0][1] + marketplaceFee;
     assert.equal(Number(marketAuthTransferVaultAccount.amount), marketplaceVaults[0][1]);
+
+    const [purchaseEvent] = await getCpiEvents(program, signature);
+    assert.equal(purchaseEvent.name, "ProductPurchased");
+    assert.isTrue(purchaseEvent.data.buyer.equals(buyer.publicKey));
+    assert.isTrue(purchaseEvent.data.seller.equals(seller.publicKey));
+    assert.isTrue(purchaseEvent.data.product.equals(productPubkey));
+    assert.equal(purchaseEvent.data.units, 1);
+    assert.equal(Number(purchaseEvent.data.grossAmount), Number(productPrice));
+    assert.equal(Number(purchaseEvent.data.fee), marketplaceFee);
+    assert.equal(Number(purchaseEvent.data.sellerAmount), Number(productPrice) - marketplaceFee);
   });
 
   it("Should register a buy (with fees and native mint)", async () => {
@@ -653,12 +948,14 @@ describe("brick", () => {
     const newPrice = new BN(88);
 
     const editProductInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: seller.publicKey,
       product: productPubkey,
       paymentMint: newPaymentMintPubkey,
     };
     await program.methods
-      .editProduct(newPrice)
+      .editProduct({ productPrice: newPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts(editProductInfoAccounts)
       .signers([seller])
       .rpc()
@@ -678,8 +975,11 @@ describe("brick", () => {
     const buyerBalance = await provider.connection.getBalance(buyer.publicKey, confirmOptions);
 
     const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: seller.publicKey,
@@ -692,15 +992,18 @@ describe("brick", () => {
       buyerTransferVault: null,
       sellerTransferVault: null,
       marketplaceTransferVault: null,
+      campaign: null,
       bountyVault: null,
       sellerReward: null,
       sellerRewardVault: null,
       buyerReward: null,
       buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
     };
 
     await program.methods
-      .registerBuy(1)
+      .registerBuy(1, maxTotalPrice, registerBuyAccounts.paymentMint)
       .accounts(registerBuyAccounts)
       .signers([buyer])
       .rpc(confirmOptions)
@@ -708,12 +1011,14 @@ describe("brick", () => {
 
     // Set the previous product configuration
     const initialEditProductInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: seller.publicKey,
       product: productPubkey,
       paymentMint: paymentMints[0],
     };
     await program.methods
-      .editProduct(productPrice)
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts(initialEditProductInfoAccounts)
       .signers([seller])
       .rpc()
@@ -730,25 +1035,25 @@ describe("brick", () => {
   });
 
   it("Should register a buy (with fees and specific mint makes fee reduction)", async () => {
-    [fee, feeReduction, sellerRewardMarketplace, buyerRewardMarketplace] = [100, 20, 0, 0];
+    [fee, feeReduction] = [100, 20];
     discountMint = paymentMints[0];
     const editMarketplaceInfoParams = {
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       useCnfts: useCnfts,
       deliverToken: deliverToken,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const editMarketplaceInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: marketplaceAuth.publicKey,
       marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
       discountMint: discountMint,
     };
 
@@ -769,8 +1074,11 @@ describe("brick", () => {
     );
 
     const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -782,15 +1090,18 @@ describe("brick", () => {
       buyerTransferVault: buyerVaults[0][0],
       sellerTransferVault: sellerVaults[0][0],
       marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: null,
       bountyVault: null,
       sellerReward: null,
       sellerRewardVault: null,
       buyerReward: null,
       buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
     };
 
     await program.methods
-      .registerBuy(1)
+      .registerBuy(1, maxTotalPrice, registerBuyAccounts.paymentMint)
       .accounts(registerBuyAccounts)
       .signers([buyer])
       .rpc(confirmOptions)
@@ -837,7 +1148,7 @@ describe("brick", () => {
     assert.equal(Number(sellerVaultAccount.amount), sellerVaults[0][1]);
   });
 
-  it("Should register a buy during promo time, users can withdraw bonus when that promo is finished (not when still active)", async () => {
+  it("Should register a buy during a campaign, users can withdraw bonus when that campaign is finished (not when still active)", async () => {
     // fill the token account controlled by the program to send the rewards
     bountyVaults[0][1] = 1000000;
     marketplaceVaults[0][1] = marketplaceVaults[0][1] - 1000000;
@@ -853,26 +1164,25 @@ describe("brick", () => {
         ),
       [marketplaceAuth as anchor.web3.Signer]
     );
-    [fee, feeReduction, sellerRewardMarketplace, buyerRewardMarketplace] = [100, 20, 20, 20];
-    rewardsEnabled = true;
+    [fee, feeReduction] = [100, 20];
     const editMarketplaceInfoParams = {
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       useCnfts: useCnfts,
       deliverToken: deliverToken,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       accessMintBump: accessMintBump,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const editMarketplaceInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
       signer: marketplaceAuth.publicKey,
       marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
       discountMint: discountMint,
     };
 
@@ -883,9 +1193,67 @@ describe("brick", () => {
       .rpc()
       .catch(console.error);
 
+    const campaignId = new BN(0);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    const campaignParams = {
+      id: campaignId,
+      paymentMint: null,
+      startTs: new BN(now - 60),
+      endTs: new BN(now + 20),
+      budget: new BN(bountyVaults[0][1]),
+      sellerReward: 20,
+      buyerReward: 20,
+      maxRewardPerPurchase: null,
+      maxRewardPerWallet: null,
+    };
+    const initCampaignAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      rewardMint: rewardMint,
+      bountyVault: bountyVaults[0][0],
+      campaign: campaign,
+    };
+
+    try {
+      await program.methods
+        .initCampaign({ ...campaignParams, endTs: campaignParams.startTs })
+        .accounts(initCampaignAccounts)
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+      assert.fail("the campaign start should be before the campaign end");
+    } catch (e) {
+      assert.include(String(e), "IncorrectCampaignWindow");
+    }
+
+    await program.methods
+      .initCampaign(campaignParams)
+      .accounts(initCampaignAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const campaignAccount = await program.account.campaign.fetch(campaign);
+    assert.equal(campaignAccount.marketplace.toString(), marketplacePubkey.toString());
+    assert.equal(campaignAccount.rewardMint.toString(), rewardMint.toString());
+    assert.equal(campaignAccount.paymentMint, null);
+    assert.equal(Number(campaignAccount.endTs), Number(campaignParams.endTs));
+    assert.equal(Number(campaignAccount.budget), bountyVaults[0][1]);
+    assert.equal(Number(campaignAccount.distributed), 0);
+
     [sellerReward] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("reward", "utf-8"), 
+        Buffer.from("reward", "utf-8"),
         seller.publicKey.toBuffer(),
         marketplacePubkey.toBuffer()
       ],
@@ -893,7 +1261,7 @@ describe("brick", () => {
     );
     const [sellerRewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("reward_vault", "utf-8"), 
+        Buffer.from("reward_vault", "utf-8"),
         seller.publicKey.toBuffer(),
         marketplacePubkey.toBuffer(),
         rewardMint.toBuffer(),
@@ -903,6 +1271,8 @@ describe("brick", () => {
     sellerRewardVaults.push([sellerRewardVault, 0]);
 
     const initSellerRewardAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
@@ -912,7 +1282,7 @@ describe("brick", () => {
       rewardMint: paymentMints[0],
       rewardVault: sellerRewardVault,
     };
-    
+
     await program.methods
       .initReward()
       .accounts(initSellerRewardAccounts)
@@ -924,11 +1294,12 @@ describe("brick", () => {
     assert.isDefined(sellerRewardAccount);
     assert.equal(sellerRewardAccount.authority.toString(), seller.publicKey.toString());
     assert.equal(sellerRewardAccount.marketplace.toString(), marketplacePubkey.toString());
-    assert.equal(sellerRewardAccount.rewardVaults[0].toString(), sellerRewardVault.toString());
+    const sellerRewardVaultAccount = await getAccount(provider.connection, sellerRewardVault);
+    assert.equal(sellerRewardVaultAccount.owner.toString(), sellerReward.toString());
 
     [buyerReward] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("reward", "utf-8"), 
+        Buffer.from("reward", "utf-8"),
         buyer.publicKey.toBuffer(),
         marketplacePubkey.toBuffer()
       ],
@@ -936,7 +1307,7 @@ describe("brick", () => {
     );
     const [buyerRewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("reward_vault", "utf-8"), 
+        Buffer.from("reward_vault", "utf-8"),
         buyer.publicKey.toBuffer(),
         marketplacePubkey.toBuffer(),
         rewardMint.toBuffer(),
@@ -946,6 +1317,8 @@ describe("brick", () => {
     buyerRewardVaults.push([buyerRewardVault, 0]);
 
     const initBuyerRewardAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
@@ -966,19 +1339,52 @@ describe("brick", () => {
     assert.isDefined(buyerRewardAccount);
     assert.equal(buyerRewardAccount.authority.toString(), buyer.publicKey.toString());
     assert.equal(buyerRewardAccount.marketplace.toString(), marketplacePubkey.toString());
-    assert.equal(buyerRewardAccount.rewardVaults[0].toString(), buyerRewardVault.toString());
+    const buyerRewardVaultAccount = await getAccount(provider.connection, buyerRewardVault);
+    assert.equal(buyerRewardVaultAccount.owner.toString(), buyerReward.toString());
+
+    // the buyer pays the campaign reward account of the seller too
+    const campaignRewards: anchor.web3.PublicKey[] = [];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaignReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          authority: user,
+          campaign: campaign,
+          campaignReward: campaignReward,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      campaignRewards.push(campaignReward);
+    }
+    const [sellerCampaignReward, buyerCampaignReward] = campaignRewards;
 
     const [paymentPubkey, bump] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("payment", "utf-8"), 
-        buyer.publicKey.toBuffer(), 
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
         productPubkey.toBuffer(),
       ],
       program.programId
     );
     const registerRewardBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -990,88 +1396,76 @@ describe("brick", () => {
       buyerTransferVault: buyerVaults[0][0],
       sellerTransferVault: sellerVaults[0][0],
       marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: campaign,
       bountyVault: bountyVaults[0][0],
       sellerReward: sellerReward,
       sellerRewardVault: sellerRewardVault,
       buyerReward: buyerReward,
       buyerRewardVault: buyerRewardVault,
+      sellerCampaignReward: sellerCampaignReward,
+      buyerCampaignReward: buyerCampaignReward,
     };
 
     await program.methods
-      .registerBuy(1)
+      .registerBuy(1, maxTotalPrice, registerRewardBuyAccounts.paymentMint)
       .accounts(registerRewardBuyAccounts)
       .signers([buyer])
       .rpc(confirmOptions)
       .catch(console.error);
 
-    const oldsellerPromo = 20;
-    const expectedSellerReward = Math.floor(Number(productPrice) * oldsellerPromo / 10000);
+    const expectedSellerReward = Math.floor(Number(productPrice) * campaignParams.sellerReward / 10000);
     const sellerRewardFunds = await getAccount(provider.connection, sellerRewardVault);
     assert.equal(Number(sellerRewardFunds.amount), expectedSellerReward);
 
-    const oldBuyerPromo = 20;
-    const expectedBuyerReward = Math.floor(Number(productPrice) * oldBuyerPromo / 10000);
-    const buyerRewardFunds = await getAccount(provider.connection, sellerRewardVault);
+    const expectedBuyerReward = Math.floor(Number(productPrice) * campaignParams.buyerReward / 10000);
+    const buyerRewardFunds = await getAccount(provider.connection, buyerRewardVault);
     assert.equal(Number(buyerRewardFunds.amount), expectedBuyerReward);
 
+    const rewardedCampaign = await program.account.campaign.fetch(campaign);
+    assert.equal(Number(rewardedCampaign.distributed), expectedSellerReward + expectedBuyerReward);
+    const buyerCampaignRewardAccount = await program.account.campaignReward.fetch(buyerCampaignReward);
+    assert.equal(Number(buyerCampaignRewardAccount.earned), expectedBuyerReward);
+    assert.equal(Number(buyerCampaignRewardAccount.withdrawn), 0);
+
     try {
       await program.methods
         .withdrawReward()
         .accounts({
+          eventAuthority,
+          program: program.programId,
           tokenProgramV0: TOKEN_PROGRAM_ID,
           signer: buyer.publicKey,
           marketplace: marketplacePubkey,
           reward: buyerReward,
+          campaign: campaign,
+          campaignReward: buyerCampaignReward,
           rewardMint: rewardMint,
           receiverVault: buyerVaults[0][0],
           rewardVault: buyerRewardVaults[0][0],
         })
       .signers([buyer])
       .rpc(confirmOptions);
+      assert.fail("rewards can not be withdrawn while the campaign is running");
     } catch (e) {
-      if (e as anchor.AnchorError)
-        assert.equal(e.error.errorCode.code, "OpenPromotion");
+      assert.include(String(e), "OpenPromotion");
     }
 
-    // promo is finished with rewardsEnable = false
-    rewardsEnabled = false;
-    const changeMarketplaceInfoParams = {
-      fee: fee,
-      feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
-      useCnfts: useCnfts,
-      deliverToken: deliverToken,
-      transferable: transferable,
-      chainCounter: chainCounter,
-      permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
-      accessMintBump: accessMintBump,
-      feePayer: FeePayer.Seller,
-    };
-    const changeMarketplaceInfoAccounts = {
-      signer: marketplaceAuth.publicKey,
-      marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
-      discountMint: discountMint,
-    };
-
-    await program.methods
-      .editMarketplace(changeMarketplaceInfoParams)
-      .accounts(changeMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
-      .catch(console.error);
+    // the campaign is finished once its end_ts has passed
+    await waitForTimestamp(provider.connection, Number(campaignParams.endTs));
 
     // only the reward auth can withdraw
     try {
       await program.methods
         .withdrawReward()
         .accounts({
+          eventAuthority,
+          program: program.programId,
           tokenProgramV0: TOKEN_PROGRAM_ID,
           signer: seller.publicKey,
           marketplace: marketplacePubkey,
           reward: buyerReward,
+          campaign: campaign,
+          campaignReward: buyerCampaignReward,
           rewardMint: rewardMint,
           receiverVault: sellerVaults[0][0],
           rewardVault: buyerRewardVaults[0][0],
@@ -1083,28 +1477,44 @@ describe("brick", () => {
         assert.equal(e.error.errorCode.code, "ConstraintSeeds");
     }
 
-    await program.methods
+    const withdrawSignature = await program.methods
       .withdrawReward()
       .accounts({
+        eventAuthority,
+        program: program.programId,
         tokenProgramV0: TOKEN_PROGRAM_ID,
         signer: buyer.publicKey,
         marketplace: marketplacePubkey,
         reward: buyerReward,
+        campaign: campaign,
+        campaignReward: buyerCampaignReward,
         rewardMint: rewardMint,
         receiverVault: buyerVaults[0][0],
         rewardVault: buyerRewardVaults[0][0],
       })
       .signers([buyer])
       .rpc(confirmOptions)
-      .catch(console.error);
+      .catch(console.error) as string;
+
+    const [withdrawEvent] = await getCpiEvents(program, withdrawSignature);
+    assert.equal(withdrawEvent.name, "RewardWithdrawn");
+    assert.isTrue(withdrawEvent.data.campaign.equals(campaign));
+    assert.isTrue(withdrawEvent.data.reward.equals(buyerReward));
+    assert.isTrue(withdrawEvent.data.authority.equals(buyer.publicKey));
+    assert.isTrue(withdrawEvent.data.rewardMint.equals(rewardMint));
+    assert.equal(Number(withdrawEvent.data.amount), expectedBuyerReward);
 
     await program.methods
       .withdrawReward()
       .accounts({
+        eventAuthority,
+        program: program.programId,
         tokenProgramV0: TOKEN_PROGRAM_ID,
         signer: seller.publicKey,
         marketplace: marketplacePubkey,
         reward: sellerReward,
+        campaign: campaign,
+        campaignReward: sellerCampaignReward,
         rewardMint: rewardMint,
         receiverVault: sellerVaults[0][0],
         rewardVault: sellerRewardVaults[0][0],
@@ -1113,6 +1523,9 @@ describe("brick", () => {
       .rpc(confirmOptions)
       .catch(console.error);
 
+    const withdrawnCampaignReward = await program.account.campaignReward.fetch(buyerCampaignReward);
+    assert.equal(Number(withdrawnCampaignReward.withdrawn), expectedBuyerReward);
+
     const marketplaceTokenVaultAccount = await getOrCreateAssociatedTokenAccount(
       provider.connection,
       marketplaceAuth as anchor.web3.Signer,
@@ -1142,60 +1555,63 @@ describe("brick", () => {
       "confirmed",
       confirmOptions,
       TOKEN_PROGRAM_ID,
-    ); 
+    );
 
     const governanceFee = Math.floor(Number(productPrice) * (fee - feeReduction) / 10000);
     marketplaceVaults[0][1] = marketplaceVaults[0][1] + governanceFee;
     buyerVaults[0][1] = buyerVaults[0][1] - Number(productPrice) + expectedBuyerReward;
-    const oldSellerPromo = 20; // Change to the actual value
-    const expectedSellerBonus = Math.floor(Number(productPrice) * oldSellerPromo / 10000);
-    sellerVaults[0][1] = sellerVaults[0][1] + Number(productPrice) - governanceFee + expectedSellerBonus;
+    sellerVaults[0][1] = sellerVaults[0][1] + Number(productPrice) - governanceFee + expectedSellerReward;
 
     assert.equal(Number(marketplaceTokenVaultAccount.amount), marketplaceVaults[0][1]);
-    assert.equal(Number(buyerTokenTransferVaultAccount.amount), buyerVaults[0][1]);    
+    assert.equal(Number(buyerTokenTransferVaultAccount.amount), buyerVaults[0][1]);
     assert.equal(Number(sellerTokenVaultAccount.amount), sellerVaults[0][1]);
   });
 
-  it("Should register a buy with SOL as payment, with rewards active (should not give rewards and not errors)", async () => {
-    [fee, feeReduction, sellerRewardMarketplace, buyerRewardMarketplace] = [100, 20, 20, 20];
-    rewardsEnabled = true;
-    [rewardMint] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("null", "utf-8")],
+  it("Should register a buy with SOL as payment during a campaign for another payment mint (should not give rewards and not errors)", async () => {
+    [fee, feeReduction] = [100, 20];
+    const campaignId = new BN(1);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
       program.programId
     );
-    const editMarketplaceInfoParams = {
-      fee: fee,
-      feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
-      useCnfts: useCnfts,
-      deliverToken: deliverToken,
-      transferable: transferable,
-      chainCounter: chainCounter,
-      permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
-      accessMintBump: accessMintBump,
-      feePayer: FeePayer.Seller,
-    };
-    const editMarketplaceInfoAccounts = {
-      signer: marketplaceAuth.publicKey,
-      marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
-      discountMint: discountMint,
-    };
-
-    await program.methods
-      .editMarketplace(editMarketplaceInfoParams)
-      .accounts(editMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
-      .catch(console.error);
-
-    const productPrice = new BN(1000);
+    const now = Math.floor(Date.now() / 1000);
     await program.methods
-      .editProduct(productPrice)
+      .initCampaign({
+        id: campaignId,
+        paymentMint: paymentMints[0],
+        startTs: new BN(now - 60),
+        endTs: new BN(now + 3600),
+        budget: new BN(1000),
+        sellerReward: 20,
+        buyerReward: 20,
+        maxRewardPerPurchase: null,
+        maxRewardPerWallet: null,
+      })
       .accounts({
-        signer: seller.publicKey,
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+        rewardMint: rewardMint,
+        bountyVault: bountyVaults[0][0],
+        campaign: campaign,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const productPrice = new BN(1000);
+    await program.methods
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: seller.publicKey,
         product: productPubkey,
         paymentMint: NATIVE_MINT,
       })
@@ -1205,15 +1621,18 @@ describe("brick", () => {
 
     const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("payment", "utf-8"), 
-        buyer.publicKey.toBuffer(), 
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
         productPubkey.toBuffer(),
       ],
       program.programId
     );
     const registerRewardBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: seller.publicKey,
@@ -1225,18 +1644,21 @@ describe("brick", () => {
       buyerTransferVault: null,
       sellerTransferVault: null,
       marketplaceTransferVault: null,
+      campaign: campaign,
       bountyVault: null,
       sellerReward: sellerReward,
       sellerRewardVault: null,
       buyerReward: buyerReward,
       buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
     };
 
     const preSellerBalance = await provider.connection.getBalance(seller.publicKey, confirmOptions);
     const preBuyerBalance = await provider.connection.getBalance(buyer.publicKey, confirmOptions);
 
     await program.methods
-      .registerBuy(1)
+      .registerBuy(1, maxTotalPrice, registerRewardBuyAccounts.paymentMint)
       .accounts(registerRewardBuyAccounts)
       .signers([buyer])
       .rpc(confirmOptions)
@@ -1248,9 +1670,12 @@ describe("brick", () => {
 
     assert.equal(preSellerBalance + Number(productPrice) - marketplaceFee, postSellerBalance);
     assert.equal(preBuyerBalance - 1000, postBuyerBalance);
+
+    const campaignAccount = await program.account.campaign.fetch(campaign);
+    assert.equal(Number(campaignAccount.distributed), 0);
   });
 
-  it("Should allow receiving rewards with different mints (always reward == payment), also tests reward enforcement (only one mint)", async () => {
+  it("Should run campaigns with different reward mints, each campaign unlocks its rewards when it ends", async () => {
     const rewardMint = await createMint(provider, confirmOptions);
     const vaultBalances = 50000;
     marketplaceVaults.push([
@@ -1281,39 +1706,41 @@ describe("brick", () => {
       vaultBalances
     ]);
 
-    [fee, feeReduction, sellerRewardMarketplace, buyerRewardMarketplace] = [100, 20, 20, 20];
-    rewardsEnabled = true;
-    const editMarketplaceInfoParams = {
-      fee: fee,
-      feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
-      useCnfts: useCnfts,
-      deliverToken: deliverToken,
-      transferable: transferable,
-      chainCounter: chainCounter,
-      permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
-      accessMintBump: accessMintBump,
-      feePayer: FeePayer.Seller,
-    };
-    const editMarketplaceInfoAccounts = {
-      signer: marketplaceAuth.publicKey,
-      marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
-      discountMint: discountMint,
-    };
-    await program.methods
-      .editMarketplace(editMarketplaceInfoParams)
-      .accounts(editMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
-      .catch(console.error);
+    const newRewardMint = await createMint(provider, confirmOptions);
+    marketplaceVaults.push([
+      await createFundedAssociatedTokenAccount(
+        provider,
+        newRewardMint,
+        vaultBalances,
+        marketplaceAuth
+      ),
+      vaultBalances
+    ]);
+    sellerVaults.push([
+      await createFundedAssociatedTokenAccount(
+        provider,
+        newRewardMint,
+        vaultBalances,
+        seller
+      ),
+      vaultBalances
+    ]);
+    buyerVaults.push([
+      await createFundedAssociatedTokenAccount(
+        provider,
+        newRewardMint,
+        vaultBalances,
+        buyer
+      ),
+      vaultBalances
+    ]);
 
     const productPrice = new BN(5000);
     await program.methods
-      .editProduct(productPrice)
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts({
+        eventAuthority,
+        program: program.programId,
         signer: seller.publicKey,
         product: productPubkey,
         paymentMint:rewardMint,
@@ -1322,114 +1749,170 @@ describe("brick", () => {
       .rpc()
       .catch(console.error);
 
-    await sleep(2000);
-
-    const [bountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("bounty_vault", "utf-8"),
-        marketplacePubkey.toBuffer(),
-        rewardMint.toBuffer()
-      ],
-      program.programId
-    );
-
-    await program.methods
-      .initBounty()
-      .accounts({
-        systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        signer: marketplaceAuth.publicKey,
-        marketplace: marketplacePubkey,
-        rewardMint: rewardMint,
-        bountyVault: bountyVault,
-      })
-      .signers([marketplaceAuth])
-      .rpc(confirmOptions)
-      .catch(console.error);
-
-    await provider.sendAndConfirm(
-      new Transaction()
-        .add(
-          createTransferInstruction(
-            marketplaceVaults[1][0],
-            bountyVault,
-            marketplaceAuth.publicKey,
-            5000
-          )
-        ),
-      [marketplaceAuth as anchor.web3.Signer]
-    );
-    bountyVaults.push([bountyVault, 5000]);
-    const [sellerRewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("reward_vault", "utf-8"), 
-        seller.publicKey.toBuffer(),
-        marketplacePubkey.toBuffer(),
-        rewardMint.toBuffer(),
-      ],
-      program.programId
-    );
-    sellerRewardVaults.push([sellerRewardVault, 0]);
+    // one bounty vault and one reward vault per user for each mint
+    const mints = [rewardMint, newRewardMint];
+    for (const [index, mint] of mints.entries()) {
+      const [bountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("bounty_vault", "utf-8"),
+          marketplacePubkey.toBuffer(),
+          mint.toBuffer()
+        ],
+        program.programId
+      );
 
-    await program.methods
-      .initRewardVault()
-      .accounts({
-        systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        signer: seller.publicKey,
-        marketplace: marketplacePubkey,
-        reward: sellerReward,
-        rewardMint: rewardMint,
-        rewardVault: sellerRewardVault,
-      })
-      .signers([seller])
-      .rpc()
-      .catch(console.error);
+      await program.methods
+        .initBounty()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: marketplaceAuth.publicKey,
+          marketplace: marketplacePubkey,
+          rewardMint: mint,
+          bountyVault: bountyVault,
+        })
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions)
+        .catch(console.error);
 
-    const [buyerRewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("reward_vault", "utf-8"), 
-        buyer.publicKey.toBuffer(),
-        marketplacePubkey.toBuffer(),
-        rewardMint.toBuffer(),
-      ],
-      program.programId
-    );
-    buyerRewardVaults.push([buyerRewardVault, 0]);
+      await provider.sendAndConfirm(
+        new Transaction()
+          .add(
+            createTransferInstruction(
+              marketplaceVaults[index + 1][0],
+              bountyVault,
+              marketplaceAuth.publicKey,
+              5000
+            )
+          ),
+        [marketplaceAuth as anchor.web3.Signer]
+      );
+      bountyVaults.push([bountyVault, 5000]);
+
+      for (const [user, reward, rewardVaults] of [
+        [seller, sellerReward, sellerRewardVaults],
+        [buyer, buyerReward, buyerRewardVaults],
+      ] as [anchor.web3.Keypair, anchor.web3.PublicKey, [anchor.web3.PublicKey, number][]][]) {
+        const [rewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("reward_vault", "utf-8"),
+            user.publicKey.toBuffer(),
+            marketplacePubkey.toBuffer(),
+            mint.toBuffer(),
+          ],
+          program.programId
+        );
+        rewardVaults.push([rewardVault, 0]);
+
+        await program.methods
+          .initRewardVault()
+          .accounts({
+            eventAuthority,
+            program: program.programId,
+            systemProgram: SystemProgram.programId,
+            tokenProgramV0: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: SYSVAR_RENT_PUBKEY,
+            signer: user.publicKey,
+            marketplace: marketplacePubkey,
+            reward: reward,
+            rewardMint: mint,
+            rewardVault: rewardVault,
+          })
+          .signers([user])
+          .rpc()
+          .catch(console.error);
+      }
+    }
 
-    await program.methods
-      .initRewardVault()
-      .accounts({
-        systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        signer: buyer.publicKey,
-        marketplace: marketplacePubkey,
-        reward: buyerReward,
-        rewardMint: rewardMint,
-        rewardVault: buyerRewardVault,
-      })
-      .signers([buyer])
-      .rpc()
-      .catch(console.error);
+    // short campaign paying rewardMint and a long one paying newRewardMint, both running at the same time
+    const now = Math.floor(Date.now() / 1000);
+    const campaigns: [anchor.web3.PublicKey, anchor.web3.PublicKey, anchor.web3.PublicKey, BN][] = [];
+    for (const [index, mint] of mints.entries()) {
+      const campaignId = new BN(2 + index);
+      const endTs = new BN(index == 0 ? now + 20 : now + 3600);
+      const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign", "utf-8"),
+          marketplacePubkey.toBuffer(),
+          campaignId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaign({
+          id: campaignId,
+          paymentMint: mint,
+          startTs: new BN(now - 60),
+          endTs: endTs,
+          budget: new BN(5000),
+          sellerReward: 20,
+          buyerReward: 20,
+          maxRewardPerPurchase: null,
+          maxRewardPerWallet: null,
+        })
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: marketplaceAuth.publicKey,
+          marketplace: marketplacePubkey,
+          rewardMint: mint,
+          bountyVault: bountyVaults[index + 1][0],
+          campaign: campaign,
+        })
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+
+      const campaignRewards: anchor.web3.PublicKey[] = [];
+      for (const user of [seller.publicKey, buyer.publicKey]) {
+        const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("campaign_reward", "utf-8"),
+            user.toBuffer(),
+            campaign.toBuffer(),
+          ],
+          program.programId
+        );
+        await program.methods
+          .initCampaignReward()
+          .accounts({
+            eventAuthority,
+            program: program.programId,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+            signer: buyer.publicKey,
+            authority: user,
+            campaign: campaign,
+            campaignReward: campaignReward,
+          })
+          .signers([buyer])
+          .rpc(confirmOptions);
+        campaignRewards.push(campaignReward);
+      }
+
+      campaigns.push([campaign, campaignRewards[0], campaignRewards[1], endTs]);
+    }
 
-    await sleep(2000);
     const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("payment", "utf-8"), 
-        buyer.publicKey.toBuffer(), 
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
         productPubkey.toBuffer(),
       ],
       program.programId
     );
-    const registerRewardBuyAccounts = {
+    const getRegisterBuyAccounts = (paymentIndex: number, campaignIndex: number) => ({
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -1437,193 +1920,223 @@ describe("brick", () => {
       marketplace: marketplacePubkey,
       product: productPubkey,
       payment: paymentPubkey,
-      paymentMint: rewardMint,
-      buyerTransferVault: buyerVaults[1][0],
-      sellerTransferVault: sellerVaults[1][0],
-      marketplaceTransferVault: marketplaceVaults[1][0],
-      bountyVault: bountyVault,
+      paymentMint: mints[paymentIndex],
+      buyerTransferVault: buyerVaults[paymentIndex + 1][0],
+      sellerTransferVault: sellerVaults[paymentIndex + 1][0],
+      marketplaceTransferVault: marketplaceVaults[paymentIndex + 1][0],
+      campaign: campaigns[campaignIndex][0],
+      bountyVault: bountyVaults[campaignIndex + 1][0],
       sellerReward: sellerReward,
-      sellerRewardVault: sellerRewardVaults[1][0],
+      sellerRewardVault: sellerRewardVaults[campaignIndex + 1][0],
       buyerReward: buyerReward,
-      buyerRewardVault: buyerRewardVaults[1][0],
-    };
+      buyerRewardVault: buyerRewardVaults[campaignIndex + 1][0],
+      sellerCampaignReward: campaigns[campaignIndex][1],
+      buyerCampaignReward: campaigns[campaignIndex][2],
+    });
 
     await program.methods
-      .registerBuy(1)
-      .accounts(registerRewardBuyAccounts)
+      .registerBuy(1, maxTotalPrice, rewardMint)
+      .accounts(getRegisterBuyAccounts(0, 0))
       .signers([buyer])
       .rpc(confirmOptions)
       .catch(console.error);
 
     // check reward vaults
-    const oldsellerPromo = 20;
-    const expectedSellerReward = Math.floor(Number(productPrice) * oldsellerPromo / 10000);
+    const expectedSellerReward = Math.floor(Number(productPrice) * 20 / 10000);
     const sellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[1][0]);
     assert.equal(Number(sellerRewardFunds.amount), expectedSellerReward);
 
-    const oldBuyerPromo = 20;
-    const expectedBuyerReward = Math.floor(Number(productPrice) * oldBuyerPromo / 10000);
+    const expectedBuyerReward = Math.floor(Number(productPrice) * 20 / 10000);
     const buyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[1][0]);
     assert.equal(Number(buyerRewardFunds.amount), expectedBuyerReward);
 
-    // second time doing the same to test another reward mint (first change marketplace reward mint to check if can be enforced to not get reward with a different mint)
-    const newRewardMint = await createMint(provider, confirmOptions);
-    await sleep(2000)
-    marketplaceVaults.push([
-      await createFundedAssociatedTokenAccount(
-        provider,
-        newRewardMint,
-        vaultBalances,
-        marketplaceAuth
-      ),
-      vaultBalances
-    ]);
-    sellerVaults.push([
-      await createFundedAssociatedTokenAccount(
-        provider,
-        newRewardMint,
-        vaultBalances,
-        seller
-      ),
-      vaultBalances
-    ]);
-    buyerVaults.push([
-      await createFundedAssociatedTokenAccount(
-        provider,
-        newRewardMint,
-        vaultBalances,
-        buyer
-      ),
-      vaultBalances
-    ]);
-
-    [fee, feeReduction, sellerRewardMarketplace, buyerRewardMarketplace] = [100, 20, 20, 20];
-    rewardsEnabled = true;
-    const newEditMarketplaceInfoParams = {
-      fee: fee,
-      feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
-      useCnfts: useCnfts,
-      deliverToken: deliverToken,
-      transferable: transferable,
-      chainCounter: chainCounter,
-      permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
-      accessMintBump: accessMintBump,
-      feePayer: FeePayer.Seller,
-    };
-    const newEditMarketplaceInfoAccounts = {
-      signer: marketplaceAuth.publicKey,
-      marketplace: marketplacePubkey,
-      rewardMint: newRewardMint,
-      discountMint: discountMint,
-    };
+    // the second campaign only rewards purchases paid with newRewardMint
     await program.methods
-      .editMarketplace(newEditMarketplaceInfoParams)
-      .accounts(newEditMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
+      .registerBuy(1, maxTotalPrice, rewardMint)
+      .accounts(getRegisterBuyAccounts(0, 1))
+      .signers([buyer])
+      .rpc(confirmOptions)
       .catch(console.error);
 
-    await sleep(2000);
-    const [newBountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("bounty_vault", "utf-8"),
-        marketplacePubkey.toBuffer(),
-        newRewardMint.toBuffer()
-      ],
-      program.programId
-    );
+    const noSellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[2][0]);
+    assert.equal(Number(noSellerRewardFunds.amount), 0);
+
+    const noBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[2][0]);
+    assert.equal(Number(noBuyerRewardFunds.amount), 0);
+
+    // now change the product mint to be able to get rewards from the second campaign
     await program.methods
-      .initBounty()
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts({
-        systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        signer: marketplaceAuth.publicKey,
-        marketplace: marketplacePubkey,
-        rewardMint: newRewardMint,
-        bountyVault: newBountyVault,
+        eventAuthority,
+        program: program.programId,
+        signer: seller.publicKey,
+        product: productPubkey,
+        paymentMint: newRewardMint,
       })
-      .signers([marketplaceAuth])
+      .signers([seller])
+      .rpc()
+      .catch(console.error);
+
+    await program.methods
+      .registerBuy(1, maxTotalPrice, newRewardMint)
+      .accounts(getRegisterBuyAccounts(1, 1))
+      .signers([buyer])
       .rpc(confirmOptions)
       .catch(console.error);
 
-    await provider.sendAndConfirm(
-      new Transaction()
-        .add(
-          createTransferInstruction(
-            marketplaceVaults[2][0],
-            newBountyVault,
-            marketplaceAuth.publicKey,
-            5000
-          )
-        ),
-      [marketplaceAuth as anchor.web3.Signer]
-    );
-    bountyVaults.push([bountyVault, 5000]);
+    const newSellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[2][0]);
+    assert.equal(Number(newSellerRewardFunds.amount), expectedSellerReward);
+
+    const newBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[2][0]);
+    assert.equal(Number(newBuyerRewardFunds.amount), expectedBuyerReward);
+
+    const getWithdrawAccounts = (user: anchor.web3.Keypair, campaignIndex: number) => ({
+      eventAuthority,
+      program: program.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      signer: user.publicKey,
+      marketplace: marketplacePubkey,
+      reward: user == seller ? sellerReward : buyerReward,
+      campaign: campaigns[campaignIndex][0],
+      campaignReward: campaigns[campaignIndex][user == seller ? 1 : 2],
+      rewardMint: mints[campaignIndex],
+      receiverVault: (user == seller ? sellerVaults : buyerVaults)[campaignIndex + 1][0],
+      rewardVault: (user == seller ? sellerRewardVaults : buyerRewardVaults)[campaignIndex + 1][0],
+    });
+
+    // the first campaign ends while the second one is still running
+    await waitForTimestamp(provider.connection, Number(campaigns[0][3]));
+
+    try {
+      await program.methods
+        .withdrawReward()
+        .accounts(getWithdrawAccounts(buyer, 1))
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("rewards can not be withdrawn while the campaign is running");
+    } catch (e) {
+      assert.include(String(e), "OpenPromotion");
+    }
+
+    const preBuyerVault1Funds = await getAccount(provider.connection, buyerVaults[1][0]);
+    await program.methods
+      .withdrawReward()
+      .accounts(getWithdrawAccounts(buyer, 0))
+      .signers([buyer])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const postBuyerVault1Funds = await getAccount(provider.connection, buyerVaults[1][0]);
+    assert.equal(Number(postBuyerVault1Funds.amount - preBuyerVault1Funds.amount), Number(buyerRewardFunds.amount));
+
+    const preSellerVault1Funds = await getAccount(provider.connection, sellerVaults[1][0]);
+    await program.methods
+      .withdrawReward()
+      .accounts(getWithdrawAccounts(seller, 0))
+      .signers([seller])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const postSellerVault1Funds = await getAccount(provider.connection, sellerVaults[1][0]);
+    assert.equal(Number(postSellerVault1Funds.amount - preSellerVault1Funds.amount), Number(sellerRewardFunds.amount));
+
+    // the rewards of the second campaign stay in the reward vaults
+    const pendingBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[2][0]);
+    assert.equal(Number(pendingBuyerRewardFunds.amount), expectedBuyerReward);
+  });
 
-    const [newSellerRewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+  it("Should reject purchases with substituted bounty or reward vaults", async () => {
+    const campaignId = new BN(4);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("reward_vault", "utf-8"), 
-        seller.publicKey.toBuffer(),
+        Buffer.from("campaign", "utf-8"),
         marketplacePubkey.toBuffer(),
-        newRewardMint.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
-    sellerRewardVaults.push([newSellerRewardVault, 0]);
+    const now = Math.floor(Date.now() / 1000);
     await program.methods
-      .initRewardVault()
+      .initCampaign({
+        id: campaignId,
+        paymentMint: paymentMints[0],
+        startTs: new BN(now - 60),
+        endTs: new BN(now + 3600),
+        budget: new BN(1000),
+        sellerReward: 100,
+        buyerReward: 100,
+        maxRewardPerPurchase: null,
+        maxRewardPerWallet: null,
+      })
       .accounts({
+        eventAuthority,
+        program: program.programId,
         systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         rent: SYSVAR_RENT_PUBKEY,
-        signer: seller.publicKey,
+        signer: marketplaceAuth.publicKey,
         marketplace: marketplacePubkey,
-        reward: sellerReward,
-        rewardMint: newRewardMint,
-        rewardVault: newSellerRewardVault,
+        rewardMint: paymentMints[0],
+        bountyVault: bountyVaults[0][0],
+        campaign: campaign,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const campaignRewards: anchor.web3.PublicKey[] = [];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaignReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          authority: user,
+          campaign: campaign,
+          campaignReward: campaignReward,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      campaignRewards.push(campaignReward);
+    }
+
+    const productPrice = new BN(1000);
+    await program.methods
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: seller.publicKey,
+        product: productPubkey,
+        paymentMint: paymentMints[0],
       })
       .signers([seller])
-      .rpc()
-      .catch(console.error);
+      .rpc(confirmOptions);
 
-    const [newBuyerRewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("reward_vault", "utf-8"), 
+        Buffer.from("payment", "utf-8"),
         buyer.publicKey.toBuffer(),
-        marketplacePubkey.toBuffer(),
-        newRewardMint.toBuffer(),
+        productPubkey.toBuffer(),
       ],
       program.programId
     );
-    buyerRewardVaults.push([newBuyerRewardVault, 0]);
-
-    await program.methods
-      .initRewardVault()
-      .accounts({
-        systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        signer: buyer.publicKey,
-        marketplace: marketplacePubkey,
-        reward: buyerReward,
-        rewardMint: newRewardMint,
-        rewardVault: newBuyerRewardVault,
-      })
-      .signers([buyer])
-      .rpc()
-      .catch(console.error);
-
-    await sleep(2000)
-    const registerNoRewardBuyAccounts = {
+    const registerRewardBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -1631,46 +2144,230 @@ describe("brick", () => {
       marketplace: marketplacePubkey,
       product: productPubkey,
       payment: paymentPubkey,
-      paymentMint: rewardMint,
-      buyerTransferVault: buyerVaults[1][0],
-      sellerTransferVault: sellerVaults[1][0],
-      marketplaceTransferVault: marketplaceVaults[1][0],
-      bountyVault: bountyVault,
+      paymentMint: paymentMints[0],
+      buyerTransferVault: buyerVaults[0][0],
+      sellerTransferVault: sellerVaults[0][0],
+      marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: campaign,
+      bountyVault: bountyVaults[0][0],
       sellerReward: sellerReward,
-      sellerRewardVault: sellerRewardVaults[1][0],
+      sellerRewardVault: sellerRewardVaults[0][0],
       buyerReward: buyerReward,
-      buyerRewardVault: buyerRewardVaults[1][0],
+      buyerRewardVault: buyerRewardVaults[0][0],
+      sellerCampaignReward: campaignRewards[0],
+      buyerCampaignReward: campaignRewards[1],
     };
 
+    // token accounts of the right mint that are not the expected pdas
+    const substitutions: [object, string][] = [
+      [{ bountyVault: marketplaceVaults[0][0] }, "IncorrectBountyVault"],
+      [{ sellerRewardVault: buyerVaults[0][0] }, "IncorrectRewardVault"],
+      [{ sellerRewardVault: buyerRewardVaults[0][0] }, "IncorrectRewardVault"],
+      [{ buyerRewardVault: sellerRewardVaults[0][0] }, "IncorrectRewardVault"],
+    ];
+    for (const [substitution, error] of substitutions) {
+      try {
+        await program.methods
+          .registerBuy(1, maxTotalPrice, paymentMints[0])
+          .accounts({ ...registerRewardBuyAccounts, ...substitution })
+          .signers([buyer])
+          .rpc(confirmOptions);
+        assert.fail("the purchase should fail with a substituted vault");
+      } catch (e) {
+        assert.include(String(e), error);
+      }
+    }
+
+    const preSellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[0][0]);
+    const preBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[0][0]);
+
     await program.methods
-      .registerBuy(1)
-      .accounts(registerNoRewardBuyAccounts)
+      .registerBuy(1, maxTotalPrice, paymentMints[0])
+      .accounts(registerRewardBuyAccounts)
       .signers([buyer])
-      .rpc(confirmOptions)
-      .catch(console.error);
+      .rpc(confirmOptions);
 
-    // check reward vaults
-    const noSellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[2][0]);
-    assert.equal(Number(noSellerRewardFunds.amount), 0);
+    const expectedReward = Math.floor(Number(productPrice) * 100 / 10000);
+    const postSellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[0][0]);
+    const postBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[0][0]);
+    assert.equal(Number(postSellerRewardFunds.amount - preSellerRewardFunds.amount), expectedReward);
+    assert.equal(Number(postBuyerRewardFunds.amount - preBuyerRewardFunds.amount), expectedReward);
+  });
 
-    const noBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[2][0]);
-    assert.equal(Number(noBuyerRewardFunds.amount), 0);
+  it("Should scale campaign rewards with the units bought and cap them without failing the purchase", async () => {
+    const dryMint = await createMint(provider, confirmOptions);
+    const bountyBalance = 700;
+    const marketplaceDryVault = await createFundedAssociatedTokenAccount(
+      provider,
+      dryMint,
+      bountyBalance,
+      marketplaceAuth
+    );
+    const [bountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bounty_vault", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        dryMint.toBuffer()
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .initBounty()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+        rewardMint: dryMint,
+        bountyVault: bountyVault,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    await provider.sendAndConfirm(
+      new Transaction()
+        .add(
+          createTransferInstruction(
+            marketplaceDryVault,
+            bountyVault,
+            marketplaceAuth.publicKey,
+            bountyBalance
+          )
+        ),
+      [marketplaceAuth as anchor.web3.Signer]
+    );
+
+    const rewardVaults: anchor.web3.PublicKey[] = [];
+    for (const [user, reward] of [
+      [seller, sellerReward],
+      [buyer, buyerReward],
+    ] as [anchor.web3.Keypair, anchor.web3.PublicKey][]) {
+      const [rewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("reward_vault", "utf-8"),
+          user.publicKey.toBuffer(),
+          marketplacePubkey.toBuffer(),
+          dryMint.toBuffer(),
+        ],
+        program.programId
+      );
+      rewardVaults.push(rewardVault);
+
+      await program.methods
+        .initRewardVault()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: user.publicKey,
+          marketplace: marketplacePubkey,
+          reward: reward,
+          rewardMint: dryMint,
+          rewardVault: rewardVault,
+        })
+        .signers([user])
+        .rpc(confirmOptions);
+    }
+
+    // 10% for each side, at most 250 per purchase and 400 per wallet, the budget is bigger than the bounty vault
+    const campaignId = new BN(5);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .initCampaign({
+        id: campaignId,
+        paymentMint: null,
+        startTs: new BN(now - 60),
+        endTs: new BN(now + 3600),
+        budget: new BN(100000),
+        sellerReward: 1000,
+        buyerReward: 1000,
+        maxRewardPerPurchase: new BN(250),
+        maxRewardPerWallet: new BN(400),
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+        rewardMint: dryMint,
+        bountyVault: bountyVault,
+        campaign: campaign,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const campaignRewards: anchor.web3.PublicKey[] = [];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaignReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          authority: user,
+          campaign: campaign,
+          campaignReward: campaignReward,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      campaignRewards.push(campaignReward);
+    }
 
-    // now change the product mint to be able to give rewards with that new mint
+    const productPrice = new BN(1000);
     await program.methods
-      .editProduct(productPrice)
+      .editProduct({ productPrice: productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
       .accounts({
+        eventAuthority,
+        program: program.programId,
         signer: seller.publicKey,
         product: productPubkey,
-        paymentMint: newRewardMint,
+        paymentMint: paymentMints[0],
       })
       .signers([seller])
-      .rpc()
-      .catch(console.error);
+      .rpc(confirmOptions);
 
-    const newRegisterRewardBuyAccounts = {
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const registerRewardBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: buyer.publicKey,
       seller: null,
@@ -1678,440 +2375,3551 @@ describe("brick", () => {
       marketplace: marketplacePubkey,
       product: productPubkey,
       payment: paymentPubkey,
-      paymentMint: newRewardMint,
-      buyerTransferVault: buyerVaults[2][0],
-      sellerTransferVault: sellerVaults[2][0],
-      marketplaceTransferVault: marketplaceVaults[2][0],
-      bountyVault: newBountyVault,
+      paymentMint: paymentMints[0],
+      buyerTransferVault: buyerVaults[0][0],
+      sellerTransferVault: sellerVaults[0][0],
+      marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: campaign,
+      bountyVault: bountyVault,
       sellerReward: sellerReward,
-      sellerRewardVault: sellerRewardVaults[2][0],
+      sellerRewardVault: rewardVaults[0],
       buyerReward: buyerReward,
-      buyerRewardVault: buyerRewardVaults[2][0],
+      buyerRewardVault: rewardVaults[1],
+      sellerCampaignReward: campaignRewards[0],
+      buyerCampaignReward: campaignRewards[1],
     };
 
-    await program.methods
-      .registerBuy(1)
-      .accounts(newRegisterRewardBuyAccounts)
-      .signers([buyer])
-      .rpc(confirmOptions)
-      .catch(console.error);
+    // [units, expected seller reward, expected buyer reward]
+    // 2 units: 10% of 2000 for each side
+    // 5 units: 10% of 5000 is cut to 250 per purchase, then to the 200 left per wallet,
+    // the buyer only gets the 100 left in the bounty vault
+    // 1 unit: the bounty vault is dry, the purchase goes through without rewards
+    const purchases: [number, number, number][] = [
+      [2, 200, 200],
+      [5, 200, 100],
+      [1, 0, 0],
+    ];
+    for (const [units, expectedSellerReward, expectedBuyerReward] of purchases) {
+      const preSellerFunds = await getAccount(provider.connection, sellerVaults[0][0]);
+      const preSellerRewardFunds = await getAccount(provider.connection, rewardVaults[0]);
+      const preBuyerRewardFunds = await getAccount(provider.connection, rewardVaults[1]);
 
-    // check reward vaults
-    const newExpectedSellerReward = Math.floor(Number(productPrice) * oldsellerPromo / 10000);
-    const newSellerRewardFunds = await getAccount(provider.connection, sellerRewardVaults[2][0]);
-    assert.equal(Number(newSellerRewardFunds.amount), newExpectedSellerReward);
+      await program.methods
+        .registerBuy(units, maxTotalPrice, paymentMints[0])
+        .accounts(registerRewardBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+
+      const postSellerFunds = await getAccount(provider.connection, sellerVaults[0][0]);
+      const postSellerRewardFunds = await getAccount(provider.connection, rewardVaults[0]);
+      const postBuyerRewardFunds = await getAccount(provider.connection, rewardVaults[1]);
+      assert.isAbove(Number(postSellerFunds.amount - preSellerFunds.amount), 0);
+      assert.equal(Number(postSellerRewardFunds.amount - preSellerRewardFunds.amount), expectedSellerReward);
+      assert.equal(Number(postBuyerRewardFunds.amount - preBuyerRewardFunds.amount), expectedBuyerReward);
+    }
 
-    const newExpectedBuyerReward = Math.floor(Number(productPrice) * oldBuyerPromo / 10000);
-    const newBuyerRewardFunds = await getAccount(provider.connection, buyerRewardVaults[2][0]);
-    assert.equal(Number(newBuyerRewardFunds.amount), newExpectedBuyerReward);
-    // withdraw rewards (both mints done before)
-    // promo is finished with rewardsEnable = false
-    rewardsEnabled = false;
-    const changeMarketplaceInfoParams = {
-      fee: fee,
-      feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
-      useCnfts: useCnfts,
-      deliverToken: deliverToken,
-      transferable: transferable,
-      chainCounter: chainCounter,
-      permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
-      accessMintBump: accessMintBump,
-      feePayer: FeePayer.Seller,
-    };
-    const changeMarketplaceInfoAccounts = {
+    const campaignAccount = await program.account.campaign.fetch(campaign);
+    const sellerCampaignReward = await program.account.campaignReward.fetch(campaignRewards[0]);
+    const buyerCampaignReward = await program.account.campaignReward.fetch(campaignRewards[1]);
+    const bountyVaultAccount = await getAccount(provider.connection, bountyVault);
+    assert.equal(Number(campaignAccount.distributed), bountyBalance);
+    assert.equal(Number(sellerCampaignReward.earned), 400);
+    assert.equal(Number(buyerCampaignReward.earned), 300);
+    assert.equal(Number(bountyVaultAccount.amount), 0);
+  });
+
+  it("Should keep the bounty vaults locked while a campaign can still pay rewards", async () => {
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    assert.isAbove(Number(marketplaceAccount.campaignsEndTs), Math.floor(Date.now() / 1000));
+
+    const bountyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
       signer: marketplaceAuth.publicKey,
       marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
-      discountMint: discountMint,
+      rewardMint: paymentMints[0],
+      bountyVault: bountyVaults[0][0],
+      receiverVault: marketplaceVaults[0][0],
     };
 
-    await program.methods
-      .editMarketplace(changeMarketplaceInfoParams)
-      .accounts(changeMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
-      .catch(console.error);
+    try {
+      await program.methods
+        .withdrawBounty(new BN(1))
+        .accounts(bountyAccounts)
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+      assert.fail("the bounty should be locked");
+    } catch (e) {
+      assert.include(String(e), "OpenPromotion");
+    }
 
-    await sleep(2000);
+    try {
+      await program.methods
+        .closeBounty()
+        .accounts(bountyAccounts)
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+      assert.fail("the bounty should be locked");
+    } catch (e) {
+      assert.include(String(e), "OpenPromotion");
+    }
 
-    const preBuyerVault1Funds = await getAccount(provider.connection, buyerVaults[1][0]);
-    await program.methods
-      .withdrawReward()
-      .accounts({
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        signer: buyer.publicKey,
-        marketplace: marketplacePubkey,
-        reward: buyerReward,
-        rewardMint: rewardMint,
-        receiverVault: buyerVaults[1][0],
-        rewardVault: buyerRewardVaults[1][0],
-      })
-      .signers([buyer])
-      .rpc()
-      .catch(console.error);
+    assert.isNotNull(await provider.connection.getAccountInfo(bountyVaults[0][0]));
+  });
 
-    await sleep(1000);
-    const postBuyerVault1Funds = await getAccount(provider.connection, buyerVaults[1][0]);
-    assert.equal(Number(postBuyerVault1Funds.amount - preBuyerVault1Funds.amount), Number(buyerRewardFunds.amount));
+  it("Should unlock campaign rewards linearly during the vesting period", async () => {
+    const vestingPeriod = 40;
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    const editMarketplaceParams = {
+      fee: marketplaceAccount.feesConfig.fee,
+      feeReduction: marketplaceAccount.feesConfig.feeReduction,
+      useCnfts: marketplaceAccount.tokenConfig.useCnfts,
+      deliverToken: marketplaceAccount.tokenConfig.deliverToken,
+      transferable: marketplaceAccount.tokenConfig.transferable,
+      chainCounter: marketplaceAccount.tokenConfig.chainCounter,
+      permissionless: marketplaceAccount.permissionConfig.permissionless,
+      feePayer: marketplaceAccount.feesConfig.feePayer,
+      vestingPeriod: new BN(vestingPeriod),
+      arbiter: anchor.web3.PublicKey.default,
+    };
+    const editMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: marketplaceAccount.feesConfig.discountMint,
+    };
+
+    try {
+      await program.methods
+        .editMarketplace({ ...editMarketplaceParams, vestingPeriod: new BN(-1) })
+        .accounts(editMarketplaceAccounts)
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+      assert.fail("the vesting period can not be negative");
+    } catch (e) {
+      assert.include(String(e), "IncorrectVestingPeriod");
+    }
 
-    const preSellerVault1Funds = await getAccount(provider.connection, sellerVaults[1][0]);
     await program.methods
-      .withdrawReward()
+      .editMarketplace(editMarketplaceParams)
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const campaignId = new BN(6);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    const endTs = now + 15;
+    await program.methods
+      .initCampaign({
+        id: campaignId,
+        paymentMint: null,
+        startTs: new BN(now - 60),
+        endTs: new BN(endTs),
+        budget: new BN(100000),
+        sellerReward: 1000,
+        buyerReward: 1000,
+        maxRewardPerPurchase: null,
+        maxRewardPerWallet: null,
+      })
       .accounts({
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        signer: seller.publicKey,
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
         marketplace: marketplacePubkey,
-        reward: sellerReward,
-        rewardMint: rewardMint,
-        receiverVault: sellerVaults[1][0],
-        rewardVault: sellerRewardVaults[1][0],
+        rewardMint: paymentMints[0],
+        bountyVault: bountyVaults[0][0],
+        campaign: campaign,
       })
-      .signers([seller])
-      .rpc()
-      .catch(console.error);
-    
-    await sleep(1000);
-    const postSellerVault1Funds = await getAccount(provider.connection, sellerVaults[1][0]);
-    assert.equal(Number(postSellerVault1Funds.amount - preSellerVault1Funds.amount), Number(sellerRewardFunds.amount));
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
 
-    const preBuyerVault2Funds = await getAccount(provider.connection, buyerVaults[2][0]);
+    // the campaign keeps its vesting period, editing the marketplace only affects new campaigns
     await program.methods
-      .withdrawReward()
+      .editMarketplace({ ...editMarketplaceParams, vestingPeriod: new BN(0) })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    const campaignAccount = await program.account.campaign.fetch(campaign);
+    assert.equal(Number(campaignAccount.vestingPeriod), vestingPeriod);
+
+    const campaignRewards: anchor.web3.PublicKey[] = [];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaignReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          authority: user,
+          campaign: campaign,
+          campaignReward: campaignReward,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      campaignRewards.push(campaignReward);
+    }
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .registerBuy(2, maxTotalPrice, paymentMints[0])
       .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
         tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
         signer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
         marketplace: marketplacePubkey,
-        reward: buyerReward,
-        rewardMint: newRewardMint,
-        receiverVault: buyerVaults[2][0],
-        rewardVault: buyerRewardVaults[2][0],
+        product: productPubkey,
+        payment: paymentPubkey,
+        paymentMint: paymentMints[0],
+        buyerTransferVault: buyerVaults[0][0],
+        sellerTransferVault: sellerVaults[0][0],
+        marketplaceTransferVault: marketplaceVaults[0][0],
+        campaign: campaign,
+        bountyVault: bountyVaults[0][0],
+        sellerReward: sellerReward,
+        sellerRewardVault: sellerRewardVaults[0][0],
+        buyerReward: buyerReward,
+        buyerRewardVault: buyerRewardVaults[0][0],
+        sellerCampaignReward: campaignRewards[0],
+        buyerCampaignReward: campaignRewards[1],
       })
       .signers([buyer])
-      .rpc()
-      .catch(console.error);
+      .rpc(confirmOptions);
+
+    const earned = Number((await program.account.campaignReward.fetch(campaignRewards[1])).earned);
+    assert.isAbove(earned, 0);
+
+    const withdrawRewardAccounts = {
+      eventAuthority,
+      program: program.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      signer: buyer.publicKey,
+      marketplace: marketplacePubkey,
+      reward: buyerReward,
+      campaign: campaign,
+      campaignReward: campaignRewards[1],
+      rewardMint: paymentMints[0],
+      receiverVault: buyerVaults[0][0],
+      rewardVault: buyerRewardVaults[0][0],
+    };
 
-    await sleep(1000);
-    const postBuyerVault2Funds = await getAccount(provider.connection, buyerVaults[2][0]);
-    assert.equal(Number(postBuyerVault2Funds.amount - preBuyerVault2Funds.amount), Number(newBuyerRewardFunds.amount));
+    // right after the end only a part of the rewards is unlocked
+    await waitForTimestamp(provider.connection, endTs + 1);
+    const preReceiverFunds = await getAccount(provider.connection, buyerVaults[0][0]);
 
-    const preSellerVault2Funds = await getAccount(provider.connection, sellerVaults[2][0]);
     await program.methods
       .withdrawReward()
-      .accounts({
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        signer: seller.publicKey,
-        marketplace: marketplacePubkey,
-        reward: sellerReward,
-        rewardMint: newRewardMint,
-        receiverVault: sellerVaults[2][0],
-        rewardVault: sellerRewardVaults[2][0],
-      })
-      .signers([seller])
-      .rpc()
-      .catch(console.error);
+      .accounts(withdrawRewardAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
 
-    await sleep(1000);
-    const postSellerVault2Funds = await getAccount(provider.connection, sellerVaults[2][0]);
-    assert.equal(Number(postSellerVault2Funds.amount - preSellerVault2Funds.amount), Number(newSellerRewardFunds.amount));
-  });
+    const partialReceiverFunds = await getAccount(provider.connection, buyerVaults[0][0]);
+    const partialWithdrawn = Number(partialReceiverFunds.amount - preReceiverFunds.amount);
+    const partialCampaignReward = await program.account.campaignReward.fetch(campaignRewards[1]);
+    assert.isAbove(partialWithdrawn, 0);
+    assert.isBelow(partialWithdrawn, earned);
+    assert.equal(Number(partialCampaignReward.withdrawn), partialWithdrawn);
 
-  it("Should handle correctly FeePayer.Buyer as config (FeePayer.Seller was used in other examples)", async () => {
-    const newPaymentMintPubkey = NATIVE_MINT;
-    const newPrice = new BN(1000);
+    // once the vesting period is over the rest can be withdrawn
+    await waitForTimestamp(provider.connection, endTs + vestingPeriod);
 
-    const editProductInfoAccounts = {
-      signer: seller.publicKey,
-      product: productPubkey,
-      paymentMint: newPaymentMintPubkey,
-    };
     await program.methods
-      .editProduct(newPrice)
-      .accounts(editProductInfoAccounts)
-      .signers([seller])
-      .rpc()
-      .catch(console.error);
+      .withdrawReward()
+      .accounts(withdrawRewardAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
 
-    const editMarketplaceInfoParams = {
-      fee: 100,
-      feeReduction: 0,
-      sellerReward: 100,
-      buyerReward: 100,
-      useCnfts: false,
-      deliverToken: false,
-      transferable: false,
-      chainCounter: true,
-      permissionless: true,
-      rewardsEnabled: false,
-      feePayer: FeePayer.Buyer,
-    };
+    const postReceiverFunds = await getAccount(provider.connection, buyerVaults[0][0]);
+    const postCampaignReward = await program.account.campaignReward.fetch(campaignRewards[1]);
+    assert.equal(Number(postReceiverFunds.amount - preReceiverFunds.amount), earned);
+    assert.equal(Number(postCampaignReward.withdrawn), earned);
+  });
 
-    const editMarketplaceInfoAccounts = {
-      signer: marketplaceAuth.publicKey,
-      marketplace: marketplacePubkey,
-      rewardMint: rewardMint,
-      discountMint: discountMint,
-    };
+  it("Should close reward accounts and their vaults once the rewards are unlocked", async () => {
+    // the buyer still has rewards of running campaigns
+    try {
+      await program.methods
+        .closeReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          signer: buyer.publicKey,
+          marketplace: marketplacePubkey,
+          reward: buyerReward,
+        })
+        .remainingAccounts([
+          { pubkey: buyerRewardVaults[0][0], isWritable: true, isSigner: false },
+          { pubkey: buyerVaults[0][0], isWritable: true, isSigner: false },
+        ])
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the reward account should be locked");
+    } catch (e) {
+      assert.include(String(e), "OpenPromotion");
+    }
 
-    await program.methods
-      .editMarketplace(editMarketplaceInfoParams)
-      .accounts(editMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
-      .catch(console.error);
-  
-    const [paymentPubkey, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+    const rewardUser = await createFundedWallet(provider, 10);
+    const rewardUserVault = await createFundedAssociatedTokenAccount(
+      provider,
+      paymentMints[0],
+      0,
+      rewardUser
+    );
+    const [reward] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("payment", "utf-8"), 
-        buyer.publicKey.toBuffer(), 
-        productPubkey.toBuffer(),
+        Buffer.from("reward", "utf-8"),
+        rewardUser.publicKey.toBuffer(),
+        marketplacePubkey.toBuffer()
+      ],
+      program.programId
+    );
+    const [rewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reward_vault", "utf-8"),
+        rewardUser.publicKey.toBuffer(),
+        marketplacePubkey.toBuffer(),
+        paymentMints[0].toBuffer(),
       ],
       program.programId
     );
 
-    const marketAuthBalance = await provider.connection.getBalance(marketplaceAuth.publicKey, confirmOptions);
-    const sellerBalance = await provider.connection.getBalance(seller.publicKey, confirmOptions);
-    const buyerBalance = await provider.connection.getBalance(buyer.publicKey, confirmOptions);
+    await program.methods
+      .initReward()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: rewardUser.publicKey,
+        marketplace: marketplacePubkey,
+        reward: reward,
+        rewardMint: paymentMints[0],
+        rewardVault: rewardVault,
+      })
+      .signers([rewardUser])
+      .rpc(confirmOptions);
 
-    const registerBuyAccounts = {
-      systemProgram: SystemProgram.programId,
+    await provider.sendAndConfirm(
+      new Transaction()
+        .add(
+          createTransferInstruction(
+            marketplaceVaults[0][0],
+            rewardVault,
+            marketplaceAuth.publicKey,
+            30,
+          )
+        ),
+      [marketplaceAuth]
+    );
+
+    const closeRewardAccounts = {
+      eventAuthority,
+      program: program.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
-      rent: SYSVAR_RENT_PUBKEY,
-      signer: buyer.publicKey,
-      seller: seller.publicKey,
-      marketplaceAuth: marketplaceAuth.publicKey,
+      signer: rewardUser.publicKey,
       marketplace: marketplacePubkey,
-      product: productPubkey,
-      payment: paymentPubkey,
-      paymentMint: newPaymentMintPubkey,
-      buyerTokenVault: null,
-      buyerTransferVault: null,
-      sellerTransferVault: null,
-      marketplaceTransferVault: null,
-      bountyVault: null,
-      sellerReward: null,
-      sellerRewardVault: null,
-      buyerReward: null,
-      buyerRewardVault: null,
+      reward: reward,
     };
 
-    await program.methods
-      .registerBuy(1)
-      .accounts(registerBuyAccounts)
-      .signers([buyer])
-      .rpc()
-      .catch(console.error);
-
-    await sleep(2000);
-    const postMarketAuthBalance = await provider.connection.getBalance(marketplaceAuth.publicKey, confirmOptions);
-    const postSellerBalance = await provider.connection.getBalance(seller.publicKey, confirmOptions);
-    const postBuyerBalance = await provider.connection.getBalance(buyer.publicKey, confirmOptions);
-    const marketplaceFee = Math.floor((Number(newPrice) * fee) / 10000);
-
-    assert.equal(postMarketAuthBalance, marketAuthBalance + marketplaceFee);
-    assert.equal(postSellerBalance, sellerBalance + Number(newPrice));
-    assert.equal(postBuyerBalance, buyerBalance - Number(newPrice) - marketplaceFee);
-  });
-
-  it("Should make the marketplace token-gated", async () => {
-    permissionless = false;
-    const editMarketplaceInfoParams = {
-      fee: fee,
-      feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
-      useCnfts: useCnfts,
-      deliverToken: deliverToken,
-      transferable: transferable,
-      chainCounter: chainCounter,
-      permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
-      accessMintBump: accessMintBump,
-      feePayer: FeePayer.Buyer,
-    };
+    // the remaining balance can only go to the user
+    try {
+      await program.methods
+        .closeReward()
+        .accounts(closeRewardAccounts)
+        .remainingAccounts([
+          { pubkey: rewardVault, isWritable: true, isSigner: false },
+          { pubkey: buyerVaults[0][0], isWritable: true, isSigner: false },
+        ])
+        .signers([rewardUser])
+        .rpc(confirmOptions);
+      assert.fail("the receiver is not owned by the user");
+    } catch (e) {
+      assert.include(String(e), "IncorrectAuthority");
+    }
 
-    const editMarketplaceInfoAccounts = {
-      signer: marketplaceAuth.publicKey,
-      marketplace: marketplacePubkey,
-      rewardMint: await createMint(provider, confirmOptions),
-      discountMint: await createMint(provider, confirmOptions),
-    };
+    const prePendingRewards = (await program.account.marketplace.fetch(marketplacePubkey)).pendingRewards;
 
     await program.methods
-      .editMarketplace(editMarketplaceInfoParams)
-      .accounts(editMarketplaceInfoAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
-      .catch(console.error);
+      .closeReward()
+      .accounts(closeRewardAccounts)
+      .remainingAccounts([
+        { pubkey: rewardVault, isWritable: true, isSigner: false },
+        { pubkey: rewardUserVault, isWritable: true, isSigner: false },
+      ])
+      .signers([rewardUser])
+      .rpc(confirmOptions);
 
-    const receiverVault = getAssociatedTokenAddressSync(accessMint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID);
-    const [request] = anchor.web3.PublicKey.findProgramAddressSync(
+    // the swept tokens were not rewards, the pending rewards of the marketplace do not change
+    const postPendingRewards = (await program.account.marketplace.fetch(marketplacePubkey)).pendingRewards;
+    assert.equal(Number(postPendingRewards), Number(prePendingRewards));
+    const rewardUserVaultAccount = await getAccount(provider.connection, rewardUserVault);
+    assert.equal(Number(rewardUserVaultAccount.amount), 30);
+    assert.isNull(await provider.connection.getAccountInfo(rewardVault));
+    assert.isNull(await provider.connection.getAccountInfo(reward));
+  });
+
+  it("Should withdraw rewards of several campaigns and mints in one instruction", async () => {
+    const batchMint = await createMint(provider, confirmOptions);
+    const bountyBalance = 10000;
+    const marketplaceBatchVault = await createFundedAssociatedTokenAccount(
+      provider,
+      batchMint,
+      bountyBalance,
+      marketplaceAuth
+    );
+    const [bountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("request", "utf-8"),
-        seller.publicKey.toBuffer(),
-        marketplacePubkey.toBuffer()
+        Buffer.from("bounty_vault", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        batchMint.toBuffer()
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .initBounty()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+        rewardMint: batchMint,
+        bountyVault: bountyVault,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    await provider.sendAndConfirm(
+      new Transaction()
+        .add(
+          createTransferInstruction(
+            marketplaceBatchVault,
+            bountyVault,
+            marketplaceAuth.publicKey,
+            bountyBalance
+          )
+        ),
+      [marketplaceAuth as anchor.web3.Signer]
+    );
+
+    const rewardVaults: anchor.web3.PublicKey[] = [];
+    for (const [user, reward] of [
+      [seller, sellerReward],
+      [buyer, buyerReward],
+    ] as [anchor.web3.Keypair, anchor.web3.PublicKey][]) {
+      const [rewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("reward_vault", "utf-8"),
+          user.publicKey.toBuffer(),
+          marketplacePubkey.toBuffer(),
+          batchMint.toBuffer(),
+        ],
+        program.programId
+      );
+      rewardVaults.push(rewardVault);
+
+      await program.methods
+        .initRewardVault()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: user.publicKey,
+          marketplace: marketplacePubkey,
+          reward: reward,
+          rewardMint: batchMint,
+          rewardVault: rewardVault,
+        })
+        .signers([user])
+        .rpc(confirmOptions);
+    }
+
+    const campaignId = new BN(7);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const now = Math.floor(Date.now() / 1000);
+    const endTs = now + 15;
+    await program.methods
+      .initCampaign({
+        id: campaignId,
+        paymentMint: null,
+        startTs: new BN(now - 60),
+        endTs: new BN(endTs),
+        budget: new BN(bountyBalance),
+        sellerReward: 1000,
+        buyerReward: 1000,
+        maxRewardPerPurchase: null,
+        maxRewardPerWallet: null,
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: marketplacePubkey,
+        rewardMint: batchMint,
+        bountyVault: bountyVault,
+        campaign: campaign,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const campaignRewards: anchor.web3.PublicKey[] = [];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaignReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          authority: user,
+          campaign: campaign,
+          campaignReward: campaignReward,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      campaignRewards.push(campaignReward);
+    }
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .registerBuy(1, maxTotalPrice, paymentMints[0])
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        payment: paymentPubkey,
+        paymentMint: paymentMints[0],
+        buyerTransferVault: buyerVaults[0][0],
+        sellerTransferVault: sellerVaults[0][0],
+        marketplaceTransferVault: marketplaceVaults[0][0],
+        campaign: campaign,
+        bountyVault: bountyVault,
+        sellerReward: sellerReward,
+        sellerRewardVault: rewardVaults[0],
+        buyerReward: buyerReward,
+        buyerRewardVault: rewardVaults[1],
+        sellerCampaignReward: campaignRewards[0],
+        buyerCampaignReward: campaignRewards[1],
+      })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    await waitForTimestamp(provider.connection, endTs);
+
+    // the seller still has the rewards of the vesting campaign (paymentMints[0]) and the ones of this campaign (batchMint)
+    const [vestingCampaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        new BN(6).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [vestingCampaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign_reward", "utf-8"),
+        seller.publicKey.toBuffer(),
+        vestingCampaign.toBuffer(),
+      ],
+      program.programId
+    );
+    const vestingCampaignRewardAccount = await program.account.campaignReward.fetch(vestingCampaignReward);
+    const vestingAvailable = Number(vestingCampaignRewardAccount.earned) - Number(vestingCampaignRewardAccount.withdrawn);
+    const batchEarned = Number((await program.account.campaignReward.fetch(campaignRewards[0])).earned);
+    assert.isAbove(vestingAvailable, 0);
+    assert.isAbove(batchEarned, 1);
+
+    const sellerBatchAta = getAssociatedTokenAddressSync(batchMint, seller.publicKey);
+    assert.isNull(await provider.connection.getAccountInfo(sellerBatchAta));
+
+    const withdrawRewardsAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      signer: seller.publicKey,
+      marketplace: marketplacePubkey,
+      reward: sellerReward,
+    };
+    const withdrawRewardsRemainingAccounts = [
+      vestingCampaign, vestingCampaignReward, paymentMints[0], sellerRewardVaults[0][0], sellerVaults[0][0],
+      campaign, campaignRewards[0], batchMint, rewardVaults[0], sellerBatchAta,
+    ].map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+
+    try {
+      await program.methods
+        .withdrawRewards([new BN(vestingAvailable), new BN(batchEarned + 1)])
+        .accounts(withdrawRewardsAccounts)
+        .remainingAccounts(withdrawRewardsRemainingAccounts)
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the amount is higher than the rewards");
+    } catch (e) {
+      assert.include(String(e), "AmountExceedsRewards");
+    }
+
+    // everything from the first campaign and a part of the second one, the receiver ATA is created
+    const partialAmount = Math.floor(batchEarned / 2);
+    const preSellerFunds = await getAccount(provider.connection, sellerVaults[0][0]);
+
+    await program.methods
+      .withdrawRewards([new BN(vestingAvailable), new BN(partialAmount)])
+      .accounts(withdrawRewardsAccounts)
+      .remainingAccounts(withdrawRewardsRemainingAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const postSellerFunds = await getAccount(provider.connection, sellerVaults[0][0]);
+    assert.equal(Number(postSellerFunds.amount - preSellerFunds.amount), vestingAvailable);
+    const sellerBatchAtaAccount = await getAccount(provider.connection, sellerBatchAta);
+    assert.equal(Number(sellerBatchAtaAccount.amount), partialAmount);
+    const partialCampaignReward = await program.account.campaignReward.fetch(campaignRewards[0]);
+    assert.equal(Number(partialCampaignReward.withdrawn), partialAmount);
+
+    // the rest of the second campaign, the ATA already exists
+    await program.methods
+      .withdrawRewards([new BN(batchEarned - partialAmount)])
+      .accounts(withdrawRewardsAccounts)
+      .remainingAccounts(withdrawRewardsRemainingAccounts.slice(5))
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const finalSellerBatchAtaAccount = await getAccount(provider.connection, sellerBatchAta);
+    assert.equal(Number(finalSellerBatchAtaAccount.amount), batchEarned);
+    const finalRewardVault = await getAccount(provider.connection, rewardVaults[0]);
+    assert.equal(Number(finalRewardVault.amount), 0);
+  });
+
+  it("Should handle correctly FeePayer.Buyer as config (FeePayer.Seller was used in other examples)", async () => {
+    const newPaymentMintPubkey = NATIVE_MINT;
+    const newPrice = new BN(1000);
+
+    const editProductInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: newPaymentMintPubkey,
+    };
+    await program.methods
+      .editProduct({ productPrice: newPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts(editProductInfoAccounts)
+      .signers([seller])
+      .rpc()
+      .catch(console.error);
+
+    const editMarketplaceInfoParams = {
+      fee: 100,
+      feeReduction: 0,
+      useCnfts: false,
+      deliverToken: false,
+      transferable: false,
+      chainCounter: true,
+      permissionless: true,
+      feePayer: FeePayer.Buyer,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
+    };
+
+    const editMarketplaceInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: discountMint,
+    };
+
+    await program.methods
+      .editMarketplace(editMarketplaceInfoParams)
+      .accounts(editMarketplaceInfoAccounts)
+      .signers([marketplaceAuth])
+      .rpc()
+      .catch(console.error);
+  
+    const [paymentPubkey, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const marketAuthBalance = await provider.connection.getBalance(marketplaceAuth.publicKey, confirmOptions);
+    const sellerBalance = await provider.connection.getBalance(seller.publicKey, confirmOptions);
+    const buyerBalance = await provider.connection.getBalance(buyer.publicKey, confirmOptions);
+
+    const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: seller.publicKey,
+      marketplaceAuth: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: newPaymentMintPubkey,
+      buyerTokenVault: null,
+      buyerTransferVault: null,
+      sellerTransferVault: null,
+      marketplaceTransferVault: null,
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    await program.methods
+      .registerBuy(1, maxTotalPrice, registerBuyAccounts.paymentMint)
+      .accounts(registerBuyAccounts)
+      .signers([buyer])
+      .rpc()
+      .catch(console.error);
+
+    await sleep(2000);
+    const postMarketAuthBalance = await provider.connection.getBalance(marketplaceAuth.publicKey, confirmOptions);
+    const postSellerBalance = await provider.connection.getBalance(seller.publicKey, confirmOptions);
+    const postBuyerBalance = await provider.connection.getBalance(buyer.publicKey, confirmOptions);
+    const marketplaceFee = Math.floor((Number(newPrice) * fee) / 10000);
+
+    assert.equal(postMarketAuthBalance, marketAuthBalance + marketplaceFee);
+    assert.equal(postSellerBalance, sellerBalance + Number(newPrice));
+    assert.equal(postBuyerBalance, buyerBalance - Number(newPrice) - marketplaceFee);
+  });
+
+  it("Should register a buy with a token 2022 payment mint with transfer fee", async () => {
+    // 1% transfer fee, the buyer pays it on top so the seller and the marketplace receive the full amounts
+    const transferFeeBasisPoints = 100;
+    const mintKeypair = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const mintLamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: provider.wallet.publicKey,
+          newAccountPubkey: mintKeypair.publicKey,
+          space: mintLen,
+          lamports: mintLamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+          mintKeypair.publicKey,
+          provider.wallet.publicKey,
+          provider.wallet.publicKey,
+          transferFeeBasisPoints,
+          BigInt(1_000_000),
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        createInitializeMintInstruction(
+          mintKeypair.publicKey,
+          0,
+          provider.wallet.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      ),
+      [mintKeypair],
+      confirmOptions,
+    );
+    const feeMint = mintKeypair.publicKey;
+
+    const [buyerFeeVault, sellerFeeVault, marketplaceFeeVault] = await Promise.all(
+      [buyer, seller, marketplaceAuth].map((wallet) => getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet as anchor.web3.Signer,
+        feeMint,
+        wallet.publicKey,
+        false,
+        "confirmed",
+        confirmOptions,
+        TOKEN_2022_PROGRAM_ID,
+      ))
+    );
+    await mintTo(
+      provider.connection,
+      buyer,
+      feeMint,
+      buyerFeeVault.address,
+      (provider.wallet as anchor.Wallet).payer,
+      100_000,
+      [],
+      confirmOptions,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    const newPrice = new BN(10_000);
+    await program.methods
+      .editProduct({ productPrice: newPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        signer: seller.publicKey,
+        product: productPubkey,
+        paymentMint: feeMint,
+      })
+      .signers([seller])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_2022_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: feeMint,
+      buyerTransferVault: buyerFeeVault.address,
+      sellerTransferVault: sellerFeeVault.address,
+      marketplaceTransferVault: marketplaceFeeVault.address,
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    // the payment token program has to be the owner of the payment mint
+    try {
+      await program.methods
+        .registerBuy(1, maxTotalPrice, registerBuyAccounts.paymentMint)
+        .accounts({ ...registerBuyAccounts, paymentTokenProgram: TOKEN_PROGRAM_ID })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the purchase should fail with the legacy token program");
+    } catch (e) {
+      assert.include(String(e), "IncorrectTokenProgram");
+    }
+
+    // marketplace config from the previous test: 1% fee paid by the buyer
+    const marketplaceFee = Math.floor((Number(newPrice) * 100) / 10000);
+
+    // the transfer fee paid on top counts in the max total price
+    try {
+      await program.methods
+        .registerBuy(1, newPrice.add(new BN(marketplaceFee)), registerBuyAccounts.paymentMint)
+        .accounts(registerBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the buyer would pay the transfer fee above the max total price");
+    } catch (e) {
+      assert.include(String(e), "PriceExceedsMaximum");
+    }
+
+    await program.methods
+      .registerBuy(1, maxTotalPrice, registerBuyAccounts.paymentMint)
+      .accounts(registerBuyAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const sellerAccount = await getAccount(provider.connection, sellerFeeVault.address, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const marketplaceAccount = await getAccount(provider.connection, marketplaceFeeVault.address, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const buyerAccount = await getAccount(provider.connection, buyerFeeVault.address, "confirmed", TOKEN_2022_PROGRAM_ID);
+
+    assert.equal(Number(sellerAccount.amount), Number(newPrice));
+    assert.equal(Number(marketplaceAccount.amount), marketplaceFee);
+    // ceil(amount / 0.99) leaves the buyer vault for each transfer
+    const sellerTransfer = Math.ceil((Number(newPrice) * 10000) / (10000 - transferFeeBasisPoints));
+    const marketplaceTransfer = Math.ceil((marketplaceFee * 10000) / (10000 - transferFeeBasisPoints));
+    assert.equal(Number(buyerAccount.amount), 100_000 - sellerTransfer - marketplaceTransfer);
+  });
+
+  it("Should reject purchases above the max total price or with an unexpected payment mint", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const paymentMint = product.sellerConfig.paymentMint;
+    const price = Number(product.sellerConfig.productPrice);
+    // marketplace config: 1% fee paid by the buyer
+    const buyerTotal = price + Math.floor((price * 100) / 10000);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_2022_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMint,
+      buyerTransferVault: getAssociatedTokenAddressSync(paymentMint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      sellerTransferVault: getAssociatedTokenAddressSync(paymentMint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      marketplaceTransferVault: getAssociatedTokenAddressSync(paymentMint, marketplaceAuth.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    // the buyer fee counts in the max price
+    try {
+      await program.methods
+        .registerBuy(1, new BN(buyerTotal - 1), paymentMint)
+        .accounts(registerBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the purchase should fail above the max total price");
+    } catch (e) {
+      assert.include(String(e), "PriceExceedsMaximum");
+    }
+
+    try {
+      await program.methods
+        .registerBuy(1, new BN(buyerTotal), NATIVE_MINT)
+        .accounts(registerBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the purchase should fail with another payment mint");
+    } catch (e) {
+      assert.include(String(e), "IncorrectPaymentMint");
+    }
+
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    await program.methods
+      .registerBuy(1, new BN(buyerTotal), paymentMint)
+      .accounts(registerBuyAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(postPaymentAccount.units, paymentAccount.units + 1);
+  });
+
+  it("Should stop selling a limited product when the max supply is reached", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const paymentMint = product.sellerConfig.paymentMint;
+    const sold = Number(product.sold);
+    const editProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: paymentMint,
+    };
+
+    try {
+      await program.methods
+        .editProduct({ productPrice: product.sellerConfig.productPrice, maxSupply: new BN(sold - 1), maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+        .accounts(editProductAccounts)
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the max supply should not be lower than the units sold");
+    } catch (e) {
+      assert.include(String(e), "IncorrectMaxSupply");
+    }
+
+    // only one unit left
+    await program.methods
+      .editProduct({ productPrice: product.sellerConfig.productPrice, maxSupply: new BN(sold + 1), maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_2022_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMint,
+      buyerTransferVault: getAssociatedTokenAddressSync(paymentMint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      sellerTransferVault: getAssociatedTokenAddressSync(paymentMint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      marketplaceTransferVault: getAssociatedTokenAddressSync(paymentMint, marketplaceAuth.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    try {
+      await program.methods
+        .registerBuy(2, maxTotalPrice, paymentMint)
+        .accounts(registerBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the purchase should fail, there is only one unit left");
+    } catch (e) {
+      assert.include(String(e), "SoldOut");
+    }
+
+    await program.methods
+      .registerBuy(1, maxTotalPrice, paymentMint)
+      .accounts(registerBuyAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    try {
+      await program.methods
+        .registerBuy(1, maxTotalPrice, paymentMint)
+        .accounts(registerBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the purchase should fail, the product is sold out");
+    } catch (e) {
+      assert.include(String(e), "SoldOut");
+    }
+
+    const postProduct = await program.account.product.fetch(productPubkey);
+    assert.equal(Number(postProduct.sold), sold + 1);
+
+    await program.methods
+      .editProduct({ productPrice: product.sellerConfig.productPrice, maxSupply: null, maxUnitsPerWallet: null, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+  });
+
+  it("Should only sell a product inside its sale window", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const paymentMint = product.sellerConfig.paymentMint;
+    const now = Math.floor(Date.now() / 1000);
+    const editProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: paymentMint,
+    };
+    const editProductParams = {
+      productPrice: product.sellerConfig.productPrice,
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      escrowTimeout: null,
+      subscriptionPeriod: null,
+    };
+
+    try {
+      await program.methods
+        .editProduct({ ...editProductParams, saleStart: new BN(now + 3600), saleEnd: new BN(now) })
+        .accounts(editProductAccounts)
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the sale start should be before the sale end");
+    } catch (e) {
+      assert.include(String(e), "IncorrectSaleWindow");
+    }
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const registerBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_2022_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMint,
+      buyerTransferVault: getAssociatedTokenAddressSync(paymentMint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      sellerTransferVault: getAssociatedTokenAddressSync(paymentMint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      marketplaceTransferVault: getAssociatedTokenAddressSync(paymentMint, marketplaceAuth.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    // the sale has not started yet and then it already ended
+    for (const [saleStart, saleEnd] of [[new BN(now + 3600), null], [null, new BN(now - 3600)]]) {
+      await program.methods
+        .editProduct({ ...editProductParams, saleStart, saleEnd })
+        .accounts(editProductAccounts)
+        .signers([seller])
+        .rpc(confirmOptions);
+
+      try {
+        await program.methods
+          .registerBuy(1, maxTotalPrice, paymentMint)
+          .accounts(registerBuyAccounts)
+          .signers([buyer])
+          .rpc(confirmOptions);
+        assert.fail("the purchase should fail outside the sale window");
+      } catch (e) {
+        assert.include(String(e), "SaleNotActive");
+      }
+    }
+
+    await program.methods
+      .editProduct({ ...editProductParams, saleStart: new BN(now - 3600), saleEnd: new BN(now + 3600) })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    await program.methods
+      .registerBuy(1, maxTotalPrice, paymentMint)
+      .accounts(registerBuyAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    await program.methods
+      .editProduct({ ...editProductParams, saleStart: null, saleEnd: null, escrowTimeout: null, subscriptionPeriod: null })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+  });
+
+  it("Should hold escrowed payments until the buyer confirms the delivery or the timeout is over", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const marketplace = await program.account.marketplace.fetch(marketplacePubkey);
+    const escrowMint = await createMint(provider, confirmOptions);
+    const buyerVault = await createFundedAssociatedTokenAccount(provider, escrowMint, 10000, buyer);
+    const sellerVault = await createFundedAssociatedTokenAccount(provider, escrowMint, 0, seller);
+    const marketplaceVault = await createFundedAssociatedTokenAccount(provider, escrowMint, 0, marketplaceAuth);
+    const price = 100;
+    const escrowTimeout = 4;
+    const editProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: escrowMint,
+    };
+    const editProductParams = {
+      productPrice: new BN(price),
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+    };
+
+    try {
+      await program.methods
+        .editProduct({ ...editProductParams, escrowTimeout: new BN(0) })
+        .accounts(editProductAccounts)
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the escrow timeout should be positive");
+    } catch (e) {
+      assert.include(String(e), "IncorrectEscrowTimeout");
+    }
+
+    await program.methods
+      .editProduct({ ...editProductParams, escrowTimeout: new BN(escrowTimeout) })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .registerBuy(1, maxTotalPrice, escrowMint)
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          seller: null,
+          marketplaceAuth: null,
+          marketplace: marketplacePubkey,
+          product: productPubkey,
+          payment: paymentPubkey,
+          paymentMint: escrowMint,
+          buyerTransferVault: buyerVault,
+          sellerTransferVault: sellerVault,
+          marketplaceTransferVault: marketplaceVault,
+          campaign: null,
+          bountyVault: null,
+          sellerReward: null,
+          sellerRewardVault: null,
+          buyerReward: null,
+          buyerRewardVault: null,
+          sellerCampaignReward: null,
+          buyerCampaignReward: null,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the product should only be sold with an escrow");
+    } catch (e) {
+      assert.include(String(e), "EscrowRequired");
+    }
+
+    const feeBasisPoints = marketplace.feesConfig.fee;
+    const buyerPaysFee = "buyer" in marketplace.feesConfig.feePayer;
+    const getEscrowAccounts = (id: number) => {
+      const [escrowPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("escrow", "utf-8"),
+          buyer.publicKey.toBuffer(),
+          productPubkey.toBuffer(),
+          new BN(id).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [escrowVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("escrow_vault", "utf-8"), escrowPubkey.toBuffer()],
+        program.programId
+      );
+      return { escrowPubkey, escrowVault };
+    };
+    const buyEscrowed = async (id: number, units: number) => {
+      const { escrowPubkey, escrowVault } = getEscrowAccounts(id);
+      await program.methods
+        .registerBuyEscrow(new BN(id), units, maxTotalPrice, escrowMint)
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          marketplace: marketplacePubkey,
+          product: productPubkey,
+          payment: paymentPubkey,
+          paymentMint: escrowMint,
+          buyerTransferVault: buyerVault,
+          escrow: escrowPubkey,
+          escrowVault,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      return { escrowPubkey, escrowVault };
+    };
+    const releaseAccounts = (escrowPubkey: anchor.web3.PublicKey, escrowVault: anchor.web3.PublicKey) => ({
+      eventAuthority,
+      program: program.programId,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      escrow: escrowPubkey,
+      paymentMint: escrowMint,
+      escrowVault,
+      sellerTransferVault: sellerVault,
+      marketplaceTransferVault: marketplaceVault,
+    });
+    const splitOf = (units: number) => {
+      const gross = price * units;
+      const fee = Math.floor((gross * feeBasisPoints) / 10000);
+      return { fee, sellerAmount: buyerPaysFee ? gross : gross - fee };
+    };
+
+    // a marketplace created by the buyer can not take the fee nor settle the disputes of this product
+    const foreignMarketplace = await createForeignMarketplace(buyer, 7);
+    const { escrowPubkey: foreignEscrow, escrowVault: foreignEscrowVault } = getEscrowAccounts(7);
+    try {
+      await program.methods
+        .registerBuyEscrow(new BN(7), 1, maxTotalPrice, escrowMint)
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          marketplace: foreignMarketplace,
+          product: productPubkey,
+          payment: paymentPubkey,
+          paymentMint: escrowMint,
+          buyerTransferVault: buyerVault,
+          escrow: foreignEscrow,
+          escrowVault: foreignEscrowVault,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the product can only be bought through its marketplace");
+    } catch (e) {
+      assert.include(String(e), "ConstraintSeeds");
+    }
+
+    // the buyer confirms the delivery of a first order before the timeout
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    const firstOrder = await buyEscrowed(0, 2);
+    const firstSplit = splitOf(2);
+    const escrowAccount = await program.account.escrow.fetch(firstOrder.escrowPubkey);
+    const escrowVaultAccount = await getAccount(provider.connection, firstOrder.escrowVault);
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(Number(escrowVaultAccount.amount), firstSplit.fee + firstSplit.sellerAmount);
+    assert.equal(Number(escrowAccount.amount), price * 2);
+    assert.equal(postPaymentAccount.units, paymentAccount.units + 2);
+    assert.equal((await program.account.product.fetch(productPubkey)).openEscrows, 1);
+
+    // the arbiter could not settle a dispute of a closed product
+    try {
+      await program.methods
+        .closeProduct()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+          tokenMetadataProgram: null,
+          signer: seller.publicKey,
+          marketplace: marketplacePubkey,
+          product: productPubkey,
+          productMint,
+          productMintVault: null,
+          metadata: null,
+          masterEdition: null,
+        })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the product can not be closed while an escrow is open");
+    } catch (e) {
+      assert.include(String(e), "CannotCloseProduct");
+    }
+
+    try {
+      await program.methods
+        .releaseEscrow()
+        .accounts({ ...releaseAccounts(firstOrder.escrowPubkey, firstOrder.escrowVault), signer: exploiter.publicKey, buyer: buyer.publicKey })
+        .signers([exploiter])
+        .rpc(confirmOptions);
+      assert.fail("the escrow should be locked until the timeout");
+    } catch (e) {
+      assert.include(String(e), "EscrowLocked");
+    }
+
+    try {
+      await program.methods
+        .confirmDelivery()
+        .accounts({ ...releaseAccounts(firstOrder.escrowPubkey, firstOrder.escrowVault), signer: exploiter.publicKey })
+        .signers([exploiter])
+        .rpc(confirmOptions);
+      assert.fail("only the buyer can confirm the delivery");
+    } catch (e) {
+      assert.include(String(e), "IncorrectAuthority");
+    }
+
+    await program.methods
+      .confirmDelivery()
+      .accounts({ ...releaseAccounts(firstOrder.escrowPubkey, firstOrder.escrowVault), signer: buyer.publicKey })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    let sellerVaultAccount = await getAccount(provider.connection, sellerVault);
+    let marketplaceVaultAccount = await getAccount(provider.connection, marketplaceVault);
+    assert.equal(Number(sellerVaultAccount.amount), firstSplit.sellerAmount);
+    assert.equal(Number(marketplaceVaultAccount.amount), firstSplit.fee);
+    assert.isNull(await provider.connection.getAccountInfo(firstOrder.escrowPubkey));
+    assert.isNull(await provider.connection.getAccountInfo(firstOrder.escrowVault));
+    assert.equal((await program.account.product.fetch(productPubkey)).openEscrows, 0);
+
+    // nobody confirms the second order, anyone can release it after the timeout
+    const secondOrder = await buyEscrowed(1, 1);
+    const secondSplit = splitOf(1);
+    const secondEscrow = await program.account.escrow.fetch(secondOrder.escrowPubkey);
+    await waitForTimestamp(provider.connection, Number(secondEscrow.releaseTs));
+
+    await program.methods
+      .releaseEscrow()
+      .accounts({ ...releaseAccounts(secondOrder.escrowPubkey, secondOrder.escrowVault), signer: exploiter.publicKey, buyer: buyer.publicKey })
+      .signers([exploiter])
+      .rpc(confirmOptions);
+
+    sellerVaultAccount = await getAccount(provider.connection, sellerVault);
+    marketplaceVaultAccount = await getAccount(provider.connection, marketplaceVault);
+    assert.equal(Number(sellerVaultAccount.amount), firstSplit.sellerAmount + secondSplit.sellerAmount);
+    assert.equal(Number(marketplaceVaultAccount.amount), firstSplit.fee + secondSplit.fee);
+    assert.isNull(await provider.connection.getAccountInfo(secondOrder.escrowPubkey));
+
+    await program.methods
+      .editProduct({ 
+        ...editProductParams,
+        productPrice: product.sellerConfig.productPrice,
+        escrowTimeout: null,
+        subscriptionPeriod: null,
+      })
+      .accounts({ ...editProductAccounts, paymentMint: product.sellerConfig.paymentMint })
+      .signers([seller])
+      .rpc(confirmOptions);
+  });
+
+  it("Should let the arbiter settle a disputed escrow with any split", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    const arbiter = await createFundedWallet(provider, 1);
+    const disputeMint = await createMint(provider, confirmOptions);
+    const buyerVault = await createFundedAssociatedTokenAccount(provider, disputeMint, 10000, buyer);
+    const sellerVault = await createFundedAssociatedTokenAccount(provider, disputeMint, 0, seller);
+    const marketplaceVault = await createFundedAssociatedTokenAccount(provider, disputeMint, 0, marketplaceAuth);
+    const price = 100;
+    const units = 2;
+    const editMarketplaceParams = {
+      fee: marketplaceAccount.feesConfig.fee,
+      feeReduction: marketplaceAccount.feesConfig.feeReduction,
+      useCnfts: marketplaceAccount.tokenConfig.useCnfts,
+      deliverToken: marketplaceAccount.tokenConfig.deliverToken,
+      transferable: marketplaceAccount.tokenConfig.transferable,
+      chainCounter: marketplaceAccount.tokenConfig.chainCounter,
+      permissionless: marketplaceAccount.permissionConfig.permissionless,
+      feePayer: marketplaceAccount.feesConfig.feePayer,
+      vestingPeriod: marketplaceAccount.vestingPeriod,
+    };
+    const editMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: marketplaceAccount.feesConfig.discountMint,
+    };
+    const editProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: disputeMint,
+    };
+    const editProductParams = {
+      productPrice: new BN(price),
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+    };
+
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, arbiter: arbiter.publicKey })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    await program.methods
+      .editProduct({ ...editProductParams, escrowTimeout: new BN(3600) })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const [escrowPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("escrow", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+        new BN(2).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [escrowVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow_vault", "utf-8"), escrowPubkey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .registerBuyEscrow(new BN(2), units, maxTotalPrice, disputeMint)
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: buyer.publicKey,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        payment: paymentPubkey,
+        paymentMint: disputeMint,
+        buyerTransferVault: buyerVault,
+        escrow: escrowPubkey,
+        escrowVault,
+      })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const escrowedAmount = Number((await getAccount(provider.connection, escrowVault)).amount);
+    const buyerAmount = 150;
+    const sellerAmount = 40;
+    const settleAccounts = {
+      eventAuthority,
+      program: program.programId,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      buyer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      escrow: escrowPubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: disputeMint,
+      escrowVault,
+      buyerTransferVault: buyerVault,
+      sellerTransferVault: sellerVault,
+      marketplaceTransferVault: marketplaceVault,
+    };
+    const disputeAccounts = {
+      eventAuthority,
+      program: program.programId,
+      escrow: escrowPubkey,
+    };
+
+    try {
+      await program.methods
+        .settleDispute(new BN(buyerAmount), new BN(sellerAmount))
+        .accounts({ ...settleAccounts, signer: arbiter.publicKey })
+        .signers([arbiter])
+        .rpc(confirmOptions);
+      assert.fail("only disputed escrows can be settled");
+    } catch (e) {
+      assert.include(String(e), "EscrowNotDisputed");
+    }
+
+    try {
+      await program.methods
+        .openDispute()
+        .accounts({ ...disputeAccounts, signer: exploiter.publicKey })
+        .signers([exploiter])
+        .rpc(confirmOptions);
+      assert.fail("only the buyer can open a dispute");
+    } catch (e) {
+      assert.include(String(e), "IncorrectAuthority");
+    }
+
+    await program.methods
+      .openDispute()
+      .accounts({ ...disputeAccounts, signer: buyer.publicKey })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    // the buyer can not pay the seller of a disputed escrow on its own
+    try {
+      await program.methods
+        .confirmDelivery()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          paymentTokenProgram: TOKEN_PROGRAM_ID,
+          signer: buyer.publicKey,
+          seller: null,
+          marketplaceAuth: null,
+          marketplace: marketplacePubkey,
+          product: productPubkey,
+          escrow: escrowPubkey,
+          paymentMint: disputeMint,
+          escrowVault,
+          sellerTransferVault: sellerVault,
+          marketplaceTransferVault: marketplaceVault,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("a disputed escrow can not be released");
+    } catch (e) {
+      assert.include(String(e), "EscrowDisputed");
+    }
+
+    try {
+      await program.methods
+        .settleDispute(new BN(buyerAmount), new BN(sellerAmount))
+        .accounts({ ...settleAccounts, signer: exploiter.publicKey })
+        .signers([exploiter])
+        .rpc(confirmOptions);
+      assert.fail("only the authority or the arbiter can settle a dispute");
+    } catch (e) {
+      assert.include(String(e), "IncorrectAuthority");
+    }
+
+    try {
+      await program.methods
+        .settleDispute(new BN(escrowedAmount), new BN(1))
+        .accounts({ ...settleAccounts, signer: arbiter.publicKey })
+        .signers([arbiter])
+        .rpc(confirmOptions);
+      assert.fail("the ruling can not pay more than the escrowed amount");
+    } catch (e) {
+      assert.include(String(e), "IncorrectRuling");
+    }
+
+    const buyerVaultAccount = await getAccount(provider.connection, buyerVault);
+    const preProduct = await program.account.product.fetch(productPubkey);
+    const prePaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    await program.methods
+      .settleDispute(new BN(buyerAmount), new BN(sellerAmount))
+      .accounts({ ...settleAccounts, signer: arbiter.publicKey })
+      .signers([arbiter])
+      .rpc(confirmOptions);
+
+    const postBuyerVaultAccount = await getAccount(provider.connection, buyerVault);
+    const sellerVaultAccount = await getAccount(provider.connection, sellerVault);
+    const marketplaceVaultAccount = await getAccount(provider.connection, marketplaceVault);
+    const escrowAccount = await program.account.escrow.fetch(escrowPubkey);
+    assert.equal(Number(postBuyerVaultAccount.amount), Number(buyerVaultAccount.amount) + buyerAmount);
+    assert.equal(Number(sellerVaultAccount.amount), sellerAmount);
+    assert.equal(Number(marketplaceVaultAccount.amount), escrowedAmount - buyerAmount - sellerAmount);
+    assert.isNull(await provider.connection.getAccountInfo(escrowVault));
+    assert.isTrue(escrowAccount.ruling.arbiter.equals(arbiter.publicKey));
+    assert.equal(Number(escrowAccount.ruling.buyerAmount), buyerAmount);
+    assert.equal(Number(escrowAccount.ruling.sellerAmount), sellerAmount);
+    assert.equal(Number(escrowAccount.ruling.fee), escrowedAmount - buyerAmount - sellerAmount);
+
+    // the units refunded by the ruling are given back, in proportion to the buyer amount
+    const refundedUnits = Math.floor(units * buyerAmount / escrowedAmount);
+    const postProduct = await program.account.product.fetch(productPubkey);
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(Number(postProduct.sold), Number(preProduct.sold) - refundedUnits);
+    assert.equal(postPaymentAccount.units, prePaymentAccount.units - refundedUnits);
+    assert.equal(
+      Number(postPaymentAccount.paid),
+      Number(prePaymentAccount.paid) - Math.floor(price * units * buyerAmount / escrowedAmount)
+    );
+
+    // the settled escrow was paid out, anyone can close it before the release timestamp
+    await program.methods
+      .releaseEscrow()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        signer: exploiter.publicKey,
+        buyer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        escrow: escrowPubkey,
+        paymentMint: disputeMint,
+        escrowVault: null,
+        sellerTransferVault: null,
+        marketplaceTransferVault: null,
+      })
+      .signers([exploiter])
+      .rpc(confirmOptions);
+    assert.isNull(await provider.connection.getAccountInfo(escrowPubkey));
+
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, arbiter: anchor.web3.PublicKey.default })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    await program.methods
+      .editProduct({ 
+        ...editProductParams,
+        productPrice: product.sellerConfig.productPrice,
+        escrowTimeout: null,
+        subscriptionPeriod: null,
+      })
+      .accounts({ ...editProductAccounts, paymentMint: product.sellerConfig.paymentMint })
+      .signers([seller])
+      .rpc(confirmOptions);
+  });
+
+  it("Should refund part of a purchase, burn the refunded product tokens and claw back the rewards", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    const editMarketplaceParams = {
+      fee: marketplaceAccount.feesConfig.fee,
+      feeReduction: marketplaceAccount.feesConfig.feeReduction,
+      useCnfts: marketplaceAccount.tokenConfig.useCnfts,
+      deliverToken: marketplaceAccount.tokenConfig.deliverToken,
+      transferable: marketplaceAccount.tokenConfig.transferable,
+      chainCounter: marketplaceAccount.tokenConfig.chainCounter,
+      permissionless: marketplaceAccount.permissionConfig.permissionless,
+      feePayer: marketplaceAccount.feesConfig.feePayer,
+      vestingPeriod: marketplaceAccount.vestingPeriod,
+      arbiter: marketplaceAccount.arbiter,
+    };
+    const editMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: marketplaceAccount.feesConfig.discountMint,
+    };
+    const editProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: paymentMints[0],
+    };
+    const editProductParams = {
+      productPrice: new BN(1000),
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+      escrowTimeout: null,
+      subscriptionPeriod: null,
+    };
+
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, useCnfts: false, deliverToken: true, chainCounter: false })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    await program.methods
+      .editProduct(editProductParams)
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    // campaign 5 is still running, the buyer and the seller rewards are in their vaults
+    const campaignId = new BN(5);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const campaignAccount = await program.account.campaign.fetch(campaign);
+    const [bountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bounty_vault", "utf-8"),
+        marketplacePubkey.toBuffer(),
+        campaignAccount.rewardMint.toBuffer(),
+      ],
+      program.programId
+    );
+    const [rewardVaults, campaignRewards] = [[], []] as anchor.web3.PublicKey[][];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      rewardVaults.push(anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("reward_vault", "utf-8"),
+          user.toBuffer(),
+          marketplacePubkey.toBuffer(),
+          campaignAccount.rewardMint.toBuffer(),
+        ],
+        program.programId
+      )[0]);
+      campaignRewards.push(anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      )[0]);
+    }
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const buyerTokenVault = (await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      buyer,
+      product.productMint,
+      buyer.publicKey,
+      false,
+      "confirmed",
+      confirmOptions,
+      TOKEN_2022_PROGRAM_ID
+    )).address;
+
+    await program.methods
+      .registerBuyToken(3, maxTotalPrice, paymentMints[0])
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        signer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        payment: paymentPubkey,
+        productMint: product.productMint,
+        paymentMint: paymentMints[0],
+        buyerTokenVault,
+        buyerTransferVault: buyerVaults[0][0],
+        sellerTransferVault: sellerVaults[0][0],
+        marketplaceTransferVault: marketplaceVaults[0][0],
+        campaign,
+        bountyVault,
+        sellerReward,
+        sellerRewardVault: rewardVaults[0],
+        buyerReward,
+        buyerRewardVault: rewardVaults[1],
+        sellerCampaignReward: campaignRewards[0],
+        buyerCampaignReward: campaignRewards[1],
+      })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const refundAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      logWrapper: null,
+      bubblegumProgram: null,
+      compressionProgram: null,
+      buyer: buyer.publicKey,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMints[0],
+      sellerTransferVault: sellerVaults[0][0],
+      buyerTransferVault: buyerVaults[0][0],
+      productMint: product.productMint,
+      buyerTokenVault,
+      treeAuthority: null,
+      merkleTree: null,
+      campaign,
+      bountyVault,
+      sellerReward,
+      sellerRewardVault: rewardVaults[0],
+      buyerReward,
+      buyerRewardVault: rewardVaults[1],
+      sellerCampaignReward: campaignRewards[0],
+      buyerCampaignReward: campaignRewards[1],
+    };
+    // the purchase was rewarded by campaign 5, the payment account records the price and the bonuses
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(paymentAccount.campaign.toString(), campaign.toString());
+    // 2 of the units, refunded at least at the average price the buyer paid for them
+    const refundAmount = paymentAccount.paid.muln(2).divn(paymentAccount.units);
+
+    try {
+      await program.methods
+        .refundPurchase({ units: 2, amount: refundAmount, cnftReceipt: null })
+        .accounts({ ...refundAccounts, signer: exploiter.publicKey })
+        .signers([exploiter])
+        .rpc(confirmOptions);
+      assert.fail("only the seller can refund a purchase");
+    } catch (e) {
+      assert.include(String(e), "IncorrectAuthority");
+    }
+
+    try {
+      await program.methods
+        .refundPurchase({ units: paymentAccount.units + 1, amount: paymentAccount.paid, cnftReceipt: null })
+        .accounts({ ...refundAccounts, signer: seller.publicKey })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the buyer did not buy that many units");
+    } catch (e) {
+      assert.include(String(e), "RefundExceedsPurchase");
+    }
+
+    try {
+      await program.methods
+        .refundPurchase({ units: 0, amount: paymentAccount.paid.addn(1), cnftReceipt: null })
+        .accounts({ ...refundAccounts, signer: seller.publicKey })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the buyer did not pay that much");
+    } catch (e) {
+      assert.include(String(e), "RefundExceedsPurchase");
+    }
+
+    // taking the units back without paying them
+    try {
+      await program.methods
+        .refundPurchase({ units: 2, amount: refundAmount.subn(1), cnftReceipt: null })
+        .accounts({ ...refundAccounts, signer: seller.publicKey })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the refund is below the price paid");
+    } catch (e) {
+      assert.include(String(e), "RefundBelowPrice");
+    }
+
+    // the rewards of the recorded campaign are always clawed back
+    try {
+      await program.methods
+        .refundPurchase({ units: 2, amount: refundAmount, cnftReceipt: null })
+        .accounts({ 
+          ...refundAccounts,
+          signer: seller.publicKey,
+          campaign: null,
+          bountyVault: null,
+          sellerReward: null,
+          sellerRewardVault: null,
+          buyerReward: null,
+          buyerRewardVault: null,
+          sellerCampaignReward: null,
+          buyerCampaignReward: null,
+        })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the campaign accounts are required to claw back the rewards");
+    } catch (e) {
+      assert.include(String(e), "OptionalAccountNotProvided");
+    }
+
+    const preProduct = await program.account.product.fetch(productPubkey);
+    const preBuyerFunds = await getAccount(provider.connection, buyerVaults[0][0]);
+    const preBuyerTokens = await getAccount(provider.connection, buyerTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const preBountyFunds = await getAccount(provider.connection, bountyVault);
+    const preSellerCampaignReward = await program.account.campaignReward.fetch(campaignRewards[0]);
+    const preBuyerCampaignReward = await program.account.campaignReward.fetch(campaignRewards[1]);
+    const sellerClawback = paymentAccount.sellerBonus.mul(refundAmount).div(paymentAccount.paid);
+    const buyerClawback = paymentAccount.buyerBonus.mul(refundAmount).div(paymentAccount.paid);
+
+    await program.methods
+      .refundPurchase({ units: 2, amount: refundAmount, cnftReceipt: null })
+      .accounts({ ...refundAccounts, signer: seller.publicKey })
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const postProduct = await program.account.product.fetch(productPubkey);
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    const postBuyerFunds = await getAccount(provider.connection, buyerVaults[0][0]);
+    const postBuyerTokens = await getAccount(provider.connection, buyerTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const postBountyFunds = await getAccount(provider.connection, bountyVault);
+    const postSellerCampaignReward = await program.account.campaignReward.fetch(campaignRewards[0]);
+    const postBuyerCampaignReward = await program.account.campaignReward.fetch(campaignRewards[1]);
+    assert.equal(Number(postProduct.sold), Number(preProduct.sold) - 2);
+    assert.equal(postPaymentAccount.units, paymentAccount.units - 2);
+    assert.equal(Number(postPaymentAccount.paid), Number(paymentAccount.paid.sub(refundAmount)));
+    assert.equal(Number(postPaymentAccount.sellerBonus), Number(paymentAccount.sellerBonus.sub(sellerClawback)));
+    assert.equal(Number(postPaymentAccount.buyerBonus), Number(paymentAccount.buyerBonus.sub(buyerClawback)));
+    assert.equal(Number(postBuyerFunds.amount - preBuyerFunds.amount), Number(refundAmount));
+    assert.equal(Number(preBuyerTokens.amount - postBuyerTokens.amount), 2);
+    assert.equal(Number(postBountyFunds.amount - preBountyFunds.amount), Number(sellerClawback.add(buyerClawback)));
+    assert.equal(Number(postSellerCampaignReward.earned), Number(preSellerCampaignReward.earned) - Number(sellerClawback));
+    assert.equal(Number(postBuyerCampaignReward.earned), Number(preBuyerCampaignReward.earned) - Number(buyerClawback));
+
+    // the purchases of escrow products are refunded by the arbiter, settle_dispute would pay them twice
+    await program.methods
+      .editProduct({ ...editProductParams, escrowTimeout: new BN(3600) })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+    try {
+      await program.methods
+        .refundPurchase({ units: 1, amount: postPaymentAccount.paid, cnftReceipt: null })
+        .accounts({ ...refundAccounts, signer: seller.publicKey })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("escrow products can only be refunded by settling a dispute");
+    } catch (e) {
+      assert.include(String(e), "EscrowRefund");
+    }
+
+    await program.methods
+      .editMarketplace(editMarketplaceParams)
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    await program.methods
+      .editProduct({ 
+        ...editProductParams,
+        productPrice: product.sellerConfig.productPrice,
+        escrowTimeout: product.escrowTimeout,
+      })
+      .accounts({ ...editProductAccounts, paymentMint: product.sellerConfig.paymentMint })
+      .signers([seller])
+      .rpc(confirmOptions);
+  });
+
+  it("Should redeem purchased units with the buyer and seller signatures", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    const editMarketplaceParams = {
+      fee: marketplaceAccount.feesConfig.fee,
+      feeReduction: marketplaceAccount.feesConfig.feeReduction,
+      useCnfts: marketplaceAccount.tokenConfig.useCnfts,
+      deliverToken: marketplaceAccount.tokenConfig.deliverToken,
+      transferable: marketplaceAccount.tokenConfig.transferable,
+      chainCounter: marketplaceAccount.tokenConfig.chainCounter,
+      permissionless: marketplaceAccount.permissionConfig.permissionless,
+      feePayer: marketplaceAccount.feesConfig.feePayer,
+      vestingPeriod: marketplaceAccount.vestingPeriod,
+      arbiter: marketplaceAccount.arbiter,
+    };
+    const editMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: marketplaceAccount.feesConfig.discountMint,
+    };
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const buyerTokenVault = getAssociatedTokenAddressSync(
+      product.productMint,
+      buyer.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const redeemAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      logWrapper: null,
+      bubblegumProgram: null,
+      compressionProgram: null,
+      buyer: buyer.publicKey,
+      seller: seller.publicKey,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      productMint: product.productMint,
+      buyerTokenVault,
+      treeAuthority: null,
+      merkleTree: null,
+    };
+
+    // counter marketplaces consume the units of the payment account
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, useCnfts: false, deliverToken: false, chainCounter: true })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    try {
+      await program.methods
+        .redeem({ units: 1, cnftReceipt: null })
+        .accounts({ ...redeemAccounts, seller: exploiter.publicKey })
+        .signers([buyer, exploiter])
+        .rpc(confirmOptions);
+      assert.fail("only the seller can redeem the units of its product");
+    } catch (e) {
+      assert.include(String(e), "IncorrectAuthority");
+    }
+
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    try {
+      await program.methods
+        .redeem({ units: paymentAccount.units + 1, cnftReceipt: null })
+        .accounts(redeemAccounts)
+        .signers([buyer, seller])
+        .rpc(confirmOptions);
+      assert.fail("the buyer does not have that many units");
+    } catch (e) {
+      assert.include(String(e), "RedeemExceedsUnits");
+    }
+
+    await program.methods
+      .redeem({ units: 1, cnftReceipt: null })
+      .accounts(redeemAccounts)
+      .signers([buyer, seller])
+      .rpc(confirmOptions);
+
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(postPaymentAccount.units, paymentAccount.units - 1);
+    // redeemed units can not be refunded, their price leaves the payment record
+    assert.equal(
+      Number(postPaymentAccount.paid), 
+      Number(paymentAccount.paid.sub(paymentAccount.paid.divn(paymentAccount.units)))
+    );
+
+    // marketplaces without a proof of payment consume the units of the payment account too
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, useCnfts: false, deliverToken: false, chainCounter: false })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    await program.methods
+      .redeem({ units: 1, cnftReceipt: null })
+      .accounts(redeemAccounts)
+      .signers([buyer, seller])
+      .rpc(confirmOptions);
+
+    const plainPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(plainPaymentAccount.units, postPaymentAccount.units - 1);
+
+    // token marketplaces burn the product tokens and consume the units of the payment account
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, useCnfts: false, deliverToken: true, chainCounter: false })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const preBuyerTokens = await getAccount(provider.connection, buyerTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const excessUnits = Number(preBuyerTokens.amount) + 1;
+    try {
+      await program.methods
+        .redeem({ units: excessUnits, cnftReceipt: null })
+        .accounts(redeemAccounts)
+        .signers([buyer, seller])
+        .rpc(confirmOptions);
+      assert.fail("the buyer does not hold that many product tokens");
+    } catch (e) {
+      assert.include(String(e), excessUnits > plainPaymentAccount.units ? "RedeemExceedsUnits" : "BurnError");
+    }
+
+    await program.methods
+      .redeem({ units: 1, cnftReceipt: null })
+      .accounts(redeemAccounts)
+      .signers([buyer, seller])
+      .rpc(confirmOptions);
+
+    const postBuyerTokens = await getAccount(provider.connection, buyerTokenVault, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.equal(Number(preBuyerTokens.amount - postBuyerTokens.amount), 1);
+    assert.equal((await program.account.payment.fetch(paymentPubkey)).units, plainPaymentAccount.units - 1);
+
+    await program.methods
+      .editMarketplace(editMarketplaceParams)
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+  });
+
+  it("Should extend subscriptions with each purchase and verify them with return data", async () => {
+    const product = await program.account.product.fetch(productPubkey);
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    const editMarketplaceParams = {
+      fee: marketplaceAccount.feesConfig.fee,
+      feeReduction: marketplaceAccount.feesConfig.feeReduction,
+      useCnfts: marketplaceAccount.tokenConfig.useCnfts,
+      deliverToken: marketplaceAccount.tokenConfig.deliverToken,
+      transferable: marketplaceAccount.tokenConfig.transferable,
+      chainCounter: marketplaceAccount.tokenConfig.chainCounter,
+      permissionless: marketplaceAccount.permissionConfig.permissionless,
+      feePayer: marketplaceAccount.feesConfig.feePayer,
+      vestingPeriod: marketplaceAccount.vestingPeriod,
+      arbiter: marketplaceAccount.arbiter,
+    };
+    const editMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: marketplaceAccount.feesConfig.discountMint,
+    };
+    const editProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: seller.publicKey,
+      product: productPubkey,
+      paymentMint: paymentMints[0],
+    };
+    const editProductParams = {
+      productPrice: new BN(1000),
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+      escrowTimeout: null,
+      subscriptionPeriod: null,
+    };
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"), 
+        buyer.publicKey.toBuffer(), 
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const verifySubscription = async (user: anchor.web3.PublicKey) => {
+      const [payment] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("payment", "utf-8"), 
+          user.toBuffer(), 
+          productPubkey.toBuffer(),
+        ],
+        program.programId
+      );
+      const ix = await program.methods
+        .verifySubscription()
+        .accounts({ buyer: user, product: productPubkey, payment })
+        .instruction();
+      const simulation = await provider.connection.simulateTransaction(
+        new anchor.web3.Transaction().add(ix), 
+        [buyer]
+      );
+      assert.isNull(simulation.value.err);
+      const data = Buffer.from(simulation.value.returnData.data[0], "base64");
+      return { active: data[0] === 1, expiresAt: Number(data.readBigInt64LE(1)) };
+    };
+
+    await program.methods
+      .editMarketplace({ ...editMarketplaceParams, useCnfts: false, deliverToken: false, chainCounter: true })
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    try {
+      await program.methods
+        .editProduct({ ...editProductParams, subscriptionPeriod: new BN(0) })
+        .accounts(editProductAccounts)
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the subscription period has to be positive");
+    } catch (e) {
+      assert.include(String(e), "IncorrectSubscriptionPeriod");
+    }
+
+    const subscriptionPeriod = 3600;
+    await program.methods
+      .editProduct({ ...editProductParams, subscriptionPeriod: new BN(subscriptionPeriod) })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    // a wallet that never bought the product is reported as expired
+    const notSubscribed = await verifySubscription(exploiter.publicKey);
+    assert.isFalse(notSubscribed.active);
+    assert.equal(notSubscribed.expiresAt, 0);
+
+    const registerBuyCounterAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: buyer.publicKey,
+      seller: null,
+      marketplaceAuth: null,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMints[0],
+      buyerTransferVault: buyerVaults[0][0],
+      sellerTransferVault: sellerVaults[0][0],
+      marketplaceTransferVault: marketplaceVaults[0][0],
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+
+    const preTs = Math.floor(Date.now() / 1000);
+    await program.methods
+      .registerBuyCounter(2, maxTotalPrice, paymentMints[0])
+      .accounts(registerBuyCounterAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const firstPayment = await program.account.payment.fetch(paymentPubkey);
+    assert.isAtLeast(Number(firstPayment.expiresAt), preTs + 2 * subscriptionPeriod - 60);
+    assert.isAtMost(Number(firstPayment.expiresAt), preTs + 2 * subscriptionPeriod + 60);
+
+    // an active subscription is extended from its expiration, not from the purchase
+    await program.methods
+      .registerBuyCounter(1, maxTotalPrice, paymentMints[0])
+      .accounts(registerBuyCounterAccounts)
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const secondPayment = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(Number(secondPayment.expiresAt), Number(firstPayment.expiresAt) + subscriptionPeriod);
+
+    const subscribed = await verifySubscription(buyer.publicKey);
+    assert.isTrue(subscribed.active);
+    assert.equal(subscribed.expiresAt, Number(secondPayment.expiresAt));
+
+    // refunds take back the time granted at purchase, not the current product period
+    await program.methods
+      .editProduct({ ...editProductParams, subscriptionPeriod: new BN(2 * subscriptionPeriod) })
+      .accounts(editProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const campaignAccounts = {
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+    if (!secondPayment.campaign.equals(anchor.web3.PublicKey.default)) {
+      const campaignAccount = await program.account.campaign.fetch(secondPayment.campaign);
+      const [sellerRewardVault, buyerRewardVault] = [seller.publicKey, buyer.publicKey].map((user) => 
+        anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("reward_vault", "utf-8"),
+            user.toBuffer(),
+            marketplacePubkey.toBuffer(),
+            campaignAccount.rewardMint.toBuffer(),
+          ],
+          program.programId
+        )[0]
+      );
+      const [sellerCampaignReward, buyerCampaignReward] = [seller.publicKey, buyer.publicKey].map((user) => 
+        anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("campaign_reward", "utf-8"),
+            user.toBuffer(),
+            secondPayment.campaign.toBuffer(),
+          ],
+          program.programId
+        )[0]
+      );
+      Object.assign(campaignAccounts, {
+        campaign: secondPayment.campaign,
+        bountyVault: anchor.web3.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("bounty_vault", "utf-8"),
+            marketplacePubkey.toBuffer(),
+            campaignAccount.rewardMint.toBuffer(),
+          ],
+          program.programId
+        )[0],
+        sellerReward,
+        sellerRewardVault,
+        buyerReward,
+        buyerRewardVault,
+        sellerCampaignReward,
+        buyerCampaignReward,
+      });
+    }
+
+    await program.methods
+      .refundPurchase({ units: 1, amount: secondPayment.paid.divn(secondPayment.units), cnftReceipt: null })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        logWrapper: null,
+        bubblegumProgram: null,
+        compressionProgram: null,
+        signer: seller.publicKey,
+        buyer: buyer.publicKey,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        payment: paymentPubkey,
+        paymentMint: paymentMints[0],
+        sellerTransferVault: sellerVaults[0][0],
+        buyerTransferVault: buyerVaults[0][0],
+        productMint: null,
+        buyerTokenVault: null,
+        treeAuthority: null,
+        merkleTree: null,
+        ...campaignAccounts,
+      })
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const refundedTime = secondPayment.subscriptionGranted.divn(secondPayment.units);
+    const refundedPayment = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(Number(secondPayment.subscriptionGranted.sub(firstPayment.subscriptionGranted)), subscriptionPeriod);
+    assert.equal(Number(refundedPayment.subscriptionGranted), Number(secondPayment.subscriptionGranted.sub(refundedTime)));
+    assert.equal(Number(refundedPayment.expiresAt), Number(secondPayment.expiresAt) - Number(refundedTime));
+
+    await program.methods
+      .editMarketplace(editMarketplaceParams)
+      .accounts(editMarketplaceAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    await program.methods
+      .editProduct({ 
+        ...editProductParams,
+        productPrice: product.sellerConfig.productPrice,
+        escrowTimeout: product.escrowTimeout,
+      })
+      .accounts({ ...editProductAccounts, paymentMint: product.sellerConfig.paymentMint })
+      .signers([seller])
+      .rpc(confirmOptions);
+
+  });
+
+  it("Should create bounty vaults for any number of reward mints", async () => {
+    // the vaults are only found by their seeds, the marketplace does not store them
+    for (let index = 0; index < 6; index++) {
+      const mint = await createMint(provider, confirmOptions);
+      const [bountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("bounty_vault", "utf-8"),
+          marketplacePubkey.toBuffer(),
+          mint.toBuffer()
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .initBounty()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: marketplaceAuth.publicKey,
+          marketplace: marketplacePubkey,
+          rewardMint: mint,
+          bountyVault: bountyVault,
+        })
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+
+      const bountyVaultAccount = await getAccount(provider.connection, bountyVault);
+      assert.equal(bountyVaultAccount.owner.toString(), marketplacePubkey.toString());
+      assert.equal(bountyVaultAccount.mint.toString(), mint.toString());
+    }
+  });
+
+  it("Should make the marketplace token-gated", async () => {
+    permissionless = false;
+    const editMarketplaceInfoParams = {
+      fee: fee,
+      feeReduction: feeReduction,
+      useCnfts: useCnfts,
+      deliverToken: deliverToken,
+      transferable: transferable,
+      chainCounter: chainCounter,
+      permissionless: permissionless,
+      accessMintBump: accessMintBump,
+      feePayer: FeePayer.Buyer,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
+    };
+
+    const editMarketplaceInfoAccounts = {
+      eventAuthority,
+      program: program.programId,
+      signer: marketplaceAuth.publicKey,
+      marketplace: marketplacePubkey,
+      discountMint: await createMint(provider, confirmOptions),
+    };
+
+    await program.methods
+      .editMarketplace(editMarketplaceInfoParams)
+      .accounts(editMarketplaceInfoAccounts)
+      .signers([marketplaceAuth])
+      .rpc()
+      .catch(console.error);
+
+    const receiverVault = getAssociatedTokenAddressSync(accessMint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const [request] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("request", "utf-8"),
+        seller.publicKey.toBuffer(),
+        marketplacePubkey.toBuffer()
       ],
       program.programId
     );
     const initRequestAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: seller.publicKey,
+      marketplace: marketplacePubkey,
+      request: request,
+    };
+    
+    await program.methods
+      .requestAccess()
+      .accounts(initRequestAccounts)
+      .signers([seller])
+      .rpc()
+      .catch(console.error);
+
+    const acceptRequestAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+      signer: marketplaceAuth.publicKey,
+      receiver: seller.publicKey,
+      marketplace: marketplacePubkey,
+      request: request,
+      accessMint: accessMint,
+      accessVault: receiverVault,
+    };
+
+    const acceptSignature = await program.methods
+      .acceptAccess()
+      .accounts(acceptRequestAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error) as string;
+
+    const [accessEvent] = await getCpiEvents(program, acceptSignature);
+    assert.equal(accessEvent.name, "AccessGranted");
+    assert.isTrue(accessEvent.data.marketplace.equals(marketplacePubkey));
+    assert.isTrue(accessEvent.data.user.equals(seller.publicKey));
+    assert.isTrue(accessEvent.data.accessMint.equals(accessMint));
+
+    [firstId, secondId] = getSplitId(uuid());
+    const balance = 1000;
+    seller = await createFundedWallet(provider, balance);
+    [productPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product", "utf-8"), 
+        firstId, 
+        secondId,
+        marketplacePubkey.toBuffer()
+      ],
+      program.programId
+    );
+    [productMint, mintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_mint", "utf-8"), 
+        productPubkey.toBuffer()
+      ],
+      program.programId
+    );
+    productPrice = new BN(100);
+    const initProductParams = {
+      firstId: [...firstId],
+      secondId: [...secondId],
+      productPrice: productPrice,
+      productMintBump: mintBump,
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+      escrowTimeout: null,
+      subscriptionPeriod: null,
+    };
+    const initProductAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       signer: seller.publicKey,
       marketplace: marketplacePubkey,
-      request: request,
+      product: productPubkey,
+      productMint: productMint,
+      paymentMint: paymentMints[0],
+      accessMint: accessMint,
+      accessVault: receiverVault,
     };
-    
     await program.methods
-      .requestAccess()
-      .accounts(initRequestAccounts)
+      .initProduct(initProductParams)
+      .accounts(initProductAccounts)
       .signers([seller])
       .rpc()
       .catch(console.error);
 
-    const acceptRequestAccounts = {
+    [firstId, secondId] = getSplitId(uuid());
+    [productPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product", "utf-8"), 
+        firstId, 
+        secondId,
+        marketplacePubkey.toBuffer()
+      ],
+      program.programId
+    );
+    [productMint, mintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_mint", "utf-8"), 
+        productPubkey.toBuffer()
+      ],
+      program.programId
+    );
+    productPrice = new BN(100);
+    const buyerVault = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      buyer as anchor.web3.Signer,
+      accessMint,
+      buyer.publicKey,
+      false,
+      "confirmed",
+      confirmOptions,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const initErrorProductParams = {
+      firstId: [...firstId],
+      secondId: [...secondId],
+      productPrice: productPrice,
+      productMintBump: mintBump,
+      maxSupply: null,
+      maxUnitsPerWallet: null,
+      saleStart: null,
+      saleEnd: null,
+      escrowTimeout: null,
+      subscriptionPeriod: null,
+    };
+    const initErrorProductAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgram2022: TOKEN_2022_PROGRAM_ID,
-      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
-      signer: marketplaceAuth.publicKey,
-      receiver: seller.publicKey,
+      signer: buyer.publicKey,
       marketplace: marketplacePubkey,
-      request: request,
+      product: productPubkey,
+      productMint: productMint,
+      paymentMint: paymentMints[0],
       accessMint: accessMint,
-      accessVault: receiverVault,
+      accessVault: buyerVault.address,
     };
+    try {
+      await program.methods
+        .initProduct(initErrorProductParams)
+        .accounts(initErrorProductAccounts)
+        .signers([buyer])
+        .rpc();
+    } catch (e) {
+      if (e as anchor.AnchorError)
+        assert.equal(e.error.errorCode.code, "NotInWithelist");
+    }
+
+    try {
+      await provider.sendAndConfirm(
+        new Transaction()
+          .add(
+            createTransferInstruction(
+              receiverVault,
+              buyerVault.address,
+              seller.publicKey,
+              1,
+              [],
+              TOKEN_2022_PROGRAM_ID
+            )
+          ),
+        [seller]
+      );
+    } catch(e) {
+      // the decimal equivalent of hexadecimal 0x25, it's 37 in decimal 
+      // ie NonTransferable error in the t2022 program 
+      assert.isTrue(e.toString().includes("0x25"));
+    }
+  });
+
+  it("Should close a product without sales", async () => {
+    // marketplace is token-gated since the previous test, seller holds the access token
+    const [closeFirstId, closeSecondId] = getSplitId(uuid());
+    const [closeProduct] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product", "utf-8"), 
+        closeFirstId, 
+        closeSecondId,
+        marketplacePubkey.toBuffer()
+      ],
+      program.programId
+    );
+    const [closeProductMint, closeMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_mint", "utf-8"), 
+        closeProduct.toBuffer()
+      ],
+      program.programId
+    );
 
     await program.methods
-      .acceptAccess()
-      .accounts(acceptRequestAccounts)
-      .signers([marketplaceAuth])
-      .rpc()
+      .initProduct({
+        firstId: [...closeFirstId],
+        secondId: [...closeSecondId],
+        productPrice: productPrice,
+        productMintBump: closeMintBump,
+        maxSupply: null,
+        maxUnitsPerWallet: null,
+        saleStart: null,
+        saleEnd: null,
+        escrowTimeout: null,
+        subscriptionPeriod: null,
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: seller.publicKey,
+        marketplace: marketplacePubkey,
+        product: closeProduct,
+        productMint: closeProductMint,
+        paymentMint: paymentMints[0],
+        accessMint: accessMint,
+        accessVault: getAssociatedTokenAddressSync(accessMint, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      })
+      .signers([seller])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const openProducts = (await program.account.marketplace.fetch(marketplacePubkey)).openProducts;
+    const closeProductAccounts = {
+      eventAuthority,
+      program: program.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      tokenMetadataProgram: null,
+      signer: seller.publicKey,
+      marketplace: marketplacePubkey,
+      product: closeProduct,
+      productMint: closeProductMint,
+      productMintVault: null,
+      metadata: null,
+      masterEdition: null,
+    };
+
+    // only the seller can close the product
+    try {
+      await program.methods
+        .closeProduct()
+        .accounts({ ...closeProductAccounts, signer: exploiter.publicKey })
+        .signers([exploiter])
+        .rpc();
+    } catch (e) {
+      if (e as anchor.AnchorError)
+        assert.equal(e.error.errorCode.code, "IncorrectAuthority");
+    }
+
+    await program.methods
+      .closeProduct()
+      .accounts(closeProductAccounts)
+      .signers([seller])
+      .rpc(confirmOptions)
       .catch(console.error);
 
-    [firstId, secondId] = getSplitId(uuid());
-    const balance = 1000;
-    seller = await createFundedWallet(provider, balance);
-    [productPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+    assert.isNull(await provider.connection.getAccountInfo(closeProduct));
+    assert.isNull(await provider.connection.getAccountInfo(closeProductMint));
+    const marketplaceAccount = await program.account.marketplace.fetch(marketplacePubkey);
+    assert.equal(marketplaceAccount.openProducts, openProducts - 1);
+  });
+
+  it("Should keep a product open while buyers hold units or an unexpired subscription", async () => {
+    // counter products have no mint supply, the product counts the units held by the buyers
+    const counterMarketplace = await createForeignMarketplace(marketplaceAuth, 3);
+    const [counterFirstId, counterSecondId] = getSplitId(uuid());
+    const [counterProduct] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product", "utf-8"),
+        counterFirstId,
+        counterSecondId,
+        counterMarketplace.toBuffer(),
+      ],
+      program.programId
+    );
+    const [counterProductMint, counterMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_mint", "utf-8"),
+        counterProduct.toBuffer(),
+      ],
+      program.programId
+    );
+    const subscriptionPeriod = 3;
+    await program.methods
+      .initProduct({
+        firstId: [...counterFirstId],
+        secondId: [...counterSecondId],
+        productPrice: new BN(1000),
+        productMintBump: counterMintBump,
+        maxSupply: null,
+        maxUnitsPerWallet: null,
+        saleStart: null,
+        saleEnd: null,
+        escrowTimeout: null,
+        subscriptionPeriod: new BN(subscriptionPeriod),
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: seller.publicKey,
+        marketplace: counterMarketplace,
+        product: counterProduct,
+        productMint: counterProductMint,
+        paymentMint: paymentMints[0],
+        accessMint: null,
+        accessVault: null,
+      })
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        counterProduct.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .registerBuyCounter(1, maxTotalPrice, paymentMints[0])
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
+        marketplace: counterMarketplace,
+        product: counterProduct,
+        payment: paymentPubkey,
+        paymentMint: paymentMints[0],
+        buyerTransferVault: buyerVaults[0][0],
+        sellerTransferVault: sellerVaults[0][0],
+        marketplaceTransferVault: marketplaceVaults[0][0],
+        campaign: null,
+        bountyVault: null,
+        sellerReward: null,
+        sellerRewardVault: null,
+        buyerReward: null,
+        buyerRewardVault: null,
+        sellerCampaignReward: null,
+        buyerCampaignReward: null,
+      })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const productAccount = await program.account.product.fetch(counterProduct);
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(Number(productAccount.outstandingUnits), 1);
+    assert.equal(Number(productAccount.subscriptionsEndTs), Number(paymentAccount.expiresAt));
+
+    const closeCounterProduct = () => program.methods
+      .closeProduct()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        tokenMetadataProgram: null,
+        signer: seller.publicKey,
+        marketplace: counterMarketplace,
+        product: counterProduct,
+        productMint: counterProductMint,
+        productMintVault: null,
+        metadata: null,
+        masterEdition: null,
+      })
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    try {
+      await closeCounterProduct();
+      assert.fail("the buyer still holds a unit");
+    } catch (e) {
+      assert.include(String(e), "CannotCloseProduct");
+    }
+
+    await program.methods
+      .redeem({ units: 1, cnftReceipt: null })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        logWrapper: null,
+        bubblegumProgram: null,
+        compressionProgram: null,
+        buyer: buyer.publicKey,
+        seller: seller.publicKey,
+        marketplace: counterMarketplace,
+        product: counterProduct,
+        payment: paymentPubkey,
+        productMint: null,
+        buyerTokenVault: null,
+        treeAuthority: null,
+        merkleTree: null,
+      })
+      .signers([buyer, seller])
+      .rpc(confirmOptions);
+    assert.equal(Number((await program.account.product.fetch(counterProduct)).outstandingUnits), 0);
+
+    // the redeemed unit keeps the subscription running
+    try {
+      await closeCounterProduct();
+      assert.fail("the subscription of the buyer did not expire");
+    } catch (e) {
+      assert.include(String(e), "CannotCloseProduct");
+    }
+
+    await waitForTimestamp(provider.connection, Number(productAccount.subscriptionsEndTs));
+    await closeCounterProduct();
+    assert.isNull(await provider.connection.getAccountInfo(counterProduct));
+  });
+
+  it("Should refund a rewarded purchase after the bounty vault of the campaign was closed", async () => {
+    const rewardedMarketplace = await createForeignMarketplace(marketplaceAuth, 2);
+    const [rewardedBountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bounty_vault", "utf-8"),
+        rewardedMarketplace.toBuffer(),
+        paymentMints[0].toBuffer(),
+      ],
+      program.programId
+    );
+    await provider.sendAndConfirm(
+      new Transaction()
+        .add(
+          createTransferInstruction(
+            marketplaceVaults[0][0],
+            rewardedBountyVault,
+            marketplaceAuth.publicKey,
+            100,
+          )
+        ),
+      [marketplaceAuth]
+    );
+
+    const [rewardedFirstId, rewardedSecondId] = getSplitId(uuid());
+    const [rewardedProduct] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product", "utf-8"),
+        rewardedFirstId,
+        rewardedSecondId,
+        rewardedMarketplace.toBuffer(),
+      ],
+      program.programId
+    );
+    const [rewardedProductMint, rewardedMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("product_mint", "utf-8"),
+        rewardedProduct.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .initProduct({
+        firstId: [...rewardedFirstId],
+        secondId: [...rewardedSecondId],
+        productPrice: new BN(1000),
+        productMintBump: rewardedMintBump,
+        maxSupply: null,
+        maxUnitsPerWallet: null,
+        saleStart: null,
+        saleEnd: null,
+        escrowTimeout: null,
+        subscriptionPeriod: null,
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: seller.publicKey,
+        marketplace: rewardedMarketplace,
+        product: rewardedProduct,
+        productMint: rewardedProductMint,
+        paymentMint: paymentMints[0],
+        accessMint: null,
+        accessVault: null,
+      })
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const [rewards, rewardVaults] = [[], []] as anchor.web3.PublicKey[][];
+    for (const user of [seller, buyer]) {
+      const [reward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("reward", "utf-8"),
+          user.publicKey.toBuffer(),
+          rewardedMarketplace.toBuffer(),
+        ],
+        program.programId
+      );
+      const [rewardVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("reward_vault", "utf-8"),
+          user.publicKey.toBuffer(),
+          rewardedMarketplace.toBuffer(),
+          paymentMints[0].toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: user.publicKey,
+          marketplace: rewardedMarketplace,
+          reward,
+          rewardMint: paymentMints[0],
+          rewardVault,
+        })
+        .signers([user])
+        .rpc(confirmOptions);
+      rewards.push(reward);
+      rewardVaults.push(rewardVault);
+    }
+
+    const campaignId = new BN(1);
+    const [campaign] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("product", "utf-8"), 
-        firstId, 
-        secondId,
-        marketplacePubkey.toBuffer()
+        Buffer.from("campaign", "utf-8"),
+        rewardedMarketplace.toBuffer(),
+        campaignId.toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
-    [productMint, mintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+    const now = Math.floor(Date.now() / 1000);
+    const endTs = now + 10;
+    await program.methods
+      .initCampaign({
+        id: campaignId,
+        paymentMint: null,
+        startTs: new BN(now - 60),
+        endTs: new BN(endTs),
+        budget: new BN(100),
+        sellerReward: 500,
+        buyerReward: 500,
+        maxRewardPerPurchase: null,
+        maxRewardPerWallet: null,
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: rewardedMarketplace,
+        rewardMint: paymentMints[0],
+        bountyVault: rewardedBountyVault,
+        campaign,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const campaignRewards: anchor.web3.PublicKey[] = [];
+    for (const user of [seller.publicKey, buyer.publicKey]) {
+      const [campaignReward] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          user.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .initCampaignReward()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+          signer: buyer.publicKey,
+          authority: user,
+          campaign,
+          campaignReward,
+        })
+        .signers([buyer])
+        .rpc(confirmOptions);
+      campaignRewards.push(campaignReward);
+    }
+
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("product_mint", "utf-8"), 
-        productPubkey.toBuffer()
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        rewardedProduct.toBuffer(),
       ],
       program.programId
     );
-    productPrice = new BN(100);
-    const initProductParams = {
-      firstId: [...firstId],
-      secondId: [...secondId],
-      productPrice: productPrice,
-      productMintBump: mintBump,
+    const campaignAccounts = {
+      campaign,
+      bountyVault: rewardedBountyVault,
+      sellerReward: rewards[0],
+      sellerRewardVault: rewardVaults[0],
+      buyerReward: rewards[1],
+      buyerRewardVault: rewardVaults[1],
+      sellerCampaignReward: campaignRewards[0],
+      buyerCampaignReward: campaignRewards[1],
     };
-    const initProductAccounts = {
+    await program.methods
+      .registerBuyCounter(1, maxTotalPrice, paymentMints[0])
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
+        marketplace: rewardedMarketplace,
+        product: rewardedProduct,
+        payment: paymentPubkey,
+        paymentMint: paymentMints[0],
+        buyerTransferVault: buyerVaults[0][0],
+        sellerTransferVault: sellerVaults[0][0],
+        marketplaceTransferVault: marketplaceVaults[0][0],
+        ...campaignAccounts,
+      })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.isAbove(Number(paymentAccount.sellerBonus), 0);
+    assert.isAbove(Number(paymentAccount.buyerBonus), 0);
+
+    // the campaign is over, the authority takes the bounty left back
+    await waitForTimestamp(provider.connection, endTs);
+    await program.methods
+      .closeBounty()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        signer: marketplaceAuth.publicKey,
+        marketplace: rewardedMarketplace,
+        rewardMint: paymentMints[0],
+        bountyVault: rewardedBountyVault,
+        receiverVault: marketplaceVaults[0][0],
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+    assert.isNull(await provider.connection.getAccountInfo(rewardedBountyVault));
+
+    const refundAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       tokenProgram2022: TOKEN_2022_PROGRAM_ID,
-      rent: SYSVAR_RENT_PUBKEY,
+      logWrapper: null,
+      bubblegumProgram: null,
+      compressionProgram: null,
       signer: seller.publicKey,
-      marketplace: marketplacePubkey,
-      product: productPubkey,
-      productMint: productMint,
+      buyer: buyer.publicKey,
+      marketplace: rewardedMarketplace,
+      product: rewardedProduct,
+      payment: paymentPubkey,
       paymentMint: paymentMints[0],
-      accessMint: accessMint,
-      accessVault: receiverVault,
+      sellerTransferVault: sellerVaults[0][0],
+      buyerTransferVault: buyerVaults[0][0],
+      productMint: null,
+      buyerTokenVault: null,
+      treeAuthority: null,
+      merkleTree: null,
+      ...campaignAccounts,
     };
+
+    // the closed vault is still checked against the campaign reward mint
+    try {
+      await program.methods
+        .refundPurchase({ units: 1, amount: paymentAccount.paid, cnftReceipt: null })
+        .accounts({ ...refundAccounts, bountyVault: bountyVaults[0][0] })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the bounty vault belongs to another marketplace");
+    } catch (e) {
+      assert.include(String(e), "IncorrectBountyVault");
+    }
+
+    const preRewardVaults = await Promise.all(rewardVaults.map((vault) => getAccount(provider.connection, vault)));
+    const preCampaignRewards = await Promise.all(campaignRewards.map((campaignReward) => program.account.campaignReward.fetch(campaignReward)));
+
     await program.methods
-      .initProduct(initProductParams)
-      .accounts(initProductAccounts)
+      .refundPurchase({ units: 1, amount: paymentAccount.paid, cnftReceipt: null })
+      .accounts(refundAccounts)
       .signers([seller])
-      .rpc()
-      .catch(console.error);
+      .rpc(confirmOptions);
 
-    [firstId, secondId] = getSplitId(uuid());
-    [productPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+    // the rewards stay with the seller and the buyer, the payment record is cleared
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    const postRewardVaults = await Promise.all(rewardVaults.map((vault) => getAccount(provider.connection, vault)));
+    const postCampaignRewards = await Promise.all(campaignRewards.map((campaignReward) => program.account.campaignReward.fetch(campaignReward)));
+    assert.equal(postPaymentAccount.units, 0);
+    assert.equal(Number(postPaymentAccount.paid), 0);
+    assert.equal(Number(postPaymentAccount.sellerBonus), 0);
+    assert.equal(Number(postPaymentAccount.buyerBonus), 0);
+    for (const i of [0, 1]) {
+      assert.equal(Number(postRewardVaults[i].amount), Number(preRewardVaults[i].amount));
+      assert.equal(Number(postCampaignRewards[i].earned), Number(preCampaignRewards[i].earned));
+    }
+  });
+
+  it("Should create another marketplace with the same wallet and close it", async () => {
+    // the main marketplace still has products listed
+    try {
+      await program.methods
+        .closeMarketplace()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+          signer: marketplaceAuth.publicKey,
+          marketplace: marketplacePubkey,
+          accessMint: accessMint,
+        })
+        .signers([marketplaceAuth])
+        .rpc();
+    } catch (e) {
+      if (e as anchor.AnchorError)
+        assert.equal(e.error.errorCode.code, "OpenProducts");
+    }
+
+    const secondMarketplaceId = new BN(1);
+    const [secondMarketplace] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("product", "utf-8"), 
-        firstId, 
-        secondId,
-        marketplacePubkey.toBuffer()
+        Buffer.from("marketplace", "utf-8"),
+        marketplaceAuth.publicKey.toBuffer(),
+        secondMarketplaceId.toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
-    [productMint, mintBump] = anchor.web3.PublicKey.findProgramAddressSync(
+    const [secondAccessMint, secondAccessMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
       [
-        Buffer.from("product_mint", "utf-8"), 
-        productPubkey.toBuffer()
+        Buffer.from("access_mint", "utf-8"),
+        secondMarketplace.toBuffer(),
       ],
       program.programId
     );
-    productPrice = new BN(100);
-    const buyerVault = await getOrCreateAssociatedTokenAccount(
-      provider.connection,
-      buyer as anchor.web3.Signer,
-      accessMint,
-      buyer.publicKey,
-      false,
-      "confirmed",
-      confirmOptions,
-      TOKEN_2022_PROGRAM_ID,
+    const [secondBountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bounty_vault", "utf-8"),
+        secondMarketplace.toBuffer(),
+        paymentMints[0].toBuffer()
+      ],
+      program.programId
     );
-    const initErrorProductParams = {
-      firstId: [...firstId],
-      secondId: [...secondId],
-      productPrice: productPrice,
-      productMintBump: mintBump,
+
+    await program.methods
+      .initMarketplace({
+        id: secondMarketplaceId,
+        fee: 0,
+        feeReduction: 0,
+        sellerReward: 0,
+        buyerReward: 0,
+        useCnfts: false,
+        deliverToken: false,
+        transferable: false,
+        chainCounter: true,
+        permissionless: true,
+        accessMintBump: secondAccessMintBump,
+        feePayer: FeePayer.Seller,
+        vestingPeriod: new BN(0),
+        arbiter: anchor.web3.PublicKey.default,
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: secondMarketplace,
+        accessMint: secondAccessMint,
+        rewardMint: paymentMints[0],
+        discountMint: paymentMints[0],
+        bountyVault: secondBountyVault,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const secondMarketplaceAccount = await program.account.marketplace.fetch(secondMarketplace);
+    assert.equal(secondMarketplaceAccount.authority.toString(), marketplaceAuth.publicKey.toString());
+    assert.equal(Number(secondMarketplaceAccount.id), Number(secondMarketplaceId));
+    assert.equal(secondMarketplaceAccount.bountyVaults, 1);
+
+    await provider.sendAndConfirm(
+      new Transaction()
+        .add(
+          createTransferInstruction(
+            marketplaceVaults[0][0],
+            secondBountyVault,
+            marketplaceAuth.publicKey,
+            100,
+          )
+        ),
+      [marketplaceAuth]
+    );
+
+    // no campaigns in this marketplace, the authority can take bounty funds back at any moment
+    const secondBountyAccounts = {
+      eventAuthority,
+      program: program.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      signer: marketplaceAuth.publicKey,
+      marketplace: secondMarketplace,
+      rewardMint: paymentMints[0],
+      bountyVault: secondBountyVault,
+      receiverVault: marketplaceVaults[0][0],
     };
-    const initErrorProductAccounts = {
-      systemProgram: SystemProgram.programId,
+    const authorityVaultBeforeWithdraw = await getAccount(provider.connection, marketplaceVaults[0][0]);
+
+    await program.methods
+      .withdrawBounty(new BN(40))
+      .accounts(secondBountyAccounts)
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const authorityVaultBefore = await getAccount(provider.connection, marketplaceVaults[0][0]);
+    assert.equal(Number(authorityVaultBefore.amount), Number(authorityVaultBeforeWithdraw.amount) + 40);
+
+    // a bounty vault can be closed without closing the marketplace
+    const bountyMint = await createMint(provider, confirmOptions);
+    const authorityBountyMintVault = await createFundedAssociatedTokenAccount(
+      provider,
+      bountyMint,
+      50,
+      marketplaceAuth
+    );
+    const [closeBountyVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bounty_vault", "utf-8"),
+        secondMarketplace.toBuffer(),
+        bountyMint.toBuffer()
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .initBounty()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        signer: marketplaceAuth.publicKey,
+        marketplace: secondMarketplace,
+        rewardMint: bountyMint,
+        bountyVault: closeBountyVault,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const marketplaceWithBounty = await program.account.marketplace.fetch(secondMarketplace);
+    assert.equal(marketplaceWithBounty.bountyVaults, 2);
+
+    await provider.sendAndConfirm(
+      new Transaction()
+        .add(
+          createTransferInstruction(
+            authorityBountyMintVault,
+            closeBountyVault,
+            marketplaceAuth.publicKey,
+            50,
+          )
+        ),
+      [marketplaceAuth]
+    );
+
+    await program.methods
+      .closeBounty()
+      .accounts({
+        ...secondBountyAccounts,
+        rewardMint: bountyMint,
+        bountyVault: closeBountyVault,
+        receiverVault: authorityBountyMintVault,
+      })
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions);
+
+    const authorityBountyMintVaultAfter = await getAccount(provider.connection, authorityBountyMintVault);
+    assert.equal(Number(authorityBountyMintVaultAfter.amount), 50);
+    assert.isNull(await provider.connection.getAccountInfo(closeBountyVault));
+
+    const marketplaceAfterCloseBounty = await program.account.marketplace.fetch(secondMarketplace);
+    assert.equal(marketplaceAfterCloseBounty.bountyVaults, 1);
+
+    const closeMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
       tokenProgram2022: TOKEN_2022_PROGRAM_ID,
-      rent: SYSVAR_RENT_PUBKEY,
-      signer: buyer.publicKey,
-      marketplace: marketplacePubkey,
-      product: productPubkey,
-      productMint: productMint,
-      paymentMint: paymentMints[0],
-      accessMint: accessMint,
-      accessVault: buyerVault.address,
+      signer: marketplaceAuth.publicKey,
+      marketplace: secondMarketplace,
+      accessMint: secondAccessMint,
     };
+
+    // the bounty vault would be left without anyone able to drain it
     try {
       await program.methods
-        .initProduct(initErrorProductParams)
-        .accounts(initErrorProductAccounts)
-        .signers([buyer])
-        .rpc();
+        .closeMarketplace()
+        .accounts(closeMarketplaceAccounts)
+        .signers([marketplaceAuth])
+        .rpc(confirmOptions);
+      assert.fail("the marketplace was closed with an open bounty vault");
     } catch (e) {
-      if (e as anchor.AnchorError)
-        assert.equal(e.error.errorCode.code, "NotInWithelist");
+      assert.include(String(e), "OpenBountyVaults");
     }
 
-    try {
-      await provider.sendAndConfirm(
-        new Transaction()
-          .add(
-            createTransferInstruction(
-              receiverVault,
-              buyerVault.address,
-              seller.publicKey,
-              1,
-              [],
-              TOKEN_2022_PROGRAM_ID
-            )
-          ),
-        [seller]
-      );
-    } catch(e) {
-      // the decimal equivalent of hexadecimal 0x25, it's 37 in decimal 
-      // ie NonTransferable error in the t2022 program 
-      assert.isTrue(e.toString().includes("0x25"));
-    }
+    await program.methods
+      .closeMarketplace()
+      .accounts(closeMarketplaceAccounts)
+      .remainingAccounts([
+        { pubkey: secondBountyVault, isWritable: true, isSigner: false },
+        { pubkey: marketplaceVaults[0][0], isWritable: true, isSigner: false },
+      ])
+      .signers([marketplaceAuth])
+      .rpc(confirmOptions)
+      .catch(console.error);
+
+    const authorityVaultAfter = await getAccount(provider.connection, marketplaceVaults[0][0]);
+    assert.equal(Number(authorityVaultAfter.amount), Number(authorityVaultBefore.amount) + 60);
+    assert.isNull(await provider.connection.getAccountInfo(secondMarketplace));
+    assert.isNull(await provider.connection.getAccountInfo(secondBountyVault));
+    assert.isNull(await provider.connection.getAccountInfo(secondAccessMint));
   });
 })
 
@@ -5,12 +5,14 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
+  getAccount,
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import { 
   createFundedAssociatedTokenAccount,
   createFundedWallet, 
   createMint, 
+  getCpiEvents,
   getSplitId 
 } from "./utils";
 import { 
@@ -26,15 +28,27 @@ import {
   SPL_ACCOUNT_COMPRESSION_ADDRESS, 
   SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, 
   SPL_NOOP_PROGRAM_ID, 
+  ConcurrentMerkleTreeAccount,
+  emptyNode,
   getConcurrentMerkleTreeAccountSize 
 } from "@solana/spl-account-compression";
-import { PROGRAM_ID as BUBBLEGUM_PROGRAM } from "@metaplex-foundation/mpl-bubblegum";
+import { 
+  PROGRAM_ID as BUBBLEGUM_PROGRAM,
+  TokenProgramVersion,
+  TokenStandard,
+  computeCreatorHash,
+  computeDataHash,
+} from "@metaplex-foundation/mpl-bubblegum";
 import { PROGRAM_ID as METADATA_PROGRAM } from "@metaplex-foundation/mpl-token-metadata";
 
 describe("brick compression", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.Brick as Program<Brick>;
+  const [eventAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority", "utf-8")],
+    program.programId
+  );
   const confirmOptions: ConfirmOptions = { commitment: "confirmed" };
 
   // Keypairs:
@@ -60,16 +74,14 @@ describe("brick compression", () => {
   let fee: number;
   let feeReduction: number;
   let rewardMint: anchor.web3.PublicKey;
-  let sellerRewardMarketplace: number;
-  let buyerRewardMarketplace: number;
   let useCnfts: boolean;
   let deliverToken: boolean;
   let transferable: boolean;
   let chainCounter: boolean;
   let permissionless: boolean;
-  let rewardsEnabled: boolean;
   let accessMint: anchor.web3.PublicKey;
   let accessMintBump: number;
+  const marketplaceId = new BN(0);
   const FeePayer = {
     Buyer: { buyer: {} },
     Seller: { seller: {} },
@@ -87,6 +99,46 @@ describe("brick compression", () => {
   let treeAuthority: anchor.web3.PublicKey;
   let bubblegumSigner: anchor.web3.PublicKey;
 
+  // receipt of the n-th purchase of the product, the leaves before it were burned so the proof is made of
+  // empty nodes, only the levels above the canopy are passed
+  const getCnftReceipt = async (nonce: number) => {
+    const tree = await ConcurrentMerkleTreeAccount.fromAccountAddress(
+      provider.connection,
+      merkleTree.publicKey,
+      "confirmed"
+    );
+    const creators = [{ address: seller.publicKey, verified: false, share: 100 }];
+    const proofLength = tree.getMaxDepth() - tree.getCanopyDepth();
+
+    return {
+      cnftReceipt: {
+        root: [...tree.getCurrentRoot()],
+        dataHash: [...computeDataHash({
+          name: "DATASET",
+          symbol: "BRICK",
+          uri: "TEST",
+          sellerFeeBasisPoints: 0,
+          primarySaleHappened: true,
+          isMutable: true,
+          editionNonce: null,
+          tokenStandard: TokenStandard.NonFungible,
+          collection: { key: productMint, verified: true },
+          uses: null,
+          tokenProgramVersion: TokenProgramVersion.Original,
+          creators,
+        })],
+        creatorHash: [...computeCreatorHash(creators)],
+        nonce: new BN(nonce),
+        index: nonce,
+      },
+      proof: [...Array(proofLength).keys()].map((level) => ({
+        pubkey: new anchor.web3.PublicKey(emptyNode(level)),
+        isWritable: false,
+        isSigner: false,
+      })),
+    };
+  };
+
   it("Should create marketplace with cNFTs config", async () => {
     rewardMint = discountMint = paymentMint = await createMint(provider, confirmOptions);
 
@@ -96,7 +148,8 @@ describe("brick compression", () => {
     [marketplacePubkey] = anchor.web3.PublicKey.findProgramAddressSync(
       [
         Buffer.from("marketplace", "utf-8"),
-        marketplaceAuth.publicKey.toBuffer()
+        marketplaceAuth.publicKey.toBuffer(),
+        marketplaceId.toArrayLike(Buffer, "le", 8),
       ],
       program.programId
     );
@@ -110,8 +163,8 @@ describe("brick compression", () => {
       program.programId
     );
 
-    fee = feeReduction = sellerRewardMarketplace = buyerRewardMarketplace = 0;
-    deliverToken = transferable = rewardsEnabled = false;
+    fee = feeReduction = 0;
+    deliverToken = transferable = false;
     chainCounter = permissionless = useCnfts = true;
 
     [accessMint, accessMintBump] = anchor.web3.PublicKey.findProgramAddressSync(
@@ -123,20 +176,22 @@ describe("brick compression", () => {
     );
 
     const initMarketplaceParams = {
+      id: marketplaceId,
       fee: fee,
       feeReduction: feeReduction,
-      sellerReward: sellerRewardMarketplace,
-      buyerReward: buyerRewardMarketplace,
       useCnfts: useCnfts,
       deliverToken: deliverToken,
       transferable: transferable,
       chainCounter: chainCounter,
       permissionless: permissionless,
-      rewardsEnabled: rewardsEnabled,
       accessMintBump: accessMintBump,
       feePayer: FeePayer.Seller,
+      vestingPeriod: new BN(0),
+      arbiter: anchor.web3.PublicKey.default,
     };
     const initMarketplaceAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
       tokenProgram2022: TOKEN_2022_PROGRAM_ID,
@@ -169,10 +224,6 @@ describe("brick compression", () => {
     assert.equal(marketplaceAccount.feesConfig.feeReduction, feeReduction);
     assert.equal(marketplaceAccount.feesConfig.feePayer.toString(), FeePayer.Seller.toString());
     assert.equal(marketplaceAccount.rewardsConfig.rewardMint.toString(), rewardMint.toString());
-    assert.equal(marketplaceAccount.rewardsConfig.bountyVaults[0].toString(), bountyVault.toString());
-    assert.equal(marketplaceAccount.rewardsConfig.sellerReward, sellerRewardMarketplace);
-    assert.equal(marketplaceAccount.rewardsConfig.buyerReward, buyerRewardMarketplace);
-    assert.equal(marketplaceAccount.rewardsConfig.rewardsEnabled, rewardsEnabled);
   });
 
   it("Should create a product account (with a tree)", async () => {
@@ -244,8 +295,16 @@ describe("brick compression", () => {
         metadataUrl: "test",
         feeBasisPoints: 0,
         productMintBump: mintBump,
+        maxSupply: null,
+        maxUnitsPerWallet: 1,
+        saleStart: null,
+        saleEnd: null,
+        escrowTimeout: null,
+        subscriptionPeriod: null,
     };
     const initProductAccounts = {
+        eventAuthority,
+        program: program.programId,
         tokenMetadataProgram: METADATA_PROGRAM,
         logWrapper: SPL_NOOP_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -289,6 +348,59 @@ describe("brick compression", () => {
     assert.equal(Number(productAccount.sellerConfig.productPrice), Number(productPrice));
   });
 
+  it("Should replace the product tree", async () => {
+    merkleTree = anchor.web3.Keypair.generate();
+    [treeAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+        [merkleTree.publicKey.toBuffer()], BUBBLEGUM_PROGRAM
+    );
+    const [height, buffer, canopy] = [14, 64, 11];
+    const space = getConcurrentMerkleTreeAccountSize(height, buffer, canopy);
+    const cost = await provider.connection.getMinimumBalanceForRentExemption(space);
+    await provider.sendAndConfirm(
+        new anchor.web3.Transaction()
+          .add(
+            SystemProgram.createAccount({
+                fromPubkey: provider.wallet.publicKey,
+                newAccountPubkey: merkleTree.publicKey,
+                lamports: cost,
+                space: space,
+                programId: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+            }),
+          ),
+        [merkleTree]
+    );
+
+    const signature = await program.methods
+      .updateTree({ maxDepth: height, maxBufferSize: buffer })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        payer: seller.publicKey,
+        signer: seller.publicKey,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        treeAuthority: treeAuthority,
+        merkleTree: merkleTree.publicKey,
+        logWrapper: SPL_NOOP_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        bubblegumProgram: BUBBLEGUM_PROGRAM,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ADDRESS,
+      })
+      .signers([seller])
+      .rpc(confirmOptions)
+      .catch(console.error) as string;
+
+    const productAccount = await program.account.product.fetch(productPubkey);
+    assert.equal(productAccount.merkleTree.toString(), merkleTree.publicKey.toString());
+
+    const [treeEvent] = await getCpiEvents(program, signature);
+    assert.equal(treeEvent.name, "TreeUpdated");
+    assert.isTrue(treeEvent.data.product.equals(productPubkey));
+    assert.isTrue(treeEvent.data.merkleTree.equals(merkleTree.publicKey));
+    assert.equal(treeEvent.data.maxDepth, height);
+    assert.equal(treeEvent.data.maxBufferSize, buffer);
+  });
+
   it("Should register a buy and mint a cNFT", async () => {
     const buyerSOLBalance = 1000;
     buyer = await createFundedWallet(provider, buyerSOLBalance);
@@ -314,9 +426,20 @@ describe("brick compression", () => {
     [bubblegumSigner] = anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("collection_cpi", "utf-8")], BUBBLEGUM_PROGRAM
     );
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
     const registerNoRewardBuyAccounts = {
+      eventAuthority,
+      program: program.programId,
       systemProgram: SystemProgram.programId,
       tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
       rent: SYSVAR_RENT_PUBKEY,
       logWrapper: SPL_NOOP_PROGRAM_ID,
       bubblegumProgram: BUBBLEGUM_PROGRAM,
@@ -327,16 +450,20 @@ describe("brick compression", () => {
       marketplaceAuth: null,
       marketplace: marketplacePubkey,
       product: productPubkey,
+      payment: paymentPubkey,
       paymentMint: paymentMint,
       productMint: productMint,
       buyerTransferVault: buyerVault,
       sellerTransferVault: sellerVault,
       marketplaceTransferVault: marketplaceVault,
+      campaign: null,
       bountyVault: null,
       sellerReward: null,
       sellerRewardVault: null,
       buyerReward: null,
       buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
       metadata: metadata,
       masterEdition: masterEdition,
       treeAuthority: treeAuthority,
@@ -345,6 +472,8 @@ describe("brick compression", () => {
     };
     const registerBuyCnftsParams = {
       amount: 1,
+      maxTotalPrice: new BN("18446744073709551615"),
+      expectedPaymentMint: registerNoRewardBuyAccounts.paymentMint,
       name: "DATASET",
       symbol: "BRICK",
       uri: "TEST"
@@ -356,5 +485,259 @@ describe("brick compression", () => {
       .signers([buyer])
       .rpc(confirmOptions)
       .catch(console.error);
+
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    assert.equal(paymentAccount.units, 1);
+
+    // the product only allows one unit per wallet
+    try {
+      await program.methods
+        .registerBuyCnft(registerBuyCnftsParams)
+        .accounts(registerNoRewardBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("the buyer already reached the wallet limit");
+    } catch (e) {
+      assert.include(String(e), "WalletLimitReached");
+    }
+
+    // every receipt stands for one unit
+    try {
+      await program.methods
+        .registerBuyCnft({ ...registerBuyCnftsParams, amount: 2 })
+        .accounts(registerNoRewardBuyAccounts)
+        .signers([buyer])
+        .rpc(confirmOptions);
+      assert.fail("a receipt can only be minted for a single unit");
+    } catch (e) {
+      assert.include(String(e), "IncorrectReceiptUnits");
+    }
+
+    // the product can not be closed while the buyer holds the receipt
+    const productAccount = await program.account.product.fetch(productPubkey);
+    assert.equal(Number(productAccount.receipts), 1);
+    try {
+      await program.methods
+        .closeProduct()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          tokenProgramV0: TOKEN_PROGRAM_ID,
+          tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+          tokenMetadataProgram: METADATA_PROGRAM,
+          signer: seller.publicKey,
+          marketplace: marketplacePubkey,
+          product: productPubkey,
+          productMint: productMint,
+          productMintVault: getAssociatedTokenAddressSync(productMint, productPubkey, true),
+          metadata: metadata,
+          masterEdition: masterEdition,
+        })
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the buyer still holds a receipt");
+    } catch (e) {
+      assert.include(String(e), "CannotCloseProduct");
+    }
+  });
+
+  it("Should refund a cNFT purchase by burning its receipt", async () => {
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+    const refundAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgramV0: TOKEN_PROGRAM_ID,
+      paymentTokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      logWrapper: SPL_NOOP_PROGRAM_ID,
+      bubblegumProgram: BUBBLEGUM_PROGRAM,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ADDRESS,
+      signer: seller.publicKey,
+      buyer: buyer.publicKey,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      paymentMint: paymentMint,
+      sellerTransferVault: sellerVault,
+      buyerTransferVault: buyerVault,
+      productMint: null,
+      buyerTokenVault: null,
+      treeAuthority: treeAuthority,
+      merkleTree: merkleTree.publicKey,
+      campaign: null,
+      bountyVault: null,
+      sellerReward: null,
+      sellerRewardVault: null,
+      buyerReward: null,
+      buyerRewardVault: null,
+      sellerCampaignReward: null,
+      buyerCampaignReward: null,
+    };
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    const { cnftReceipt, proof } = await getCnftReceipt(0);
+
+    // the receipt stands for the only unit bought
+    try {
+      await program.methods
+        .refundPurchase({ units: 2, amount: paymentAccount.paid, cnftReceipt })
+        .accounts(refundAccounts)
+        .remainingAccounts(proof)
+        .signers([seller])
+        .rpc(confirmOptions);
+      assert.fail("the buyer only bought one unit");
+    } catch (e) {
+      assert.include(String(e), "RefundExceedsPurchase");
+    }
+
+    const preBuyerVault = await getAccount(provider.connection, buyerVault);
+
+    await program.methods
+      .refundPurchase({ units: 1, amount: paymentAccount.paid, cnftReceipt })
+      .accounts(refundAccounts)
+      .remainingAccounts(proof)
+      .signers([seller])
+      .rpc(confirmOptions);
+
+    const postBuyerVault = await getAccount(provider.connection, buyerVault);
+    const postPaymentAccount = await program.account.payment.fetch(paymentPubkey);
+    const productAccount = await program.account.product.fetch(productPubkey);
+    assert.equal(Number(postBuyerVault.amount - preBuyerVault.amount), Number(paymentAccount.paid));
+    assert.equal(postPaymentAccount.units, 0);
+    assert.equal(Number(productAccount.sold), 0);
+    assert.equal(Number(productAccount.receipts), 0);
+  });
+
+  it("Should redeem a cNFT unit by burning its receipt and close the product", async () => {
+    const [paymentPubkey] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment", "utf-8"),
+        buyer.publicKey.toBuffer(),
+        productPubkey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // the refunded unit can be bought again, the second receipt is the leaf 1 of the tree
+    await program.methods
+      .registerBuyCnft({
+        amount: 1,
+        maxTotalPrice: new BN("18446744073709551615"),
+        expectedPaymentMint: paymentMint,
+        name: "DATASET",
+        symbol: "BRICK",
+        uri: "TEST"
+      })
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        logWrapper: SPL_NOOP_PROGRAM_ID,
+        bubblegumProgram: BUBBLEGUM_PROGRAM,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_ADDRESS,
+        tokenMetadataProgram: METADATA_PROGRAM,
+        signer: buyer.publicKey,
+        seller: null,
+        marketplaceAuth: null,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        payment: paymentPubkey,
+        paymentMint: paymentMint,
+        productMint: productMint,
+        buyerTransferVault: buyerVault,
+        sellerTransferVault: sellerVault,
+        marketplaceTransferVault: marketplaceVault,
+        campaign: null,
+        bountyVault: null,
+        sellerReward: null,
+        sellerRewardVault: null,
+        buyerReward: null,
+        buyerRewardVault: null,
+        sellerCampaignReward: null,
+        buyerCampaignReward: null,
+        metadata: metadata,
+        masterEdition: masterEdition,
+        treeAuthority: treeAuthority,
+        bubblegumSigner: bubblegumSigner,
+        merkleTree: merkleTree.publicKey,
+      })
+      .signers([buyer])
+      .rpc(confirmOptions);
+
+    const redeemAccounts = {
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      logWrapper: SPL_NOOP_PROGRAM_ID,
+      bubblegumProgram: BUBBLEGUM_PROGRAM,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_ADDRESS,
+      buyer: buyer.publicKey,
+      seller: seller.publicKey,
+      marketplace: marketplacePubkey,
+      product: productPubkey,
+      payment: paymentPubkey,
+      productMint: null,
+      buyerTokenVault: null,
+      treeAuthority: treeAuthority,
+      merkleTree: merkleTree.publicKey,
+    };
+    const { cnftReceipt, proof } = await getCnftReceipt(1);
+
+    // burning the receipt without consuming its unit would let the buyer keep the unit
+    try {
+      await program.methods
+        .redeem({ units: 0, cnftReceipt })
+        .accounts(redeemAccounts)
+        .remainingAccounts(proof)
+        .signers([buyer, seller])
+        .rpc(confirmOptions);
+      assert.fail("a receipt stands for one unit");
+    } catch (e) {
+      assert.include(String(e), "IncorrectReceiptUnits");
+    }
+
+    await program.methods
+      .redeem({ units: 1, cnftReceipt })
+      .accounts(redeemAccounts)
+      .remainingAccounts(proof)
+      .signers([buyer, seller])
+      .rpc(confirmOptions);
+
+    const paymentAccount = await program.account.payment.fetch(paymentPubkey);
+    const productAccount = await program.account.product.fetch(productPubkey);
+    assert.equal(paymentAccount.units, 0);
+    assert.equal(Number(productAccount.receipts), 0);
+
+    // every receipt was burned
+    await program.methods
+      .closeProduct()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        tokenMetadataProgram: METADATA_PROGRAM,
+        signer: seller.publicKey,
+        marketplace: marketplacePubkey,
+        product: productPubkey,
+        productMint: productMint,
+        productMintVault: getAssociatedTokenAddressSync(productMint, productPubkey, true),
+        metadata: metadata,
+        masterEdition: masterEdition,
+      })
+      .signers([seller])
+      .rpc(confirmOptions);
+    assert.isNull(await provider.connection.getAccountInfo(productPubkey));
   });
-})
\ No newline at end of file
+});
@@ -0,0 +1,299 @@
+// Writes the accounts loaded by the test validator (see Anchor.toml) to test the migrate instructions,
+// each one with a layout of a previous version. Run it with `node tests/brick/fixtures/generate.js`
+// and commit the json files if a legacy layout or the fixtures change.
+import { createHash, createPrivateKey, createPublicKey } from "crypto";
+import { writeFileSync } from "fs";
+import { dirname, join } from "path";
+import { fileURLToPath } from "url";
+
+const PROGRAM_ID = "brick5uEiJqSkfuAvMtKmq7kiuEVmbjVMiigyV51GRF";
+const TOKEN_PROGRAM_ID = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+// same seed as migrate.ts, the authority signs the migrations
+const AUTHORITY_SEED = "brick migration fixtures";
+const FIXTURES_DIR = dirname(fileURLToPath(import.meta.url));
+
+const ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+function encodeBase58(bytes) {
+  let value = BigInt("0x" + (Buffer.from(bytes).toString("hex") || "0"));
+  let encoded = "";
+  while (value > 0n) {
+    encoded = ALPHABET[Number(value % 58n)] + encoded;
+    value /= 58n;
+  }
+  for (const byte of bytes) {
+    if (byte !== 0) break;
+    encoded = "1" + encoded;
+  }
+  return encoded;
+}
+
+function decodeBase58(str) {
+  let value = 0n;
+  for (const char of str) value = value * 58n + BigInt(ALPHABET.indexOf(char));
+  const hex = value.toString(16).padStart(64, "0");
+  return Buffer.from(hex, "hex");
+}
+
+// ed25519 point decompression, program addresses have to be off the curve
+const P = 2n ** 255n - 19n;
+const D = P - (121665n * modPow(121666n, P - 2n)) % P;
+
+function modPow(base, exponent) {
+  let result = 1n;
+  base = ((base % P) + P) % P;
+  while (exponent > 0n) {
+    if (exponent & 1n) result = (result * base) % P;
+    base = (base * base) % P;
+    exponent >>= 1n;
+  }
+  return result;
+}
+
+function isOnCurve(bytes) {
+  const copy = Buffer.from(bytes);
+  copy[31] &= 0x7f;
+  const y = BigInt("0x" + Buffer.from(copy).reverse().toString("hex"));
+  if (y >= P) return false;
+  const y2 = (y * y) % P;
+  const u = (y2 - 1n + P) % P;
+  const v = (D * y2 + 1n) % P;
+  const v3 = (modPow(v, 3n) * u) % P;
+  const x = (v3 * modPow((modPow(v, 7n) * u) % P, (P - 5n) / 8n)) % P;
+  const vx2 = (v * x * x) % P;
+  return vx2 === u || vx2 === (P - u) % P;
+}
+
+function findProgramAddress(seeds, programId = PROGRAM_ID) {
+  for (let bump = 255; bump >= 0; bump--) {
+    const hash = createHash("sha256")
+      .update(Buffer.concat([...seeds, Buffer.from([bump]), decodeBase58(programId), Buffer.from("ProgramDerivedAddress")]))
+      .digest();
+    if (!isOnCurve(hash)) return [hash, bump];
+  }
+  throw new Error("no program address");
+}
+
+function keyFromSeed(seed) {
+  const privateKey = createPrivateKey({
+    key: Buffer.concat([
+      Buffer.from("302e020100300506032b657004220420", "hex"),
+      createHash("sha256").update(seed).digest(),
+    ]),
+    format: "der",
+    type: "pkcs8",
+  });
+  return createPublicKey(privateKey).export({ format: "der", type: "spki" }).subarray(-32);
+}
+
+function discriminator(name) {
+  return createHash("sha256").update(`account:${name}`).digest().subarray(0, 8);
+}
+
+// borsh
+const u8 = (value) => Buffer.from([value]);
+const bool = (value) => u8(value ? 1 : 0);
+const u16 = (value) => { const buffer = Buffer.alloc(2); buffer.writeUInt16LE(value); return buffer; };
+const u32 = (value) => { const buffer = Buffer.alloc(4); buffer.writeUInt32LE(value); return buffer; };
+const u64 = (value) => { const buffer = Buffer.alloc(8); buffer.writeBigUInt64LE(BigInt(value)); return buffer; };
+const i64 = (value) => { const buffer = Buffer.alloc(8); buffer.writeBigInt64LE(BigInt(value)); return buffer; };
+const vec = (items) => Buffer.concat([u32(items.length), ...items]);
+const none = () => u8(0);
+const DEFAULT_KEY = Buffer.alloc(32);
+
+function writeAccount(name, address, owner, data) {
+  const lamports = (128 + data.length) * 6960;
+  writeFileSync(
+    join(FIXTURES_DIR, `${name}.json`),
+    JSON.stringify({
+      pubkey: encodeBase58(address),
+      account: {
+        lamports,
+        data: [data.toString("base64"), "base64"],
+        owner,
+        executable: false,
+        rentEpoch: 0,
+        space: data.length,
+      },
+    }, null, 2) + "\n"
+  );
+}
+
+const authority = keyFromSeed(AUTHORITY_SEED);
+const rewardMint = keyFromSeed("brick migration fixtures reward mint");
+const paymentMint = keyFromSeed("brick migration fixtures payment mint");
+const productMint = keyFromSeed("brick migration fixtures product mint");
+const merkleTree = keyFromSeed("brick migration fixtures merkle tree");
+
+// common marketplace fields, up to the rewards config
+const marketplaceHead = (version, id) => Buffer.concat([
+  u8(version),
+  authority,        // authority
+  authority,        // creator
+  DEFAULT_KEY,      // pending_authority
+  u64(id),
+  // TokenConfig: use_cnfts, deliver_token, transferable, chain_counter
+  bool(false), bool(true), bool(false), bool(true),
+  // PermissionConfig: access_mint, permissionless
+  DEFAULT_KEY, bool(true),
+  // FeesConfig: discount_mint, fee, fee_reduction, fee_payer (Seller)
+  DEFAULT_KEY, u16(250), u16(10), u8(1),
+]);
+
+const marketplaceAddress = (id) => findProgramAddress([Buffer.from("marketplace"), authority, u64(id)]);
+
+// version 1, vault vectors in the rewards config and the bumps
+const [marketplaceV1, marketplaceV1Bump] = marketplaceAddress(1);
+const [bountyVault] = findProgramAddress([Buffer.from("bounty_vault"), marketplaceV1, rewardMint]);
+{
+  const data = Buffer.concat([
+    discriminator("Marketplace"),
+    marketplaceHead(1, 1),
+    // RewardsConfigV1: reward_mint, bounty_vaults, seller_reward, buyer_reward, rewards_enabled
+    rewardMint, vec([bountyVault]), u16(100), u16(100), bool(true),
+    // MarketplaceBumpsV1: bump, vault_bumps, access_mint_bump
+    u8(marketplaceV1Bump), vec([u8(254)]), u8(0),
+    u32(2),           // open_products
+    u64(100),         // pending_rewards
+    bool(false),      // paused
+  ]);
+  writeAccount("marketplace_v1", marketplaceV1, PROGRAM_ID, Buffer.concat([data, Buffer.alloc(412 - data.length)]));
+}
+
+// token account of a bounty vault, the version 1 vault is counted from the stored vector and the
+// version 3 vault is passed to the migration
+const bountyVaultData = (marketplace) => Buffer.concat([
+  rewardMint,       // mint
+  marketplace,      // owner
+  u64(0),           // amount
+  u32(0), DEFAULT_KEY, // delegate
+  u8(1),            // state: initialized
+  u32(0), u64(0),   // is_native
+  u64(0),           // delegated_amount
+  u32(0), DEFAULT_KEY, // close_authority
+]);
+writeAccount("bounty_vault_v1", bountyVault, TOKEN_PROGRAM_ID, bountyVaultData(marketplaceV1));
+
+// version 3, campaigns end timestamp, no vault vectors
+const [marketplaceV3, marketplaceV3Bump] = marketplaceAddress(3);
+writeAccount("marketplace_v3", marketplaceV3, PROGRAM_ID, Buffer.concat([
+  discriminator("Marketplace"),
+  marketplaceHead(3, 3),
+  // RewardsConfigV3: reward_mint, seller_reward, buyer_reward, rewards_enabled
+  rewardMint, u16(100), u16(100), bool(true),
+  // MarketplaceBumps: bump, access_mint_bump
+  u8(marketplaceV3Bump), u8(0),
+  u32(1),           // open_products
+  u64(0),           // pending_rewards
+  bool(true),       // paused
+  i64(1700000000),  // campaigns_end_ts
+]));
+
+const [bountyVaultV3] = findProgramAddress([Buffer.from("bounty_vault"), marketplaceV3, rewardMint]);
+writeAccount("bounty_vault_v3", bountyVaultV3, TOKEN_PROGRAM_ID, bountyVaultData(marketplaceV3));
+
+// version 4, vesting period in the rewards config, no arbiter yet
+const [marketplaceV4, marketplaceV4Bump] = marketplaceAddress(4);
+writeAccount("marketplace_v4", marketplaceV4, PROGRAM_ID, Buffer.concat([
+  discriminator("Marketplace"),
+  marketplaceHead(4, 4),
+  // RewardsConfigV5: reward_mint, seller_reward, buyer_reward, rewards_enabled, vesting_period
+  rewardMint, u16(100), u16(100), bool(true), i64(600),
+  // MarketplaceBumps: bump, access_mint_bump
+  u8(marketplaceV4Bump), u8(0),
+  u32(0),           // open_products
+  u64(0),           // pending_rewards
+  bool(false),      // paused
+  i64(0),           // campaigns_end_ts
+]));
+
+// created before version 1, derived from its authority
+const [unversionedMarketplace, unversionedMarketplaceBump] = findProgramAddress([Buffer.from("marketplace"), authority]);
+{
+  const data = Buffer.concat([
+    discriminator("Marketplace"),
+    authority,
+    // TokenConfig
+    bool(false), bool(true), bool(false), bool(true),
+    // PermissionConfig
+    DEFAULT_KEY, bool(true),
+    // FeesConfig
+    DEFAULT_KEY, u16(250), u16(10), u8(1),
+    // RewardsConfig
+    rewardMint, vec([]), u16(100), u16(100), bool(true),
+    // MarketplaceBumps
+    u8(unversionedMarketplaceBump), vec([]), u8(0),
+  ]);
+  writeAccount("marketplace_unversioned", unversionedMarketplace, PROGRAM_ID, Buffer.concat([data, Buffer.alloc(317 - data.length)]));
+}
+
+// products of the version 1 marketplace
+const productId = (id) => {
+  const data = Buffer.alloc(64, 32);
+  data.write(id);
+  return [data.subarray(0, 32), data.subarray(32)];
+};
+const productHead = (version, [firstId, secondId], tree, bump) => Buffer.concat([
+  u8(version),
+  authority,
+  firstId,
+  secondId,
+  marketplaceV1,
+  productMint,
+  tree,
+  // SellerConfig: payment_mint, product_price
+  paymentMint, u64(1000),
+  // ProductBumps: bump, mint_bump
+  u8(bump), u8(0),
+]);
+const productAddress = ([firstId, secondId]) => findProgramAddress([Buffer.from("product"), firstId, secondId, marketplaceV1]);
+
+// version 1, no inventory
+const productV1Id = productId("product v1");
+const [productV1, productV1Bump] = productAddress(productV1Id);
+writeAccount("product_v1", productV1, PROGRAM_ID, Buffer.concat([
+  discriminator("Product"),
+  productHead(1, productV1Id, DEFAULT_KEY, productV1Bump),
+]));
+
+// version 2, tree product with units sold, its receipts were not counted
+const productV2Id = productId("product v2");
+const [productV2, productV2Bump] = productAddress(productV2Id);
+writeAccount("product_v2", productV2, PROGRAM_ID, Buffer.concat([
+  discriminator("Product"),
+  productHead(2, productV2Id, merkleTree, productV2Bump),
+  none(),           // max_supply
+  u64(3),           // sold
+]));
+
+// version 1, vault vectors
+const [rewardV1, rewardV1Bump] = findProgramAddress([Buffer.from("reward"), authority, marketplaceV1]);
+{
+  const data = Buffer.concat([
+    discriminator("Reward"),
+    u8(1),
+    authority,
+    marketplaceV1,
+    vec([keyFromSeed("brick migration fixtures reward vault")]),
+    // RewardBumps: bump, vault_bumps
+    u8(rewardV1Bump), vec([u8(254)]),
+  ]);
+  writeAccount("reward_v1", rewardV1, PROGRAM_ID, Buffer.concat([data, Buffer.alloc(247 - data.length)]));
+}
+
+// version 1, the price paid is valued at the product price by the migration
+const [paymentV1, paymentV1Bump] = findProgramAddress([Buffer.from("payment"), authority, productV1]);
+writeAccount("payment_v1", paymentV1, PROGRAM_ID, Buffer.concat([
+  discriminator("Payment"),
+  u8(1),
+  u32(2),           // units
+  u8(paymentV1Bump),
+]));
+
+// created before version 1
+const [unversionedPayment, unversionedPaymentBump] = findProgramAddress([Buffer.from("payment"), authority, productV2]);
+writeAccount("payment_unversioned", unversionedPayment, PROGRAM_ID, Buffer.concat([
+  discriminator("Payment"),
+  u32(1),           // units
+  u8(unversionedPaymentBump),
+]));
@@ -0,0 +1,267 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { ConfirmOptions, SystemProgram } from "@solana/web3.js";
+import { Brick } from "../../target/types/brick";
+import BN from "bn.js";
+import { createHash } from "crypto";
+import { getSplitId } from "./utils";
+
+// the legacy accounts are loaded by the test validator, see tests/brick/fixtures/generate.js
+describe("brick migrations", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Brick as Program<Brick>;
+  const [eventAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority", "utf-8")],
+    program.programId
+  );
+  const confirmOptions: ConfirmOptions = { commitment: "confirmed" };
+
+  const fixtureKey = (seed: string) =>
+    anchor.web3.Keypair.fromSeed(createHash("sha256").update(seed).digest());
+  // signs the migrations, it created every fixture
+  const authority = fixtureKey("brick migration fixtures");
+  const rewardMint = fixtureKey("brick migration fixtures reward mint").publicKey;
+  const merkleTree = fixtureKey("brick migration fixtures merkle tree").publicKey;
+
+  const marketplaceAddress = (id: number) => anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("marketplace", "utf-8"),
+      authority.publicKey.toBuffer(),
+      new BN(id).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  )[0];
+  const productAddress = (id: string) => anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("product", "utf-8"),
+      ...getSplitId(id),
+      marketplaceAddress(1).toBuffer(),
+    ],
+    program.programId
+  )[0];
+  const paymentAddress = (product: anchor.web3.PublicKey) => anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("payment", "utf-8"),
+      authority.publicKey.toBuffer(),
+      product.toBuffer(),
+    ],
+    program.programId
+  )[0];
+  const migrateMarketplace = (marketplace: anchor.web3.PublicKey) => program.methods
+    .migrateMarketplace()
+    .accounts({
+      eventAuthority,
+      program: program.programId,
+      systemProgram: SystemProgram.programId,
+      signer: authority.publicKey,
+      marketplace,
+    })
+    .signers([authority]);
+
+  it("Should migrate marketplaces from every legacy layout", async () => {
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(authority.publicKey, anchor.web3.LAMPORTS_PER_SOL),
+      "confirmed"
+    );
+
+    const bountyVaultMeta = (marketplace: anchor.web3.PublicKey) => ({
+      pubkey: anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("bounty_vault", "utf-8"),
+          marketplace.toBuffer(),
+          rewardMint.toBuffer(),
+        ],
+        program.programId
+      )[0],
+      isSigner: false,
+      isWritable: false,
+    });
+
+    // version 1 stored the bounty vaults, they are counted from the stored vector
+    const marketplaceV1 = marketplaceAddress(1);
+    await migrateMarketplace(marketplaceV1).rpc(confirmOptions);
+
+    const migratedV1 = await program.account.marketplace.fetch(marketplaceV1);
+    assert.equal(migratedV1.version, 6);
+    assert.equal(migratedV1.authority.toString(), authority.publicKey.toString());
+    assert.equal(migratedV1.creator.toString(), authority.publicKey.toString());
+    assert.equal(Number(migratedV1.id), 1);
+    assert.isTrue(migratedV1.tokenConfig.deliverToken);
+    assert.isTrue(migratedV1.tokenConfig.chainCounter);
+    assert.isTrue(migratedV1.permissionConfig.permissionless);
+    assert.equal(migratedV1.feesConfig.fee, 250);
+    assert.equal(migratedV1.feesConfig.feeReduction, 10);
+    assert.deepEqual(migratedV1.feesConfig.feePayer, { seller: {} });
+    assert.equal(migratedV1.rewardsConfig.rewardMint.toString(), rewardMint.toString());
+    assert.equal(migratedV1.openProducts, 2);
+    assert.equal(Number(migratedV1.pendingRewards), 100);
+    assert.equal(migratedV1.bountyVaults, 1);
+
+    // versions 2 to 5 did not store the bounty vaults, the vault of the reward mint is always required
+    const marketplaceV3 = marketplaceAddress(3);
+    try {
+      await migrateMarketplace(marketplaceV3).rpc(confirmOptions);
+      assert.fail("the bounty vault of the reward mint is required");
+    } catch (e) {
+      assert.include(String(e), "IncorrectRemainingAccounts");
+    }
+
+    try {
+      await migrateMarketplace(marketplaceV3)
+        .remainingAccounts([bountyVaultMeta(marketplaceV1)])
+        .rpc(confirmOptions);
+      assert.fail("the bounty vault of another marketplace can not be counted");
+    } catch (e) {
+      assert.include(String(e), "IncorrectATA");
+    }
+
+    try {
+      await migrateMarketplace(marketplaceV3)
+        .remainingAccounts([bountyVaultMeta(marketplaceV3), bountyVaultMeta(marketplaceV3)])
+        .rpc(confirmOptions);
+      assert.fail("a bounty vault can only be counted once");
+    } catch (e) {
+      assert.include(String(e), "IncorrectRemainingAccounts");
+    }
+
+    // versions 2 and 3 had the campaigns end timestamp
+    await migrateMarketplace(marketplaceV3)
+      .remainingAccounts([bountyVaultMeta(marketplaceV3)])
+      .rpc(confirmOptions);
+
+    const migratedV3 = await program.account.marketplace.fetch(marketplaceV3);
+    assert.equal(migratedV3.version, 6);
+    assert.equal(Number(migratedV3.campaignsEndTs), 1700000000);
+    assert.isTrue(migratedV3.paused);
+    assert.equal(migratedV3.openProducts, 1);
+    assert.equal(Number(migratedV3.vestingPeriod), 0);
+    assert.equal(migratedV3.bountyVaults, 1);
+
+    // version 4 had the vesting period in the rewards config and no arbiter
+    const marketplaceV4 = marketplaceAddress(4);
+    await migrateMarketplace(marketplaceV4)
+      .remainingAccounts([bountyVaultMeta(marketplaceV4)])
+      .rpc(confirmOptions);
+
+    const migratedV4 = await program.account.marketplace.fetch(marketplaceV4);
+    assert.equal(migratedV4.version, 6);
+    assert.equal(Number(migratedV4.vestingPeriod), 600);
+    assert.equal(migratedV4.arbiter.toString(), anchor.web3.PublicKey.default.toString());
+    assert.equal(migratedV4.rewardsConfig.rewardMint.toString(), rewardMint.toString());
+    // the vault of the reward mint does not exist, it is not counted
+    assert.equal(migratedV4.bountyVaults, 0);
+
+    // migrated accounts only need to be migrated once
+    await migrateMarketplace(marketplaceV4).rpc(confirmOptions);
+    assert.equal(Number((await program.account.marketplace.fetch(marketplaceV4)).vestingPeriod), 600);
+
+    // the marketplaces created before version 1 were derived from their authority
+    const [unversionedMarketplace] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("marketplace", "utf-8"),
+        authority.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    try {
+      await migrateMarketplace(unversionedMarketplace).rpc(confirmOptions);
+      assert.fail("accounts created before version 1 can not be migrated");
+    } catch (e) {
+      assert.include(String(e), "UnversionedAccount");
+    }
+  });
+
+  it("Should migrate the products, rewards and payments of a legacy marketplace", async () => {
+    const productV1 = productAddress("product v1");
+    const productV2 = productAddress("product v2");
+
+    for (const product of [productV1, productV2]) {
+      await program.methods
+        .migrateProduct()
+        .accounts({
+          eventAuthority,
+          program: program.programId,
+          systemProgram: SystemProgram.programId,
+          signer: authority.publicKey,
+          product,
+        })
+        .signers([authority])
+        .rpc(confirmOptions);
+    }
+
+    const migratedProductV1 = await program.account.product.fetch(productV1);
+    assert.equal(migratedProductV1.version, 6);
+    assert.equal(Number(migratedProductV1.sellerConfig.productPrice), 1000);
+    assert.equal(Number(migratedProductV1.sold), 0);
+    assert.isNull(migratedProductV1.maxSupply);
+    assert.isNull(migratedProductV1.subscriptionPeriod);
+    assert.equal(Number(migratedProductV1.receipts), 0);
+    assert.equal(Number(migratedProductV1.outstandingUnits), 0);
+
+    // the receipts of the units sold by a tree product were not counted
+    const migratedProductV2 = await program.account.product.fetch(productV2);
+    assert.equal(migratedProductV2.merkleTree.toString(), merkleTree.toString());
+    assert.equal(Number(migratedProductV2.sold), 3);
+    assert.equal(Number(migratedProductV2.receipts), 3);
+    assert.equal(Number(migratedProductV2.outstandingUnits), 3);
+
+    const [reward] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("reward", "utf-8"),
+        authority.publicKey.toBuffer(),
+        marketplaceAddress(1).toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .migrateReward()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        signer: authority.publicKey,
+        reward,
+      })
+      .signers([authority])
+      .rpc(confirmOptions);
+
+    const migratedReward = await program.account.reward.fetch(reward);
+    assert.equal(migratedReward.version, 3);
+    assert.equal(migratedReward.authority.toString(), authority.publicKey.toString());
+    assert.equal(migratedReward.marketplace.toString(), marketplaceAddress(1).toString());
+    assert.equal(Number(migratedReward.pendingRewards), 0);
+
+    // the units bought before version 3 are valued at the product price
+    const migratePayment = (product: anchor.web3.PublicKey) => program.methods
+      .migratePayment()
+      .accounts({
+        eventAuthority,
+        program: program.programId,
+        systemProgram: SystemProgram.programId,
+        signer: authority.publicKey,
+        product,
+        payment: paymentAddress(product),
+      })
+      .signers([authority])
+      .rpc(confirmOptions);
+
+    await migratePayment(productV1);
+
+    const migratedPayment = await program.account.payment.fetch(paymentAddress(productV1));
+    assert.equal(migratedPayment.version, 3);
+    assert.equal(migratedPayment.units, 2);
+    assert.equal(Number(migratedPayment.paid), 2000);
+    assert.equal(Number(migratedPayment.expiresAt), 0);
+    assert.equal(Number(migratedPayment.subscriptionGranted), 0);
+    assert.equal(migratedPayment.campaign.toString(), anchor.web3.PublicKey.default.toString());
+
+    try {
+      await migratePayment(productV2);
+      assert.fail("accounts created before version 1 can not be migrated");
+    } catch (e) {
+      assert.include(String(e), "UnversionedAccount");
+    }
+  });
+});
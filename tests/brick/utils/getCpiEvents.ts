@@ -0,0 +1,26 @@
+import { Program, utils } from "@coral-xyz/anchor";
+import { Brick } from "../../../target/types/brick";
+
+// emit_cpi! events are the data of the self cpi inner instructions,
+// the first 8 bytes are the event ix tag and then the encoded event.
+export const getCpiEvents = async (
+  program: Program<Brick>,
+  signature: string
+) => {
+  const tx = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = tx.transaction.message.getAccountKeys();
+
+  return tx.meta.innerInstructions
+    .flatMap((inner) => inner.instructions)
+    .filter((ix) => accountKeys.get(ix.programIdIndex).equals(program.programId))
+    .map((ix) => {
+      const data = utils.bytes.bs58.decode(ix.data);
+      return program.coder.events.decode(
+        utils.bytes.base64.encode(data.subarray(8))
+      ) as { name: string; data: any } | null;
+    })
+    .filter((event) => event !== null);
+};
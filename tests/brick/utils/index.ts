@@ -1,6 +1,8 @@
 export * from "./createFundedWallet";
 export * from "./createMint";
 export * from "./createFundedAssociatedTokenAccount";
+export * from "./getCpiEvents";
+export * from "./waitForTimestamp";
 
 export function delay(ms: number) {
   return new Promise((resolve) => setTimeout(resolve, ms));
@@ -0,0 +1,15 @@
+import { Connection } from "@solana/web3.js";
+
+// the program reads the cluster clock, it can drift from the local one
+export const waitForTimestamp = async (
+  connection: Connection,
+  timestamp: number
+) => {
+  for (;;) {
+    const slot = await connection.getSlot("confirmed");
+    const blockTime = await connection.getBlockTime(slot);
+    if (blockTime !== null && blockTime >= timestamp) return;
+
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+  }
+};
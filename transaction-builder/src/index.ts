@@ -85,9 +85,9 @@ app.get('/registerBuy', async (req: Request, res: Response) => {
         const itemHash = await generateAlephMessage({ 
             product: product.toString(), 
             seller, signer, 
-            units: Number(params.amount), 
+            units: 1, 
             paymentMint, 
-            totalAmount: Number(productInfo.sellerConfig.productPrice) * params.amount 
+            totalAmount: Number(productInfo.sellerConfig.productPrice)
         }, messagesSigner);
         const accounts = {
             signer: new PublicKey(signer),
@@ -99,8 +99,9 @@ app.get('/registerBuy', async (req: Request, res: Response) => {
             merkleTree: new PublicKey(productInfo.merkleTree),
         };
         const parsedParams = {
-            rewardsActive: params.rewardsActive === 'true' ? true : false,
-            amount: Number(params.amount),
+            maxTotalPrice: Number(params.maxTotalPrice),
+            campaign: params.campaign ? new PublicKey(params.campaign) : undefined,
+            rewardMint: params.rewardMint ? new PublicKey(params.rewardMint) : undefined,
             name: params.name,
             uri: `https://api1.aleph.im/api/v0/messages.json?hashes=${itemHash}`,
         }
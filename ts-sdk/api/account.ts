@@ -39,11 +39,13 @@ export async function queryAccounts(url: string, filters: AccountsFilters): Prom
                         }
                         rewardsConfig {
                             rewardMint
-                            bountyVaults
-                            sellerReward
-                            buyerReward
-                            rewardsEnabled
                         }
+                        openProducts
+                        pendingRewards
+                        paused
+                        arbiter
+                        vestingPeriod
+                        bountyVaults
                     }
                     ...on Product {
                         authority
@@ -64,11 +66,11 @@ export async function queryAccounts(url: string, filters: AccountsFilters): Prom
                     ...on Reward {
                         authority
                         marketplace
-                        rewardVaults
                         bumps {
                             bump
-                            vaultBumps
                         }
+                        lockedUntil
+                        pendingRewards
                     }
                     ...on Access {
                         authority
@@ -1,8 +1,8 @@
-import { BrickEvent, InstructionType } from "../types"
+import { BrickEvent, EventType } from "../types"
 
 export type EventsFilters = {
     account: string
-    types?: InstructionType[]
+    types?: EventType[]
     signer?: string
     startDate?: number
     endDate?: number
@@ -29,322 +29,349 @@ async function queryEvents(url: string, filters: EventsFilters): Promise<BrickEv
                 type
                 account
                 signer
-                ... on AcceptAccessEvent {
+                ... on MarketplaceInitializedEvent {
                     info {
-                        systemProgram
-                        tokenProgram2022
-                        associatedTokenProgram
-                        rent
-                        signer
-                        receiver
+                        eventAuthority
                         marketplace
-                        request
-                        accessMint
-                        accessVault
+                        authority
+                        id
+                        tokenConfig {
+                            useCnfts
+                            deliverToken
+                            transferable
+                            chainCounter
+                        }
+                        permissionConfig {
+                            accessMint
+                            permissionless
+                        }
+                        feesConfig {
+                            discountMint
+                            fee
+                            feeReduction
+                            feePayer
+                        }
+                        rewardsConfig {
+                            rewardMint
+                        }
+                        arbiter
+                        vestingPeriod
                     }
                 }
-                ... on AirdropAccessEvent {
+                ... on MarketplaceEditedEvent {
                     info {
-                        systemProgram
-                        tokenProgram2022
-                        associatedTokenProgram
-                        rent
-                        signer
-                        receiver
+                        eventAuthority
                         marketplace
-                        accessMint
-                        accessVault
+                        tokenConfig {
+                            useCnfts
+                            deliverToken
+                            transferable
+                            chainCounter
+                        }
+                        permissionConfig {
+                            accessMint
+                            permissionless
+                        }
+                        feesConfig {
+                            discountMint
+                            fee
+                            feeReduction
+                            feePayer
+                        }
+                        rewardsConfig {
+                            rewardMint
+                        }
+                        arbiter
+                        vestingPeriod
                     }
                 }
-                ... on EditProductEvent {
+                ... on MarketplaceAuthorityProposedEvent {
                     info {
-                        signer
-                        product
-                        paymentMint
-                        productPrice
+                        eventAuthority
+                        marketplace
+                        authority
+                        pendingAuthority
+                    }
+                }
+                ... on MarketplaceAuthorityAcceptedEvent {
+                    info {
+                        eventAuthority
+                        marketplace
+                        previousAuthority
+                        authority
+                    }
+                }
+                ... on MarketplacePauseSetEvent {
+                    info {
+                        eventAuthority
+                        marketplace
+                        paused
+                    }
+                }
+                ... on MarketplaceClosedEvent {
+                    info {
+                        eventAuthority
+                        marketplace
+                        authority
                     }
                 }
-                ... on EditMarketplaceEvent {
+                ... on BountyVaultInitializedEvent {
                     info {
-                        signer
+                        eventAuthority
                         marketplace
+                        bountyVault
                         rewardMint
-                        discountMint
-                        params {
-                            fee
-                            feeReduction
-                            sellerReward
-                            buyerReward
-                            useCnfts
-                            deliverToken
-                            transferable
-                            chainCounter
-                            permissionless
-                            rewardsEnabled
-                            feePayer
-                        }
                     }
                 }
-                ... on InitBountyEvent {
+                ... on BountyWithdrawnEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        associatedTokenProgram
-                        rent
-                        signer
+                        eventAuthority
                         marketplace
+                        bountyVault
                         rewardMint
+                        receiverVault
+                        amount
+                    }
+                }
+                ... on BountyVaultClosedEvent {
+                    info {
+                        eventAuthority
+                        marketplace
                         bountyVault
+                        rewardMint
                     }
                 }
-                ... on InitMarketplaceEvent {
+                ... on AccessRequestedEvent {
                     info {
-                        systemProgram
-                        tokenProgram2022
-                        tokenProgramV0
-                        rent
-                        signer
+                        eventAuthority
                         marketplace
+                        user
+                    }
+                }
+                ... on AccessGrantedEvent {
+                    info {
+                        eventAuthority
+                        marketplace
+                        user
                         accessMint
-                        rewardMint
-                        discountMint
-                        bountyVault
-                        params {
-                            fee
-                            feeReduction
-                            sellerReward
-                            buyerReward
-                            useCnfts
-                            deliverToken
-                            transferable
-                            chainCounter
-                            permissionless
-                            rewardsEnabled
-                            accessMintBump
-                            feePayer
-                        }
                     }
                 }
-                ... on InitProductTreeEvent {
+                ... on ProductInitializedEvent {
                     info {
-                        tokenMetadataProgram
-                        logWrapper
-                        bubblegumProgram
-                        compressionProgram
-                        tokenProgramV0
-                        associatedTokenProgram
-                        rent
-                        signer
+                        eventAuthority
                         marketplace
                         product
+                        seller
                         productMint
-                        paymentMint
-                        accessMint
-                        productMintVault
-                        accessVault
-                        masterEdition
-                        metadata
                         merkleTree
-                        treeAuthority
-                        params {
-                            firstId
-                            secondId
-                            productPrice
-                            maxDepth
-                            maxBufferSize
-                            name
-                            metadataUrl
-                            feeBasisPoints
-                        }
+                        firstId
+                        secondId
+                        paymentMint
+                        productPrice
+                        maxSupply
+                        maxUnitsPerWallet
+                        saleStart
+                        saleEnd
+                        escrowTimeout
+                        subscriptionPeriod
                     }
                 }
-                ... on InitProductEvent {
+                ... on ProductEditedEvent {
                     info {
-                        systemProgram
-                        tokenProgram2022
-                        rent
-                        signer
-                        marketplace
+                        eventAuthority
                         product
-                        productMint
                         paymentMint
-                        accessMint
-                        accessVault
-                        params {
-                            firstId
-                            secondId
-                            productPrice
-                            productMintBump
-                        }
+                        productPrice
+                        maxSupply
+                        maxUnitsPerWallet
+                        saleStart
+                        saleEnd
+                        escrowTimeout
+                        subscriptionPeriod
                     }
                 }
-                ... on InitRewardVaultEvent {
+                ... on ProductClosedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        associatedTokenProgram
-                        rent
-                        signer
+                        eventAuthority
                         marketplace
-                        reward
-                        rewardMint
-                        rewardVault
+                        product
+                        seller
                     }
                 }
-                ... on InitRewardEvent {
+                ... on TreeUpdatedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        signer
-                        marketplace
-                        reward
-                        rewardMint
-                        rewardVault
+                        eventAuthority
+                        product
+                        merkleTree
+                        maxDepth
+                        maxBufferSize
                     }
                 }
-                ... on RegisterBuyCnftEvent {
+                ... on ProductPurchasedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        logWrapper
-                        bubblegumProgram
-                        compressionProgram
-                        tokenMetadataProgram
-                        signer
-                        seller
-                        marketplaceAuth
+                        eventAuthority
                         marketplace
                         product
+                        buyer
+                        seller
                         paymentMint
-                        productMint
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
+                        units
+                        grossAmount
+                        fee
+                        sellerAmount
+                        campaign
                         sellerReward
-                        sellerRewardVault
                         buyerReward
-                        buyerRewardVault
-                        metadata
-                        masterEdition
-                        treeAuthority
-                        merkleTree
-                        params {
-                            amount
-                            name
-                            symbol
-                            uri
-                        }
                     }
                 }
-                ... on RegisterBuyCounterEvent {
+                ... on EscrowCreatedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        signer
-                        seller
-                        marketplaceAuth
+                        eventAuthority
                         marketplace
                         product
-                        payment
+                        escrow
+                        buyer
+                        seller
                         paymentMint
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
-                        sellerReward
-                        sellerRewardVault
-                        buyerReward
-                        buyerRewardVault
-                        amount
+                        units
+                        grossAmount
+                        escrowedAmount
+                        releaseTs
                     }
                 }
-                ... on RegisterBuyTokenEvent {
+                ... on EscrowReleasedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        tokenProgram2022
-                        signer
+                        eventAuthority
+                        marketplace
+                        product
+                        escrow
+                        buyer
                         seller
-                        marketplaceAuth
+                        releasedBy
+                        fee
+                        sellerAmount
+                        confirmed
+                    }
+                }
+                ... on PurchaseRefundedEvent {
+                    info {
+                        eventAuthority
                         marketplace
                         product
-                        productMint
+                        buyer
+                        seller
                         paymentMint
-                        buyerTokenVault
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
-                        sellerReward
-                        sellerRewardVault
-                        buyerReward
-                        buyerRewardVault
+                        units
                         amount
+                        sellerClawback
+                        buyerClawback
+                    }
+                }
+                ... on ProductRedeemedEvent {
+                    info {
+                        eventAuthority
+                        marketplace
+                        product
+                        buyer
+                        seller
+                        units
                     }
                 }
-                ... on RegisterBuyEvent {
+                ... on DisputeOpenedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        signer
+                        eventAuthority
+                        marketplace
+                        product
+                        escrow
+                        buyer
                         seller
-                        marketplaceAuth
+                    }
+                }
+                ... on DisputeSettledEvent {
+                    info {
+                        eventAuthority
                         marketplace
                         product
-                        payment
+                        escrow
+                        buyer
+                        seller
+                        ruling {
+                            arbiter
+                            buyerAmount
+                            sellerAmount
+                            fee
+                            settledTs
+                        }
+                    }
+                }
+                ... on CampaignInitializedEvent {
+                    info {
+                        eventAuthority
+                        marketplace
+                        campaign
+                        id
+                        rewardMint
                         paymentMint
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
+                        startTs
+                        endTs
+                        budget
                         sellerReward
-                        sellerRewardVault
                         buyerReward
-                        buyerRewardVault
-                        amount
+                        maxRewardPerPurchase
+                        maxRewardPerWallet
+                        vestingPeriod
+                    }
+                }
+                ... on CampaignRewardInitializedEvent {
+                    info {
+                        eventAuthority
+                        campaign
+                        campaignReward
+                        authority
                     }
                 }
-                ... on RequestAccessEvent {
+                ... on RewardClosedEvent {
                     info {
-                        systemProgram
-                        rent
-                        signer
+                        eventAuthority
                         marketplace
-                        request
+                        reward
+                        authority
+                        swept
                     }
                 }
-                ... on UpdateTreeEvent {
+                ... on RewardInitializedEvent {
                     info {
-                        payer
-                        signer
+                        eventAuthority
                         marketplace
-                        product
-                        treeAuthority
-                        merkleTree
-                        logWrapper
-                        systemProgram
-                        bubblegumProgram
-                        compressionProgram
-                        params {
-                        maxDepth
-                        maxBufferSize
-                        }
+                        reward
+                        authority
+                        rewardVault
+                    }
+                }
+                ... on RewardVaultInitializedEvent {
+                    info {
+                        eventAuthority
+                        reward
+                        rewardVault
+                        rewardMint
                     }
                 }
-                ... on WithdrawRewardEvent {
+                ... on RewardWithdrawnEvent {
                     info {
-                        tokenProgramV0
-                        signer
+                        eventAuthority
                         marketplace
+                        campaign
                         reward
+                        authority
                         rewardMint
-                        receiverVault
-                        rewardVault
+                        amount
+                    }
+                }
+                ... on AccountMigratedEvent {
+                    info {
+                        eventAuthority
+                        account
+                        version
                     }
                 }
             }
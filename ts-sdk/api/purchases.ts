@@ -1,5 +1,4 @@
-import { InstructionType } from '../types';
-import { IX_DATA_LAYOUT } from '../utils/solita/instructions';
+import { decodeBase58, isProductPurchased, productPurchasedStruct } from '../utils/events';
 import { Connection } from '@solana/web3.js';
 
 export async function queryPurchases(url: string, owner: string, productMint: string): Promise<number> {
@@ -51,9 +50,15 @@ export async function queryPurchases(url: string, owner: string, productMint: st
         const responseBody = await response.json();
         const connection: Connection = new Connection(url);    
         const tx = await connection.getTransaction(responseBody.result.items[0][0], {commitment: 'confirmed', maxSupportedTransactionVersion: 1});
-        const [context] = IX_DATA_LAYOUT[InstructionType.RegisterBuyCnft].deserialize(tx?.transaction.message.compiledInstructions[1].data);
-        const { ...result } = context;
-        unitsPurchased += result.params.amount; 
+        // the purchased units come from the ProductPurchased event emitted through a self cpi
+        for (const inner of tx?.meta?.innerInstructions ?? []) {
+            for (const ix of inner.instructions) {
+                const data = decodeBase58(ix.data);
+                if (!isProductPurchased(data)) continue;
+                const [event] = productPurchasedStruct.deserialize(data);
+                unitsPurchased += event.units;
+            }
+        }
     }
     return unitsPurchased;
 }
\ No newline at end of file
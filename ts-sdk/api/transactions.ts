@@ -25,110 +25,36 @@ export async function queryTransactions(url: string, filters: UserTranasctionFil
                 type
                 account
                 signer
-                ... on RegisterBuyCnftEvent {
+                ... on ProductPurchasedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        logWrapper
-                        bubblegumProgram
-                        compressionProgram
-                        tokenMetadataProgram
-                        signer
-                        seller
-                        marketplaceAuth
+                        eventAuthority
                         marketplace
                         product
-                        paymentMint
-                        productMint
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
-                        sellerReward
-                        sellerRewardVault
-                        buyerReward
-                        buyerRewardVault
-                        metadata
-                        masterEdition
-                        treeAuthority
-                        merkleTree
-                        params {
-                            amount
-                            name
-                            symbol
-                            uri
-                        }
-                    }
-                }
-                ... on RegisterBuyCounterEvent {
-                    info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        signer
+                        buyer
                         seller
-                        marketplaceAuth
-                        marketplace
-                        product
-                        payment
                         paymentMint
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
+                        units
+                        grossAmount
+                        fee
+                        sellerAmount
+                        campaign
                         sellerReward
-                        sellerRewardVault
                         buyerReward
-                        buyerRewardVault
-                        amount
                     }
                 }
-                ... on RegisterBuyTokenEvent {
+                ... on EscrowCreatedEvent {
                     info {
-                        systemProgram
-                        tokenProgramV0
-                        tokenProgram2022
-                        signer
-                        seller
-                        marketplaceAuth
+                        eventAuthority
                         marketplace
                         product
-                        productMint
-                        paymentMint
-                        buyerTokenVault
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
-                        sellerReward
-                        sellerRewardVault
-                        buyerReward
-                        buyerRewardVault
-                        amount
-                    }
-                }
-                ... on RegisterBuyEvent {
-                    info {
-                        systemProgram
-                        tokenProgramV0
-                        rent
-                        signer
+                        escrow
+                        buyer
                         seller
-                        marketplaceAuth
-                        marketplace
-                        product
-                        payment
                         paymentMint
-                        buyerTransferVault
-                        sellerTransferVault
-                        marketplaceTransferVault
-                        bountyVault
-                        sellerReward
-                        sellerRewardVault
-                        buyerReward
-                        buyerRewardVault
-                        amount
+                        units
+                        grossAmount
+                        escrowedAmount
+                        releaseTs
                     }
                 }
             }
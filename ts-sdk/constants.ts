@@ -2,6 +2,11 @@ import { PublicKey } from '@solana/web3.js'
 
 export const BRICK_PROGRAM_ID = 'brick5uEiJqSkfuAvMtKmq7kiuEVmbjVMiigyV51GRF';
 export const BRICK_PROGRAM_ID_PK = new PublicKey(BRICK_PROGRAM_ID);
+// signs the self cpi that logs the program events, every instruction that emits events needs it
+export const EVENT_AUTHORITY_PK = PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority", "utf-8")],
+    BRICK_PROGRAM_ID_PK
+)[0];
 export const METADATA_PROGRAM_ID_PK = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 export const BUBBLEGUM_PROGRAM_ID_PK = new PublicKey("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
 export const NOOP_PROGRAM_ID_PK = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
@@ -1,16 +1,9 @@
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { AcceptAccessInstructionAccounts, createAcceptAccessInstruction } from "../utils";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 
-export async function createAcceptAccessTransaction(connection: Connection, signer: PublicKey, receiver: PublicKey): Promise<VersionedTransaction> {
-  const [marketplace] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("marketplace", "utf-8"),
-        signer.toBuffer()
-      ],
-      BRICK_PROGRAM_ID_PK
-  );
+export async function createAcceptAccessTransaction(connection: Connection, signer: PublicKey, marketplace: PublicKey, receiver: PublicKey): Promise<VersionedTransaction> {
   const [accessMint] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("access_mint", "utf-8"),
@@ -37,6 +30,8 @@ export async function createAcceptAccessTransaction(connection: Connection, sign
       request,
       accessMint,
       accessVault: getAssociatedTokenAddressSync(accessMint, receiver, false, TOKEN_2022_PROGRAM_ID),
+      eventAuthority: EVENT_AUTHORITY_PK,
+      program: BRICK_PROGRAM_ID_PK,
   };
   const ix = createAcceptAccessInstruction(accounts);
   let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
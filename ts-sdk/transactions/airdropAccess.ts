@@ -1,16 +1,9 @@
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { AirdropAccessInstructionAccounts, createAirdropAccessInstruction } from "../utils";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 
-export async function createAirdropAccessTransaction(connection: Connection, signer: PublicKey, receiver: PublicKey) {
-  const [marketplace] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("marketplace", "utf-8"),
-        signer.toBuffer()
-      ],
-      BRICK_PROGRAM_ID_PK
-  );
+export async function createAirdropAccessTransaction(connection: Connection, signer: PublicKey, marketplace: PublicKey, receiver: PublicKey) {
   const [accessMint] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("access_mint", "utf-8"),
@@ -28,6 +21,8 @@ export async function createAirdropAccessTransaction(connection: Connection, sig
       marketplace,
       accessMint,
       accessVault: getAssociatedTokenAddressSync(accessMint, receiver, false, TOKEN_2022_PROGRAM_ID),
+      eventAuthority: EVENT_AUTHORITY_PK,
+      program: BRICK_PROGRAM_ID_PK,
   };
   const ix = createAirdropAccessInstruction(accounts);
   let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
@@ -1,10 +1,10 @@
 import { EditMarketplaceInstructionAccounts, EditMarketplaceParams, createEditMarketplaceInstruction } from "../utils/solita"
 import { Connection, PublicKey, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 
 type EditMarketplaceAccounts = {
     signer: PublicKey,
-    rewardMint: PublicKey,
+    marketplace: PublicKey,
     discountMint: PublicKey,
 };
 
@@ -13,17 +13,10 @@ export async function createEditMarketplaceTransaction(
     accounts: EditMarketplaceAccounts, 
     params: EditMarketplaceParams
 ): Promise<VersionedTransaction> {
-    const [marketplace] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("marketplace", "utf-8"),
-          accounts.signer.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-
     const ixAccounts: EditMarketplaceInstructionAccounts = {
         ...accounts,
-        marketplace,
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
     const ix = createEditMarketplaceInstruction(ixAccounts, { params });
     let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
@@ -1,14 +1,21 @@
-import { EditProductInstructionArgs } from "../types";
-import { EditProductInstructionAccounts, createEditProductInstruction } from "../utils/solita"
+import { EditProductInstructionAccounts, EditProductInstructionArgs, createEditProductInstruction } from "../utils/solita"
 import { Connection, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
+
+type EditProductAccounts = Omit<EditProductInstructionAccounts, "eventAuthority" | "program">
 
 // to-do: Multiple edits in the same transaction (applicable to other instructions)
 export async function createEditProducTransaction(
     connection: Connection, 
-    accounts: EditProductInstructionAccounts, 
+    accounts: EditProductAccounts, 
     params: EditProductInstructionArgs
 ): Promise<VersionedTransaction> {
-    const ix = createEditProductInstruction(accounts, params);
+    const ixAccounts: EditProductInstructionAccounts = {
+        ...accounts,
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
+    };
+    const ix = createEditProductInstruction(ixAccounts, params);
     let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
     const messageV0 = new TransactionMessage({
         payerKey: accounts.signer,
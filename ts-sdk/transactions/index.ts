@@ -2,8 +2,14 @@ export * from './acceptAccess'
 export * from './airdropAccess'
 export * from './editMarketplace'
 export * from './editProduct'
+export * from './initBounty'
 export * from './initMarketplace'
 export * from './initProduct'
 export * from './initProductTree'
+export * from './initReward'
+export * from './initRewardVault'
+export * from './registerBuy'
 export * from './registerBuyCnft'
+export * from './registerBuyCounter'
+export * from './registerBuyToken'
 export * from './requestAccess'
\ No newline at end of file
@@ -1,7 +1,7 @@
-import { InitMarketplaceInstructionAccounts, PaymentFeePayer, createInitMarketplaceInstruction, InitMarketplaceParams as InitMarketplaceParamsBump } from "../utils";
+import { InitMarketplaceInstructionAccounts, PaymentFeePayer, createInitMarketplaceInstruction, InitMarketplaceParams as InitMarketplaceParamsBump, getMarketplaceAddress } from "../utils";
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 
 type InitMarketplaceAccounts = {
     signer: PublicKey
@@ -10,17 +10,20 @@ type InitMarketplaceAccounts = {
 }
 
 type InitMarketplaceParams = {
+    // the marketplace address is derived from the signer and this id
+    id: number
     fee: number
     feeReduction: number
-    sellerReward: number
-    buyerReward: number
     useCnfts: boolean
     deliverToken: boolean
     transferable: boolean
     chainCounter: boolean
     permissionless: boolean
-    rewardsEnabled: boolean
     feePayer: PaymentFeePayer
+    // seconds the campaign rewards take to unlock after the campaign ends, 0 = no vesting
+    vestingPeriod: number
+    // PublicKey.default = disputes are settled only by the marketplace authority
+    arbiter: PublicKey
 }
 
 export async function createInitMarketplaceTransaction(
@@ -28,13 +31,7 @@ export async function createInitMarketplaceTransaction(
     accounts: InitMarketplaceAccounts, 
     params: InitMarketplaceParams
 ): Promise<VersionedTransaction> {
-    const [marketplace] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("marketplace", "utf-8"),
-          accounts.signer.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
+    const marketplace = getMarketplaceAddress(accounts.signer, params.id);
     const [bountyVault] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("bounty_vault", "utf-8"),
@@ -60,6 +57,8 @@ export async function createInitMarketplaceTransaction(
         marketplace,
         accessMint,
         bountyVault,
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
     const paramsBump: InitMarketplaceParamsBump = {
         ...params,
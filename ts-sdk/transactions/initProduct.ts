@@ -1,9 +1,8 @@
 import { InitProductInstructionAccounts, createInitProductInstruction, splitId } from "../utils";
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { TOKEN_2022_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
-import { InitProductInstructionArgs } from "../types";
-import BN from "bn.js";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
+import { InitProductInstructionArgs } from "../utils/solita";
 import { normalizePrice } from "../utils/normalizePrice";
 
 type InitProductAccounts = {
@@ -15,6 +14,17 @@ type InitProductAccounts = {
  type InitProductParams = {
     id: string
     productPrice: number
+    // unset = unlimited units
+    maxSupply?: number
+    // unset = a wallet can buy any number of units
+    maxUnitsPerWallet?: number
+    // unix timestamps of the sale window, unset = no limit
+    saleStart?: number
+    saleEnd?: number
+    // seconds the payment stays in escrow, unset = the seller is paid at purchase
+    escrowTimeout?: number
+    // seconds of access each unit bought adds, unset = not a subscription
+    subscriptionPeriod?: number
 }
 
 export async function createInitProductTransaction(
@@ -55,6 +65,8 @@ export async function createInitProductTransaction(
         productMint,
         accessMint,
         accessVault: getAssociatedTokenAddressSync(accessMint, accounts.signer, false, TOKEN_2022_PROGRAM_ID),
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
     const args: InitProductInstructionArgs = {
         params: {
@@ -62,6 +74,12 @@ export async function createInitProductTransaction(
             secondId: [...secondId],
             productPrice: normalizePrice(params.productPrice, accounts.paymentMint.toString()),
             productMintBump,
+            maxSupply: params.maxSupply ?? null,
+            maxUnitsPerWallet: params.maxUnitsPerWallet ?? null,
+            saleStart: params.saleStart ?? null,
+            saleEnd: params.saleEnd ?? null,
+            escrowTimeout: params.escrowTimeout ?? null,
+            subscriptionPeriod: params.subscriptionPeriod ?? null,
         }
     };
     const ix = createInitProductInstruction(ixAccounts, args);
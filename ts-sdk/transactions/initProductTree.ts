@@ -1,8 +1,8 @@
 import { InitProductTreeInstructionAccounts, createInitProductTreeInstruction, getConcurrentMerkleTreeAccountSize, splitId } from "../utils";
 import { Connection, Keypair, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
-import { BRICK_PROGRAM_ID_PK, BUBBLEGUM_PROGRAM_ID_PK, COMPRESSION_PROGRAM_ID_PK, METADATA_PROGRAM_ID_PK, NOOP_PROGRAM_ID_PK } from "../constants";
-import { InitProductTreeInstructionArgs } from "../types";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK, BUBBLEGUM_PROGRAM_ID_PK, COMPRESSION_PROGRAM_ID_PK, METADATA_PROGRAM_ID_PK, NOOP_PROGRAM_ID_PK } from "../constants";
+import { InitProductTreeInstructionArgs } from "../utils/solita";
 import { normalizePrice } from "../utils/normalizePrice";
 
 type InitProductTreeAccounts = {
@@ -20,6 +20,17 @@ type InitProductTreeParams = {
     height: number
     buffer: number
     canopy: number
+    // unset = unlimited units
+    maxSupply?: number
+    // unset = a wallet can buy any number of units
+    maxUnitsPerWallet?: number
+    // unix timestamps of the sale window, unset = no limit
+    saleStart?: number
+    saleEnd?: number
+    // seconds the payment stays in escrow, unset = the seller is paid at purchase
+    escrowTimeout?: number
+    // seconds of access each unit bought adds, unset = not a subscription
+    subscriptionPeriod?: number
 }
 
 export async function createInitProductTreeTransaction(
@@ -102,6 +113,8 @@ export async function createInitProductTreeTransaction(
         metadata: metadata,
         merkleTree: merkleTree.publicKey,
         treeAuthority: treeAuthority,
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
     
     const args: InitProductTreeInstructionArgs = {
@@ -114,6 +127,12 @@ export async function createInitProductTreeTransaction(
             name: params.name,
             metadataUrl: params.metadataUrl,
             feeBasisPoints: params.feeBasisPoints,
+            maxSupply: params.maxSupply ?? null,
+            maxUnitsPerWallet: params.maxUnitsPerWallet ?? null,
+            saleStart: params.saleStart ?? null,
+            saleEnd: params.saleEnd ?? null,
+            escrowTimeout: params.escrowTimeout ?? null,
+            subscriptionPeriod: params.subscriptionPeriod ?? null,
         }
     };
     const ix = createInitProductTreeInstruction(ixAccounts, args);
@@ -1,10 +1,11 @@
 import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { InitRewardVaultInstructionAccounts, createInitRewardVaultInstruction } from "../utils/solita"
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 
 type InitRewardVaultAccounts = {
     signer: PublicKey
+    marketplace: PublicKey
     rewardMint: PublicKey
 }
 
@@ -12,18 +13,11 @@ export async function createInitRewardVaultTransaction(
     connection: Connection, 
     accounts: InitRewardVaultAccounts, 
 ): Promise<VersionedTransaction> {
-    const [marketplace] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("marketplace", "utf-8"),
-          accounts.signer.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
     const [reward] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("reward", "utf-8"), 
           accounts.signer.toBuffer(),
-          marketplace.toBuffer()
+          accounts.marketplace.toBuffer()
         ],
         BRICK_PROGRAM_ID_PK
     );
@@ -31,7 +25,7 @@ export async function createInitRewardVaultTransaction(
         [
           Buffer.from("reward_vault", "utf-8"), 
           accounts.signer.toBuffer(),
-          marketplace.toBuffer(),
+          accounts.marketplace.toBuffer(),
           accounts.rewardMint.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
@@ -42,9 +36,10 @@ export async function createInitRewardVaultTransaction(
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgramV0: TOKEN_PROGRAM_ID,
         rent: SYSVAR_RENT_PUBKEY,
-        marketplace,
         reward,
         rewardVault,
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
     const ix = createInitRewardVaultInstruction(ixAccounts);
     let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
@@ -1,7 +1,8 @@
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 import { RegisterBuyInstructionAccounts, createRegisterBuyInstruction } from "../utils/solita"
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { bignum } from "@metaplex-foundation/beet";
 
 type RegisterBuyAccounts = {
     signer: PublicKey
@@ -10,29 +11,91 @@ type RegisterBuyAccounts = {
     seller: PublicKey
     marketplaceAuth: PublicKey
     paymentMint: PublicKey
+    // token program of the payment mint, legacy token program by default
+    paymentTokenProgram?: PublicKey
 }
 
 type RegisterBuyParams = {
-    rewardsActive: boolean
     amount: number
+    // the purchase fails if the total price (fee included when the buyer pays it) is above it
+    maxTotalPrice: bignum
+    // campaign that rewards the purchase, the purchase is not rewarded if it is not set
+    campaign?: PublicKey
+    rewardMint?: PublicKey
 }
 
 export async function createRegisterBuyTransaction(
-    connection: Connection, 
-    accounts: RegisterBuyAccounts, 
+    connection: Connection,
+    accounts: RegisterBuyAccounts,
     params: RegisterBuyParams
 ): Promise<VersionedTransaction> {
+    const paymentTokenProgram = accounts.paymentTokenProgram ?? TOKEN_PROGRAM_ID;
+    const rewarded = !!params.campaign && !!params.rewardMint;
     const [payment] = PublicKey.findProgramAddressSync(
         [
-            Buffer.from("payment", "utf-8"), 
-            accounts.signer.toBuffer(), 
+            Buffer.from("payment", "utf-8"),
+            accounts.signer.toBuffer(),
             accounts.product.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
+    const ixAccounts: RegisterBuyInstructionAccounts = {
+        ...accounts,
+        systemProgram: SystemProgram.programId,
+        tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram,
+        rent: SYSVAR_RENT_PUBKEY,
+        payment,
+        buyerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, paymentTokenProgram) : null,
+        sellerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, paymentTokenProgram) : null,
+        marketplaceTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, paymentTokenProgram) : null,
+        ...(rewarded ? getCampaignRewardAccounts(accounts, params.campaign!, params.rewardMint!) : {}),
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
+    };
+    const ix = createRegisterBuyInstruction(ixAccounts, {
+        amount: params.amount,
+        maxTotalPrice: params.maxTotalPrice,
+        expectedPaymentMint: accounts.paymentMint,
+    });
+    let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
+    const messageV0 = new TransactionMessage({
+        payerKey: accounts.signer,
+        recentBlockhash: blockhash,
+        instructions: [ix],
+    }).compileToV0Message();
+
+    return new VersionedTransaction(messageV0);
+}
+
+type CampaignRewardAccounts = {
+    campaign: PublicKey
+    bountyVault: PublicKey
+    sellerReward: PublicKey
+    sellerRewardVault: PublicKey
+    buyerReward: PublicKey
+    buyerRewardVault: PublicKey
+    sellerCampaignReward: PublicKey
+    buyerCampaignReward: PublicKey
+}
+
+// accounts a purchase needs to be rewarded by a campaign, the vaults hold the campaign reward mint
+export function getCampaignRewardAccounts(
+    accounts: { signer: PublicKey, seller: PublicKey, marketplace: PublicKey },
+    campaign: PublicKey,
+    rewardMint: PublicKey,
+): CampaignRewardAccounts {
+    const [bountyVault] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("bounty_vault", "utf-8"),
+          accounts.marketplace.toBuffer(),
+          rewardMint.toBuffer()
+        ],
+        BRICK_PROGRAM_ID_PK
+    );
     const [sellerReward] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("reward", "utf-8"), 
+          Buffer.from("reward", "utf-8"),
           accounts.seller.toBuffer(),
           accounts.marketplace.toBuffer()
         ],
@@ -40,16 +103,16 @@ export async function createRegisterBuyTransaction(
     );
     const [sellerRewardVault] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("reward_vault", "utf-8"), 
+          Buffer.from("reward_vault", "utf-8"),
           accounts.seller.toBuffer(),
           accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
+          rewardMint.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
     const [buyerReward] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("reward", "utf-8"), 
+          Buffer.from("reward", "utf-8"),
           accounts.signer.toBuffer(),
           accounts.marketplace.toBuffer()
         ],
@@ -57,43 +120,38 @@ export async function createRegisterBuyTransaction(
     );
     const [buyerRewardVault] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
+          Buffer.from("reward_vault", "utf-8"),
+          accounts.signer.toBuffer(),
           accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
+          rewardMint.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
-    const [bountyVault] = PublicKey.findProgramAddressSync(
+    const [sellerCampaignReward] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("bounty_vault", "utf-8"),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer()
+          Buffer.from("campaign_reward", "utf-8"),
+          accounts.seller.toBuffer(),
+          campaign.toBuffer(),
+        ],
+        BRICK_PROGRAM_ID_PK
+    );
+    const [buyerCampaignReward] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("campaign_reward", "utf-8"),
+          accounts.signer.toBuffer(),
+          campaign.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
-    const ixAccounts: RegisterBuyInstructionAccounts = {
-        ...accounts,
-        systemProgram: SystemProgram.programId,
-        tokenProgramV0: TOKEN_PROGRAM_ID,
-        rent: SYSVAR_RENT_PUBKEY,
-        payment,
-        buyerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, TOKEN_PROGRAM_ID) : null,
-        sellerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, TOKEN_PROGRAM_ID) : null,
-        marketplaceTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, TOKEN_PROGRAM_ID) : null,
-        bountyVault: params.rewardsActive ? bountyVault : null,
-        sellerReward: params.rewardsActive ? sellerReward : null,
-        sellerRewardVault: params.rewardsActive ? sellerRewardVault : null,
-        buyerReward: params.rewardsActive ? buyerReward : null,
-        buyerRewardVault: params.rewardsActive ? buyerRewardVault : null,
-    };
-    const ix = createRegisterBuyInstruction(ixAccounts, {amount: params.amount});
-    let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
-    const messageV0 = new TransactionMessage({
-        payerKey: accounts.signer,
-        recentBlockhash: blockhash,
-        instructions: [ix],
-    }).compileToV0Message();
 
-    return new VersionedTransaction(messageV0);
-}
\ No newline at end of file
+    return {
+        campaign,
+        bountyVault,
+        sellerReward,
+        sellerRewardVault,
+        buyerReward,
+        buyerRewardVault,
+        sellerCampaignReward,
+        buyerCampaignReward,
+    };
+}
@@ -1,7 +1,9 @@
-import { BRICK_PROGRAM_ID_PK, BUBBLEGUM_PROGRAM_ID_PK, COMPRESSION_PROGRAM_ID_PK, METADATA_PROGRAM_ID_PK, NOOP_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK, BUBBLEGUM_PROGRAM_ID_PK, COMPRESSION_PROGRAM_ID_PK, METADATA_PROGRAM_ID_PK, NOOP_PROGRAM_ID_PK } from "../constants";
 import { RegisterBuyCnftInstructionAccounts, RegisterBuyCnftInstructionArgs, createRegisterBuyCnftInstruction } from "../utils/solita"
 import { ComputeBudgetProgram, Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { bignum } from "@metaplex-foundation/beet";
+import { getCampaignRewardAccounts } from "./registerBuy";
 
 type RegisterBuyCnftAccounts = {
     signer: PublicKey
@@ -11,11 +13,16 @@ type RegisterBuyCnftAccounts = {
     marketplaceAuth: PublicKey
     paymentMint: PublicKey
     merkleTree: PublicKey
+    // token program of the payment mint, legacy token program by default
+    paymentTokenProgram?: PublicKey
 }
 
 type RegisterBuyCnftParams = {
-    rewardsActive: boolean
-    amount: number
+    // the purchase fails if the total price (fee included when the buyer pays it) is above it
+    maxTotalPrice: bignum
+    // campaign that rewards the purchase, the purchase is not rewarded if it is not set
+    campaign?: PublicKey
+    rewardMint?: PublicKey
     name: string
     uri: string
 }
@@ -25,6 +32,8 @@ export async function createRegisterBuyCnftTransaction(
     accounts: RegisterBuyCnftAccounts, 
     params: RegisterBuyCnftParams
 ): Promise<VersionedTransaction> {
+    const paymentTokenProgram = accounts.paymentTokenProgram ?? TOKEN_PROGRAM_ID;
+    const rewarded = !!params.campaign && !!params.rewardMint;
     const [productMint] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("product_mint", "utf-8"), 
@@ -55,45 +64,11 @@ export async function createRegisterBuyCnftTransaction(
     const [bubblegumSigner] = PublicKey.findProgramAddressSync(
         [Buffer.from("collection_cpi", "utf-8")], BUBBLEGUM_PROGRAM_ID_PK
     );
-    const [sellerReward] = PublicKey.findProgramAddressSync(
+    const [payment] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("reward", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [sellerRewardVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [buyerReward] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward", "utf-8"), 
-          accounts.signer.toBuffer(),
-          accounts.marketplace.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [buyerRewardVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [bountyVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("bounty_vault", "utf-8"),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer()
+            Buffer.from("payment", "utf-8"),
+            accounts.signer.toBuffer(),
+            accounts.product.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
@@ -101,28 +76,31 @@ export async function createRegisterBuyCnftTransaction(
         ...accounts,
         systemProgram: SystemProgram.programId,
         tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram,
         rent: SYSVAR_RENT_PUBKEY,
         logWrapper: NOOP_PROGRAM_ID_PK,
         bubblegumProgram: BUBBLEGUM_PROGRAM_ID_PK,
         compressionProgram: COMPRESSION_PROGRAM_ID_PK,
         tokenMetadataProgram:  METADATA_PROGRAM_ID_PK,
+        payment,
         productMint,
-        buyerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, TOKEN_PROGRAM_ID) : null,
-        sellerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, TOKEN_PROGRAM_ID) : null,
-        marketplaceTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, TOKEN_PROGRAM_ID) : null,
-        bountyVault: params.rewardsActive ? bountyVault : null,
-        sellerReward: params.rewardsActive ? sellerReward : null,
-        sellerRewardVault: params.rewardsActive ? sellerRewardVault : null,
-        buyerReward: params.rewardsActive ? buyerReward : null,
-        buyerRewardVault: params.rewardsActive ? buyerRewardVault : null,
+        buyerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, paymentTokenProgram) : null,
+        sellerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, paymentTokenProgram) : null,
+        marketplaceTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, paymentTokenProgram) : null,
+        ...(rewarded ? getCampaignRewardAccounts(accounts, params.campaign!, params.rewardMint!) : {}),
         metadata: metadata,
         masterEdition: masterEdition,
         treeAuthority: treeAuthority,
         bubblegumSigner: bubblegumSigner,
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
     const args: RegisterBuyCnftInstructionArgs = {
         params: {
-            amount: params.amount,
+            // every unit gets its own receipt, a transaction buys one
+            amount: 1,
+            maxTotalPrice: params.maxTotalPrice,
+            expectedPaymentMint: accounts.paymentMint,
             name: params.name,
             symbol: "BRICK",
             uri: params.uri,
@@ -1,7 +1,9 @@
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 import { RegisterBuyCounterInstructionAccounts, createRegisterBuyCounterInstruction } from "../utils/solita"
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { NATIVE_MINT, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { bignum } from "@metaplex-foundation/beet";
+import { getCampaignRewardAccounts } from "./registerBuy";
 
 type RegisterBuyCounterAccounts = {
     signer: PublicKey
@@ -10,84 +12,53 @@ type RegisterBuyCounterAccounts = {
     seller: PublicKey
     marketplaceAuth: PublicKey
     paymentMint: PublicKey
+    // token program of the payment mint, legacy token program by default
+    paymentTokenProgram?: PublicKey
 }
 
 type RegisterBuyCounterParams = {
-    rewardsActive: boolean
     amount: number
+    // the purchase fails if the total price (fee included when the buyer pays it) is above it
+    maxTotalPrice: bignum
+    // campaign that rewards the purchase, the purchase is not rewarded if it is not set
+    campaign?: PublicKey
+    rewardMint?: PublicKey
 }
 
 export async function createRegisterBuyCounterTransaction(
-    connection: Connection, 
-    accounts: RegisterBuyCounterAccounts, 
+    connection: Connection,
+    accounts: RegisterBuyCounterAccounts,
     params: RegisterBuyCounterParams
 ): Promise<VersionedTransaction> {
+    const paymentTokenProgram = accounts.paymentTokenProgram ?? TOKEN_PROGRAM_ID;
+    const rewarded = !!params.campaign && !!params.rewardMint;
     const [payment] = PublicKey.findProgramAddressSync(
         [
-            Buffer.from("payment", "utf-8"), 
-            accounts.signer.toBuffer(), 
+            Buffer.from("payment", "utf-8"),
+            accounts.signer.toBuffer(),
             accounts.product.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
-    const [sellerReward] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [sellerRewardVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [buyerReward] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward", "utf-8"), 
-          accounts.signer.toBuffer(),
-          accounts.marketplace.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [buyerRewardVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [bountyVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("bounty_vault", "utf-8"),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
     const ixAccounts: RegisterBuyCounterInstructionAccounts = {
         ...accounts,
         systemProgram: SystemProgram.programId,
         tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram,
         rent: SYSVAR_RENT_PUBKEY,
         payment,
-        buyerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, TOKEN_PROGRAM_ID) : null,
-        sellerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, TOKEN_PROGRAM_ID) : null,
-        marketplaceTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, TOKEN_PROGRAM_ID) : null,
-        bountyVault: params.rewardsActive ? bountyVault : null,
-        sellerReward: params.rewardsActive ? sellerReward : null,
-        sellerRewardVault: params.rewardsActive ? sellerRewardVault : null,
-        buyerReward: params.rewardsActive ? buyerReward : null,
-        buyerRewardVault: params.rewardsActive ? buyerRewardVault : null,
+        buyerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, paymentTokenProgram) : null,
+        sellerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, paymentTokenProgram) : null,
+        marketplaceTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, paymentTokenProgram) : null,
+        ...(rewarded ? getCampaignRewardAccounts(accounts, params.campaign!, params.rewardMint!) : {}),
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
-    const ix = createRegisterBuyCounterInstruction(ixAccounts, {amount: params.amount});
+    const ix = createRegisterBuyCounterInstruction(ixAccounts, {
+        amount: params.amount,
+        maxTotalPrice: params.maxTotalPrice,
+        expectedPaymentMint: accounts.paymentMint,
+    });
     let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
     const messageV0 = new TransactionMessage({
         payerKey: accounts.signer,
@@ -96,4 +67,4 @@ export async function createRegisterBuyCounterTransaction(
     }).compileToV0Message();
 
     return new VersionedTransaction(messageV0);
-}
\ No newline at end of file
+}
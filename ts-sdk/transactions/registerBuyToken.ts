@@ -1,7 +1,9 @@
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 import { RegisterBuyTokenInstructionAccounts, createRegisterBuyTokenInstruction } from "../utils/solita"
 import { Connection, PublicKey, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { NATIVE_MINT, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { bignum } from "@metaplex-foundation/beet";
+import { getCampaignRewardAccounts } from "./registerBuy";
 
 type RegisterBuyTokenAccounts = {
     signer: PublicKey
@@ -10,65 +12,39 @@ type RegisterBuyTokenAccounts = {
     seller: PublicKey
     marketplaceAuth: PublicKey
     paymentMint: PublicKey
+    // token program of the payment mint, legacy token program by default
+    paymentTokenProgram?: PublicKey
 }
 
 type RegisterBuyTokenParams = {
-    rewardsActive: boolean
     transferable: boolean
     amount: number
+    // the purchase fails if the total price (fee included when the buyer pays it) is above it
+    maxTotalPrice: bignum
+    // campaign that rewards the purchase, the purchase is not rewarded if it is not set
+    campaign?: PublicKey
+    rewardMint?: PublicKey
 }
 
-export async function createRegisterBuyCounterTransaction(
-    connection: Connection, 
-    accounts: RegisterBuyTokenAccounts, 
+export async function createRegisterBuyTokenTransaction(
+    connection: Connection,
+    accounts: RegisterBuyTokenAccounts,
     params: RegisterBuyTokenParams
 ): Promise<VersionedTransaction> {
+    const paymentTokenProgram = accounts.paymentTokenProgram ?? TOKEN_PROGRAM_ID;
+    const rewarded = !!params.campaign && !!params.rewardMint;
     const [productMint] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("product_mint", "utf-8"), 
+          Buffer.from("product_mint", "utf-8"),
           accounts.product.toBuffer()
         ],
         BRICK_PROGRAM_ID_PK
     );
-    const [sellerReward] = PublicKey.findProgramAddressSync(
+    const [payment] = PublicKey.findProgramAddressSync(
         [
-          Buffer.from("reward", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [sellerRewardVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [buyerReward] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward", "utf-8"), 
-          accounts.signer.toBuffer(),
-          accounts.marketplace.toBuffer()
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [buyerRewardVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("reward_vault", "utf-8"), 
-          accounts.seller.toBuffer(),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer(),
-        ],
-        BRICK_PROGRAM_ID_PK
-    );
-    const [bountyVault] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("bounty_vault", "utf-8"),
-          accounts.marketplace.toBuffer(),
-          accounts.paymentMint.toBuffer()
+            Buffer.from("payment", "utf-8"),
+            accounts.signer.toBuffer(),
+            accounts.product.toBuffer(),
         ],
         BRICK_PROGRAM_ID_PK
     );
@@ -76,19 +52,23 @@ export async function createRegisterBuyCounterTransaction(
         ...accounts,
         systemProgram: SystemProgram.programId,
         tokenProgramV0: TOKEN_PROGRAM_ID,
+        paymentTokenProgram,
         tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+        payment,
         productMint,
         buyerTokenVault: params.transferable ? getAssociatedTokenAddressSync(productMint, accounts.signer, false, TOKEN_PROGRAM_ID) : getAssociatedTokenAddressSync(productMint, accounts.signer, false, TOKEN_2022_PROGRAM_ID),
-        buyerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, TOKEN_PROGRAM_ID) : null,
-        sellerTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, TOKEN_PROGRAM_ID) : null,
-        marketplaceTransferVault: accounts.paymentMint !== NATIVE_MINT ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, TOKEN_PROGRAM_ID) : null,
-        bountyVault: params.rewardsActive ? bountyVault : null,
-        sellerReward: params.rewardsActive ? sellerReward : null,
-        sellerRewardVault: params.rewardsActive ? sellerRewardVault : null,
-        buyerReward: params.rewardsActive ? buyerReward : null,
-        buyerRewardVault: params.rewardsActive ? buyerRewardVault : null,
+        buyerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.signer, false, paymentTokenProgram) : null,
+        sellerTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.seller, false, paymentTokenProgram) : null,
+        marketplaceTransferVault: !accounts.paymentMint.equals(NATIVE_MINT) ? getAssociatedTokenAddressSync(accounts.paymentMint, accounts.marketplaceAuth, false, paymentTokenProgram) : null,
+        ...(rewarded ? getCampaignRewardAccounts(accounts, params.campaign!, params.rewardMint!) : {}),
+        eventAuthority: EVENT_AUTHORITY_PK,
+        program: BRICK_PROGRAM_ID_PK,
     };
-    const ix = createRegisterBuyTokenInstruction(ixAccounts, {amount: params.amount});
+    const ix = createRegisterBuyTokenInstruction(ixAccounts, {
+        amount: params.amount,
+        maxTotalPrice: params.maxTotalPrice,
+        expectedPaymentMint: accounts.paymentMint,
+    });
     let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
     const messageV0 = new TransactionMessage({
         payerKey: accounts.signer,
@@ -97,4 +77,4 @@ export async function createRegisterBuyCounterTransaction(
     }).compileToV0Message();
 
     return new VersionedTransaction(messageV0);
-}
\ No newline at end of file
+}
@@ -1,6 +1,6 @@
 import { Connection, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, TransactionMessage, VersionedTransaction } from "@solana/web3.js";
 import { RequestAccessInstructionAccounts, createRequestAccessInstruction } from "../utils/solita";
-import { BRICK_PROGRAM_ID_PK } from "../constants";
+import { BRICK_PROGRAM_ID_PK, EVENT_AUTHORITY_PK } from "../constants";
 
 type RequestAccessAccounts = {
   signer: PublicKey
@@ -24,6 +24,8 @@ export async function createRequestAccessTransaction(
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
       request,
+      eventAuthority: EVENT_AUTHORITY_PK,
+      program: BRICK_PROGRAM_ID_PK,
   };
   const ix = createRequestAccessInstruction(ixAccounts);
   let blockhash = (await connection.getLatestBlockhash('finalized')).blockhash;
@@ -1,21 +1,22 @@
 import BN from 'bn.js'
 
 export type Access = {
+  version: number
   authority: string
   marketplace: string
   bump: number
 }
 
 export type TokenConfig = {
-    useCnfts: boolean
-    deliverToken: boolean
-    transferable: boolean
-    chainCounter: boolean
+  useCnfts: boolean
+  deliverToken: boolean
+  transferable: boolean
+  chainCounter: boolean
 }
 
 export type PermissionConfig = {
-    accessMint: string
-    permissionless: boolean
+  accessMint: string
+  permissionless: boolean
 }
 
 export enum PaymentFeePayer {
@@ -24,46 +25,53 @@ export enum PaymentFeePayer {
 }
 
 export type FeesConfig = {
-    discountMint: string
-    fee: number
-    feeReduction: number
-    feePayer: PaymentFeePayer
+  discountMint: string
+  fee: number
+  feeReduction: number
+  feePayer: PaymentFeePayer
 }
 
 export type RewardsConfig = {
-    rewardMint: string
-    bountyVaults: string
-    sellerReward: number
-    buyerReward: number
-    rewardsEnabled: boolean
+  rewardMint: string
 }
 
 export type MarketplaceBumps = {
-    bump: number
-    vaultBumps: Uint8Array
-    accessMintBump: number
+  bump: number
+  accessMintBump: number
 }
 
 export type Marketplace = {
+  version: number
   authority: string
+  creator: string
+  pendingAuthority: string
+  id: BN
   tokenConfig: TokenConfig
   permissionConfig: PermissionConfig
   feesConfig: FeesConfig
   rewardsConfig: RewardsConfig
   bumps: MarketplaceBumps
+  openProducts: number
+  pendingRewards: BN
+  paused: boolean
+  campaignsEndTs: BN
+  arbiter: string
+  vestingPeriod: BN
+  bountyVaults: number
 }
 
 export type SellerConfig = {
-    paymentMint: string;
-    productPrice: BN;
+  paymentMint: string
+  productPrice: BN
 }
 
 export type ProductBumps = {
-    paymentMint: string;
-    productPrice: string;
+  bump: number
+  mintBump: number
 }
 
 export type Product = {
+  version: number
   authority: string
   firstId: number[]
   secondId: number[]
@@ -72,23 +80,51 @@ export type Product = {
   merkleTree: string
   sellerConfig: SellerConfig
   bumps: ProductBumps
+  maxSupply: BN | null
+  sold: BN
+  maxUnitsPerWallet: number | null
+  saleStart: BN | null
+  saleEnd: BN | null
+  escrowTimeout: BN | null
+  subscriptionPeriod: BN | null
+  receipts: BN
+  openEscrows: number
+  outstandingUnits: BN
+  subscriptionsEndTs: BN
 }
 
 export type RewardBumps = {
-    bump: number
-    vaultBumps: Uint8Array
+  bump: number
 }
 
 export type Reward = {
+  version: number
   authority: string
   marketplace: string
-  rewardVaults: string[]
   bumps: RewardBumps
+  lockedUntil: BN
+  createdTs: BN
+  pendingRewards: BN
 }
 
 export type Payment = {
+  version: number
   units: number
   bump: number
+  expiresAt: BN
+  paid: BN
+  campaign: string
+  sellerBonus: BN
+  buyerBonus: BN
+  subscriptionGranted: BN
+}
+
+export type Ruling = {
+  arbiter: string
+  buyerAmount: BN
+  sellerAmount: BN
+  fee: BN
+  settledTs: BN
 }
 
 export type ParsedAccountsData =
@@ -114,400 +150,349 @@ export type BrickAccountInfo = {
     data: ParsedAccountsData
 } 
 
-export enum InstructionType {
-  AcceptAccess = 'AcceptAccess',
-  AirdropAccess = 'AirdropAccess',
-  EditProduct = 'EditProduct',
-  EditMarketplace = 'EditMarketplace',
-  InitBounty = 'InitBounty',
-  InitMarketplace = 'InitMarketplace',
-  InitProductTree = 'InitProductTree',
-  InitProduct = 'InitProduct',
-  InitRewardVault = 'InitRewardVault',
-  InitReward = 'InitReward',
-  RegisterBuyCnft = 'RegisterBuyCnft',
-  RegisterBuyCounter = 'RegisterBuyCounter',
-  RegisterBuyToken = 'RegisterBuyToken',
-  RegisterBuy = 'RegisterBuy',
-  RequestAccess = 'RequestAccess',
-  UpdateTree = 'UpdateTree',
-  WithdrawReward = 'WithdrawReward',
+export enum EventType {
+  MarketplaceInitialized = 'MarketplaceInitialized',
+  MarketplaceEdited = 'MarketplaceEdited',
+  MarketplaceAuthorityProposed = 'MarketplaceAuthorityProposed',
+  MarketplaceAuthorityAccepted = 'MarketplaceAuthorityAccepted',
+  MarketplacePauseSet = 'MarketplacePauseSet',
+  MarketplaceClosed = 'MarketplaceClosed',
+  BountyVaultInitialized = 'BountyVaultInitialized',
+  BountyWithdrawn = 'BountyWithdrawn',
+  BountyVaultClosed = 'BountyVaultClosed',
+  AccessRequested = 'AccessRequested',
+  AccessGranted = 'AccessGranted',
+  ProductInitialized = 'ProductInitialized',
+  ProductEdited = 'ProductEdited',
+  ProductClosed = 'ProductClosed',
+  TreeUpdated = 'TreeUpdated',
+  ProductPurchased = 'ProductPurchased',
+  EscrowCreated = 'EscrowCreated',
+  EscrowReleased = 'EscrowReleased',
+  PurchaseRefunded = 'PurchaseRefunded',
+  ProductRedeemed = 'ProductRedeemed',
+  DisputeOpened = 'DisputeOpened',
+  DisputeSettled = 'DisputeSettled',
+  CampaignInitialized = 'CampaignInitialized',
+  CampaignRewardInitialized = 'CampaignRewardInitialized',
+  RewardClosed = 'RewardClosed',
+  RewardInitialized = 'RewardInitialized',
+  RewardVaultInitialized = 'RewardVaultInitialized',
+  RewardWithdrawn = 'RewardWithdrawn',
+  AccountMigrated = 'AccountMigrated',
 }
 
 // These *Info types are used to fetch events from the indexer api
-export type AcceptAccessInfo = {
-  systemProgram: string
-  tokenProgram2022: string
-  associatedTokenProgram: string
-  rent: string
-  signer: string
-  receiver: string
+export type MarketplaceInitializedInfo = {
+  eventAuthority: string
   marketplace: string
-  request: string
-  accessMint: string
-  accessVault: string
+  authority: string
+  id: BN
+  tokenConfig: TokenConfig
+  permissionConfig: PermissionConfig
+  feesConfig: FeesConfig
+  rewardsConfig: RewardsConfig
+  arbiter: string
+  vestingPeriod: BN
 }
 
-export type AirdropAccessInfo = {
-  systemProgram: string
-  tokenProgram2022: string
-  associatedTokenProgram: string
-  rent: string
-  signer: string
-  receiver: string
+export type MarketplaceEditedInfo = {
+  eventAuthority: string
   marketplace: string
-  accessMint: string
-  accessVault: string
+  tokenConfig: TokenConfig
+  permissionConfig: PermissionConfig
+  feesConfig: FeesConfig
+  rewardsConfig: RewardsConfig
+  arbiter: string
+  vestingPeriod: BN
 }
 
-export type EditProductAccountsInstruction = {
-  signer: string
-  product: string
-  paymentMint: string
+export type MarketplaceAuthorityProposedInfo = {
+  eventAuthority: string
+  marketplace: string
+  authority: string
+  pendingAuthority: string
 }
 
-export type EditProductInstructionArgs = {
-    productPrice: BN
+export type MarketplaceAuthorityAcceptedInfo = {
+  eventAuthority: string
+  marketplace: string
+  previousAuthority: string
+  authority: string
 }
 
-export type EditProductInfo = EditProductInstructionArgs &
-  EditProductAccountsInstruction
+export type MarketplacePauseSetInfo = {
+  eventAuthority: string
+  marketplace: string
+  paused: boolean
+}
 
-export type EditMarketplaceAccountsInstruction = {
-  signer: string
+export type MarketplaceClosedInfo = {
+  eventAuthority: string
   marketplace: string
-  rewardMint: string
-  discountMint: string
+  authority: string
 }
 
-export type EditMarketplaceInstructionArgs = {
-  params: {
-    fee: number
-    feeReduction: number
-    sellerReward: number
-    buyerReward: number
-    useCnfts: boolean
-    deliverToken: boolean
-    transferable: boolean
-    chainCounter: boolean
-    permissionless: boolean
-    rewardsEnabled: boolean
-    feePayer: PaymentFeePayer
-  }
-}
-
-export type EditMarketplaceInfo = EditMarketplaceInstructionArgs &
-  EditMarketplaceAccountsInstruction
-
-export type InitBountyInfo = {
-  systemProgram: string
-  tokenProgramV0: string
-  associatedTokenProgram: string
-  rent: string
-  signer: string
+export type BountyVaultInitializedInfo = {
+  eventAuthority: string
   marketplace: string
-  rewardMint: string
   bountyVault: string
+  rewardMint: string
 }
 
-export type InitMarketplaceAccountsInstruction = {
-  systemProgram: string
-  tokenProgram2022: string
-  tokenProgramV0: string
-  rent: string
-  signer: string
+export type BountyWithdrawnInfo = {
+  eventAuthority: string
   marketplace: string
-  accessMint: string
-  rewardMint: string
-  discountMint: string
   bountyVault: string
+  rewardMint: string
+  receiverVault: string
+  amount: BN
 }
 
-export type InitMarketplaceInstructionArgs = {
-  params: {
-    fee: number
-    feeReduction: number
-    sellerReward: number
-    buyerReward: number
-    useCnfts: boolean
-    deliverToken: boolean
-    transferable: boolean
-    chainCounter: boolean
-    permissionless: boolean
-    rewardsEnabled: boolean
-    accessMintBump: number
-    feePayer: PaymentFeePayer
-  }
-}
-
-export type InitMarketplaceInfo = InitMarketplaceInstructionArgs &
-  InitMarketplaceAccountsInstruction
-
-export type InitProductTreeAccountsInstruction = {
-  tokenMetadataProgram: string
-  logWrapper: string
-  systemProgram: string
-  bubblegumProgram: string
-  compressionProgram: string
-  tokenProgramV0: string
-  associatedTokenProgram: string
-  rent: string
-  signer: string
+export type BountyVaultClosedInfo = {
+  eventAuthority: string
   marketplace: string
-  product: string
-  productMint: string
-  paymentMint: string
-  accessMint: string
-  productMintVault: string
-  accessVault: string
-  masterEdition: string
-  metadata: string
-  merkleTree: string
-  treeAuthority: string
+  bountyVault: string
+  rewardMint: string
 }
 
-export type InitProductTreeInstructionArgs = {
-  params: {
-      firstId: number[] /* size: 32 */
-      secondId: number[] /* size: 32 */
-      productPrice: BN
-      maxDepth: number
-      maxBufferSize: number
-      name: string
-      metadataUrl: string
-      feeBasisPoints: number
-  }
+export type AccessRequestedInfo = {
+  eventAuthority: string
+  marketplace: string
+  user: string
 }
 
-export type InitProductTreeInfo = InitProductTreeInstructionArgs &
-  InitProductTreeAccountsInstruction
+export type AccessGrantedInfo = {
+  eventAuthority: string
+  marketplace: string
+  user: string
+  accessMint: string
+}
 
-export type InitProductAccountsInstruction = {
-  systemProgram: string
-  tokenProgram2022: string
-  rent: string
-  signer: string
+export type ProductInitializedInfo = {
+  eventAuthority: string
   marketplace: string
   product: string
+  seller: string
   productMint: string
+  merkleTree: string
+  firstId: number[]
+  secondId: number[]
   paymentMint: string
-  accessMint: string
-  accessVault: string
+  productPrice: BN
+  maxSupply: BN | null
+  maxUnitsPerWallet: number | null
+  saleStart: BN | null
+  saleEnd: BN | null
+  escrowTimeout: BN | null
+  subscriptionPeriod: BN | null
 }
 
-export type InitProductInstructionArgs = {
-    params: {
-        firstId: number[] /* size: 32 */
-        secondId: number[] /* size: 32 */
-        productPrice: BN
-        productMintBump: number
-    }
+export type ProductEditedInfo = {
+  eventAuthority: string
+  product: string
+  paymentMint: string
+  productPrice: BN
+  maxSupply: BN | null
+  maxUnitsPerWallet: number | null
+  saleStart: BN | null
+  saleEnd: BN | null
+  escrowTimeout: BN | null
+  subscriptionPeriod: BN | null
 }
 
-export type InitProductInfo = InitProductInstructionArgs &
-  InitProductAccountsInstruction
-
-export type InitRewardVaultInfo = {
-  systemProgram: string
-  tokenProgramV0: string
-  associatedTokenProgram: string
-  rent: string
-  signer: string
+export type ProductClosedInfo = {
+  eventAuthority: string
   marketplace: string
-  reward: string
-  rewardMint: string
-  rewardVault: string
+  product: string
+  seller: string
 }
 
-export type InitRewardInfo = {
-  systemProgram: string
-  tokenProgramV0: string
-  rent: string
-  signer: string
-  marketplace: string
-  reward: string
-  rewardMint: string
-  rewardVault: string
+export type TreeUpdatedInfo = {
+  eventAuthority: string
+  product: string
+  merkleTree: string
+  maxDepth: number
+  maxBufferSize: number
 }
 
-export type RegisterBuyCnftAccountsInstruction = {
-  systemProgram: string
-  tokenProgramV0: string
-  rent: string
-  logWrapper: string
-  bubblegumProgram: string
-  compressionProgram: string
-  tokenMetadataProgram: string
-  signer: string
-  seller: string
-  marketplaceAuth: string
+export type ProductPurchasedInfo = {
+  eventAuthority: string
   marketplace: string
   product: string
+  buyer: string
+  seller: string
   paymentMint: string
-  productMint: string
-  buyerTransferVault: string
-  sellerTransferVault: string
-  marketplaceTransferVault: string
-  bountyVault: string
-  sellerReward: string
-  sellerRewardVault: string
-  buyerReward: string
-  buyerRewardVault: string
-  metadata: string
-  masterEdition: string
-  treeAuthority: string
-  bubblegumSigner: string
-  merkleTree: string
-}
-
-export type RegisterBuyCnftInstructionArgs = {
-  params: {
-    amount: number
-    name: string
-    symbol: string
-    uri: string
-  }
+  units: number
+  grossAmount: BN
+  fee: BN
+  sellerAmount: BN
+  campaign: string
+  sellerReward: BN
+  buyerReward: BN
 }
 
-export type RegisterBuyCnftInfo = RegisterBuyCnftInstructionArgs &
-  RegisterBuyCnftAccountsInstruction
-
-export type RegisterBuyCounterAccountsInstruction = {
-  systemProgram: string
-  tokenProgramV0: string
-  rent: string
-  signer: string
-  seller: string
-  marketplaceAuth: string
+export type EscrowCreatedInfo = {
+  eventAuthority: string
   marketplace: string
   product: string
-  payment: string
+  escrow: string
+  buyer: string
+  seller: string
   paymentMint: string
-  buyerTransferVault: string
-  sellerTransferVault: string
-  marketplaceTransferVault: string
-  bountyVault: string
-  sellerReward: string
-  sellerRewardVault: string
-  buyerReward: string
-  buyerRewardVault: string
+  units: number
+  grossAmount: BN
+  escrowedAmount: BN
+  releaseTs: BN
 }
 
-export type RegisterBuyInstructionArgs = {
-  amount: number
+export type EscrowReleasedInfo = {
+  eventAuthority: string
+  marketplace: string
+  product: string
+  escrow: string
+  buyer: string
+  seller: string
+  releasedBy: string
+  fee: BN
+  sellerAmount: BN
+  confirmed: boolean
 }
 
-export type RegisterBuyCounterInfo = RegisterBuyInstructionArgs &
-  RegisterBuyCounterAccountsInstruction
-
-export type RegisterBuyTokenAccountsInstruction = {
-  systemProgram: string
-  tokenProgramV0: string
-  tokenProgram2022: string
-  signer: string
-  seller: string
-  marketplaceAuth: string
+export type PurchaseRefundedInfo = {
+  eventAuthority: string
   marketplace: string
   product: string
-  productMint: string
+  buyer: string
+  seller: string
   paymentMint: string
-  buyerTokenVault: string
-  buyerTransferVault: string
-  sellerTransferVault: string
-  marketplaceTransferVault: string
-  bountyVault: string
-  sellerReward: string
-  sellerRewardVault: string
-  buyerReward: string
-  buyerRewardVault: string
+  units: number
+  amount: BN
+  sellerClawback: BN
+  buyerClawback: BN
 }
 
-export type RegisterBuyTokenInfo = RegisterBuyInstructionArgs &
-  RegisterBuyTokenAccountsInstruction
-
-export type RegisterBuyAccountsInstruction = {
-  systemProgram: string
-  tokenProgramV0: string
-  rent: string
-  signer: string
+export type ProductRedeemedInfo = {
+  eventAuthority: string
+  marketplace: string
+  product: string
+  buyer: string
   seller: string
-  marketplaceAuth: string
+  units: number
+}
+
+export type DisputeOpenedInfo = {
+  eventAuthority: string
   marketplace: string
   product: string
-  payment: string
-  paymentMint: string
-  buyerTransferVault: string
-  sellerTransferVault: string
-  marketplaceTransferVault: string
-  bountyVault: string
-  sellerReward: string
-  sellerRewardVault: string
-  buyerReward: string
-  buyerRewardVault: string
+  escrow: string
+  buyer: string
+  seller: string
 }
 
-export type RegisterBuyInfo = RegisterBuyInstructionArgs&
-  RegisterBuyAccountsInstruction
+export type DisputeSettledInfo = {
+  eventAuthority: string
+  marketplace: string
+  product: string
+  escrow: string
+  buyer: string
+  seller: string
+  ruling: Ruling
+}
 
-export type RequestAccessInfo = {
-  systemProgram: string
-  rent: string
-  signer: string
+export type CampaignInitializedInfo = {
+  eventAuthority: string
   marketplace: string
-  request: string
+  campaign: string
+  id: BN
+  rewardMint: string
+  paymentMint: string | null
+  startTs: BN
+  endTs: BN
+  budget: BN
+  sellerReward: number
+  buyerReward: number
+  maxRewardPerPurchase: BN | null
+  maxRewardPerWallet: BN | null
+  vestingPeriod: BN
+}
+
+export type CampaignRewardInitializedInfo = {
+  eventAuthority: string
+  campaign: string
+  campaignReward: string
+  authority: string
 }
 
-export type UpdateTreeAccountsInstruction = {
-  payer: string
-  signer: string
+export type RewardClosedInfo = {
+  eventAuthority: string
   marketplace: string
-  product: string
-  treeAuthority: string
-  merkleTree: string
-  logWrapper: string
-  systemProgram: string
-  bubblegumProgram: string
-  compressionProgram: string
+  reward: string
+  authority: string
+  swept: BN
 }
 
-export type UpdateTreeInstructionArgs = {
-  params: {
-    maxDepth: number
-    maxBufferSize: number
-  }
+export type RewardInitializedInfo = {
+  eventAuthority: string
+  marketplace: string
+  reward: string
+  authority: string
+  rewardVault: string
 }
 
-export type UpdateTreeInfo = UpdateTreeInstructionArgs &
-  UpdateTreeAccountsInstruction
+export type RewardVaultInitializedInfo = {
+  eventAuthority: string
+  reward: string
+  rewardVault: string
+  rewardMint: string
+}
 
-export type WithdrawRewardInfo = {
-  tokenProgramV0: string
-  signer: string
+export type RewardWithdrawnInfo = {
+  eventAuthority: string
   marketplace: string
+  campaign: string
   reward: string
+  authority: string
   rewardMint: string
-  receiverVault: string
-  rewardVault: string
+  amount: BN
+}
+
+export type AccountMigratedInfo = {
+  eventAuthority: string
+  account: string
+  version: number
 }
 
 export type EventInfo =
-  | AcceptAccessInfo
-  | AirdropAccessInfo
-  | EditProductInfo
-  | EditMarketplaceInfo
-  | InitBountyInfo
-  | InitMarketplaceInfo
-  | InitProductTreeInfo
-  | InitProductInfo
-  | InitRewardVaultInfo
-  | InitRewardInfo
-  | RegisterBuyCnftInfo
-  | RegisterBuyCounterInfo
-  | RegisterBuyTokenInfo
-  | RegisterBuyInfo
-  | RequestAccessInfo
-  | UpdateTreeInfo
-  | WithdrawRewardInfo
+  | MarketplaceInitializedInfo
+  | MarketplaceEditedInfo
+  | MarketplaceAuthorityProposedInfo
+  | MarketplaceAuthorityAcceptedInfo
+  | MarketplacePauseSetInfo
+  | MarketplaceClosedInfo
+  | BountyVaultInitializedInfo
+  | BountyWithdrawnInfo
+  | BountyVaultClosedInfo
+  | AccessRequestedInfo
+  | AccessGrantedInfo
+  | ProductInitializedInfo
+  | ProductEditedInfo
+  | ProductClosedInfo
+  | TreeUpdatedInfo
+  | ProductPurchasedInfo
+  | EscrowCreatedInfo
+  | EscrowReleasedInfo
+  | PurchaseRefundedInfo
+  | ProductRedeemedInfo
+  | DisputeOpenedInfo
+  | DisputeSettledInfo
+  | CampaignInitializedInfo
+  | CampaignRewardInitializedInfo
+  | RewardClosedInfo
+  | RewardInitializedInfo
+  | RewardVaultInitializedInfo
+  | RewardWithdrawnInfo
+  | AccountMigratedInfo
 
 type EventBase<EventType> = {
     id: string;
@@ -516,124 +501,207 @@ type EventBase<EventType> = {
     account: string;
 }
 
-export type AcceptAccessEvent = EventBase<InstructionType> & {
-  info: AcceptAccessInfo
+export type MarketplaceInitializedEvent = EventBase<EventType> & {
+  info: MarketplaceInitializedInfo
   signer: string
   account: string
 }
 
-export type AirdropAccessEvent = EventBase<InstructionType> & {
-  info: AirdropAccessInfo
+export type MarketplaceEditedEvent = EventBase<EventType> & {
+  info: MarketplaceEditedInfo
   signer: string
   account: string
 }
 
-export type EditProductEvent = EventBase<InstructionType> & {
-  info: EditProductInfo
+export type MarketplaceAuthorityProposedEvent = EventBase<EventType> & {
+  info: MarketplaceAuthorityProposedInfo
   signer: string
   account: string
 }
 
-export type EditMarketplaceEvent = EventBase<InstructionType> & {
-  info: EditMarketplaceInfo
+export type MarketplaceAuthorityAcceptedEvent = EventBase<EventType> & {
+  info: MarketplaceAuthorityAcceptedInfo
   signer: string
   account: string
 }
 
-export type InitBountyEvent = EventBase<InstructionType> & {
-  info: InitBountyInfo
+export type MarketplacePauseSetEvent = EventBase<EventType> & {
+  info: MarketplacePauseSetInfo
   signer: string
   account: string
 }
 
-export type InitMarketplaceEvent = EventBase<InstructionType> & {
-  info: InitMarketplaceInfo
+export type MarketplaceClosedEvent = EventBase<EventType> & {
+  info: MarketplaceClosedInfo
   signer: string
   account: string
 }
 
-export type InitProductTreeEvent = EventBase<InstructionType> & {
-  info: InitProductTreeInfo
+export type BountyVaultInitializedEvent = EventBase<EventType> & {
+  info: BountyVaultInitializedInfo
   signer: string
   account: string
 }
 
-export type InitProductEvent = EventBase<InstructionType> & {
-  info: InitProductInfo
+export type BountyWithdrawnEvent = EventBase<EventType> & {
+  info: BountyWithdrawnInfo
   signer: string
   account: string
 }
 
-export type InitRewardVaultEvent = EventBase<InstructionType> & {
-  info: InitRewardVaultInfo
+export type BountyVaultClosedEvent = EventBase<EventType> & {
+  info: BountyVaultClosedInfo
   signer: string
   account: string
 }
 
-export type InitRewardEvent = EventBase<InstructionType> & {
-  info: InitRewardInfo
+export type AccessRequestedEvent = EventBase<EventType> & {
+  info: AccessRequestedInfo
   signer: string
   account: string
 }
 
-export type RegisterBuyCnftEvent = EventBase<InstructionType> & {
-  info: RegisterBuyCnftInfo
+export type AccessGrantedEvent = EventBase<EventType> & {
+  info: AccessGrantedInfo
   signer: string
   account: string
 }
 
-export type RegisterBuyCounterEvent = EventBase<InstructionType> & {
-  info: RegisterBuyCounterInfo
+export type ProductInitializedEvent = EventBase<EventType> & {
+  info: ProductInitializedInfo
   signer: string
   account: string
 }
 
-export type RegisterBuyTokenEvent = EventBase<InstructionType> & {
-  info: RegisterBuyTokenInfo
+export type ProductEditedEvent = EventBase<EventType> & {
+  info: ProductEditedInfo
   signer: string
   account: string
 }
 
-export type RegisterBuyEvent = EventBase<InstructionType> & {
-  info: RegisterBuyInfo
+export type ProductClosedEvent = EventBase<EventType> & {
+  info: ProductClosedInfo
   signer: string
   account: string
 }
 
-export type RequestAccessEvent = EventBase<InstructionType> & {
-  info: RequestAccessInfo
+export type TreeUpdatedEvent = EventBase<EventType> & {
+  info: TreeUpdatedInfo
   signer: string
   account: string
 }
 
-export type UpdateTreeEvent = EventBase<InstructionType> & {
-  info: UpdateTreeInfo
+export type ProductPurchasedEvent = EventBase<EventType> & {
+  info: ProductPurchasedInfo
   signer: string
   account: string
 }
 
-export type WithdrawRewardEvent = EventBase<InstructionType> & {
-  info: WithdrawRewardInfo
+export type EscrowCreatedEvent = EventBase<EventType> & {
+  info: EscrowCreatedInfo
   signer: string
   account: string
 }
 
-export type BrickEvent =
-  | AcceptAccessEvent
-  | AirdropAccessEvent
-  | EditProductEvent
-  | EditMarketplaceEvent
-  | InitBountyEvent
-  | InitMarketplaceEvent
-  | InitProductTreeEvent
-  | InitProductEvent
-  | InitRewardVaultEvent
-  | InitRewardEvent
-  | RegisterBuyCnftEvent
-  | RegisterBuyCounterEvent
-  | RegisterBuyTokenEvent
-  | RegisterBuyEvent
-  | RequestAccessEvent
-  | UpdateTreeEvent
-  | WithdrawRewardEvent
+export type EscrowReleasedEvent = EventBase<EventType> & {
+  info: EscrowReleasedInfo
+  signer: string
+  account: string
+}
+
+export type PurchaseRefundedEvent = EventBase<EventType> & {
+  info: PurchaseRefundedInfo
+  signer: string
+  account: string
+}
+
+export type ProductRedeemedEvent = EventBase<EventType> & {
+  info: ProductRedeemedInfo
+  signer: string
+  account: string
+}
+
+export type DisputeOpenedEvent = EventBase<EventType> & {
+  info: DisputeOpenedInfo
+  signer: string
+  account: string
+}
+
+export type DisputeSettledEvent = EventBase<EventType> & {
+  info: DisputeSettledInfo
+  signer: string
+  account: string
+}
 
+export type CampaignInitializedEvent = EventBase<EventType> & {
+  info: CampaignInitializedInfo
+  signer: string
+  account: string
+}
+
+export type CampaignRewardInitializedEvent = EventBase<EventType> & {
+  info: CampaignRewardInitializedInfo
+  signer: string
+  account: string
+}
+
+export type RewardClosedEvent = EventBase<EventType> & {
+  info: RewardClosedInfo
+  signer: string
+  account: string
+}
+
+export type RewardInitializedEvent = EventBase<EventType> & {
+  info: RewardInitializedInfo
+  signer: string
+  account: string
+}
+
+export type RewardVaultInitializedEvent = EventBase<EventType> & {
+  info: RewardVaultInitializedInfo
+  signer: string
+  account: string
+}
+
+export type RewardWithdrawnEvent = EventBase<EventType> & {
+  info: RewardWithdrawnInfo
+  signer: string
+  account: string
+}
+
+export type AccountMigratedEvent = EventBase<EventType> & {
+  info: AccountMigratedInfo
+  signer: string
+  account: string
+}
+
+export type BrickEvent =
+  | MarketplaceInitializedEvent
+  | MarketplaceEditedEvent
+  | MarketplaceAuthorityProposedEvent
+  | MarketplaceAuthorityAcceptedEvent
+  | MarketplacePauseSetEvent
+  | MarketplaceClosedEvent
+  | BountyVaultInitializedEvent
+  | BountyWithdrawnEvent
+  | BountyVaultClosedEvent
+  | AccessRequestedEvent
+  | AccessGrantedEvent
+  | ProductInitializedEvent
+  | ProductEditedEvent
+  | ProductClosedEvent
+  | TreeUpdatedEvent
+  | ProductPurchasedEvent
+  | EscrowCreatedEvent
+  | EscrowReleasedEvent
+  | PurchaseRefundedEvent
+  | ProductRedeemedEvent
+  | DisputeOpenedEvent
+  | DisputeSettledEvent
+  | CampaignInitializedEvent
+  | CampaignRewardInitializedEvent
+  | RewardClosedEvent
+  | RewardInitializedEvent
+  | RewardVaultInitializedEvent
+  | RewardWithdrawnEvent
+  | AccountMigratedEvent
@@ -0,0 +1,75 @@
+import * as web3 from '@solana/web3.js'
+import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+
+// emit_cpi! events are self-invoked instructions prefixed by the anchor event tag and the event discriminator
+export const eventIxTag = [228, 69, 165, 46, 81, 203, 154, 29]
+
+export type ProductPurchased = {
+  eventIxTag: number[]
+  eventDiscriminator: number[]
+  marketplace: web3.PublicKey
+  product: web3.PublicKey
+  buyer: web3.PublicKey
+  seller: web3.PublicKey
+  paymentMint: web3.PublicKey
+  units: number
+  grossAmount: beet.bignum
+  fee: beet.bignum
+  sellerAmount: beet.bignum
+  campaign: web3.PublicKey
+  sellerReward: beet.bignum
+  buyerReward: beet.bignum
+}
+
+export const productPurchasedDiscriminator = [149, 80, 32, 62, 22, 245, 46, 227]
+
+export const productPurchasedStruct = new beet.BeetArgsStruct<ProductPurchased>(
+  [
+    ['eventIxTag', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['eventDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['marketplace', beetSolana.publicKey],
+    ['product', beetSolana.publicKey],
+    ['buyer', beetSolana.publicKey],
+    ['seller', beetSolana.publicKey],
+    ['paymentMint', beetSolana.publicKey],
+    ['units', beet.u32],
+    ['grossAmount', beet.u64],
+    ['fee', beet.u64],
+    ['sellerAmount', beet.u64],
+    ['campaign', beetSolana.publicKey],
+    ['sellerReward', beet.u64],
+    ['buyerReward', beet.u64],
+  ],
+  'ProductPurchased',
+)
+
+const BASE58_ALPHABET = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz'
+
+// inner instructions are returned base58 encoded by the rpc
+export function decodeBase58(str: string): Buffer {
+  const bytes: number[] = []
+  for (const char of str) {
+    let carry = BASE58_ALPHABET.indexOf(char)
+    if (carry < 0) throw new Error(`Invalid base58 character ${char}`)
+    for (let i = 0; i < bytes.length; i++) {
+      carry += bytes[i] * 58
+      bytes[i] = carry & 0xff
+      carry >>= 8
+    }
+    while (carry > 0) {
+      bytes.push(carry & 0xff)
+      carry >>= 8
+    }
+  }
+  for (const char of str) {
+    if (char !== '1') break
+    bytes.push(0)
+  }
+  return Buffer.from(bytes.reverse())
+}
+
+export function isProductPurchased(data: Buffer): boolean {
+  const header = [...eventIxTag, ...productPurchasedDiscriminator]
+  return data.length >= header.length && header.every((byte, i) => data[i] === byte)
+}
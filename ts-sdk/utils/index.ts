@@ -1,5 +1,10 @@
+import BN from "bn.js";
+import { PublicKey } from "@solana/web3.js";
+import { BRICK_PROGRAM_ID_PK } from "../constants";
+
 export * from './solita'
 export * from './compression'
+export * from './events'
 
 export function splitId(str: string): [Buffer, Buffer]{
     const bytes = new TextEncoder().encode(str);
@@ -9,4 +14,17 @@ export function splitId(str: string): [Buffer, Buffer]{
     data.set(bytes);
     
     return [Buffer.from(data.slice(0, 32)), Buffer.from(data.slice(32))];
+}
+
+// marketplaces are derived from their creator and an id, so a wallet can create several of them
+export function getMarketplaceAddress(creator: PublicKey, id: number | BN): PublicKey {
+    const [marketplace] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("marketplace", "utf-8"),
+          creator.toBuffer(),
+          new BN(id).toArrayLike(Buffer, "le", 8),
+        ],
+        BRICK_PROGRAM_ID_PK
+    );
+    return marketplace;
 }
\ No newline at end of file
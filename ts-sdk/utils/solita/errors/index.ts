@@ -493,29 +493,6 @@ export class OpenPromotionError extends Error {
 createErrorFromCodeLookup.set(0x1785, () => new OpenPromotionError())
 createErrorFromNameLookup.set('OpenPromotion', () => new OpenPromotionError())
 
-/**
- * VaultsVectorFull: 'The max size of reward vaults are set at 5'
- *
- * @category Errors
- * @category generated
- */
-export class VaultsVectorFullError extends Error {
-  readonly code: number = 0x1786
-  readonly name: string = 'VaultsVectorFull'
-  constructor() {
-    super('The max size of reward vaults are set at 5')
-    if (typeof Error.captureStackTrace === 'function') {
-      Error.captureStackTrace(this, VaultsVectorFullError)
-    }
-  }
-}
-
-createErrorFromCodeLookup.set(0x1786, () => new VaultsVectorFullError())
-createErrorFromNameLookup.set(
-  'VaultsVectorFull',
-  () => new VaultsVectorFullError(),
-)
-
 /**
  * ExtensionNotSupported: 'Currently this extension is not supported'
  *
@@ -523,7 +500,7 @@ createErrorFromNameLookup.set(
  * @category generated
  */
 export class ExtensionNotSupportedError extends Error {
-  readonly code: number = 0x1787
+  readonly code: number = 0x1786
   readonly name: string = 'ExtensionNotSupported'
   constructor() {
     super('Currently this extension is not supported')
@@ -533,7 +510,7 @@ export class ExtensionNotSupportedError extends Error {
   }
 }
 
-createErrorFromCodeLookup.set(0x1787, () => new ExtensionNotSupportedError())
+createErrorFromCodeLookup.set(0x1786, () => new ExtensionNotSupportedError())
 createErrorFromNameLookup.set(
   'ExtensionNotSupported',
   () => new ExtensionNotSupportedError(),
@@ -546,7 +523,7 @@ createErrorFromNameLookup.set(
  * @category generated
  */
 export class CreateAccountErrorError extends Error {
-  readonly code: number = 0x1788
+  readonly code: number = 0x1787
   readonly name: string = 'CreateAccountError'
   constructor() {
     super('Error creating mint account')
@@ -556,7 +533,7 @@ export class CreateAccountErrorError extends Error {
   }
 }
 
-createErrorFromCodeLookup.set(0x1788, () => new CreateAccountErrorError())
+createErrorFromCodeLookup.set(0x1787, () => new CreateAccountErrorError())
 createErrorFromNameLookup.set(
   'CreateAccountError',
   () => new CreateAccountErrorError(),
@@ -569,7 +546,7 @@ createErrorFromNameLookup.set(
  * @category generated
  */
 export class MintExtensionErrorError extends Error {
-  readonly code: number = 0x1789
+  readonly code: number = 0x1788
   readonly name: string = 'MintExtensionError'
   constructor() {
     super('Error extension init')
@@ -579,7 +556,7 @@ export class MintExtensionErrorError extends Error {
   }
 }
 
-createErrorFromCodeLookup.set(0x1789, () => new MintExtensionErrorError())
+createErrorFromCodeLookup.set(0x1788, () => new MintExtensionErrorError())
 createErrorFromNameLookup.set(
   'MintExtensionError',
   () => new MintExtensionErrorError(),
@@ -592,7 +569,7 @@ createErrorFromNameLookup.set(
  * @category generated
  */
 export class InitMintErrorError extends Error {
-  readonly code: number = 0x178a
+  readonly code: number = 0x1789
   readonly name: string = 'InitMintError'
   constructor() {
     super('Error mint init')
@@ -602,7 +579,7 @@ export class InitMintErrorError extends Error {
   }
 }
 
-createErrorFromCodeLookup.set(0x178a, () => new InitMintErrorError())
+createErrorFromCodeLookup.set(0x1789, () => new InitMintErrorError())
 createErrorFromNameLookup.set('InitMintError', () => new InitMintErrorError())
 
 /**
@@ -612,7 +589,7 @@ createErrorFromNameLookup.set('InitMintError', () => new InitMintErrorError())
  * @category generated
  */
 export class OptionalAccountNotProvidedError extends Error {
-  readonly code: number = 0x178b
+  readonly code: number = 0x178a
   readonly name: string = 'OptionalAccountNotProvided'
   constructor() {
     super('Optional account not provided')
@@ -623,7 +600,7 @@ export class OptionalAccountNotProvidedError extends Error {
 }
 
 createErrorFromCodeLookup.set(
-  0x178b,
+  0x178a,
   () => new OptionalAccountNotProvidedError(),
 )
 createErrorFromNameLookup.set(
@@ -638,7 +615,7 @@ createErrorFromNameLookup.set(
  * @category generated
  */
 export class NotInWithelistError extends Error {
-  readonly code: number = 0x178c
+  readonly code: number = 0x178b
   readonly name: string = 'NotInWithelist'
   constructor() {
     super('You dont have the token that allows you to create products')
@@ -648,9 +625,864 @@ export class NotInWithelistError extends Error {
   }
 }
 
-createErrorFromCodeLookup.set(0x178c, () => new NotInWithelistError())
+createErrorFromCodeLookup.set(0x178b, () => new NotInWithelistError())
 createErrorFromNameLookup.set('NotInWithelist', () => new NotInWithelistError())
 
+/**
+ * OpenProducts: 'There are still products listed in this marketplace'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OpenProductsError extends Error {
+  readonly code: number = 0x178c
+  readonly name: string = 'OpenProducts'
+  constructor() {
+    super('There are still products listed in this marketplace')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OpenProductsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new OpenProductsError())
+createErrorFromNameLookup.set('OpenProducts', () => new OpenProductsError())
+
+/**
+ * PendingRewards: 'There are still rewards that users have not withdrawn'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PendingRewardsError extends Error {
+  readonly code: number = 0x178d
+  readonly name: string = 'PendingRewards'
+  constructor() {
+    super('There are still rewards that users have not withdrawn')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PendingRewardsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new PendingRewardsError())
+createErrorFromNameLookup.set('PendingRewards', () => new PendingRewardsError())
+
+/**
+ * IncorrectRemainingAccounts: 'You are providing a wrong number of remaining accounts'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectRemainingAccountsError extends Error {
+  readonly code: number = 0x178e
+  readonly name: string = 'IncorrectRemainingAccounts'
+  constructor() {
+    super('You are providing a wrong number of remaining accounts')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectRemainingAccountsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x178e,
+  () => new IncorrectRemainingAccountsError(),
+)
+createErrorFromNameLookup.set(
+  'IncorrectRemainingAccounts',
+  () => new IncorrectRemainingAccountsError(),
+)
+
+/**
+ * MarketplacePaused: 'The marketplace is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MarketplacePausedError extends Error {
+  readonly code: number = 0x178f
+  readonly name: string = 'MarketplacePaused'
+  constructor() {
+    super('The marketplace is paused')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MarketplacePausedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new MarketplacePausedError())
+createErrorFromNameLookup.set(
+  'MarketplacePaused',
+  () => new MarketplacePausedError(),
+)
+
+/**
+ * IncorrectTransferFee: 'The transfer fee of the payment mint can not be covered'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectTransferFeeError extends Error {
+  readonly code: number = 0x1790
+  readonly name: string = 'IncorrectTransferFee'
+  constructor() {
+    super('The transfer fee of the payment mint can not be covered')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectTransferFeeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new IncorrectTransferFeeError())
+createErrorFromNameLookup.set(
+  'IncorrectTransferFee',
+  () => new IncorrectTransferFeeError(),
+)
+
+/**
+ * PriceExceedsMaximum: 'The purchase costs more than the maximum price set by the buyer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PriceExceedsMaximumError extends Error {
+  readonly code: number = 0x1791
+  readonly name: string = 'PriceExceedsMaximum'
+  constructor() {
+    super('The purchase costs more than the maximum price set by the buyer')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PriceExceedsMaximumError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new PriceExceedsMaximumError())
+createErrorFromNameLookup.set(
+  'PriceExceedsMaximum',
+  () => new PriceExceedsMaximumError(),
+)
+
+/**
+ * SoldOut: 'The product is sold out'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SoldOutError extends Error {
+  readonly code: number = 0x1792
+  readonly name: string = 'SoldOut'
+  constructor() {
+    super('The product is sold out')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SoldOutError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new SoldOutError())
+createErrorFromNameLookup.set('SoldOut', () => new SoldOutError())
+
+/**
+ * IncorrectMaxSupply: 'The max supply can not be lower than the units already sold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectMaxSupplyError extends Error {
+  readonly code: number = 0x1793
+  readonly name: string = 'IncorrectMaxSupply'
+  constructor() {
+    super('The max supply can not be lower than the units already sold')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectMaxSupplyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new IncorrectMaxSupplyError())
+createErrorFromNameLookup.set(
+  'IncorrectMaxSupply',
+  () => new IncorrectMaxSupplyError(),
+)
+
+/**
+ * WalletLimitReached: 'This wallet can not buy more units of this product'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletLimitReachedError extends Error {
+  readonly code: number = 0x1794
+  readonly name: string = 'WalletLimitReached'
+  constructor() {
+    super('This wallet can not buy more units of this product')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletLimitReachedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new WalletLimitReachedError())
+createErrorFromNameLookup.set(
+  'WalletLimitReached',
+  () => new WalletLimitReachedError(),
+)
+
+/**
+ * SaleNotActive: 'The product can not be bought outside its sale window'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SaleNotActiveError extends Error {
+  readonly code: number = 0x1795
+  readonly name: string = 'SaleNotActive'
+  constructor() {
+    super('The product can not be bought outside its sale window')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SaleNotActiveError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new SaleNotActiveError())
+createErrorFromNameLookup.set('SaleNotActive', () => new SaleNotActiveError())
+
+/**
+ * IncorrectSaleWindow: 'The sale start has to be before the sale end'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectSaleWindowError extends Error {
+  readonly code: number = 0x1796
+  readonly name: string = 'IncorrectSaleWindow'
+  constructor() {
+    super('The sale start has to be before the sale end')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectSaleWindowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new IncorrectSaleWindowError())
+createErrorFromNameLookup.set(
+  'IncorrectSaleWindow',
+  () => new IncorrectSaleWindowError(),
+)
+
+/**
+ * IncorrectCampaignWindow: 'The campaign start has to be before the campaign end'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectCampaignWindowError extends Error {
+  readonly code: number = 0x1797
+  readonly name: string = 'IncorrectCampaignWindow'
+  constructor() {
+    super('The campaign start has to be before the campaign end')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectCampaignWindowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new IncorrectCampaignWindowError())
+createErrorFromNameLookup.set(
+  'IncorrectCampaignWindow',
+  () => new IncorrectCampaignWindowError(),
+)
+
+/**
+ * IncorrectCampaign: 'The campaign reward account does not belong to this campaign'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectCampaignError extends Error {
+  readonly code: number = 0x1798
+  readonly name: string = 'IncorrectCampaign'
+  constructor() {
+    super('The campaign reward account does not belong to this campaign')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectCampaignError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new IncorrectCampaignError())
+createErrorFromNameLookup.set(
+  'IncorrectCampaign',
+  () => new IncorrectCampaignError(),
+)
+
+/**
+ * IncorrectBountyVault: 'The bounty vault is not the marketplace vault of that mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectBountyVaultError extends Error {
+  readonly code: number = 0x1799
+  readonly name: string = 'IncorrectBountyVault'
+  constructor() {
+    super('The bounty vault is not the marketplace vault of that mint')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectBountyVaultError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new IncorrectBountyVaultError())
+createErrorFromNameLookup.set(
+  'IncorrectBountyVault',
+  () => new IncorrectBountyVaultError(),
+)
+
+/**
+ * IncorrectRewardVault: 'The reward vault is not the user vault of that mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectRewardVaultError extends Error {
+  readonly code: number = 0x179a
+  readonly name: string = 'IncorrectRewardVault'
+  constructor() {
+    super('The reward vault is not the user vault of that mint')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectRewardVaultError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new IncorrectRewardVaultError())
+createErrorFromNameLookup.set(
+  'IncorrectRewardVault',
+  () => new IncorrectRewardVaultError(),
+)
+
+/**
+ * IncorrectVestingPeriod: 'The vesting period can not be negative'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectVestingPeriodError extends Error {
+  readonly code: number = 0x179b
+  readonly name: string = 'IncorrectVestingPeriod'
+  constructor() {
+    super('The vesting period can not be negative')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectVestingPeriodError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new IncorrectVestingPeriodError())
+createErrorFromNameLookup.set(
+  'IncorrectVestingPeriod',
+  () => new IncorrectVestingPeriodError(),
+)
+
+/**
+ * RewardsAlreadySwept: 'These rewards were swept when a previous reward account was closed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardsAlreadySweptError extends Error {
+  readonly code: number = 0x179c
+  readonly name: string = 'RewardsAlreadySwept'
+  constructor() {
+    super('These rewards were swept when a previous reward account was closed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardsAlreadySweptError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new RewardsAlreadySweptError())
+createErrorFromNameLookup.set(
+  'RewardsAlreadySwept',
+  () => new RewardsAlreadySweptError(),
+)
+
+/**
+ * AmountExceedsRewards: 'The amount is higher than the unlocked rewards'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AmountExceedsRewardsError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'AmountExceedsRewards'
+  constructor() {
+    super('The amount is higher than the unlocked rewards')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AmountExceedsRewardsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new AmountExceedsRewardsError())
+createErrorFromNameLookup.set(
+  'AmountExceedsRewards',
+  () => new AmountExceedsRewardsError(),
+)
+
+/**
+ * EscrowRequired: 'This product can only be bought with an escrowed purchase'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowRequiredError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'EscrowRequired'
+  constructor() {
+    super('This product can only be bought with an escrowed purchase')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new EscrowRequiredError())
+createErrorFromNameLookup.set('EscrowRequired', () => new EscrowRequiredError())
+
+/**
+ * EscrowNotEnabled: 'This product does not accept escrowed purchases'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowNotEnabledError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'EscrowNotEnabled'
+  constructor() {
+    super('This product does not accept escrowed purchases')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowNotEnabledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new EscrowNotEnabledError())
+createErrorFromNameLookup.set(
+  'EscrowNotEnabled',
+  () => new EscrowNotEnabledError(),
+)
+
+/**
+ * IncorrectEscrowTimeout: 'The escrow timeout has to be positive'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectEscrowTimeoutError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'IncorrectEscrowTimeout'
+  constructor() {
+    super('The escrow timeout has to be positive')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectEscrowTimeoutError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new IncorrectEscrowTimeoutError())
+createErrorFromNameLookup.set(
+  'IncorrectEscrowTimeout',
+  () => new IncorrectEscrowTimeoutError(),
+)
+
+/**
+ * EscrowLocked: 'The escrow can not be released yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowLockedError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'EscrowLocked'
+  constructor() {
+    super('The escrow can not be released yet')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowLockedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new EscrowLockedError())
+createErrorFromNameLookup.set('EscrowLocked', () => new EscrowLockedError())
+
+/**
+ * IncorrectEscrow: 'The escrow does not belong to this marketplace'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectEscrowError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'IncorrectEscrow'
+  constructor() {
+    super('The escrow does not belong to this marketplace')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectEscrowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new IncorrectEscrowError())
+createErrorFromNameLookup.set(
+  'IncorrectEscrow',
+  () => new IncorrectEscrowError(),
+)
+
+/**
+ * EscrowDisputed: 'The escrow is disputed, only the marketplace arbiter can pay it out'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowDisputedError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'EscrowDisputed'
+  constructor() {
+    super('The escrow is disputed, only the marketplace arbiter can pay it out')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowDisputedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new EscrowDisputedError())
+createErrorFromNameLookup.set('EscrowDisputed', () => new EscrowDisputedError())
+
+/**
+ * EscrowNotDisputed: 'The escrow is not disputed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowNotDisputedError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'EscrowNotDisputed'
+  constructor() {
+    super('The escrow is not disputed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowNotDisputedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new EscrowNotDisputedError())
+createErrorFromNameLookup.set(
+  'EscrowNotDisputed',
+  () => new EscrowNotDisputedError(),
+)
+
+/**
+ * DisputeWindowClosed: 'Disputes can only be opened before the escrow release timestamp'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeWindowClosedError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'DisputeWindowClosed'
+  constructor() {
+    super('Disputes can only be opened before the escrow release timestamp')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeWindowClosedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new DisputeWindowClosedError())
+createErrorFromNameLookup.set(
+  'DisputeWindowClosed',
+  () => new DisputeWindowClosedError(),
+)
+
+/**
+ * IncorrectRuling: 'The ruling pays out more than the escrowed amount'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectRulingError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'IncorrectRuling'
+  constructor() {
+    super('The ruling pays out more than the escrowed amount')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectRulingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new IncorrectRulingError())
+createErrorFromNameLookup.set(
+  'IncorrectRuling',
+  () => new IncorrectRulingError(),
+)
+
+/**
+ * RefundExceedsPurchase: 'The buyer did not buy that many units or did not pay that amount'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RefundExceedsPurchaseError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'RefundExceedsPurchase'
+  constructor() {
+    super('The buyer did not buy that many units or did not pay that amount')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RefundExceedsPurchaseError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new RefundExceedsPurchaseError())
+createErrorFromNameLookup.set(
+  'RefundExceedsPurchase',
+  () => new RefundExceedsPurchaseError(),
+)
+
+/**
+ * CnftReceiptRequired: 'The cnft receipt is required to burn it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CnftReceiptRequiredError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'CnftReceiptRequired'
+  constructor() {
+    super('The cnft receipt is required to burn it')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CnftReceiptRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new CnftReceiptRequiredError())
+createErrorFromNameLookup.set(
+  'CnftReceiptRequired',
+  () => new CnftReceiptRequiredError(),
+)
+
+/**
+ * RedeemExceedsUnits: 'The buyer does not have that many units left'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RedeemExceedsUnitsError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'RedeemExceedsUnits'
+  constructor() {
+    super('The buyer does not have that many units left')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RedeemExceedsUnitsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new RedeemExceedsUnitsError())
+createErrorFromNameLookup.set(
+  'RedeemExceedsUnits',
+  () => new RedeemExceedsUnitsError(),
+)
+
+/**
+ * IncorrectSubscriptionPeriod: 'The subscription period has to be positive'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectSubscriptionPeriodError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'IncorrectSubscriptionPeriod'
+  constructor() {
+    super('The subscription period has to be positive')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectSubscriptionPeriodError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17aa,
+  () => new IncorrectSubscriptionPeriodError(),
+)
+createErrorFromNameLookup.set(
+  'IncorrectSubscriptionPeriod',
+  () => new IncorrectSubscriptionPeriodError(),
+)
+
+/**
+ * NotASubscription: 'This product is not a subscription'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotASubscriptionError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'NotASubscription'
+  constructor() {
+    super('This product is not a subscription')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotASubscriptionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new NotASubscriptionError())
+createErrorFromNameLookup.set(
+  'NotASubscription',
+  () => new NotASubscriptionError(),
+)
+
+/**
+ * OpenBountyVaults: 'All the open bounty vaults have to be closed with the marketplace'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OpenBountyVaultsError extends Error {
+  readonly code: number = 0x17ac
+  readonly name: string = 'OpenBountyVaults'
+  constructor() {
+    super('All the open bounty vaults have to be closed with the marketplace')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OpenBountyVaultsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new OpenBountyVaultsError())
+createErrorFromNameLookup.set(
+  'OpenBountyVaults',
+  () => new OpenBountyVaultsError(),
+)
+
+/**
+ * RefundBelowPrice: 'The refund is lower than the price paid for the units given back'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RefundBelowPriceError extends Error {
+  readonly code: number = 0x17ad
+  readonly name: string = 'RefundBelowPrice'
+  constructor() {
+    super('The refund is lower than the price paid for the units given back')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RefundBelowPriceError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new RefundBelowPriceError())
+createErrorFromNameLookup.set(
+  'RefundBelowPrice',
+  () => new RefundBelowPriceError(),
+)
+
+/**
+ * IncorrectPaymentOwner: 'The payment account is not owned by the program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectPaymentOwnerError extends Error {
+  readonly code: number = 0x17ae
+  readonly name: string = 'IncorrectPaymentOwner'
+  constructor() {
+    super('The payment account is not owned by the program')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectPaymentOwnerError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new IncorrectPaymentOwnerError())
+createErrorFromNameLookup.set(
+  'IncorrectPaymentOwner',
+  () => new IncorrectPaymentOwnerError(),
+)
+
+/**
+ * UnversionedAccount: 'Accounts created before the versioned layouts can not be migrated'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnversionedAccountError extends Error {
+  readonly code: number = 0x17af
+  readonly name: string = 'UnversionedAccount'
+  constructor() {
+    super('Accounts created before the versioned layouts can not be migrated')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnversionedAccountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new UnversionedAccountError())
+createErrorFromNameLookup.set(
+  'UnversionedAccount',
+  () => new UnversionedAccountError(),
+)
+
+/**
+ * IncorrectReceiptUnits: 'A cnft receipt stands for a single unit'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectReceiptUnitsError extends Error {
+  readonly code: number = 0x17b0
+  readonly name: string = 'IncorrectReceiptUnits'
+  constructor() {
+    super('A cnft receipt stands for a single unit')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectReceiptUnitsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new IncorrectReceiptUnitsError())
+createErrorFromNameLookup.set(
+  'IncorrectReceiptUnits',
+  () => new IncorrectReceiptUnitsError(),
+)
+
+/**
+ * EscrowRefund: 'Purchases of escrow products are refunded by settling a dispute'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowRefundError extends Error {
+  readonly code: number = 0x17b1
+  readonly name: string = 'EscrowRefund'
+  constructor() {
+    super('Purchases of escrow products are refunded by settling a dispute')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowRefundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new EscrowRefundError())
+createErrorFromNameLookup.set('EscrowRefund', () => new EscrowRefundError())
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -30,6 +30,8 @@ export const acceptAccessStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] request
  * @property [_writable_] accessMint
  * @property [_writable_] accessVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category AcceptAccess
  * @category generated
@@ -45,6 +47,8 @@ export type AcceptAccessInstructionAccounts = {
   request: web3.PublicKey
   accessMint: web3.PublicKey
   accessVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -118,6 +122,16 @@ export function createAcceptAccessInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
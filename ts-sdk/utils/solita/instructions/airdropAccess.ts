@@ -29,6 +29,8 @@ export const airdropAccessStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] marketplace
  * @property [_writable_] accessMint
  * @property [_writable_] accessVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category AirdropAccess
  * @category generated
@@ -43,6 +45,8 @@ export type AirdropAccessInstructionAccounts = {
   marketplace: web3.PublicKey
   accessMint: web3.PublicKey
   accessVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -111,6 +115,16 @@ export function createAirdropAccessInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -10,7 +10,7 @@ import * as web3 from '@solana/web3.js'
 import {
   EditMarketplaceParams,
   editMarketplaceParamsBeet,
-} from '../types/EditMarketplaceParams.js'
+} from '../types/EditMarketplaceParams'
 
 /**
  * @category Instructions
@@ -41,8 +41,9 @@ export const editMarketplaceStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_, **signer**] signer
  * @property [_writable_] marketplace
- * @property [] rewardMint
  * @property [] discountMint
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category EditMarketplace
  * @category generated
@@ -50,8 +51,9 @@ export const editMarketplaceStruct = new beet.BeetArgsStruct<
 export type EditMarketplaceInstructionAccounts = {
   signer: web3.PublicKey
   marketplace: web3.PublicKey
-  rewardMint: web3.PublicKey
   discountMint: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -90,12 +92,17 @@ export function createEditMarketplaceInstruction(
       isSigner: false,
     },
     {
-      pubkey: accounts.rewardMint,
+      pubkey: accounts.discountMint,
       isWritable: false,
       isSigner: false,
     },
     {
-      pubkey: accounts.discountMint,
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
       isWritable: false,
       isSigner: false,
     },
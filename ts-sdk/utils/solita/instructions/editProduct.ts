@@ -7,6 +7,10 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import {
+  EditProductParams,
+  editProductParamsBeet,
+} from '../types/EditProductParams'
 
 /**
  * @category Instructions
@@ -14,21 +18,21 @@ import * as web3 from '@solana/web3.js'
  * @category generated
  */
 export type EditProductInstructionArgs = {
-  productPrice: beet.bignum
+  params: EditProductParams
 }
 /**
  * @category Instructions
  * @category EditProduct
  * @category generated
  */
-export const editProductStruct = new beet.BeetArgsStruct<
+export const editProductStruct = new beet.FixableBeetArgsStruct<
   EditProductInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
 >(
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
-    ['productPrice', beet.u64],
+    ['params', editProductParamsBeet],
   ],
   'EditProductInstructionArgs',
 )
@@ -38,6 +42,8 @@ export const editProductStruct = new beet.BeetArgsStruct<
  * @property [_writable_, **signer**] signer
  * @property [_writable_] product
  * @property [] paymentMint
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category EditProduct
  * @category generated
@@ -46,6 +52,8 @@ export type EditProductInstructionAccounts = {
   signer: web3.PublicKey
   product: web3.PublicKey
   paymentMint: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -88,6 +96,16 @@ export function createEditProductInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
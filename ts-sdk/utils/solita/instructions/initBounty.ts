@@ -28,6 +28,8 @@ export const initBountyStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] marketplace
  * @property [] rewardMint
  * @property [_writable_] bountyVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category InitBounty
  * @category generated
@@ -41,6 +43,8 @@ export type InitBountyInstructionAccounts = {
   marketplace: web3.PublicKey
   rewardMint: web3.PublicKey
   bountyVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -104,6 +108,16 @@ export function createInitBountyInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -10,7 +10,7 @@ import * as web3 from '@solana/web3.js'
 import {
   InitMarketplaceParams,
   initMarketplaceParamsBeet,
-} from '../types/InitMarketplaceParams.js'
+} from '../types/InitMarketplaceParams'
 
 /**
  * @category Instructions
@@ -47,6 +47,8 @@ export const initMarketplaceStruct = new beet.BeetArgsStruct<
  * @property [] rewardMint
  * @property [] discountMint
  * @property [_writable_] bountyVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category InitMarketplace
  * @category generated
@@ -62,6 +64,8 @@ export type InitMarketplaceInstructionAccounts = {
   rewardMint: web3.PublicKey
   discountMint: web3.PublicKey
   bountyVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -139,6 +143,16 @@ export function createInitMarketplaceInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
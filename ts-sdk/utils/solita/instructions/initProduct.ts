@@ -11,6 +11,7 @@ import {
   InitProductParams,
   initProductParamsBeet,
 } from '../types/InitProductParams'
+import { BRICK_PROGRAM_ID_PK } from '../../../constants'
 
 /**
  * @category Instructions
@@ -25,7 +26,7 @@ export type InitProductInstructionArgs = {
  * @category InitProduct
  * @category generated
  */
-export const initProductStruct = new beet.BeetArgsStruct<
+export const initProductStruct = new beet.FixableBeetArgsStruct<
   InitProductInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
@@ -47,6 +48,8 @@ export const initProductStruct = new beet.BeetArgsStruct<
  * @property [] paymentMint
  * @property [_writable_] accessMint (optional)
  * @property [_writable_] accessVault (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category InitProduct
  * @category generated
@@ -60,8 +63,10 @@ export type InitProductInstructionAccounts = {
   product: web3.PublicKey
   productMint: web3.PublicKey
   paymentMint: web3.PublicKey
-  accessMint?: web3.PublicKey
-  accessVault?: web3.PublicKey
+  accessMint?: web3.PublicKey | null
+  accessVault?: web3.PublicKey | null
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -142,19 +147,36 @@ export function createInitProductInstruction(
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   if (accounts.accessVault != null) {
-    if (accounts.accessMint == null) {
-      throw new Error(
-        "When providing 'accessVault' then 'accounts.accessMint' need(s) to be provided as well.",
-      )
-    }
     keys.push({
       pubkey: accounts.accessVault,
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
+  keys.push({
+    pubkey: accounts.eventAuthority,
+    isWritable: false,
+    isSigner: false,
+  })
+  keys.push({
+    pubkey: accounts.program,
+    isWritable: false,
+    isSigner: false,
+  })
 
   if (accounts.anchorRemainingAccounts != null) {
     for (const acc of accounts.anchorRemainingAccounts) {
@@ -11,6 +11,7 @@ import {
   InitProductTreeParams,
   initProductTreeParamsBeet,
 } from '../types/InitProductTreeParams'
+import { BRICK_PROGRAM_ID_PK } from '../../../constants'
 
 /**
  * @category Instructions
@@ -57,6 +58,8 @@ export const initProductTreeStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] metadata
  * @property [_writable_] merkleTree
  * @property [_writable_] treeAuthority
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category InitProductTree
  * @category generated
@@ -75,13 +78,15 @@ export type InitProductTreeInstructionAccounts = {
   product: web3.PublicKey
   productMint: web3.PublicKey
   paymentMint: web3.PublicKey
-  accessMint?: web3.PublicKey
+  accessMint?: web3.PublicKey | null
   productMintVault: web3.PublicKey
-  accessVault?: web3.PublicKey
+  accessVault?: web3.PublicKey | null
   masterEdition: web3.PublicKey
   metadata: web3.PublicKey
   merkleTree: web3.PublicKey
   treeAuthority: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -187,6 +192,12 @@ export function createInitProductTreeInstruction(
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   keys.push({
     pubkey: accounts.productMintVault,
@@ -194,16 +205,17 @@ export function createInitProductTreeInstruction(
     isSigner: false,
   })
   if (accounts.accessVault != null) {
-    if (accounts.accessMint == null) {
-      throw new Error(
-        "When providing 'accessVault' then 'accounts.accessMint' need(s) to be provided as well.",
-      )
-    }
     keys.push({
       pubkey: accounts.accessVault,
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   keys.push({
     pubkey: accounts.masterEdition,
@@ -225,6 +237,16 @@ export function createInitProductTreeInstruction(
     isWritable: true,
     isSigner: false,
   })
+  keys.push({
+    pubkey: accounts.eventAuthority,
+    isWritable: false,
+    isSigner: false,
+  })
+  keys.push({
+    pubkey: accounts.program,
+    isWritable: false,
+    isSigner: false,
+  })
 
   if (accounts.anchorRemainingAccounts != null) {
     for (const acc of accounts.anchorRemainingAccounts) {
@@ -28,6 +28,8 @@ export const initRewardStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] reward
  * @property [] rewardMint
  * @property [_writable_] rewardVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category InitReward
  * @category generated
@@ -41,6 +43,8 @@ export type InitRewardInstructionAccounts = {
   reward: web3.PublicKey
   rewardMint: web3.PublicKey
   rewardVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -104,6 +108,16 @@ export function createInitRewardInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -25,10 +25,12 @@ export const initRewardVaultStruct = new beet.BeetArgsStruct<{
  * @property [] tokenProgramV0
  * @property [] associatedTokenProgram
  * @property [_writable_, **signer**] signer
- * @property [_writable_] marketplace
- * @property [_writable_] reward
+ * @property [] marketplace
+ * @property [] reward
  * @property [] rewardMint
  * @property [_writable_] rewardVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category InitRewardVault
  * @category generated
@@ -43,6 +45,8 @@ export type InitRewardVaultInstructionAccounts = {
   reward: web3.PublicKey
   rewardMint: web3.PublicKey
   rewardVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -93,12 +97,12 @@ export function createInitRewardVaultInstruction(
     },
     {
       pubkey: accounts.marketplace,
-      isWritable: true,
+      isWritable: false,
       isSigner: false,
     },
     {
       pubkey: accounts.reward,
-      isWritable: true,
+      isWritable: false,
       isSigner: false,
     },
     {
@@ -111,6 +115,16 @@ export function createInitRewardVaultInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -6,6 +6,7 @@
  */
 
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as web3 from '@solana/web3.js'
 import { BRICK_PROGRAM_ID_PK } from '../../../constants'
 
@@ -16,6 +17,8 @@ import { BRICK_PROGRAM_ID_PK } from '../../../constants'
  */
 export type RegisterBuyInstructionArgs = {
   amount: number
+  maxTotalPrice: beet.bignum
+  expectedPaymentMint: web3.PublicKey
 }
 /**
  * @category Instructions
@@ -30,6 +33,8 @@ export const registerBuyStruct = new beet.BeetArgsStruct<
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['amount', beet.u32],
+    ['maxTotalPrice', beet.u64],
+    ['expectedPaymentMint', beetSolana.publicKey],
   ],
   'RegisterBuyInstructionArgs',
 )
@@ -37,6 +42,7 @@ export const registerBuyStruct = new beet.BeetArgsStruct<
  * Accounts required by the _registerBuy_ instruction
  *
  * @property [] tokenProgramV0
+ * @property [] paymentTokenProgram
  * @property [_writable_, **signer**] signer
  * @property [_writable_] seller (optional)
  * @property [_writable_] marketplaceAuth (optional)
@@ -47,11 +53,16 @@ export const registerBuyStruct = new beet.BeetArgsStruct<
  * @property [_writable_] buyerTransferVault (optional)
  * @property [_writable_] sellerTransferVault (optional)
  * @property [_writable_] marketplaceTransferVault (optional)
+ * @property [_writable_] campaign (optional)
  * @property [_writable_] bountyVault (optional)
  * @property [_writable_] sellerReward (optional)
  * @property [_writable_] sellerRewardVault (optional)
  * @property [_writable_] buyerReward (optional)
  * @property [_writable_] buyerRewardVault (optional)
+ * @property [_writable_] sellerCampaignReward (optional)
+ * @property [_writable_] buyerCampaignReward (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category RegisterBuy
  * @category generated
@@ -59,6 +70,7 @@ export const registerBuyStruct = new beet.BeetArgsStruct<
 export type RegisterBuyInstructionAccounts = {
   systemProgram?: web3.PublicKey
   tokenProgramV0: web3.PublicKey
+  paymentTokenProgram: web3.PublicKey
   rent?: web3.PublicKey
   signer: web3.PublicKey
   seller?: web3.PublicKey | null
@@ -70,11 +82,16 @@ export type RegisterBuyInstructionAccounts = {
   buyerTransferVault?: web3.PublicKey | null
   sellerTransferVault?: web3.PublicKey | null
   marketplaceTransferVault?: web3.PublicKey | null
+  campaign?: web3.PublicKey | null
   bountyVault?: web3.PublicKey | null
   sellerReward?: web3.PublicKey | null
   sellerRewardVault?: web3.PublicKey | null
   buyerReward?: web3.PublicKey | null
   buyerRewardVault?: web3.PublicKey | null
+  sellerCampaignReward?: web3.PublicKey | null
+  buyerCampaignReward?: web3.PublicKey | null
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -117,6 +134,11 @@ export function createRegisterBuyInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.paymentTokenProgram,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.rent ?? web3.SYSVAR_RENT_PUBKEY,
       isWritable: false,
@@ -135,18 +157,25 @@ export function createRegisterBuyInstruction(
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   if (accounts.marketplaceAuth != null) {
-    if (accounts.seller == null) {
-      throw new Error(
-        "When providing 'marketplaceAuth' then 'accounts.seller' need(s) to be provided as well.",
-      )
-    }
     keys.push({
       pubkey: accounts.marketplaceAuth,
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   keys.push({
     pubkey: accounts.marketplace,
@@ -207,6 +236,19 @@ export function createRegisterBuyInstruction(
       isSigner: false,
     })
   }
+  if (accounts.campaign != null) {
+    keys.push({
+      pubkey: accounts.campaign,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
   if (accounts.bountyVault != null) {
     keys.push({
       pubkey: accounts.bountyVault,
@@ -272,6 +314,42 @@ export function createRegisterBuyInstruction(
       isSigner: false,
     })
   }
+  if (accounts.sellerCampaignReward != null) {
+    keys.push({
+      pubkey: accounts.sellerCampaignReward,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
+  if (accounts.buyerCampaignReward != null) {
+    keys.push({
+      pubkey: accounts.buyerCampaignReward,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
+  keys.push({
+    pubkey: accounts.eventAuthority,
+    isWritable: false,
+    isSigner: false,
+  })
+  keys.push({
+    pubkey: accounts.program,
+    isWritable: false,
+    isSigner: false,
+  })
 
   if (accounts.anchorRemainingAccounts != null) {
     for (const acc of accounts.anchorRemainingAccounts) {
@@ -41,6 +41,7 @@ export const registerBuyCnftStruct = new beet.FixableBeetArgsStruct<
  * Accounts required by the _registerBuyCnft_ instruction
  *
  * @property [] tokenProgramV0
+ * @property [] paymentTokenProgram
  * @property [] logWrapper
  * @property [] bubblegumProgram
  * @property [] compressionProgram
@@ -50,21 +51,27 @@ export const registerBuyCnftStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] marketplaceAuth (optional)
  * @property [_writable_] marketplace
  * @property [_writable_] product
+ * @property [_writable_] payment
  * @property [] paymentMint
  * @property [_writable_] productMint
  * @property [_writable_] buyerTransferVault (optional)
  * @property [_writable_] sellerTransferVault (optional)
  * @property [_writable_] marketplaceTransferVault (optional)
+ * @property [_writable_] campaign (optional)
  * @property [_writable_] bountyVault (optional)
  * @property [_writable_] sellerReward (optional)
  * @property [_writable_] sellerRewardVault (optional)
  * @property [_writable_] buyerReward (optional)
  * @property [_writable_] buyerRewardVault (optional)
+ * @property [_writable_] sellerCampaignReward (optional)
+ * @property [_writable_] buyerCampaignReward (optional)
  * @property [_writable_] metadata
  * @property [_writable_] masterEdition
  * @property [_writable_] treeAuthority
  * @property [] bubblegumSigner
  * @property [_writable_] merkleTree
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category RegisterBuyCnft
  * @category generated
@@ -72,6 +79,7 @@ export const registerBuyCnftStruct = new beet.FixableBeetArgsStruct<
 export type RegisterBuyCnftInstructionAccounts = {
   systemProgram?: web3.PublicKey
   tokenProgramV0: web3.PublicKey
+  paymentTokenProgram: web3.PublicKey
   rent?: web3.PublicKey
   logWrapper: web3.PublicKey
   bubblegumProgram: web3.PublicKey
@@ -82,21 +90,27 @@ export type RegisterBuyCnftInstructionAccounts = {
   marketplaceAuth?: web3.PublicKey | null
   marketplace: web3.PublicKey
   product: web3.PublicKey
+  payment: web3.PublicKey
   paymentMint: web3.PublicKey
   productMint: web3.PublicKey
   buyerTransferVault?: web3.PublicKey | null
   sellerTransferVault?: web3.PublicKey | null
   marketplaceTransferVault?: web3.PublicKey | null
+  campaign?: web3.PublicKey | null
   bountyVault?: web3.PublicKey | null
   sellerReward?: web3.PublicKey | null
   sellerRewardVault?: web3.PublicKey | null
   buyerReward?: web3.PublicKey | null
   buyerRewardVault?: web3.PublicKey | null
+  sellerCampaignReward?: web3.PublicKey | null
+  buyerCampaignReward?: web3.PublicKey | null
   metadata: web3.PublicKey
   masterEdition: web3.PublicKey
   treeAuthority: web3.PublicKey
   bubblegumSigner: web3.PublicKey
   merkleTree: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -139,6 +153,11 @@ export function createRegisterBuyCnftInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.paymentTokenProgram,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.rent ?? web3.SYSVAR_RENT_PUBKEY,
       isWritable: false,
@@ -185,11 +204,6 @@ export function createRegisterBuyCnftInstruction(
     })
   }
   if (accounts.marketplaceAuth != null) {
-    if (accounts.seller == null) {
-      throw new Error(
-        "When providing 'marketplaceAuth' then 'accounts.seller' need(s) to be provided as well.",
-      )
-    }
     keys.push({
       pubkey: accounts.marketplaceAuth,
       isWritable: true,
@@ -212,6 +226,11 @@ export function createRegisterBuyCnftInstruction(
     isWritable: true,
     isSigner: false,
   })
+  keys.push({
+    pubkey: accounts.payment,
+    isWritable: true,
+    isSigner: false,
+  })
   keys.push({
     pubkey: accounts.paymentMint,
     isWritable: false,
@@ -261,6 +280,19 @@ export function createRegisterBuyCnftInstruction(
       isSigner: false,
     })
   }
+  if (accounts.campaign != null) {
+    keys.push({
+      pubkey: accounts.campaign,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
   if (accounts.bountyVault != null) {
     keys.push({
       pubkey: accounts.bountyVault,
@@ -326,6 +358,32 @@ export function createRegisterBuyCnftInstruction(
       isSigner: false,
     })
   }
+  if (accounts.sellerCampaignReward != null) {
+    keys.push({
+      pubkey: accounts.sellerCampaignReward,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
+  if (accounts.buyerCampaignReward != null) {
+    keys.push({
+      pubkey: accounts.buyerCampaignReward,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
   keys.push({
     pubkey: accounts.metadata,
     isWritable: true,
@@ -351,6 +409,16 @@ export function createRegisterBuyCnftInstruction(
     isWritable: true,
     isSigner: false,
   })
+  keys.push({
+    pubkey: accounts.eventAuthority,
+    isWritable: false,
+    isSigner: false,
+  })
+  keys.push({
+    pubkey: accounts.program,
+    isWritable: false,
+    isSigner: false,
+  })
 
   if (accounts.anchorRemainingAccounts != null) {
     for (const acc of accounts.anchorRemainingAccounts) {
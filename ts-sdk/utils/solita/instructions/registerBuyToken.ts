@@ -6,6 +6,7 @@
  */
 
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as web3 from '@solana/web3.js'
 import { BRICK_PROGRAM_ID_PK } from '../../../constants'
 
@@ -16,6 +17,8 @@ import { BRICK_PROGRAM_ID_PK } from '../../../constants'
  */
 export type RegisterBuyTokenInstructionArgs = {
   amount: number
+  maxTotalPrice: beet.bignum
+  expectedPaymentMint: web3.PublicKey
 }
 /**
  * @category Instructions
@@ -30,6 +33,8 @@ export const registerBuyTokenStruct = new beet.BeetArgsStruct<
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['amount', beet.u32],
+    ['maxTotalPrice', beet.u64],
+    ['expectedPaymentMint', beetSolana.publicKey],
   ],
   'RegisterBuyTokenInstructionArgs',
 )
@@ -37,23 +42,30 @@ export const registerBuyTokenStruct = new beet.BeetArgsStruct<
  * Accounts required by the _registerBuyToken_ instruction
  *
  * @property [] tokenProgramV0
+ * @property [] paymentTokenProgram
  * @property [] tokenProgram2022
  * @property [_writable_, **signer**] signer
  * @property [_writable_] seller (optional)
  * @property [_writable_] marketplaceAuth (optional)
  * @property [_writable_] marketplace
  * @property [_writable_] product
+ * @property [_writable_] payment
  * @property [_writable_] productMint
  * @property [] paymentMint
  * @property [_writable_] buyerTokenVault
  * @property [_writable_] buyerTransferVault (optional)
  * @property [_writable_] sellerTransferVault (optional)
  * @property [_writable_] marketplaceTransferVault (optional)
+ * @property [_writable_] campaign (optional)
  * @property [_writable_] bountyVault (optional)
  * @property [_writable_] sellerReward (optional)
  * @property [_writable_] sellerRewardVault (optional)
  * @property [_writable_] buyerReward (optional)
  * @property [_writable_] buyerRewardVault (optional)
+ * @property [_writable_] sellerCampaignReward (optional)
+ * @property [_writable_] buyerCampaignReward (optional)
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category RegisterBuyToken
  * @category generated
@@ -61,23 +73,30 @@ export const registerBuyTokenStruct = new beet.BeetArgsStruct<
 export type RegisterBuyTokenInstructionAccounts = {
   systemProgram?: web3.PublicKey
   tokenProgramV0: web3.PublicKey
+  paymentTokenProgram: web3.PublicKey
   tokenProgram2022: web3.PublicKey
   signer: web3.PublicKey
   seller?: web3.PublicKey | null
   marketplaceAuth?: web3.PublicKey | null
   marketplace: web3.PublicKey
   product: web3.PublicKey
+  payment: web3.PublicKey
   productMint: web3.PublicKey
   paymentMint: web3.PublicKey
   buyerTokenVault: web3.PublicKey
   buyerTransferVault?: web3.PublicKey | null
   sellerTransferVault?: web3.PublicKey | null
   marketplaceTransferVault?: web3.PublicKey | null
+  campaign?: web3.PublicKey | null
   bountyVault?: web3.PublicKey | null
   sellerReward?: web3.PublicKey | null
   sellerRewardVault?: web3.PublicKey | null
   buyerReward?: web3.PublicKey | null
   buyerRewardVault?: web3.PublicKey | null
+  sellerCampaignReward?: web3.PublicKey | null
+  buyerCampaignReward?: web3.PublicKey | null
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -120,6 +139,11 @@ export function createRegisterBuyTokenInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.paymentTokenProgram,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.tokenProgram2022,
       isWritable: false,
@@ -138,18 +162,25 @@ export function createRegisterBuyTokenInstruction(
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   if (accounts.marketplaceAuth != null) {
-    if (accounts.seller == null) {
-      throw new Error(
-        "When providing 'marketplaceAuth' then 'accounts.seller' need(s) to be provided as well.",
-      )
-    }
     keys.push({
       pubkey: accounts.marketplaceAuth,
       isWritable: true,
       isSigner: false,
     })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
   }
   keys.push({
     pubkey: accounts.marketplace,
@@ -161,6 +192,11 @@ export function createRegisterBuyTokenInstruction(
     isWritable: true,
     isSigner: false,
   })
+  keys.push({
+    pubkey: accounts.payment,
+    isWritable: true,
+    isSigner: false,
+  })
   keys.push({
     pubkey: accounts.productMint,
     isWritable: true,
@@ -215,6 +251,19 @@ export function createRegisterBuyTokenInstruction(
       isSigner: false,
     })
   }
+  if (accounts.campaign != null) {
+    keys.push({
+      pubkey: accounts.campaign,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
   if (accounts.bountyVault != null) {
     keys.push({
       pubkey: accounts.bountyVault,
@@ -280,6 +329,42 @@ export function createRegisterBuyTokenInstruction(
       isSigner: false,
     })
   }
+  if (accounts.sellerCampaignReward != null) {
+    keys.push({
+      pubkey: accounts.sellerCampaignReward,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
+  if (accounts.buyerCampaignReward != null) {
+    keys.push({
+      pubkey: accounts.buyerCampaignReward,
+      isWritable: true,
+      isSigner: false,
+    })
+  } else {
+    keys.push({
+      pubkey: BRICK_PROGRAM_ID_PK,
+      isWritable: false,
+      isSigner: false,
+    })
+  }
+  keys.push({
+    pubkey: accounts.eventAuthority,
+    isWritable: false,
+    isSigner: false,
+  })
+  keys.push({
+    pubkey: accounts.program,
+    isWritable: false,
+    isSigner: false,
+  })
 
   if (accounts.anchorRemainingAccounts != null) {
     for (const acc of accounts.anchorRemainingAccounts) {
@@ -25,6 +25,8 @@ export const requestAccessStruct = new beet.BeetArgsStruct<{
  * @property [_writable_, **signer**] signer
  * @property [_writable_] marketplace
  * @property [_writable_] request
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category RequestAccess
  * @category generated
@@ -35,6 +37,8 @@ export type RequestAccessInstructionAccounts = {
   signer: web3.PublicKey
   marketplace: web3.PublicKey
   request: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -83,6 +87,16 @@ export function createRequestAccessInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -10,7 +10,7 @@ import * as web3 from '@solana/web3.js'
 import {
   UpdateProductTreeParams,
   updateProductTreeParamsBeet,
-} from '../types/UpdateProductTreeParams.js'
+} from '../types/UpdateProductTreeParams'
 
 /**
  * @category Instructions
@@ -48,6 +48,8 @@ export const updateTreeStruct = new beet.BeetArgsStruct<
  * @property [] logWrapper
  * @property [] bubblegumProgram
  * @property [] compressionProgram
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category UpdateTree
  * @category generated
@@ -63,6 +65,8 @@ export type UpdateTreeInstructionAccounts = {
   systemProgram?: web3.PublicKey
   bubblegumProgram: web3.PublicKey
   compressionProgram: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -140,6 +144,16 @@ export function createUpdateTreeInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -26,9 +26,13 @@ export const withdrawRewardStruct = new beet.BeetArgsStruct<{
  * @property [_writable_, **signer**] signer
  * @property [_writable_] marketplace
  * @property [_writable_] reward
+ * @property [] campaign
+ * @property [_writable_] campaignReward
  * @property [] rewardMint
  * @property [_writable_] receiverVault
  * @property [_writable_] rewardVault
+ * @property [] eventAuthority
+ * @property [] program
  * @category Instructions
  * @category WithdrawReward
  * @category generated
@@ -38,9 +42,13 @@ export type WithdrawRewardInstructionAccounts = {
   signer: web3.PublicKey
   marketplace: web3.PublicKey
   reward: web3.PublicKey
+  campaign: web3.PublicKey
+  campaignReward: web3.PublicKey
   rewardMint: web3.PublicKey
   receiverVault: web3.PublicKey
   rewardVault: web3.PublicKey
+  eventAuthority: web3.PublicKey
+  program: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -84,6 +92,16 @@ export function createWithdrawRewardInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.campaign,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.campaignReward,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.rewardMint,
       isWritable: false,
@@ -99,6 +117,16 @@ export function createWithdrawRewardInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.eventAuthority,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.program,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -5,20 +5,21 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { PaymentFeePayer, paymentFeePayerBeet } from './PaymentFeePayer'
 export type EditMarketplaceParams = {
   fee: number
   feeReduction: number
-  sellerReward: number
-  buyerReward: number
   useCnfts: boolean
   deliverToken: boolean
   transferable: boolean
   chainCounter: boolean
   permissionless: boolean
-  rewardsEnabled: boolean
   feePayer: PaymentFeePayer
+  vestingPeriod: beet.bignum
+  arbiter: web3.PublicKey
 }
 
 /**
@@ -30,15 +31,14 @@ export const editMarketplaceParamsBeet =
     [
       ['fee', beet.u16],
       ['feeReduction', beet.u16],
-      ['sellerReward', beet.u16],
-      ['buyerReward', beet.u16],
       ['useCnfts', beet.bool],
       ['deliverToken', beet.bool],
       ['transferable', beet.bool],
       ['chainCounter', beet.bool],
       ['permissionless', beet.bool],
-      ['rewardsEnabled', beet.bool],
       ['feePayer', paymentFeePayerBeet],
+      ['vestingPeriod', beet.i64],
+      ['arbiter', beetSolana.publicKey],
     ],
     'EditMarketplaceParams',
   )
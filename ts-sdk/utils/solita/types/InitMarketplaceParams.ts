@@ -5,21 +5,23 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { PaymentFeePayer, paymentFeePayerBeet } from './PaymentFeePayer'
 export type InitMarketplaceParams = {
+  id: beet.bignum
   fee: number
   feeReduction: number
-  sellerReward: number
-  buyerReward: number
   useCnfts: boolean
   deliverToken: boolean
   transferable: boolean
   chainCounter: boolean
   permissionless: boolean
-  rewardsEnabled: boolean
   accessMintBump: number
   feePayer: PaymentFeePayer
+  vestingPeriod: beet.bignum
+  arbiter: web3.PublicKey
 }
 
 /**
@@ -29,18 +31,18 @@ export type InitMarketplaceParams = {
 export const initMarketplaceParamsBeet =
   new beet.BeetArgsStruct<InitMarketplaceParams>(
     [
+      ['id', beet.u64],
       ['fee', beet.u16],
       ['feeReduction', beet.u16],
-      ['sellerReward', beet.u16],
-      ['buyerReward', beet.u16],
       ['useCnfts', beet.bool],
       ['deliverToken', beet.bool],
       ['transferable', beet.bool],
       ['chainCounter', beet.bool],
       ['permissionless', beet.bool],
-      ['rewardsEnabled', beet.bool],
       ['accessMintBump', beet.u8],
       ['feePayer', paymentFeePayerBeet],
+      ['vestingPeriod', beet.i64],
+      ['arbiter', beetSolana.publicKey],
     ],
     'InitMarketplaceParams',
   )
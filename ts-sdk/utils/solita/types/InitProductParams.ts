@@ -11,18 +11,31 @@ export type InitProductParams = {
   secondId: number[] /* size: 32 */
   productPrice: beet.bignum
   productMintBump: number
+  maxSupply: beet.COption<beet.bignum>
+  maxUnitsPerWallet: beet.COption<number>
+  saleStart: beet.COption<beet.bignum>
+  saleEnd: beet.COption<beet.bignum>
+  escrowTimeout: beet.COption<beet.bignum>
+  subscriptionPeriod: beet.COption<beet.bignum>
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const initProductParamsBeet = new beet.BeetArgsStruct<InitProductParams>(
-  [
-    ['firstId', beet.uniformFixedSizeArray(beet.u8, 32)],
-    ['secondId', beet.uniformFixedSizeArray(beet.u8, 32)],
-    ['productPrice', beet.u64],
-    ['productMintBump', beet.u8],
-  ],
-  'InitProductParams',
-)
+export const initProductParamsBeet =
+  new beet.FixableBeetArgsStruct<InitProductParams>(
+    [
+      ['firstId', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ['secondId', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ['productPrice', beet.u64],
+      ['productMintBump', beet.u8],
+      ['maxSupply', beet.coption(beet.u64)],
+      ['maxUnitsPerWallet', beet.coption(beet.u32)],
+      ['saleStart', beet.coption(beet.i64)],
+      ['saleEnd', beet.coption(beet.i64)],
+      ['escrowTimeout', beet.coption(beet.i64)],
+      ['subscriptionPeriod', beet.coption(beet.i64)],
+    ],
+    'InitProductParams',
+  )
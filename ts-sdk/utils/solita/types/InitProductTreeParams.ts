@@ -15,6 +15,12 @@ export type InitProductTreeParams = {
   name: string
   metadataUrl: string
   feeBasisPoints: number
+  maxSupply: beet.COption<beet.bignum>
+  maxUnitsPerWallet: beet.COption<number>
+  saleStart: beet.COption<beet.bignum>
+  saleEnd: beet.COption<beet.bignum>
+  escrowTimeout: beet.COption<beet.bignum>
+  subscriptionPeriod: beet.COption<beet.bignum>
 }
 
 /**
@@ -32,6 +38,12 @@ export const initProductTreeParamsBeet =
       ['name', beet.utf8String],
       ['metadataUrl', beet.utf8String],
       ['feeBasisPoints', beet.u16],
+      ['maxSupply', beet.coption(beet.u64)],
+      ['maxUnitsPerWallet', beet.coption(beet.u32)],
+      ['saleStart', beet.coption(beet.i64)],
+      ['saleEnd', beet.coption(beet.i64)],
+      ['escrowTimeout', beet.coption(beet.i64)],
+      ['subscriptionPeriod', beet.coption(beet.i64)],
     ],
     'InitProductTreeParams',
   )
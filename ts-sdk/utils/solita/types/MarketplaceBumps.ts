@@ -8,7 +8,6 @@
 import * as beet from '@metaplex-foundation/beet'
 export type MarketplaceBumps = {
   bump: number
-  vaultBumps: Uint8Array
   accessMintBump: number
 }
 
@@ -16,12 +15,10 @@ export type MarketplaceBumps = {
  * @category userTypes
  * @category generated
  */
-export const marketplaceBumpsBeet =
-  new beet.FixableBeetArgsStruct<MarketplaceBumps>(
-    [
-      ['bump', beet.u8],
-      ['vaultBumps', beet.bytes],
-      ['accessMintBump', beet.u8],
-    ],
-    'MarketplaceBumps',
-  )
+export const marketplaceBumpsBeet = new beet.BeetArgsStruct<MarketplaceBumps>(
+  [
+    ['bump', beet.u8],
+    ['accessMintBump', beet.u8],
+  ],
+  'MarketplaceBumps',
+)
@@ -5,9 +5,13 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 export type RegisterBuyCnftParams = {
   amount: number
+  maxTotalPrice: beet.bignum
+  expectedPaymentMint: web3.PublicKey
   name: string
   symbol: string
   uri: string
@@ -21,6 +25,8 @@ export const registerBuyCnftParamsBeet =
   new beet.FixableBeetArgsStruct<RegisterBuyCnftParams>(
     [
       ['amount', beet.u32],
+      ['maxTotalPrice', beet.u64],
+      ['expectedPaymentMint', beetSolana.publicKey],
       ['name', beet.utf8String],
       ['symbol', beet.utf8String],
       ['uri', beet.utf8String],
@@ -8,17 +8,15 @@
 import * as beet from '@metaplex-foundation/beet'
 export type RewardBumps = {
   bump: number
-  vaultBumps: Uint8Array
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const rewardBumpsBeet = new beet.FixableBeetArgsStruct<RewardBumps>(
+export const rewardBumpsBeet = new beet.BeetArgsStruct<RewardBumps>(
   [
     ['bump', beet.u8],
-    ['vaultBumps', beet.bytes],
   ],
   'RewardBumps',
 )
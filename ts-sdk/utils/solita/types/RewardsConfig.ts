@@ -10,23 +10,15 @@ import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
 export type RewardsConfig = {
   rewardMint: web3.PublicKey
-  bountyVaults: web3.PublicKey[]
-  sellerReward: number
-  buyerReward: number
-  rewardsEnabled: boolean
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const rewardsConfigBeet = new beet.FixableBeetArgsStruct<RewardsConfig>(
+export const rewardsConfigBeet = new beet.BeetArgsStruct<RewardsConfig>(
   [
     ['rewardMint', beetSolana.publicKey],
-    ['bountyVaults', beet.array(beetSolana.publicKey)],
-    ['sellerReward', beet.u16],
-    ['buyerReward', beet.u16],
-    ['rewardsEnabled', beet.bool],
   ],
   'RewardsConfig',
 )
@@ -1,4 +1,5 @@
 export * from './EditMarketplaceParams.js'
+export * from './EditProductParams.js'
 export * from './FeesConfig.js'
 export * from './InitMarketplaceParams.js'
 export * from './InitProductParams.js'
@@ -15,6 +16,7 @@ export * from './TokenConfig.js'
 export * from './UpdateProductTreeParams.js'
 
 import { EditMarketplaceParams } from './EditMarketplaceParams.js'
+import { EditProductParams } from './EditProductParams.js'
 import { FeesConfig } from './FeesConfig.js'
 import { InitMarketplaceParams } from './InitMarketplaceParams.js'
 import { InitProductParams } from './InitProductParams.js'
@@ -33,6 +35,7 @@ import { UpdateProductTreeParams } from './UpdateProductTreeParams.js'
 export type ParsedTypes =
   | PaymentFeePayer
   | EditMarketplaceParams
+  | EditProductParams
   | InitMarketplaceParams
   | InitProductTreeParams
   | InitProductParams